@@ -82,6 +82,8 @@ export interface CaptureResponse {
   };
 }
 
+export type IntegrityStatus = 'unchecked' | 'verified' | 'mismatch' | 'missing';
+
 export interface CapturePublicData {
   capture_id: string;
   confidence_level: string;
@@ -89,8 +91,17 @@ export interface CapturePublicData {
   uploaded_at: string;
   location_coarse?: string;
   evidence: EvidencePackage;
+  /** Short-lived presigned URL; refetch the capture after photo_url_expires_at */
   photo_url?: string;
-  depth_map_url?: string;
+  photo_url_expires_at?: string;
+  /** Server-generated renderings (appear once background processing finishes) */
+  thumbnail_url?: string;
+  thumbnail_webp_url?: string;
+  poster_url?: string;
+  depth_heatmap_url?: string;
+  derivatives_expires_at?: string;
+  /** Last re-hash of stored media against the recorded hash */
+  integrity_status?: IntegrityStatus;
 }
 
 export interface CapturePublicResponse {
@@ -101,7 +112,7 @@ export interface CapturePublicResponse {
   };
 }
 
-export type VerificationStatus = 'verified' | 'c2pa_only' | 'no_record';
+export type VerificationStatus = 'verified' | 'c2pa_only' | 'no_record' | 'deleted';
 
 export interface C2paManifestInfo {
   claim_generator: string;
@@ -147,6 +158,11 @@ export interface FileVerificationResponse {
     };
     captured_at?: string;
     media_type?: 'photo' | 'video';
+    integrity_status?: IntegrityStatus;
+    // Set when status is 'deleted': the capture existed but was removed
+    deleted_at?: string;
+    deleted_by?: 'owner' | 'retention';
+    evidence_digest?: string;
   };
   meta: {
     request_id: string;
@@ -0,0 +1,10 @@
+-- Migration: Add hash chain genesis to devices table
+-- Purpose: Bind v2 video hash chains to the device registration challenge
+-- so the backend can recompute H_n = SHA256(H_{n-1} || d_n) from H_0
+
+-- H_0 = SHA256("realitycam.hash_chain.v2.genesis" || registration_challenge)
+-- NULL for unverified devices and devices registered before this migration
+ALTER TABLE devices
+ADD COLUMN IF NOT EXISTS hash_chain_genesis BYTEA;
+
+COMMENT ON COLUMN devices.hash_chain_genesis IS 'Genesis hash (32 bytes) for v2 video hash chains, derived from the registration challenge. NULL for unverified or legacy devices.';
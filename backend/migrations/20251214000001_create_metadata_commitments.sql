@@ -0,0 +1,23 @@
+-- Migration: Create metadata_commitments table
+-- Purpose: Salted hash commitments for hash-only capture metadata. Privacy
+-- mode can withhold a field while committing to it; the owning device may
+-- later disclose the value and salt, which the backend checks against the
+-- stored commitment.
+
+-- commitment = SHA256("realitycam.commitment.v1" || 0x00 || field || 0x00 || u32_be(length(salt)) || salt || value)
+CREATE TABLE metadata_commitments (
+    capture_id      UUID NOT NULL REFERENCES captures(id) ON DELETE CASCADE,
+    field           TEXT NOT NULL CHECK (field IN ('location', 'timestamp', 'device_model')),
+    commitment      BYTEA NOT NULL,
+    disclosed_value TEXT,
+    disclosed_salt  BYTEA,
+    disclosed_at    TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (capture_id, field)
+);
+
+COMMENT ON TABLE metadata_commitments IS 'Per-field salted hash commitments for hash-only capture metadata';
+COMMENT ON COLUMN metadata_commitments.field IS 'Committed metadata field: location, timestamp, or device_model';
+COMMENT ON COLUMN metadata_commitments.commitment IS 'SHA-256 commitment (32 bytes) bound by the capture assertion';
+COMMENT ON COLUMN metadata_commitments.disclosed_value IS 'Canonical field value once disclosed by the owning device. NULL while undisclosed.';
+COMMENT ON COLUMN metadata_commitments.disclosed_salt IS 'Salt revealed with the disclosure. NULL while undisclosed.';
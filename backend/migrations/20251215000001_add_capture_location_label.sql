@@ -0,0 +1,8 @@
+-- Migration: Add reverse-geocoded location label to captures
+-- Purpose: Human-readable place name ("San Francisco, CA, US") for the
+-- published coarse location, resolved offline from the bundled gazetteer
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS location_label TEXT;
+
+COMMENT ON COLUMN captures.location_label IS 'Nearest gazetteer place for location_coarse (e.g. "San Francisco, CA, US"). NULL when no location or no nearby place.';
@@ -0,0 +1,23 @@
+-- Migration: Create device_capabilities table
+-- Purpose: Registry of known device models and their depth hardware. Replaces
+-- the hard-coded iPhone Pro whitelist. Seeded (upserted) at startup from
+-- data/device_capabilities.tsv; rows may also be added directly for new
+-- hardware and are picked up on the next restart.
+
+CREATE TABLE device_capabilities (
+    platform          TEXT NOT NULL CHECK (platform IN ('ios', 'android')),
+    identifier        TEXT NOT NULL,
+    brand             TEXT NOT NULL,
+    marketing_name    TEXT NOT NULL,
+    has_lidar         BOOLEAN NOT NULL,
+    depth_sensor      TEXT NOT NULL CHECK (depth_sensor IN ('lidar', 'tof', 'dual_camera', 'arcore', 'none')),
+    depth_resolutions TEXT[] NOT NULL DEFAULT '{}',
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (platform, identifier)
+);
+
+COMMENT ON TABLE device_capabilities IS 'Known device models with LiDAR presence, depth sensor type and expected depth resolutions';
+COMMENT ON COLUMN device_capabilities.identifier IS 'iOS hardware identifier (e.g. iPhone16,1) or Android Build.MODEL. A trailing * matches regional variants.';
+COMMENT ON COLUMN device_capabilities.depth_sensor IS 'Depth source: lidar, tof, dual_camera, arcore (software), or none';
+COMMENT ON COLUMN device_capabilities.depth_resolutions IS 'Expected depth map resolutions as WxH strings';
@@ -0,0 +1,15 @@
+-- Migration: Add attestation-derived identity to devices
+-- Purpose: Android Key Attestation with ID attestation carries the device's
+-- brand, model and device codename in the TEE-enforced authorization list.
+-- These are stored separately from the client-claimed model so captures can be
+-- cross-checked against a hardware-backed identity. iOS App Attest does not
+-- carry model identifiers, so these stay NULL for iOS devices.
+
+ALTER TABLE devices
+ADD COLUMN IF NOT EXISTS attested_brand TEXT,
+ADD COLUMN IF NOT EXISTS attested_model TEXT,
+ADD COLUMN IF NOT EXISTS attested_device TEXT;
+
+COMMENT ON COLUMN devices.attested_brand IS 'Brand from TEE-enforced Android ID attestation (attestationIdBrand). NULL when not attested.';
+COMMENT ON COLUMN devices.attested_model IS 'Model from TEE-enforced Android ID attestation (attestationIdModel). NULL when not attested.';
+COMMENT ON COLUMN devices.attested_device IS 'Device codename from TEE-enforced Android ID attestation (attestationIdDevice). NULL when not attested.';
@@ -0,0 +1,13 @@
+-- Migration: Add assertion counter to captures
+-- Purpose: Records the device assertion counter that authenticated each
+-- capture. Counter order is the device's true capture order, so captured_at
+-- values can be checked for monotonicity against earlier and later captures.
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS assertion_counter BIGINT;
+
+-- Per-device history lookups for timestamp consistency checks
+CREATE INDEX IF NOT EXISTS idx_captures_device_counter ON captures(device_id, assertion_counter);
+CREATE INDEX IF NOT EXISTS idx_captures_device_captured_at ON captures(device_id, captured_at);
+
+COMMENT ON COLUMN captures.assertion_counter IS 'Device assertion counter of the capture assertion. NULL when the assertion did not verify or the platform has no counter.';
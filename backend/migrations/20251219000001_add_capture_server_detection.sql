@@ -0,0 +1,9 @@
+-- Migration: Add server-side detection results to captures
+-- Purpose: Stores the backend's independent moire/halftone analysis of the
+-- uploaded photo next to the client-reported detection_results, so the two
+-- can be compared. NULL for hash-only captures (no photo on the server).
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS server_detection_results JSONB;
+
+COMMENT ON COLUMN captures.server_detection_results IS 'Server-computed detection results (moire analysis, halftone flag, agreement with the client result)';
@@ -0,0 +1,130 @@
+-- Migration: Upgrade stored evidence to the unified schema (version 2)
+-- Purpose: Photo, video and hash-only captures now share one versioned
+-- EvidencePackage (see schemas/evidence.schema.json). Evidence written before
+-- this migration has no schema_version and comes in three shapes:
+--   1. Video uploads awaiting verification: a hand-written placeholder with
+--      "pending" statuses and ad-hoc hash_chain/metadata objects
+--   2. Photo and hash-only evidence: already an EvidencePackage, missing the
+--      schema_version/capture_type/capture_mode fields
+--   3. Hash-only video evidence: as (2) plus hash_chain, missing the video section
+-- Hash-only videos were stored with the default capture_type 'photo', so video
+-- evidence is recognised by its hash_chain object.
+
+-- 1. Rebuild pending video placeholders in the v2 shape
+UPDATE captures c
+SET evidence = jsonb_build_object(
+    'schema_version', 2,
+    'platform', 'ios',
+    'capture_type', 'video',
+    'capture_mode', c.capture_mode,
+    'hardware_attestation', jsonb_build_object(
+        'status', 'pending',
+        'level', 'secure_enclave',
+        'device_model', d.model,
+        'assertion_verified', false,
+        'counter_valid', false
+    ),
+    'depth_analysis', jsonb_build_object(
+        'status', 'pending',
+        'depth_variance', 0,
+        'depth_layers', 0,
+        'edge_coherence', 0,
+        'min_depth', 0,
+        'max_depth', 0,
+        'is_likely_real_scene', false,
+        'method', 'lidar'
+    ),
+    'metadata', jsonb_build_object(
+        'timestamp_valid', false,
+        'timestamp_delta_seconds', 0,
+        'model_verified', false,
+        'model_name', d.model,
+        'resolution_valid', false,
+        'location_available', false,
+        'location_opted_out', false
+    ),
+    'processing', jsonb_build_object(
+        'processed_at', to_char(c.uploaded_at AT TIME ZONE 'UTC', 'YYYY-MM-DD"T"HH24:MI:SS"Z"'),
+        'processing_time_ms', 0,
+        'backend_version', 'unknown'
+    ),
+    'video', jsonb_build_object(
+        'duration_ms', COALESCE(c.duration_ms, 0),
+        'frame_count', COALESCE(c.frame_count, 0),
+        'partial_attestation', jsonb_build_object(
+            'is_partial', COALESCE(c.is_partial, false),
+            'verified_frames', 0,
+            'total_frames', COALESCE(c.frame_count, 0)
+        )
+    )
+)
+FROM devices d
+WHERE d.id = c.device_id
+  AND c.capture_type = 'video'
+  AND NOT (c.evidence ? 'schema_version')
+  AND c.evidence->'hardware_attestation'->>'status' = 'pending';
+
+-- 2. Normalise security level strings to the typed enums (unknown values were
+--    previously passed through verbatim)
+UPDATE captures
+SET evidence = jsonb_set(
+    evidence,
+    '{hardware_attestation,security_level}',
+    jsonb_build_object(
+        'attestation_level',
+        CASE
+            WHEN evidence->'hardware_attestation'->'security_level'->>'attestation_level'
+                IN ('secure_enclave', 'strongbox', 'tee')
+            THEN evidence->'hardware_attestation'->'security_level'->>'attestation_level'
+            ELSE 'unverified'
+        END,
+        'platform',
+        CASE
+            WHEN lower(evidence->'hardware_attestation'->'security_level'->>'platform') = 'android'
+            THEN 'android'
+            ELSE 'ios'
+        END
+    ) || CASE
+        WHEN evidence->'hardware_attestation'->'security_level'->>'keymaster_level'
+            IN ('software', 'tee', 'strongbox')
+        THEN jsonb_build_object(
+            'keymaster_level',
+            evidence->'hardware_attestation'->'security_level'->>'keymaster_level'
+        )
+        ELSE '{}'::jsonb
+    END
+)
+WHERE NOT (evidence ? 'schema_version')
+  AND jsonb_typeof(evidence->'hardware_attestation'->'security_level') = 'object';
+
+-- 3. Add the video section to hash-only video evidence
+UPDATE captures
+SET evidence = evidence || jsonb_build_object(
+    'video', jsonb_build_object(
+        'duration_ms', COALESCE(duration_ms, (evidence->'hash_chain'->>'verified_duration_ms')::BIGINT, 0),
+        'frame_count', COALESCE(frame_count, (evidence->'hash_chain'->>'total_frames')::INTEGER, 0),
+        'partial_attestation', jsonb_build_object(
+            'is_partial',
+            COALESCE(is_partial, false) OR evidence->'hash_chain'->>'status' = 'partial',
+            'verified_frames', COALESCE((evidence->'hash_chain'->>'verified_frames')::INTEGER, 0),
+            'total_frames', COALESCE(frame_count, (evidence->'hash_chain'->>'total_frames')::INTEGER, 0)
+        )
+    )
+)
+WHERE NOT (evidence ? 'schema_version')
+  AND evidence ? 'hash_chain'
+  AND NOT (evidence ? 'video');
+
+-- 4. Stamp the remaining evidence with the v2 top-level fields
+UPDATE captures
+SET evidence = evidence || jsonb_build_object(
+    'schema_version', 2,
+    'platform', COALESCE(evidence->>'platform', 'ios'),
+    'capture_type',
+    CASE WHEN capture_type = 'video' OR evidence ? 'hash_chain' THEN 'video' ELSE 'photo' END,
+    'capture_mode', capture_mode
+)
+WHERE NOT (evidence ? 'schema_version')
+  AND evidence ? 'hardware_attestation';
+
+COMMENT ON COLUMN captures.evidence IS 'Evidence package (unified schema, see evidence.schema_version and GET /api/v1/evidence/schema)';
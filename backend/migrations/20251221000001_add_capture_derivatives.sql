@@ -0,0 +1,15 @@
+-- Migration: Add server-generated media derivatives to captures
+-- Purpose: Thumbnails, video poster frames and depth heatmaps are rendered in
+-- the background after upload. thumbnail_s3_key (JPEG) already exists; this
+-- adds the WebP thumbnail, the full-size video poster frame and the
+-- false-colour depth heatmap shown on the verification page.
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS thumbnail_webp_s3_key TEXT,
+ADD COLUMN IF NOT EXISTS poster_s3_key TEXT,
+ADD COLUMN IF NOT EXISTS depth_heatmap_s3_key TEXT;
+
+COMMENT ON COLUMN captures.thumbnail_s3_key IS 'Storage key of the generated JPEG thumbnail (photo, or video poster frame)';
+COMMENT ON COLUMN captures.thumbnail_webp_s3_key IS 'Storage key of the generated WebP thumbnail';
+COMMENT ON COLUMN captures.poster_s3_key IS 'Storage key of the JPEG poster frame extracted from a video capture';
+COMMENT ON COLUMN captures.depth_heatmap_s3_key IS 'Storage key of the false-colour PNG rendering of the depth map';
@@ -0,0 +1,20 @@
+-- Migration: Track stored media integrity
+-- Purpose: The integrity scrubber periodically re-hashes stored media against
+-- target_media_hash. Results are recorded per capture and shown by verification.
+--   unchecked - not yet scrubbed (or no stored media to check)
+--   verified  - stored bytes hash to target_media_hash
+--   mismatch  - stored bytes differ from target_media_hash (bit-rot or tampering)
+--   missing   - the stored object no longer exists
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS integrity_status TEXT NOT NULL DEFAULT 'unchecked'
+    CHECK (integrity_status IN ('unchecked', 'verified', 'mismatch', 'missing')),
+ADD COLUMN IF NOT EXISTS integrity_checked_at TIMESTAMPTZ;
+
+-- Scrubber picks the least recently checked captures with stored media first
+CREATE INDEX IF NOT EXISTS idx_captures_integrity_checked_at
+    ON captures (integrity_checked_at NULLS FIRST)
+    WHERE media_stored;
+
+COMMENT ON COLUMN captures.integrity_status IS 'Result of the last stored-media integrity check: unchecked, verified, mismatch or missing';
+COMMENT ON COLUMN captures.integrity_checked_at IS 'When the integrity scrubber last re-hashed the stored media';
@@ -0,0 +1,53 @@
+-- Migration: Capture deletion, retention and audit trail
+-- Purpose: Owners can delete captures and operators can enforce retention.
+-- A deleted capture leaves a tombstone (media hash + evidence digest) so that
+-- /verify-file can still report that the file was captured and later deleted.
+
+-- Tombstones of deleted captures (no media, no location, no evidence body)
+CREATE TABLE capture_tombstones (
+    capture_id          UUID PRIMARY KEY,
+    device_id           UUID NOT NULL,
+    target_media_hash   BYTEA NOT NULL,
+    evidence_digest     BYTEA NOT NULL,
+    capture_type        TEXT NOT NULL,
+    capture_mode        TEXT NOT NULL,
+    captured_at         TIMESTAMPTZ NOT NULL,
+    uploaded_at         TIMESTAMPTZ NOT NULL,
+    deleted_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_by          TEXT NOT NULL CHECK (deleted_by IN ('owner', 'retention'))
+);
+
+-- Not unique: the same file may be captured, deleted and captured again
+CREATE INDEX idx_capture_tombstones_hash ON capture_tombstones USING hash(target_media_hash);
+
+COMMENT ON TABLE capture_tombstones IS 'Minimal record of deleted captures for /verify-file';
+COMMENT ON COLUMN capture_tombstones.evidence_digest IS 'SHA-256 of the evidence JSON at deletion time';
+COMMENT ON COLUMN capture_tombstones.deleted_by IS 'Who deleted the capture: owner (device request) or retention (policy sweep)';
+
+-- Audit trail of capture deletions and retention actions
+-- capture_id is not a foreign key: entries outlive the capture they describe
+CREATE TABLE capture_audit_log (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    capture_id  UUID NOT NULL,
+    device_id   UUID,
+    action      TEXT NOT NULL,
+    actor       TEXT NOT NULL,
+    details     JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_capture_audit_log_capture ON capture_audit_log(capture_id);
+CREATE INDEX idx_capture_audit_log_created ON capture_audit_log(created_at);
+
+COMMENT ON TABLE capture_audit_log IS 'Audit log of capture deletions and retention actions';
+COMMENT ON COLUMN capture_audit_log.action IS 'Action: deleted, location_cleared';
+COMMENT ON COLUMN capture_audit_log.actor IS 'Who acted: owner (device request) or retention (policy sweep)';
+
+-- Verification history survives capture deletion, detached from the capture
+ALTER TABLE verification_logs
+DROP CONSTRAINT IF EXISTS verification_logs_capture_id_fkey,
+ADD CONSTRAINT verification_logs_capture_id_fkey
+    FOREIGN KEY (capture_id) REFERENCES captures(id) ON DELETE SET NULL;
+
+-- Retention sweeps select captures by mode and age
+CREATE INDEX IF NOT EXISTS idx_captures_mode_uploaded ON captures (capture_mode, uploaded_at);
@@ -0,0 +1,16 @@
+-- Migration: Envelope encryption at rest
+-- Purpose: Captures written with MASTER_KEY_FILE set have their precise
+-- location, depth map and original media sealed with a per-capture data key.
+-- The wrapped data key travels inside each sealed blob; data_key_id records
+-- which master key wrapped it so `rotate-keys` can find captures still on an
+-- old key. NULL means the capture was stored unencrypted.
+
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS data_key_id TEXT;
+
+-- Rotation scans captures not yet on the active master key
+CREATE INDEX IF NOT EXISTS idx_captures_data_key_id
+    ON captures (data_key_id)
+    WHERE data_key_id IS NOT NULL;
+
+COMMENT ON COLUMN captures.data_key_id IS 'Master key ID wrapping the capture data key; NULL if stored unencrypted';
@@ -0,0 +1,23 @@
+-- Migration: Record verification activity
+-- Purpose: verification_logs is now written for every /verify-file,
+-- /verify/{id}, capture upload and device registration. Each row records
+-- what happened (outcome) and, for uploads and registrations, the device.
+-- Client IPs are reduced per VERIFICATION_LOG_IP_MODE before storage:
+--   truncate - client_ip holds the /24 (IPv4) or /48 (IPv6) network
+--   hash     - client_ip is NULL; client_ip_hash holds a salted SHA-256
+--   none     - neither is stored
+
+ALTER TABLE verification_logs
+ADD COLUMN IF NOT EXISTS outcome TEXT NOT NULL DEFAULT 'unknown',
+ADD COLUMN IF NOT EXISTS device_id UUID,
+ADD COLUMN IF NOT EXISTS client_ip_hash TEXT;
+
+-- Owners read a capture's history newest first; operators aggregate by time
+CREATE INDEX IF NOT EXISTS idx_verification_logs_capture_created
+    ON verification_logs (capture_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_verification_logs_created
+    ON verification_logs (created_at);
+
+COMMENT ON COLUMN verification_logs.outcome IS 'Result of the action, e.g. verified, no_record, success, accepted, registered, not_found, rejected';
+COMMENT ON COLUMN verification_logs.device_id IS 'Device that uploaded or registered (no FK: logs outlive devices)';
+COMMENT ON COLUMN verification_logs.client_ip_hash IS 'Salted SHA-256 of the client IP when VERIFICATION_LOG_IP_MODE=hash';
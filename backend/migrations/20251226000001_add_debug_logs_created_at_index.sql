@@ -0,0 +1,6 @@
+-- Migration: Index debug_logs by insert time
+-- Purpose: The TTL cleanup task (DEBUG_LOGS_TTL_DAYS) and the live tail
+-- (GET /debug/logs/stream) both select on created_at, the server insert
+-- time, rather than the client-supplied timestamp.
+
+CREATE INDEX IF NOT EXISTS idx_debug_logs_created_at ON debug_logs (created_at);
@@ -0,0 +1,29 @@
+-- Migration: Full-text and payload querying for debug logs
+-- Purpose: GET /debug/logs gains full-text search (q) over event and payload,
+-- JSONB payload containment, device/session filters and keyset pagination
+-- on (timestamp, id).
+
+-- Words of the event name plus payload keys and string/number values.
+-- The 'simple' configuration keeps identifiers and codes as written
+-- (no stemming or stop words).
+ALTER TABLE debug_logs
+ADD COLUMN IF NOT EXISTS search_vector TSVECTOR GENERATED ALWAYS AS (
+    to_tsvector('simple', event)
+    || jsonb_to_tsvector('simple', payload, '["string", "numeric", "key"]')
+) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_debug_logs_search ON debug_logs USING GIN (search_vector);
+
+-- Payload containment (payload @> '{"status":"400"}')
+CREATE INDEX IF NOT EXISTS idx_debug_logs_payload ON debug_logs USING GIN (payload jsonb_path_ops);
+
+-- Keyset pagination in either direction
+CREATE INDEX IF NOT EXISTS idx_debug_logs_timestamp_id ON debug_logs (timestamp, id);
+
+-- Device and session timelines
+CREATE INDEX IF NOT EXISTS idx_debug_logs_device ON debug_logs (device_id, timestamp)
+    WHERE device_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_debug_logs_session ON debug_logs (session_id, timestamp)
+    WHERE session_id IS NOT NULL;
+
+COMMENT ON COLUMN debug_logs.search_vector IS 'Full-text index of event and payload keys/values (simple configuration)';
@@ -0,0 +1,15 @@
+-- Migration: Leases on shared content-addressed media keys
+-- Purpose: An upload leases each media key before it checks for or writes
+-- the object, and deletion only removes a shared object when no capture row
+-- and no unexpired lease references it. Both sides take an advisory lock on
+-- the key, so a deletion cannot remove an object an in-flight upload is
+-- about to reference before its capture row exists.
+
+CREATE TABLE media_key_leases (
+    key         TEXT PRIMARY KEY,
+    expires_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_media_key_leases_expires_at ON media_key_leases (expires_at);
+
+COMMENT ON TABLE media_key_leases IS 'Media keys held by uploads that have not yet inserted their capture row';
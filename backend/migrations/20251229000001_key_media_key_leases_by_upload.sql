@@ -0,0 +1,16 @@
+-- Migration: Key media key leases by the leasing upload
+-- Purpose: Uploads release their leases as soon as their capture row commits.
+-- Concurrent uploads of the same content-addressed key each hold their own
+-- lease row, so one upload finishing does not release another's lease.
+
+-- Leases are short-lived; any outstanding ones belong to uploads that have
+-- already finished or failed
+DELETE FROM media_key_leases;
+
+ALTER TABLE media_key_leases ADD COLUMN capture_id UUID NOT NULL;
+ALTER TABLE media_key_leases DROP CONSTRAINT media_key_leases_pkey;
+ALTER TABLE media_key_leases ADD PRIMARY KEY (key, capture_id);
+
+CREATE INDEX idx_media_key_leases_capture_id ON media_key_leases (capture_id);
+
+COMMENT ON COLUMN media_key_leases.capture_id IS 'Capture being uploaded; its leases are released once the capture row commits';
@@ -39,13 +39,16 @@ pub mod types;
 /// Request ID header name
 const X_REQUEST_ID: &str = "x-request-id";
 
+/// Cross-stack correlation ID header sent by the iOS and web clients
+const X_CORRELATION_ID: &str = "x-correlation-id";
+
 #[tokio::main]
 async fn main() {
     // Load configuration first
     let config = config::Config::load();
 
     // Initialize tracing with format based on config
-    init_tracing(&config.log_format);
+    let debug_log_writer = init_tracing(&config);
 
     tracing::info!("Starting RealityCam API server");
 
@@ -61,6 +64,12 @@ async fn main() {
         .expect("Failed to run database migrations");
     tracing::info!("Database migrations completed");
 
+    // Start writing backend events queued for debug_logs since startup
+    if let Some(writer) = debug_log_writer {
+        let _debug_log_handle = writer.spawn(pool.clone());
+        tracing::info!("Backend debug log writer spawned");
+    }
+
     // Initialize challenge store for attestation verification (AC-1, AC-2)
     let challenge_store = services::ChallengeStore::new();
     tracing::info!("Challenge store initialized");
@@ -69,9 +78,142 @@ async fn main() {
     let _cleanup_handle = services::ChallengeStore::spawn_cleanup_task(challenge_store.clone());
     tracing::info!("Challenge cleanup task spawned");
 
-    // Initialize S3 storage service (shared across all requests)
-    let storage = services::StorageService::new(&config).await;
-    tracing::info!("S3 storage service initialized");
+    // Load master keys for envelope encryption at rest, if configured
+    let envelope = config.master_key_file.as_deref().map(|path| {
+        let keyring = services::LocalKeyring::load(path).expect("Failed to load master key file");
+        let keys = keyring.key_count();
+        let envelope = services::Envelope::new(std::sync::Arc::new(keyring));
+        tracing::info!(
+            keys,
+            active_key_id = envelope.active_key_id(),
+            "Master keys loaded, encrypting capture data at rest"
+        );
+        std::sync::Arc::new(envelope)
+    });
+
+    // Initialize blob storage service (shared across all requests)
+    let mut storage = services::StorageService::new(&config)
+        .await
+        .with_key_leases(pool.clone());
+    if let Some(envelope) = &envelope {
+        storage = storage.with_envelope(envelope.clone());
+    }
+    let storage = std::sync::Arc::new(storage);
+    tracing::info!(
+        backend = storage.backend_name(),
+        "Storage service initialized"
+    );
+
+    // `realitycam-api rotate-keys`: re-wrap data keys under the active master key
+    if std::env::args().nth(1).as_deref() == Some("rotate-keys") {
+        let exit_code = match rotate_keys(pool.clone(), storage, envelope).await {
+            Ok(report) if report.failed == 0 => {
+                tracing::info!(?report, "Key rotation finished");
+                0
+            }
+            Ok(report) => {
+                tracing::error!(?report, "Key rotation left captures on old keys");
+                1
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Key rotation failed");
+                1
+            }
+        };
+        pool.close().await;
+        std::process::exit(exit_code);
+    }
+
+    // Periodically re-hash stored media against recorded hashes
+    if config.integrity_scrub_interval_secs > 0 {
+        let _scrub_handle = services::IntegrityScrubber::new(
+            pool.clone(),
+            storage.clone(),
+            config.integrity_scrub_batch_size,
+        )
+        .spawn(std::time::Duration::from_secs(
+            config.integrity_scrub_interval_secs,
+        ));
+        tracing::info!(
+            interval_secs = config.integrity_scrub_interval_secs,
+            "Integrity scrubber task spawned"
+        );
+    }
+
+    // Periodically delete debug logs older than DEBUG_LOGS_TTL_DAYS
+    if config.debug_logs_cleanup_interval_secs > 0 && config.debug_logs_ttl_days > 0 {
+        let _debug_logs_cleanup_handle = services::debug_logs::spawn_cleanup_task(
+            pool.clone(),
+            config.debug_logs_ttl_days,
+            std::time::Duration::from_secs(config.debug_logs_cleanup_interval_secs),
+        );
+        tracing::info!(
+            interval_secs = config.debug_logs_cleanup_interval_secs,
+            ttl_days = config.debug_logs_ttl_days,
+            "Debug log cleanup task spawned"
+        );
+    }
+
+    // Load offline gazetteer for coarse location labels (no network lookups)
+    let gazetteer = services::Gazetteer::load(config.gazetteer_path.as_deref())
+        .expect("Failed to load gazetteer");
+    tracing::info!(
+        places = gazetteer.place_count(),
+        "Gazetteer loaded for reverse geocoding"
+    );
+
+    // Seed the device capability registry and reload it from the database so
+    // rows added directly for new hardware are included
+    services::DeviceRegistry::load(config.device_capabilities_path.as_deref())
+        .expect("Failed to load device capability data")
+        .seed(&pool)
+        .await
+        .expect("Failed to seed device capabilities");
+    let device_registry = services::DeviceRegistry::from_db(&pool)
+        .await
+        .expect("Failed to load device capability registry");
+    tracing::info!(
+        devices = device_registry.device_count(),
+        "Device capability registry loaded"
+    );
+
+    // Load detection scoring weights (per client detection algorithm version)
+    let detection_policy =
+        services::DetectionPolicy::load(config.detection_weights_path.as_deref())
+            .expect("Failed to load detection weights");
+    tracing::info!(
+        versions = detection_policy.version_count(),
+        "Detection scoring policy loaded"
+    );
+
+    // Load confidence policy profiles (selected per request with ?policy=)
+    let confidence_policies =
+        services::ConfidencePolicies::load(config.confidence_policies_path.as_deref())
+            .expect("Failed to load confidence policies");
+    tracing::info!(
+        policies = confidence_policies.policy_count(),
+        "Confidence policy profiles loaded"
+    );
+
+    // Apply retention rules (capture deletion, precise-location clearing)
+    let retention_policies =
+        services::RetentionPolicies::load(config.retention_policies_path.as_deref())
+            .expect("Failed to load retention policies");
+    tracing::info!(
+        rules = retention_policies.rule_count(),
+        "Retention policies loaded"
+    );
+    if config.retention_sweep_interval_secs > 0 {
+        let _retention_handle =
+            services::RetentionSweeper::new(pool.clone(), storage.clone(), retention_policies)
+                .spawn(std::time::Duration::from_secs(
+                    config.retention_sweep_interval_secs,
+                ));
+        tracing::info!(
+            interval_secs = config.retention_sweep_interval_secs,
+            "Retention sweeper task spawned"
+        );
+    }
 
     // Build CORS layer
     let cors = build_cors_layer(&config.cors_origins);
@@ -84,7 +226,12 @@ async fn main() {
         db: pool.clone(),
         challenge_store,
         config: std::sync::Arc::new(config.clone()),
-        storage: std::sync::Arc::new(storage),
+        storage,
+        gazetteer: std::sync::Arc::new(gazetteer),
+        device_registry: std::sync::Arc::new(device_registry),
+        detection_policy: std::sync::Arc::new(detection_policy),
+        confidence_policies: std::sync::Arc::new(confidence_policies),
+        envelope,
     };
 
     // Build the router with middleware stack
@@ -108,11 +255,20 @@ async fn main() {
                             .and_then(|s| Uuid::parse_str(s).ok())
                             .unwrap_or_else(Uuid::new_v4);
 
+                        // Client correlation ID for debug_logs (iOS and web send one)
+                        let correlation_id = request
+                            .headers()
+                            .get(X_CORRELATION_ID)
+                            .and_then(|v| v.to_str().ok())
+                            .and_then(|s| Uuid::parse_str(s).ok())
+                            .unwrap_or(request_id);
+
                         tracing::info_span!(
                             "http_request",
                             method = %request.method(),
                             uri = %request.uri(),
                             request_id = %request_id,
+                            correlation_id = %correlation_id,
                         )
                     })
                     .on_response(
@@ -152,25 +308,53 @@ async fn main() {
     tracing::info!("Server shutdown complete");
 }
 
+/// Runs `rotate-keys`: re-wraps all capture data keys under the active master key
+async fn rotate_keys(
+    pool: sqlx::PgPool,
+    storage: std::sync::Arc<services::StorageService>,
+    envelope: Option<std::sync::Arc<services::Envelope>>,
+) -> anyhow::Result<services::KeyRotationReport> {
+    let envelope =
+        envelope.ok_or_else(|| anyhow::anyhow!("rotate-keys requires MASTER_KEY_FILE"))?;
+    let report = services::KeyRotator::new(pool, storage, envelope, 100)
+        .run()
+        .await?;
+    Ok(report)
+}
+
 /// Initialize tracing subscriber based on format preference.
-fn init_tracing(log_format: &str) {
+///
+/// When debug logs are enabled, selected backend events are also copied into
+/// debug_logs; the returned writer must be spawned once the database is up.
+fn init_tracing(config: &config::Config) -> Option<services::DebugLogWriter> {
     let env_filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new("info,sqlx=warn,tower_http=debug"));
 
-    match log_format {
+    let (debug_log_layer, debug_log_writer) = config
+        .debug_logs_backend_filter
+        .clone()
+        .filter(|_| config.debug_logs_enabled)
+        .map(|filter| services::DebugLogLayer::new(filter, config.debug_logs_max_batch))
+        .unzip();
+
+    match config.log_format.as_str() {
         "json" => {
             tracing_subscriber::registry()
                 .with(env_filter)
                 .with(fmt::layer().json().with_span_events(FmtSpan::CLOSE))
+                .with(debug_log_layer)
                 .init();
         }
         _ => {
             tracing_subscriber::registry()
                 .with(env_filter)
                 .with(fmt::layer().with_span_events(FmtSpan::CLOSE))
+                .with(debug_log_layer)
                 .init();
         }
     }
+
+    debug_log_writer
 }
 
 /// Build CORS layer from configured origins.
@@ -474,6 +474,21 @@ pub async fn update_device_counter(
     Ok(())
 }
 
+/// Looks up the attestation-derived model of a device (Android ID attestation)
+/// Returns None when the device's attestation carried no model identity
+pub async fn lookup_attested_model(
+    db: &PgPool,
+    device_id: Uuid,
+) -> Result<Option<String>, sqlx::Error> {
+    let attested_model: Option<Option<String>> =
+        sqlx::query_scalar("SELECT attested_model FROM devices WHERE id = $1")
+            .bind(device_id)
+            .fetch_optional(db)
+            .await?;
+
+    Ok(attested_model.flatten())
+}
+
 /// Verifies device assertion signature
 /// Returns the new counter value if successful
 fn verify_device_assertion(
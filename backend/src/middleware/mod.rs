@@ -3,7 +3,10 @@
 //! Contains authentication and authorization middleware for protecting routes.
 
 pub mod device_auth;
+pub mod verification_log;
 
 pub use device_auth::{
-    lookup_device, update_device_counter, DeviceAuthConfig, DeviceAuthLayer, DeviceContext,
+    lookup_attested_model, lookup_device, update_device_counter, DeviceAuthConfig, DeviceAuthLayer,
+    DeviceContext,
 };
+pub use verification_log::{log_verification_activity, AuditOutcome};
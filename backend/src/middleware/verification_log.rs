@@ -0,0 +1,215 @@
+//! Verification Activity Logging Middleware
+//!
+//! Records verification lookups, uploads and registrations in
+//! `verification_logs` once the response is ready. The outcome is taken from
+//! an `AuditOutcome` the handler attached to its response, or derived from
+//! the status code when there is none (e.g. errors and auth rejections).
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header::USER_AGENT, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponseParts, Response, ResponseParts},
+};
+use uuid::Uuid;
+
+use crate::services::verification_log::{
+    NewVerificationLog, VerificationAction, VerificationLogger, MAX_USER_AGENT_LEN,
+};
+
+// ============================================================================
+// Handler Outcome
+// ============================================================================
+
+/// Outcome details a handler attaches to its response for the activity log
+///
+/// Return it as a response part, e.g. `(StatusCode::CREATED, audit, Json(body))`.
+#[derive(Debug, Clone, Default)]
+pub struct AuditOutcome {
+    /// Outcome recorded instead of the status-derived one (e.g. "verified")
+    pub outcome: Option<String>,
+    /// Capture concerned, if not in the path
+    pub capture_id: Option<Uuid>,
+    /// Device that made the request
+    pub device_id: Option<Uuid>,
+}
+
+impl AuditOutcome {
+    /// Outcome with the given label
+    pub fn new(outcome: impl Into<String>) -> Self {
+        Self {
+            outcome: Some(outcome.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the capture concerned
+    pub fn capture(mut self, capture_id: Uuid) -> Self {
+        self.capture_id = Some(capture_id);
+        self
+    }
+
+    /// Sets the requesting device
+    pub fn device(mut self, device_id: Uuid) -> Self {
+        self.device_id = Some(device_id);
+        self
+    }
+}
+
+impl IntoResponseParts for AuditOutcome {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        res.extensions_mut().insert(self);
+        Ok(res)
+    }
+}
+
+// ============================================================================
+// Classification
+// ============================================================================
+
+/// Which logged action a request is, with the capture ID from its path
+pub fn classify_request(method: &Method, path: &str) -> Option<(VerificationAction, Option<Uuid>)> {
+    let path = path.strip_prefix("/api/v1")?.trim_end_matches('/');
+
+    match (method, path) {
+        (&Method::POST, "/verify-file") => Some((VerificationAction::VerifyFile, None)),
+        (&Method::POST, "/captures" | "/captures/video" | "/captures/hash-only") => {
+            Some((VerificationAction::Upload, None))
+        }
+        (&Method::POST, "/devices/register") => Some((VerificationAction::Register, None)),
+        (&Method::GET, _) => {
+            // /verify/{id} only; media and other sub-resources are not checks
+            let id = path.strip_prefix("/verify/")?;
+            if id.contains('/') {
+                return None;
+            }
+            Some((VerificationAction::Verify, Uuid::parse_str(id).ok()))
+        }
+        _ => None,
+    }
+}
+
+/// Outcome recorded when the handler did not attach one
+pub fn outcome_for_status(status: StatusCode) -> &'static str {
+    match status {
+        s if s.is_success() => "success",
+        StatusCode::NOT_FOUND => "not_found",
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => "unauthorized",
+        StatusCode::TOO_MANY_REQUESTS => "rate_limited",
+        s if s.is_client_error() => "rejected",
+        _ => "error",
+    }
+}
+
+// ============================================================================
+// Middleware
+// ============================================================================
+
+/// Records logged actions after the inner service has responded
+pub async fn log_verification_activity(
+    State(logger): State<Arc<VerificationLogger>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some((action, path_capture_id)) = classify_request(request.method(), request.uri().path())
+    else {
+        return next.run(request).await;
+    };
+
+    let client_ip = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let user_agent = request
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect::<String>());
+
+    let response = next.run(request).await;
+
+    let audit = response
+        .extensions()
+        .get::<AuditOutcome>()
+        .cloned()
+        .unwrap_or_default();
+    logger.record(NewVerificationLog {
+        action,
+        outcome: audit
+            .outcome
+            .unwrap_or_else(|| outcome_for_status(response.status()).to_string()),
+        capture_id: audit.capture_id.or(path_capture_id),
+        device_id: audit.device_id,
+        client_ip,
+        user_agent,
+    });
+
+    response
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_request() {
+        let id = Uuid::new_v4();
+
+        assert_eq!(
+            classify_request(&Method::POST, "/api/v1/verify-file"),
+            Some((VerificationAction::VerifyFile, None))
+        );
+        assert_eq!(
+            classify_request(&Method::GET, &format!("/api/v1/verify/{id}")),
+            Some((VerificationAction::Verify, Some(id)))
+        );
+        assert_eq!(
+            classify_request(&Method::GET, "/api/v1/verify/not-a-uuid"),
+            Some((VerificationAction::Verify, None))
+        );
+        assert_eq!(
+            classify_request(&Method::POST, "/api/v1/captures/hash-only/"),
+            Some((VerificationAction::Upload, None))
+        );
+        assert_eq!(
+            classify_request(&Method::POST, "/api/v1/devices/register"),
+            Some((VerificationAction::Register, None))
+        );
+
+        assert_eq!(
+            classify_request(&Method::GET, &format!("/api/v1/verify/{id}/media")),
+            None
+        );
+        assert_eq!(
+            classify_request(&Method::GET, &format!("/api/v1/captures/{id}")),
+            None
+        );
+        assert_eq!(classify_request(&Method::GET, "/health"), None);
+    }
+
+    #[test]
+    fn test_outcome_for_status() {
+        assert_eq!(outcome_for_status(StatusCode::CREATED), "success");
+        assert_eq!(outcome_for_status(StatusCode::NOT_FOUND), "not_found");
+        assert_eq!(outcome_for_status(StatusCode::UNAUTHORIZED), "unauthorized");
+        assert_eq!(
+            outcome_for_status(StatusCode::TOO_MANY_REQUESTS),
+            "rate_limited"
+        );
+        assert_eq!(outcome_for_status(StatusCode::BAD_REQUEST), "rejected");
+        assert_eq!(
+            outcome_for_status(StatusCode::INTERNAL_SERVER_ERROR),
+            "error"
+        );
+    }
+}
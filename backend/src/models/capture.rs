@@ -89,6 +89,9 @@ pub struct Capture {
     /// Coarse location (city/region) for privacy (optional)
     pub location_coarse: Option<String>,
 
+    /// Reverse-geocoded place label for the coarse location (optional)
+    pub location_label: Option<String>,
+
     /// When the photo was originally captured
     pub captured_at: DateTime<Utc>,
 
@@ -141,6 +144,10 @@ pub struct Capture {
     /// Multi-signal detection results from iOS (JSONB)
     /// Contains moire, texture, artifacts, aggregated_confidence, cross_validation
     pub detection_results: Option<serde_json::Value>,
+
+    /// Server-side detection results computed from the uploaded photo (JSONB)
+    #[sqlx(default)]
+    pub server_detection_results: Option<serde_json::Value>,
 }
 
 /// Parameters for creating a new capture record
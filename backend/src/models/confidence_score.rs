@@ -0,0 +1,296 @@
+//! Confidence scoring engine
+//!
+//! Photos, videos and hash-only captures all reduce their evidence to a
+//! `ConfidenceScore`: a 0-100 score, the confidence bucket, and the factors
+//! that produced them, so it is visible which check decided the outcome.
+//!
+//! ## Scoring
+//! - Each check contributes signed points as a `ScoreFactor`, displayed as
+//!   e.g. `+50 hardware: attestation verified` or `-40 depth: screen-like plane`
+//! - The score is the sum of points, clamped to 0-100
+//! - Buckets: `HIGH_MIN_SCORE` and above is High, `MEDIUM_MIN_SCORE` and above
+//!   is Medium, anything lower is Low
+//! - A failed check vetoes: the bucket is Suspicious and the score is at most
+//!   `SUSPICIOUS_MAX_SCORE`
+//! - A cap limits the bucket (and the score to the top of that bucket), e.g.
+//!   device-computed depth of limited trust caps at Medium
+//!
+//! Factors are ordered by impact: vetoes, then caps, then by points.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::ConfidenceLevel;
+
+/// Lowest score in the High bucket
+pub const HIGH_MIN_SCORE: u8 = 85;
+
+/// Lowest score in the Medium bucket
+pub const MEDIUM_MIN_SCORE: u8 = 40;
+
+/// Highest score a Suspicious capture can have
+pub const SUSPICIOUS_MAX_SCORE: u8 = 20;
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// How a factor constrains the bucket beyond its points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FactorEffect {
+    /// Forces Suspicious
+    Suspicious,
+    /// Caps the bucket at Medium
+    CapMedium,
+    /// Caps the bucket at Low
+    CapLow,
+}
+
+/// A single check's contribution to the confidence score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ScoreFactor {
+    /// Check that produced the factor (e.g. "hardware", "depth")
+    pub check: String,
+    /// Signed points added to the score
+    pub points: i32,
+    /// Human-readable reason
+    pub reason: String,
+    /// Constraint on the bucket, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effect: Option<FactorEffect>,
+}
+
+impl std::fmt::Display for ScoreFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+} {}: {}", self.points, self.check, self.reason)
+    }
+}
+
+/// Numeric, explainable confidence result
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ConfidenceScore {
+    /// Score from 0 (no trust) to 100 (all checks passed)
+    pub score: u8,
+    /// Confidence bucket
+    pub level: ConfidenceLevel,
+    /// Contributing factors, most decisive first
+    pub factors: Vec<ScoreFactor>,
+}
+
+impl ConfidenceScore {
+    /// Applies a cap decided after scoring (e.g. unverified device)
+    ///
+    /// Does nothing when the bucket is already at or below `level`.
+    pub fn capped(self, level: ConfidenceLevel, check: &str, reason: &str) -> Self {
+        if rank(self.level) <= rank(level) {
+            return self;
+        }
+        let mut builder = ScoreBuilder {
+            factors: self.factors,
+        };
+        builder.cap(level, check, 0, reason);
+        builder.finish()
+    }
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+/// Accumulates factors into a `ConfidenceScore`
+#[derive(Debug, Default)]
+pub struct ScoreBuilder {
+    factors: Vec<ScoreFactor>,
+}
+
+impl ScoreBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds points for a check
+    pub fn add(&mut self, check: &str, points: i32, reason: impl Into<String>) {
+        self.push(check, points, reason.into(), None);
+    }
+
+    /// Adds a failed check that forces Suspicious
+    pub fn veto(&mut self, check: &str, points: i32, reason: impl Into<String>) {
+        self.push(check, points, reason.into(), Some(FactorEffect::Suspicious));
+    }
+
+    /// Adds a check that caps the bucket at `level` (Medium or Low)
+    pub fn cap(
+        &mut self,
+        level: ConfidenceLevel,
+        check: &str,
+        points: i32,
+        reason: impl Into<String>,
+    ) {
+        let effect = match level {
+            ConfidenceLevel::Medium => FactorEffect::CapMedium,
+            ConfidenceLevel::Low => FactorEffect::CapLow,
+            ConfidenceLevel::Suspicious => FactorEffect::Suspicious,
+            ConfidenceLevel::High => return self.add(check, points, reason),
+        };
+        self.push(check, points, reason.into(), Some(effect));
+    }
+
+    fn push(&mut self, check: &str, points: i32, reason: String, effect: Option<FactorEffect>) {
+        self.factors.push(ScoreFactor {
+            check: check.to_string(),
+            points,
+            reason,
+            effect,
+        });
+    }
+
+    /// Computes the score and bucket
+    pub fn finish(mut self) -> ConfidenceScore {
+        let total: i32 = self.factors.iter().map(|f| f.points).sum();
+        let mut score = total.clamp(0, 100) as u8;
+
+        let mut level = if score >= HIGH_MIN_SCORE {
+            ConfidenceLevel::High
+        } else if score >= MEDIUM_MIN_SCORE {
+            ConfidenceLevel::Medium
+        } else {
+            ConfidenceLevel::Low
+        };
+
+        for effect in self.factors.iter().filter_map(|f| f.effect) {
+            let ceiling = match effect {
+                FactorEffect::Suspicious => ConfidenceLevel::Suspicious,
+                FactorEffect::CapMedium => ConfidenceLevel::Medium,
+                FactorEffect::CapLow => ConfidenceLevel::Low,
+            };
+            if rank(ceiling) < rank(level) {
+                level = ceiling;
+            }
+        }
+        score = score.min(max_score(level));
+
+        self.factors.sort_by_key(|f| {
+            let effect_rank = match f.effect {
+                Some(FactorEffect::Suspicious) => 0,
+                Some(FactorEffect::CapLow) => 1,
+                Some(FactorEffect::CapMedium) => 2,
+                None => 3,
+            };
+            (effect_rank, -f.points.abs())
+        });
+
+        ConfidenceScore {
+            score,
+            level,
+            factors: self.factors,
+        }
+    }
+}
+
+/// Orders buckets from least to most trusted
+fn rank(level: ConfidenceLevel) -> u8 {
+    match level {
+        ConfidenceLevel::Suspicious => 0,
+        ConfidenceLevel::Low => 1,
+        ConfidenceLevel::Medium => 2,
+        ConfidenceLevel::High => 3,
+    }
+}
+
+/// Highest score consistent with a bucket
+fn max_score(level: ConfidenceLevel) -> u8 {
+    match level {
+        ConfidenceLevel::High => 100,
+        ConfidenceLevel::Medium => HIGH_MIN_SCORE - 1,
+        ConfidenceLevel::Low => MEDIUM_MIN_SCORE - 1,
+        ConfidenceLevel::Suspicious => SUSPICIOUS_MAX_SCORE,
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buckets_from_points() {
+        let score = |points: i32| {
+            let mut builder = ScoreBuilder::new();
+            builder.add("check", points, "reason");
+            builder.finish()
+        };
+        assert_eq!(score(90).level, ConfidenceLevel::High);
+        assert_eq!(score(85).level, ConfidenceLevel::High);
+        assert_eq!(score(84).level, ConfidenceLevel::Medium);
+        assert_eq!(score(40).level, ConfidenceLevel::Medium);
+        assert_eq!(score(39).level, ConfidenceLevel::Low);
+        assert_eq!(score(-30).score, 0);
+        assert_eq!(score(150).score, 100);
+    }
+
+    #[test]
+    fn test_veto_forces_suspicious() {
+        let mut builder = ScoreBuilder::new();
+        builder.add("hardware", 50, "attestation verified");
+        builder.veto("depth", -40, "screen-like plane");
+        builder.add("metadata", 30, "valid");
+        let result = builder.finish();
+
+        assert_eq!(result.level, ConfidenceLevel::Suspicious);
+        assert!(result.score <= SUSPICIOUS_MAX_SCORE);
+        assert_eq!(
+            result.factors[0].to_string(),
+            "-40 depth: screen-like plane"
+        );
+        assert_eq!(result.factors[0].effect, Some(FactorEffect::Suspicious));
+    }
+
+    #[test]
+    fn test_cap_limits_bucket_and_score() {
+        let mut builder = ScoreBuilder::new();
+        builder.add("hardware", 50, "attestation verified");
+        builder.add("depth", 40, "real scene");
+        builder.cap(ConfidenceLevel::Medium, "depth", 0, "trust 0.6");
+        let result = builder.finish();
+
+        assert_eq!(result.level, ConfidenceLevel::Medium);
+        assert_eq!(result.score, HIGH_MIN_SCORE - 1);
+        assert_eq!(result.factors[0].effect, Some(FactorEffect::CapMedium));
+        assert_eq!(result.factors[1].check, "hardware");
+    }
+
+    #[test]
+    fn test_capped_after_scoring() {
+        let mut builder = ScoreBuilder::new();
+        builder.add("hardware", 50, "attestation verified");
+        builder.add("depth", 40, "real scene");
+        let result = builder.finish();
+        assert_eq!(result.level, ConfidenceLevel::High);
+
+        let capped = result.capped(ConfidenceLevel::Medium, "device", "not fully verified");
+        assert_eq!(capped.level, ConfidenceLevel::Medium);
+        assert_eq!(capped.factors.len(), 3);
+
+        // Already below the cap: unchanged
+        let low = ScoreBuilder::new().finish();
+        let unchanged = low
+            .clone()
+            .capped(ConfidenceLevel::Medium, "device", "not fully verified");
+        assert_eq!(unchanged, low);
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut builder = ScoreBuilder::new();
+        builder.add("hardware", 50, "attestation verified");
+        let json = serde_json::to_value(builder.finish()).unwrap();
+        assert_eq!(json["score"], 50);
+        assert_eq!(json["level"], "medium");
+        assert_eq!(json["factors"][0]["points"], 50);
+        assert!(json["factors"][0].get("effect").is_none());
+    }
+}
@@ -3,6 +3,7 @@
 //! This module defines structs for storing and querying debug logs
 //! with correlation IDs for tracing requests across iOS, backend, and web.
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -170,6 +171,19 @@ pub struct DebugLogQuery {
     pub event: Option<String>,
     /// Filter logs after this timestamp
     pub since: Option<DateTime<Utc>>,
+    /// Filter logs before this timestamp
+    pub until: Option<DateTime<Utc>>,
+    /// Filter by device ID (exact match)
+    pub device_id: Option<Uuid>,
+    /// Filter by app session ID (exact match)
+    pub session_id: Option<Uuid>,
+    /// Full-text search over event and payload (web search syntax:
+    /// words, "quoted phrases", -excluded)
+    pub q: Option<String>,
+    /// JSON object the payload must contain, e.g. `{"status":"400"}`
+    pub payload: Option<String>,
+    /// Opaque cursor from a previous page's `next_cursor`
+    pub cursor: Option<String>,
     /// Maximum number of results (default: 100, max: 1000)
     pub limit: Option<u32>,
     /// Sort order: "asc" or "desc" (default: "desc")
@@ -207,8 +221,75 @@ impl DebugLogQuery {
                 return Err(format!("Invalid order: {order}. Must be asc or desc"));
             }
         }
+        if let (Some(since), Some(until)) = (self.since, self.until) {
+            if since > until {
+                return Err("since must not be after until".to_string());
+            }
+        }
+        if self.q.as_deref().is_some_and(|q| q.trim().is_empty()) {
+            return Err("q must not be empty".to_string());
+        }
+        self.payload_filter()?;
+        self.page_cursor()?;
         Ok(())
     }
+
+    /// Parses the `payload` filter, which must be a JSON object
+    pub fn payload_filter(&self) -> Result<Option<serde_json::Value>, String> {
+        let Some(payload) = &self.payload else {
+            return Ok(None);
+        };
+        match serde_json::from_str(payload) {
+            Ok(value @ serde_json::Value::Object(_)) => Ok(Some(value)),
+            _ => Err("payload must be a JSON object, e.g. {\"status\":\"400\"}".to_string()),
+        }
+    }
+
+    /// Decodes the pagination `cursor`
+    pub fn page_cursor(&self) -> Result<Option<LogCursor>, String> {
+        self.cursor.as_deref().map(LogCursor::decode).transpose()
+    }
+}
+
+// ============================================================================
+// Pagination Cursor
+// ============================================================================
+
+/// Keyset position after the last entry of a page: (timestamp, id)
+///
+/// Clients see it only as an opaque string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogCursor {
+    pub timestamp: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl LogCursor {
+    /// Cursor positioned after `log`
+    pub fn after(log: &DebugLog) -> Self {
+        Self {
+            timestamp: log.timestamp,
+            id: log.id,
+        }
+    }
+
+    /// Encodes as URL-safe base64 of "<timestamp micros>:<id>"
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}:{}", self.timestamp.timestamp_micros(), self.id))
+    }
+
+    /// Decodes a string produced by `encode`
+    pub fn decode(cursor: &str) -> Result<Self, String> {
+        let invalid = || "Invalid cursor".to_string();
+        let decoded = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+        let (micros, id) = decoded.split_once(':').ok_or_else(invalid)?;
+        let micros: i64 = micros.parse().map_err(|_| invalid())?;
+        Ok(Self {
+            timestamp: DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?,
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+        })
+    }
 }
 
 // ============================================================================
@@ -288,6 +369,46 @@ pub struct QueryLogsResponse {
     pub count: usize,
     /// Whether there are more results available
     pub has_more: bool,
+    /// Cursor for the next page (pass as `cursor`), present when has_more
+    pub next_cursor: Option<String>,
+}
+
+// ============================================================================
+// Session Timeline Response
+// ============================================================================
+
+/// Summary of one app session across sources
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SessionSummary {
+    /// Total number of entries in the session
+    pub total: i64,
+    /// Distinct requests (correlation IDs) in the session
+    pub requests: i64,
+    /// Devices that logged in the session
+    pub device_ids: Vec<Uuid>,
+    /// Counts grouped by source
+    pub by_source: SourceCounts,
+    /// Counts grouped by level
+    pub by_level: LevelCounts,
+    /// Timestamp of the first entry
+    pub started_at: Option<DateTime<Utc>>,
+    /// Timestamp of the last entry
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+/// Response for the session timeline: summary plus one page of entries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionTimelineResponse {
+    /// The app session
+    pub session_id: Uuid,
+    /// Summary over the whole session (ignores filters and paging)
+    pub summary: SessionSummary,
+    /// Entries in time order
+    pub logs: Vec<DebugLog>,
+    /// Whether there are more entries available
+    pub has_more: bool,
+    /// Cursor for the next page (pass as `cursor`), present when has_more
+    pub next_cursor: Option<String>,
 }
 
 // ============================================================================
@@ -483,4 +604,44 @@ mod tests {
         assert!(json.contains("\"oldest\":"));
         assert!(json.contains("\"newest\":"));
     }
+
+    #[test]
+    fn test_log_cursor_round_trip() {
+        let cursor = LogCursor {
+            timestamp: DateTime::from_timestamp_micros(1_766_000_000_123_456).unwrap(),
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(LogCursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(LogCursor::decode("not-a-cursor").is_err());
+        assert!(LogCursor::decode(&URL_SAFE_NO_PAD.encode("1:2")).is_err());
+    }
+
+    #[test]
+    fn test_query_validate_new_filters() {
+        let valid = DebugLogQuery {
+            payload: Some(r#"{"status":"400"}"#.to_string()),
+            q: Some("upload -timeout".to_string()),
+            ..Default::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let not_object = DebugLogQuery {
+            payload: Some("[1,2]".to_string()),
+            ..Default::default()
+        };
+        assert!(not_object.validate().is_err());
+
+        let bad_cursor = DebugLogQuery {
+            cursor: Some("%%%".to_string()),
+            ..Default::default()
+        };
+        assert!(bad_cursor.validate().is_err());
+
+        let inverted = DebugLogQuery {
+            since: Some(Utc::now()),
+            until: Some(Utc::now() - chrono::Duration::hours(1)),
+            ..Default::default()
+        };
+        assert!(inverted.validate().is_err());
+    }
 }
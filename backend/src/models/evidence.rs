@@ -4,17 +4,52 @@
 //! including hardware attestation results and confidence level calculations.
 //!
 //! Story 10-5: Extended for unified evidence schema supporting both iOS and Android platforms.
-
+//!
+//! ## Schema
+//! `EvidencePackage` is the single evidence model for photo, video and
+//! hash-only captures. Video-only details live in `video`, hash chain results
+//! in `hash_chain`. Every package carries `schema_version`
+//! (`EVIDENCE_SCHEMA_VERSION`); evidence stored before versioning is upgraded
+//! by migration. The JSON Schema is published at `GET /api/v1/evidence/schema`
+//! and committed as `schemas/evidence.schema.json`.
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::types::hash_only::AnalysisSource;
+use super::confidence_score::{ConfidenceScore, ScoreBuilder};
+use crate::types::detection::ConfidenceFlag;
+use crate::types::hash_chain_verification::{HashChainVerification, VerificationStatus};
+use crate::types::hash_only::{AnalysisSource, CaptureMode};
+use crate::types::location_privacy::LocationPrivacyEvidence;
+use crate::types::video_depth_analysis::VideoDepthAnalysis;
+
+/// Current evidence schema version
+pub const EVIDENCE_SCHEMA_VERSION: u32 = 2;
+
+/// Client analysis trust at or above which confidence is not capped
+pub const ANALYSIS_TRUST_FULL: f64 = 0.8;
+
+/// Client analysis trust below which depth analysis earns no credit
+pub const ANALYSIS_TRUST_MIN: f64 = 0.4;
+
+/// Score points for passing hardware attestation (photos)
+const HARDWARE_POINTS: i32 = 50;
+
+/// Score points for a real-scene depth analysis (photos)
+const DEPTH_POINTS: i32 = 40;
+
+/// Score points for each passing metadata check (photos)
+const METADATA_POINTS: i32 = 5;
+
+/// Score points lost for a broken hash chain
+const HASH_CHAIN_POINTS: i32 = 40;
 
 // ============================================================================
 // Check Status Enum
 // ============================================================================
 
 /// Status of an evidence check
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum CheckStatus {
     /// Check passed successfully
@@ -23,6 +58,62 @@ pub enum CheckStatus {
     Fail,
     /// Check could not be performed (data unavailable)
     Unavailable,
+    /// Check has not run yet (video processing pending)
+    Pending,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "pass"),
+            CheckStatus::Fail => write!(f, "fail"),
+            CheckStatus::Unavailable => write!(f, "unavailable"),
+            CheckStatus::Pending => write!(f, "pending"),
+        }
+    }
+}
+
+// ============================================================================
+// Capture Descriptors
+// ============================================================================
+
+/// Platform of the capturing device
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    /// iOS with DCAppAttest (default for evidence predating Story 10-5)
+    #[default]
+    Ios,
+    /// Android with Key Attestation
+    Android,
+}
+
+impl From<&str> for Platform {
+    fn from(s: &str) -> Self {
+        if s.eq_ignore_ascii_case("android") {
+            Platform::Android
+        } else {
+            Platform::Ios
+        }
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Platform::Ios => write!(f, "ios"),
+            Platform::Android => write!(f, "android"),
+        }
+    }
+}
+
+/// Media type of a capture
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureType {
+    #[default]
+    Photo,
+    Video,
 }
 
 // ============================================================================
@@ -30,7 +121,7 @@ pub enum CheckStatus {
 // ============================================================================
 
 /// Level of hardware attestation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum AttestationLevel {
     /// Device with verified Secure Enclave attestation (iOS)
     #[serde(rename = "secure_enclave")]
@@ -57,6 +148,43 @@ impl From<&str> for AttestationLevel {
     }
 }
 
+impl std::fmt::Display for AttestationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttestationLevel::SecureEnclave => write!(f, "secure_enclave"),
+            AttestationLevel::StrongBox => write!(f, "strongbox"),
+            AttestationLevel::TrustedEnvironment => write!(f, "tee"),
+            AttestationLevel::Unverified => write!(f, "unverified"),
+        }
+    }
+}
+
+/// Android KeyMaster security level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum KeymasterLevel {
+    /// Keys held in software only
+    #[serde(rename = "software")]
+    Software,
+    /// Keys held in the Trusted Execution Environment
+    #[serde(rename = "tee")]
+    TrustedEnvironment,
+    /// Keys held in the StrongBox hardware security module
+    #[serde(rename = "strongbox")]
+    StrongBox,
+}
+
+impl KeymasterLevel {
+    /// Parses the database representation ("software", "tee", "strongbox")
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "software" => Some(KeymasterLevel::Software),
+            "tee" => Some(KeymasterLevel::TrustedEnvironment),
+            "strongbox" => Some(KeymasterLevel::StrongBox),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Security Level Info Structure (Story 10-2)
 // ============================================================================
@@ -65,16 +193,16 @@ impl From<&str> for AttestationLevel {
 ///
 /// Provides platform-specific attestation details for display
 /// on verification pages and API responses.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SecurityLevelInfo {
     /// Primary attestation security level: "strongbox", "tee", "secure_enclave"
-    pub attestation_level: String,
+    pub attestation_level: AttestationLevel,
     /// Android KeyMaster security level (may differ from attestation level)
     /// NULL for iOS devices
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub keymaster_level: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keymaster_level: Option<KeymasterLevel>,
     /// Platform identifier: "ios" or "android"
-    pub platform: String,
+    pub platform: Platform,
 }
 
 // ============================================================================
@@ -82,7 +210,7 @@ pub struct SecurityLevelInfo {
 // ============================================================================
 
 /// Confidence level for a capture based on evidence analysis
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ConfidenceLevel {
     /// Both hardware attestation and depth analysis pass
@@ -103,7 +231,7 @@ pub enum ConfidenceLevel {
 ///
 /// Records the result of verifying the per-capture assertion
 /// against the device's registered public key.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HardwareAttestation {
     /// Overall status of the hardware attestation check
     pub status: CheckStatus,
@@ -183,7 +311,7 @@ impl HardwareAttestation {
 /// to verify it represents a real 3D scene.
 ///
 /// Story 10-5: Added `method` and `unavailable_reason` fields for cross-platform support.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DepthAnalysis {
     /// Overall status of the depth analysis check
     pub status: CheckStatus,
@@ -210,6 +338,14 @@ pub struct DepthAnalysis {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub unavailable_reason: Option<String>,
+    /// Server plausibility score for device-computed analysis (0.0 - 1.0)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub analysis_trust: Option<f64>,
+    /// Plausibility findings that reduced `analysis_trust`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub plausibility_issues: Vec<String>,
 }
 
 impl Default for DepthAnalysis {
@@ -226,6 +362,8 @@ impl Default for DepthAnalysis {
             source: None,
             method: None,
             unavailable_reason: None,
+            analysis_trust: None,
+            plausibility_issues: Vec::new(),
         }
     }
 }
@@ -248,6 +386,8 @@ impl DepthAnalysis {
             source: None,
             method: None,
             unavailable_reason: Some("android_no_lidar".to_string()),
+            analysis_trust: None,
+            plausibility_issues: Vec::new(),
         }
     }
 
@@ -272,29 +412,114 @@ impl DepthAnalysis {
 ///
 /// Records the result of validating capture metadata including:
 /// - Timestamp (within 15 minute window of server time)
-/// - Device model (iPhone Pro whitelist)
-/// - Resolution (known LiDAR formats)
+/// - Device model (device capability registry)
+/// - Resolution (expected depth formats for the model)
 /// - Location (valid GPS coordinates)
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct MetadataEvidence {
     /// Whether the timestamp is within acceptable bounds (15 min window)
     pub timestamp_valid: bool,
+    /// Consistency of the timestamp with the device's capture history
+    /// (None when the history check was not run)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_consistency: Option<TimestampConsistency>,
     /// Delta between captured_at and server time in seconds
     /// Positive = captured in past, Negative = captured in future
     pub timestamp_delta_seconds: i64,
-    /// Whether the device model is verified (iPhone Pro whitelist)
+    /// Whether the device model is registered with an active depth sensor
     pub model_verified: bool,
     /// The device model name
     pub model_name: String,
-    /// Whether depth map resolution matches known LiDAR formats
+    /// Whether the model matches a hardware-attested device identity
+    /// (Android ID attestation; iOS App Attest carries no model)
+    #[serde(default)]
+    pub model_attested: bool,
+    /// Whether the model contradicts the registered or attested device model
+    #[serde(default)]
+    pub model_mismatch: bool,
+    /// Whether depth map resolution matches the model's expected depth formats
     pub resolution_valid: bool,
     /// Whether valid location data is available
     pub location_available: bool,
     /// Whether user opted out of location sharing
     pub location_opted_out: bool,
+    /// Whether the precise location passed travel-speed and accuracy checks
+    /// against the device's other captures (None when not checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_plausible: Option<bool>,
+    /// Why the location is implausible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_plausibility_reason: Option<String>,
     /// Coarse location (city/region level, for display)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location_coarse: Option<String>,
+    /// Location privacy policy applied to `location_coarse`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_privacy: Option<LocationPrivacyEvidence>,
+    /// Reverse-geocoded place label for `location_coarse` (e.g. "San Francisco, CA, US")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_label: Option<String>,
+}
+
+/// Per-device temporal consistency of a capture timestamp
+///
+/// `captured_at` must not precede device registration, must be monotonic in
+/// assertion counter order, and must not imply an impossible capture rate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TimestampConsistency {
+    /// Whether all history checks passed
+    pub consistent: bool,
+    /// Why the timestamp is inconsistent (violations joined with "; ")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+// ============================================================================
+// Detection Assessment
+// ============================================================================
+
+/// Effect of the client detection signals on confidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionVerdict {
+    /// Signals are consistent with a real scene (no effect)
+    Pass,
+    /// No usable signals (no effect)
+    Inconclusive,
+    /// Signals point to a screen or print (caps confidence at Medium)
+    Caution,
+    /// Critical anomaly or failed cross-validation (Suspicious)
+    Critical,
+}
+
+/// A single signal's contribution to the detection score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DetectionFactor {
+    /// Signal name (e.g. "moire", "cross_validation")
+    pub signal: String,
+    /// Authenticity score of the signal (0.0 = artificial, 1.0 = real scene)
+    pub score: f64,
+    /// Normalized weight applied to the signal
+    pub weight: f64,
+    /// Contribution to the detection score (negative for penalties)
+    pub contribution: f64,
+    /// Human-readable explanation
+    pub reason: String,
+}
+
+/// Client detection results merged under the scoring policy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DetectionAssessment {
+    /// Algorithm version whose weights were applied ("*" for the fallback)
+    pub algorithm_version: String,
+    /// Weighted authenticity score (0.0 to 1.0), None without usable signals
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    /// Effect on confidence
+    pub verdict: DetectionVerdict,
+    /// Contributing factors, in evaluation order
+    #[serde(default)]
+    pub factors: Vec<DetectionFactor>,
 }
 
 // ============================================================================
@@ -304,7 +529,7 @@ pub struct MetadataEvidence {
 /// Processing information for evidence generation
 ///
 /// Records timing and version info for the evidence processing pipeline.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 pub struct ProcessingInfo {
     /// When processing completed (ISO 8601)
     pub processed_at: String,
@@ -312,6 +537,9 @@ pub struct ProcessingInfo {
     pub processing_time_ms: u64,
     /// Backend version that processed the capture
     pub backend_version: String,
+    /// Checks performed, when recorded (e.g. "hardware", "hash_chain")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks_performed: Vec<String>,
 }
 
 impl ProcessingInfo {
@@ -321,18 +549,220 @@ impl ProcessingInfo {
             processed_at: chrono::Utc::now().to_rfc3339(),
             processing_time_ms,
             backend_version: backend_version.to_string(),
+            checks_performed: Vec::new(),
+        }
+    }
+
+    /// Records the checks performed
+    pub fn with_checks(mut self, checks: Vec<String>) -> Self {
+        self.checks_performed = checks;
+        self
+    }
+}
+
+// ============================================================================
+// Hash Chain Evidence (Story 7-10)
+// ============================================================================
+
+/// Outcome of hash chain verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HashChainStatus {
+    /// Chain intact and attested
+    Pass,
+    /// Interrupted recording verified up to a checkpoint
+    Partial,
+    /// Chain broken or attestation invalid
+    Fail,
+}
+
+impl From<VerificationStatus> for HashChainStatus {
+    fn from(status: VerificationStatus) -> Self {
+        match status {
+            VerificationStatus::Pass => HashChainStatus::Pass,
+            VerificationStatus::Partial => HashChainStatus::Partial,
+            VerificationStatus::Fail => HashChainStatus::Fail,
+        }
+    }
+}
+
+impl std::fmt::Display for HashChainStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashChainStatus::Pass => write!(f, "pass"),
+            HashChainStatus::Partial => write!(f, "partial"),
+            HashChainStatus::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// Hash chain verification evidence for videos
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HashChainEvidence {
+    /// Verification outcome
+    pub status: HashChainStatus,
+    /// Number of frames verified
+    pub verified_frames: u32,
+    /// Total frames in the recording
+    pub total_frames: u32,
+    /// Whether chain structure and final hash are intact
+    pub chain_intact: bool,
+    /// Whether the attestation over the final (or checkpoint) hash is valid
+    pub attestation_valid: bool,
+    /// Reason for partial or failed verification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_reason: Option<String>,
+    /// Duration covered by verified frames in milliseconds
+    pub verified_duration_ms: u32,
+    /// Whether a checkpoint was verified (interrupted recordings)
+    pub checkpoint_verified: bool,
+    /// Index of the verified checkpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_index: Option<u32>,
+    /// Whether every link was recomputed from the frame digests (v2 chains)
+    #[serde(default)]
+    pub derivation_verified: bool,
+    /// Whether the first frame derives from the device's registration-bound genesis
+    #[serde(default)]
+    pub genesis_bound: bool,
+    /// First frame whose hash does not chain from its predecessor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broken_at_frame: Option<u32>,
+}
+
+impl HashChainEvidence {
+    /// Creates evidence from a hash chain verification result
+    pub fn from_verification(v: &HashChainVerification) -> Self {
+        Self {
+            status: v.status.into(),
+            verified_frames: v.frame_count,
+            total_frames: v.frame_count,
+            chain_intact: v.chain_structure_valid && v.final_hash_matches,
+            attestation_valid: v.attestation_valid,
+            partial_reason: v.failure_reason.clone(),
+            verified_duration_ms: v.duration_ms,
+            checkpoint_verified: v.is_partial && v.checkpoint_index.is_some(),
+            checkpoint_index: v.checkpoint_index,
+            derivation_verified: v.derivation_verified,
+            genesis_bound: v.genesis_bound,
+            broken_at_frame: v.broken_at_frame,
+        }
+    }
+
+    /// Creates failed hash chain evidence
+    pub fn fail(reason: &str) -> Self {
+        Self {
+            status: HashChainStatus::Fail,
+            verified_frames: 0,
+            total_frames: 0,
+            chain_intact: false,
+            attestation_valid: false,
+            partial_reason: Some(reason.to_string()),
+            verified_duration_ms: 0,
+            checkpoint_verified: false,
+            checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
+        }
+    }
+}
+
+// ============================================================================
+// Video Evidence (Story 7-11)
+// ============================================================================
+
+/// Temporal depth analysis summary across video keyframes (Story 7-9)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TemporalDepthEvidence {
+    /// Depth consistency across keyframes (0.0-1.0)
+    pub depth_consistency: f32,
+    /// Motion coherence between depth and video (0.0-1.0)
+    pub motion_coherence: f32,
+    /// Scene stability (0.0-1.0)
+    pub scene_stability: f32,
+    /// Whether the recording is likely of a real 3D scene
+    pub is_likely_real_scene: bool,
+    /// Keyframe indices flagged as suspicious
+    #[serde(default)]
+    pub suspicious_frames: Vec<u32>,
+}
+
+impl TemporalDepthEvidence {
+    /// Creates evidence from a temporal depth analysis result
+    pub fn from_analysis(a: &VideoDepthAnalysis) -> Self {
+        Self {
+            depth_consistency: a.depth_consistency,
+            motion_coherence: a.motion_coherence,
+            scene_stability: a.scene_stability,
+            is_likely_real_scene: a.is_likely_real_scene,
+            suspicious_frames: a.suspicious_frames.clone(),
+        }
+    }
+}
+
+/// Attestation coverage of interrupted recordings
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PartialAttestationInfo {
+    /// Whether the recording was interrupted
+    pub is_partial: bool,
+    /// Checkpoint index the attestation covers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_index: Option<u32>,
+    /// Frames covered by the attestation
+    pub verified_frames: u32,
+    /// Total frames recorded
+    pub total_frames: u32,
+    /// Why attestation is partial
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl PartialAttestationInfo {
+    /// Complete recording attested up to the final frame
+    pub fn complete(frame_count: u32) -> Self {
+        Self {
+            is_partial: false,
+            checkpoint_index: None,
+            verified_frames: frame_count,
+            total_frames: frame_count,
+            reason: None,
+        }
+    }
+
+    /// Interrupted recording attested up to a checkpoint
+    pub fn partial(checkpoint_index: u32, verified_frames: u32, total_frames: u32) -> Self {
+        Self {
+            is_partial: true,
+            checkpoint_index: Some(checkpoint_index),
+            verified_frames,
+            total_frames,
+            reason: Some("checkpoint_attestation".to_string()),
         }
     }
 }
 
+/// Evidence specific to video captures
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VideoDetails {
+    /// Recording duration in milliseconds
+    pub duration_ms: u64,
+    /// Number of frames recorded
+    pub frame_count: u32,
+    /// Temporal depth analysis (None when unavailable)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temporal_depth: Option<TemporalDepthEvidence>,
+    /// Attestation coverage of the recording
+    pub partial_attestation: PartialAttestationInfo,
+}
+
 // ============================================================================
 // Evidence Package Structure
 // ============================================================================
 
-/// Default platform for backward compatibility (Story 10-5)
-/// All existing captures before Story 10-5 are iOS, so we default to "ios".
-fn default_platform() -> String {
-    "ios".to_string()
+/// Schema version of evidence stored before versioning
+fn legacy_schema_version() -> u32 {
+    1
 }
 
 /// Complete evidence package for a capture (Story 4-7, Story 10-5)
@@ -342,12 +772,21 @@ fn default_platform() -> String {
 /// metadata validation, and processing info.
 ///
 /// Story 10-5: Added `platform` field to support both iOS and Android captures.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EvidencePackage {
-    /// Platform: "ios" or "android" (Story 10-5)
-    /// Defaults to "ios" for backward compatibility with existing evidence.
-    #[serde(default = "default_platform")]
-    pub platform: String,
+    /// Evidence schema version (`EVIDENCE_SCHEMA_VERSION`)
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+    /// Platform (Story 10-5)
+    /// Defaults to iOS for backward compatibility with existing evidence.
+    #[serde(default)]
+    pub platform: Platform,
+    /// Media type of the capture
+    #[serde(default)]
+    pub capture_type: CaptureType,
+    /// Full upload or hash-only (privacy mode)
+    #[serde(default)]
+    pub capture_mode: CaptureMode,
     /// Hardware attestation evidence
     pub hardware_attestation: HardwareAttestation,
     /// Depth analysis evidence
@@ -356,6 +795,22 @@ pub struct EvidencePackage {
     pub metadata: MetadataEvidence,
     /// Processing information (timing, version)
     pub processing: ProcessingInfo,
+    /// Video-only evidence (duration, temporal depth, partial attestation)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<VideoDetails>,
+    /// Hash chain verification for videos
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_chain: Option<HashChainEvidence>,
+    /// Concerns raised by server-side detection (e.g. screen detected,
+    /// server and client disagree)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub confidence_flags: Vec<ConfidenceFlag>,
+    /// Client multi-signal detection merged under the scoring policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detection: Option<DetectionAssessment>,
+    /// Numeric confidence with contributing factors (absent in legacy evidence)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence_score: Option<ConfidenceScore>,
 }
 
 impl EvidencePackage {
@@ -368,13 +823,13 @@ impl EvidencePackage {
         metadata: MetadataEvidence,
         processing: ProcessingInfo,
     ) -> Self {
-        Self {
-            platform: "ios".to_string(),
+        Self::new(
+            Platform::Ios,
             hardware_attestation,
             depth_analysis,
             metadata,
             processing,
-        }
+        )
     }
 
     /// Creates evidence package for Android capture (Story 10-5)
@@ -386,13 +841,13 @@ impl EvidencePackage {
         metadata: MetadataEvidence,
         processing: ProcessingInfo,
     ) -> Self {
-        Self {
-            platform: "android".to_string(),
+        Self::new(
+            Platform::Android,
             hardware_attestation,
             depth_analysis,
             metadata,
             processing,
-        }
+        )
     }
 
     /// Creates evidence package with specified platform (Story 10-5)
@@ -405,46 +860,237 @@ impl EvidencePackage {
         depth_analysis: DepthAnalysis,
         metadata: MetadataEvidence,
         processing: ProcessingInfo,
+    ) -> Self {
+        Self::new(
+            Platform::from(platform),
+            hardware_attestation,
+            depth_analysis,
+            metadata,
+            processing,
+        )
+    }
+
+    fn new(
+        platform: Platform,
+        hardware_attestation: HardwareAttestation,
+        depth_analysis: DepthAnalysis,
+        metadata: MetadataEvidence,
+        processing: ProcessingInfo,
     ) -> Self {
         Self {
-            platform: platform.to_lowercase(),
+            schema_version: EVIDENCE_SCHEMA_VERSION,
+            platform,
+            capture_type: CaptureType::Photo,
+            capture_mode: CaptureMode::Full,
             hardware_attestation,
             depth_analysis,
             metadata,
             processing,
+            video: None,
+            hash_chain: None,
+            confidence_flags: Vec::new(),
+            detection: None,
+            confidence_score: None,
         }
     }
 
+    /// Marks the package as a hash-only (privacy mode) capture
+    pub fn with_capture_mode(mut self, capture_mode: CaptureMode) -> Self {
+        self.capture_mode = capture_mode;
+        self
+    }
+
+    /// Attaches video evidence and marks the package as a video capture
+    pub fn with_video(mut self, video: VideoDetails) -> Self {
+        self.capture_type = CaptureType::Video;
+        self.video = Some(video);
+        self
+    }
+
+    /// Attaches hash chain evidence (hash-only video captures)
+    pub fn with_hash_chain(mut self, hash_chain: HashChainEvidence) -> Self {
+        self.hash_chain = Some(hash_chain);
+        self
+    }
+
+    /// Attaches confidence flags raised by server-side detection
+    pub fn with_confidence_flags(mut self, flags: Vec<ConfidenceFlag>) -> Self {
+        self.confidence_flags = flags;
+        self
+    }
+
+    /// Attaches the detection assessment
+    pub fn with_detection(mut self, detection: DetectionAssessment) -> Self {
+        self.detection = Some(detection);
+        self
+    }
+
+    /// Attaches the computed confidence score
+    pub fn with_confidence_score(mut self, score: ConfidenceScore) -> Self {
+        self.confidence_score = Some(score);
+        self
+    }
+
     /// Calculates the confidence level based on all evidence
     ///
+    /// Shorthand for `calculate_score().level`.
+    pub fn calculate_confidence(&self) -> ConfidenceLevel {
+        self.calculate_score().level
+    }
+
+    /// Calculates the numeric confidence score with its contributing factors
+    ///
     /// Logic:
-    /// - If any check explicitly failed -> Suspicious
-    /// - If both hw and depth pass -> High
-    /// - If either hw or depth pass -> Medium
-    /// - If both unavailable -> Low
+    /// - If any check explicitly failed (including a hash chain) or detection
+    ///   reports a critical anomaly -> Suspicious
+    /// - Device-computed depth with `analysis_trust` below 0.4 does not count as
+    ///   passing; below 0.8 it caps confidence at Medium
+    /// - Any confidence flag or a detection Caution verdict caps confidence
+    ///   at Medium
+    /// - Hardware pass earns 50 points, depth pass 40, and each passing
+    ///   metadata check (timestamp, model) 5, so:
+    ///   - If both hw and depth pass -> High
+    ///   - If either hw or depth pass -> Medium
+    ///   - If both unavailable -> Low
     ///
     /// Story 10-5 Note: This logic naturally handles Android captures where
     /// depth is unavailable (hw_pass=true, depth_pass=false -> Medium).
-    pub fn calculate_confidence(&self) -> ConfidenceLevel {
-        // If any check explicitly failed, mark as suspicious
-        if self.hardware_attestation.status == CheckStatus::Fail
-            || self.depth_analysis.status == CheckStatus::Fail
+    pub fn calculate_score(&self) -> ConfidenceScore {
+        let mut score = ScoreBuilder::new();
+
+        match self.hardware_attestation.status {
+            CheckStatus::Pass => score.add("hardware", HARDWARE_POINTS, "attestation verified"),
+            CheckStatus::Fail => {
+                let reason = if !self.hardware_attestation.assertion_verified {
+                    "assertion signature invalid"
+                } else if !self.hardware_attestation.counter_valid {
+                    "assertion counter replayed"
+                } else {
+                    "attestation failed"
+                };
+                score.veto("hardware", -HARDWARE_POINTS, reason)
+            }
+            CheckStatus::Unavailable => score.add("hardware", 0, "attestation unavailable"),
+            CheckStatus::Pending => score.add("hardware", 0, "attestation pending"),
+        }
+
+        // Device-computed depth analysis only counts as far as it is plausible
+        let depth = &self.depth_analysis;
+        let analysis_trust = depth.analysis_trust.unwrap_or(1.0);
+        match depth.status {
+            CheckStatus::Fail => score.veto("depth", -DEPTH_POINTS, "screen-like plane"),
+            CheckStatus::Pass if !depth.is_likely_real_scene => {
+                score.add("depth", 0, "scene not confirmed as real")
+            }
+            CheckStatus::Pass if analysis_trust < ANALYSIS_TRUST_MIN => score.add(
+                "depth",
+                0,
+                format!("device analysis trust {analysis_trust:.2} too low to count"),
+            ),
+            CheckStatus::Pass => {
+                score.add(
+                    "depth",
+                    DEPTH_POINTS,
+                    format!("real 3D scene ({} depth layers)", depth.depth_layers),
+                );
+                if analysis_trust < ANALYSIS_TRUST_FULL {
+                    score.cap(
+                        ConfidenceLevel::Medium,
+                        "depth",
+                        0,
+                        format!("device analysis trust {analysis_trust:.2}"),
+                    );
+                }
+            }
+            CheckStatus::Pending => score.add("depth", 0, "depth analysis pending"),
+            CheckStatus::Unavailable => score.add(
+                "depth",
+                0,
+                depth
+                    .unavailable_reason
+                    .clone()
+                    .unwrap_or_else(|| "depth unavailable".to_string()),
+            ),
+        }
+
+        if let Some(chain) = self
+            .hash_chain
+            .as_ref()
+            .filter(|c| c.status == HashChainStatus::Fail)
         {
-            return ConfidenceLevel::Suspicious;
+            let reason = match chain.broken_at_frame {
+                Some(frame) => format!("hash chain broken at frame {frame}"),
+                None => "hash chain verification failed".to_string(),
+            };
+            score.veto("hash_chain", -HASH_CHAIN_POINTS, reason);
         }
 
-        let hw_pass = self.hardware_attestation.status == CheckStatus::Pass;
-        let depth_pass = self.depth_analysis.is_likely_real_scene
-            && self.depth_analysis.status == CheckStatus::Pass;
+        if self.metadata.timestamp_valid {
+            score.add("timestamp", METADATA_POINTS, "within server time window");
+        }
+        if self.metadata.model_verified {
+            score.add(
+                "device_model",
+                METADATA_POINTS,
+                "registered with a depth sensor",
+            );
+        }
+
+        for flag in &self.confidence_flags {
+            let name = serde_json::to_value(flag)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default();
+            score.cap(ConfidenceLevel::Medium, "server_detection", 0, name);
+        }
 
-        match (hw_pass, depth_pass) {
-            (true, true) => ConfidenceLevel::High,
-            (true, false) | (false, true) => ConfidenceLevel::Medium,
-            (false, false) => ConfidenceLevel::Low,
+        if let Some(detection) = &self.detection {
+            let reason = match detection.score {
+                Some(value) => format!("client signals scored {value:.2}"),
+                None => "no usable client signals".to_string(),
+            };
+            match detection.verdict {
+                DetectionVerdict::Critical => score.veto(
+                    "detection",
+                    0,
+                    detection
+                        .factors
+                        .iter()
+                        .find(|f| f.signal == "anomaly")
+                        .map(|f| f.reason.clone())
+                        .unwrap_or(reason),
+                ),
+                DetectionVerdict::Caution => {
+                    score.cap(ConfidenceLevel::Medium, "detection", 0, reason)
+                }
+                DetectionVerdict::Pass | DetectionVerdict::Inconclusive => {
+                    score.add("detection", 0, reason)
+                }
+            }
         }
+
+        score.finish()
     }
 }
 
+/// Identifier of the published evidence JSON Schema
+pub const EVIDENCE_SCHEMA_ID: &str = "https://realitycam.app/schemas/evidence.schema.json";
+
+/// JSON Schema for `EvidencePackage` at `EVIDENCE_SCHEMA_VERSION`
+pub fn evidence_json_schema() -> serde_json::Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(EvidencePackage))
+        .expect("evidence schema serializes to JSON");
+    if let Some(object) = schema.as_object_mut() {
+        object.insert("$id".to_string(), EVIDENCE_SCHEMA_ID.into());
+        object.insert("title".to_string(), "RealityCam evidence package".into());
+        object.insert(
+            "x-schema-version".to_string(),
+            EVIDENCE_SCHEMA_VERSION.into(),
+        );
+    }
+    schema
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -522,9 +1168,9 @@ mod tests {
     #[test]
     fn test_security_level_info_serialization() {
         let info = SecurityLevelInfo {
-            attestation_level: "strongbox".to_string(),
-            keymaster_level: Some("strongbox".to_string()),
-            platform: "android".to_string(),
+            attestation_level: AttestationLevel::StrongBox,
+            keymaster_level: Some(KeymasterLevel::StrongBox),
+            platform: Platform::Android,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -536,9 +1182,9 @@ mod tests {
     #[test]
     fn test_security_level_info_ios_omits_keymaster() {
         let info = SecurityLevelInfo {
-            attestation_level: "secure_enclave".to_string(),
+            attestation_level: AttestationLevel::SecureEnclave,
             keymaster_level: None,
-            platform: "ios".to_string(),
+            platform: Platform::Ios,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -551,26 +1197,29 @@ mod tests {
     fn test_security_level_info_deserialization() {
         let json = r#"{"attestation_level":"tee","keymaster_level":"tee","platform":"android"}"#;
         let info: SecurityLevelInfo = serde_json::from_str(json).unwrap();
-        assert_eq!(info.attestation_level, "tee");
-        assert_eq!(info.keymaster_level, Some("tee".to_string()));
-        assert_eq!(info.platform, "android");
+        assert_eq!(info.attestation_level, AttestationLevel::TrustedEnvironment);
+        assert_eq!(
+            info.keymaster_level,
+            Some(KeymasterLevel::TrustedEnvironment)
+        );
+        assert_eq!(info.platform, Platform::Android);
     }
 
     #[test]
     fn test_hardware_attestation_with_security_level() {
         let hw = HardwareAttestation::pass("Pixel 8 Pro".to_string(), AttestationLevel::StrongBox)
             .with_security_level(Some(SecurityLevelInfo {
-                attestation_level: "strongbox".to_string(),
-                keymaster_level: Some("strongbox".to_string()),
-                platform: "android".to_string(),
+                attestation_level: AttestationLevel::StrongBox,
+                keymaster_level: Some(KeymasterLevel::StrongBox),
+                platform: Platform::Android,
             }));
 
         assert_eq!(hw.status, CheckStatus::Pass);
         assert_eq!(hw.level, AttestationLevel::StrongBox);
         assert!(hw.security_level.is_some());
         let sl = hw.security_level.unwrap();
-        assert_eq!(sl.attestation_level, "strongbox");
-        assert_eq!(sl.platform, "android");
+        assert_eq!(sl.attestation_level, AttestationLevel::StrongBox);
+        assert_eq!(sl.platform, Platform::Android);
     }
 
     #[test]
@@ -711,6 +1360,51 @@ mod tests {
         assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Suspicious);
     }
 
+    #[test]
+    fn test_score_depth_fail_explains_veto() {
+        let depth = DepthAnalysis {
+            status: CheckStatus::Fail,
+            is_likely_real_scene: false,
+            ..Default::default()
+        };
+
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            depth,
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        let score = evidence.calculate_score();
+
+        assert_eq!(score.level, ConfidenceLevel::Suspicious);
+        assert!(score.score <= crate::models::SUSPICIOUS_MAX_SCORE);
+        assert_eq!(score.factors[0].to_string(), "-40 depth: screen-like plane");
+    }
+
+    #[test]
+    fn test_score_both_pass_is_high_and_serialized() {
+        let depth = DepthAnalysis {
+            status: CheckStatus::Pass,
+            is_likely_real_scene: true,
+            ..Default::default()
+        };
+
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            depth,
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        let score = evidence.calculate_score();
+        assert_eq!(score.level, ConfidenceLevel::High);
+        assert_eq!(score.score, 90);
+        assert_eq!(score.factors[0].check, "hardware");
+
+        let json = serde_json::to_value(evidence.with_confidence_score(score)).unwrap();
+        assert_eq!(json["confidence_score"]["score"], 90);
+        assert_eq!(json["confidence_score"]["level"], "high");
+    }
+
     #[test]
     fn test_confidence_depth_pass_hw_unavailable_is_medium() {
         let depth = DepthAnalysis {
@@ -906,7 +1600,7 @@ mod tests {
             ProcessingInfo::default(),
         );
 
-        assert_eq!(evidence.platform, "ios");
+        assert_eq!(evidence.platform, Platform::Ios);
     }
 
     #[test]
@@ -918,7 +1612,7 @@ mod tests {
             ProcessingInfo::default(),
         );
 
-        assert_eq!(evidence.platform, "android");
+        assert_eq!(evidence.platform, Platform::Android);
     }
 
     #[test]
@@ -931,7 +1625,7 @@ mod tests {
             ProcessingInfo::default(),
         );
 
-        assert_eq!(evidence.platform, "android");
+        assert_eq!(evidence.platform, Platform::Android);
     }
 
     #[test]
@@ -998,7 +1692,7 @@ mod tests {
         }"#;
 
         let evidence: EvidencePackage = serde_json::from_str(json).unwrap();
-        assert_eq!(evidence.platform, "ios"); // Default value
+        assert_eq!(evidence.platform, Platform::Ios); // Default value
     }
 
     #[test]
@@ -1172,6 +1866,167 @@ mod tests {
         assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Suspicious);
     }
 
+    #[test]
+    fn test_confidence_hash_chain_fail_is_suspicious() {
+        let depth = DepthAnalysis {
+            status: CheckStatus::Pass,
+            is_likely_real_scene: true,
+            ..Default::default()
+        };
+
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            depth,
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::High);
+
+        let evidence = evidence.with_hash_chain(HashChainEvidence::fail("Checkpoint 1 unattested"));
+        assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Suspicious);
+    }
+
+    #[test]
+    fn test_confidence_capped_by_analysis_trust() {
+        let depth = |trust: Option<f64>| DepthAnalysis {
+            status: CheckStatus::Pass,
+            is_likely_real_scene: true,
+            source: Some(AnalysisSource::Device),
+            analysis_trust: trust,
+            ..Default::default()
+        };
+        let evidence = |trust: Option<f64>| {
+            EvidencePackage::for_ios(
+                HardwareAttestation::pass(
+                    "iPhone 15 Pro".to_string(),
+                    AttestationLevel::SecureEnclave,
+                ),
+                depth(trust),
+                MetadataEvidence::default(),
+                ProcessingInfo::default(),
+            )
+        };
+
+        assert_eq!(evidence(None).calculate_confidence(), ConfidenceLevel::High);
+        assert_eq!(
+            evidence(Some(0.9)).calculate_confidence(),
+            ConfidenceLevel::High
+        );
+        assert_eq!(
+            evidence(Some(0.6)).calculate_confidence(),
+            ConfidenceLevel::Medium
+        );
+        assert_eq!(
+            evidence(Some(0.2)).calculate_confidence(),
+            ConfidenceLevel::Medium
+        );
+
+        let mut no_hw = evidence(Some(0.2));
+        no_hw.hardware_attestation.status = CheckStatus::Unavailable;
+        assert_eq!(no_hw.calculate_confidence(), ConfidenceLevel::Low);
+    }
+
+    #[test]
+    fn test_confidence_capped_by_detection_flags() {
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            DepthAnalysis {
+                status: CheckStatus::Pass,
+                is_likely_real_scene: true,
+                ..Default::default()
+            },
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::High);
+        assert!(!serde_json::to_value(&evidence)
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .contains_key("confidence_flags"));
+
+        let flagged = evidence.with_confidence_flags(vec![ConfidenceFlag::ServerClientDisagree]);
+        assert_eq!(flagged.calculate_confidence(), ConfidenceLevel::Medium);
+        let json = serde_json::to_value(&flagged).unwrap();
+        assert_eq!(json["confidence_flags"][0], "server_client_disagree");
+    }
+
+    #[test]
+    fn test_confidence_detection_verdicts() {
+        let evidence = |verdict: DetectionVerdict| {
+            EvidencePackage::for_ios(
+                HardwareAttestation::pass(
+                    "iPhone 15 Pro".to_string(),
+                    AttestationLevel::SecureEnclave,
+                ),
+                DepthAnalysis {
+                    status: CheckStatus::Pass,
+                    is_likely_real_scene: true,
+                    ..Default::default()
+                },
+                MetadataEvidence::default(),
+                ProcessingInfo::default(),
+            )
+            .with_detection(DetectionAssessment {
+                algorithm_version: "1.0".to_string(),
+                score: None,
+                verdict,
+                factors: Vec::new(),
+            })
+        };
+
+        assert_eq!(
+            evidence(DetectionVerdict::Pass).calculate_confidence(),
+            ConfidenceLevel::High
+        );
+        assert_eq!(
+            evidence(DetectionVerdict::Inconclusive).calculate_confidence(),
+            ConfidenceLevel::High
+        );
+        assert_eq!(
+            evidence(DetectionVerdict::Caution).calculate_confidence(),
+            ConfidenceLevel::Medium
+        );
+        assert_eq!(
+            evidence(DetectionVerdict::Critical).calculate_confidence(),
+            ConfidenceLevel::Suspicious
+        );
+    }
+
+    #[test]
+    fn test_depth_analysis_trust_serialization() {
+        let depth = DepthAnalysis {
+            analysis_trust: Some(0.5),
+            plausibility_issues: vec!["depth_layers 40 impossible".to_string()],
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&depth).unwrap();
+        assert_eq!(json["analysis_trust"], 0.5);
+        assert_eq!(json["plausibility_issues"][0], "depth_layers 40 impossible");
+
+        let legacy = serde_json::to_value(DepthAnalysis::default()).unwrap();
+        assert!(legacy.get("analysis_trust").is_none());
+        assert!(legacy.get("plausibility_issues").is_none());
+    }
+
+    #[test]
+    fn test_evidence_without_hash_chain_omits_field() {
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::unavailable(
+                "iPhone 15 Pro".to_string(),
+                AttestationLevel::SecureEnclave,
+            ),
+            DepthAnalysis::default(),
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        let json = serde_json::to_value(&evidence).unwrap();
+        assert!(json.get("hash_chain").is_none());
+
+        let parsed: EvidencePackage = serde_json::from_value(json).unwrap();
+        assert!(parsed.hash_chain.is_none());
+    }
+
     #[test]
     fn test_backward_compatibility_deserialize_depth_without_method() {
         // Legacy depth analysis without method field should work
@@ -1231,7 +2086,7 @@ mod tests {
         let evidence: EvidencePackage = serde_json::from_str(json).unwrap();
 
         // Platform should default to "ios"
-        assert_eq!(evidence.platform, "ios");
+        assert_eq!(evidence.platform, Platform::Ios);
 
         // Confidence should be HIGH (both hw and depth pass)
         assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::High);
@@ -1270,7 +2125,7 @@ mod tests {
         let json = serde_json::to_string(&original).unwrap();
         let parsed: EvidencePackage = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(parsed.platform, "ios");
+        assert_eq!(parsed.platform, Platform::Ios);
         assert_eq!(parsed.depth_analysis.method, Some("lidar".to_string()));
         assert_eq!(
             parsed.calculate_confidence(),
@@ -1290,7 +2145,7 @@ mod tests {
         let json = serde_json::to_string(&original).unwrap();
         let parsed: EvidencePackage = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(parsed.platform, "android");
+        assert_eq!(parsed.platform, Platform::Android);
         assert_eq!(
             parsed.depth_analysis.unavailable_reason,
             Some("android_no_lidar".to_string())
@@ -1371,7 +2226,7 @@ mod tests {
         let evidence: EvidencePackage = serde_json::from_str(legacy_json).unwrap();
 
         // AC 2.1: Platform defaults to "ios"
-        assert_eq!(evidence.platform, "ios");
+        assert_eq!(evidence.platform, Platform::Ios);
 
         // AC 2.2: Method defaults to None
         assert_eq!(evidence.depth_analysis.method, None);
@@ -1435,7 +2290,7 @@ mod tests {
         }"#;
 
         let evidence: EvidencePackage = serde_json::from_str(json).unwrap();
-        assert_eq!(evidence.platform, "ios");
+        assert_eq!(evidence.platform, Platform::Ios);
         assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Medium);
     }
 
@@ -1477,7 +2332,7 @@ mod tests {
         }"#;
 
         let evidence: EvidencePackage = serde_json::from_str(json).unwrap();
-        assert_eq!(evidence.platform, "ios");
+        assert_eq!(evidence.platform, Platform::Ios);
         assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Suspicious);
     }
 
@@ -1527,4 +2382,222 @@ mod tests {
         // Verify platform field is present in response
         assert!(response_json.contains("\"platform\":\"ios\""));
     }
+
+    // ========================================================================
+    // Schema Versioning Tests
+    // ========================================================================
+
+    #[test]
+    fn test_new_evidence_carries_schema_version() {
+        let evidence = EvidencePackage::for_android(
+            HardwareAttestation::pass("Pixel 8".to_string(), AttestationLevel::StrongBox),
+            DepthAnalysis::default(),
+            MetadataEvidence::default(),
+            ProcessingInfo::new(10, "0.1.0"),
+        );
+        let json = serde_json::to_value(&evidence).unwrap();
+
+        assert_eq!(json["schema_version"], EVIDENCE_SCHEMA_VERSION);
+        assert_eq!(json["platform"], "android");
+        assert_eq!(json["capture_type"], "photo");
+        assert_eq!(json["capture_mode"], "full");
+    }
+
+    #[test]
+    fn test_unversioned_evidence_deserializes_as_legacy() {
+        let json = r#"{
+            "hardware_attestation": {"status": "pass", "level": "secure_enclave", "device_model": "iPhone 15 Pro", "assertion_verified": true, "counter_valid": true},
+            "depth_analysis": {"status": "pass", "depth_variance": 2.4, "depth_layers": 5, "edge_coherence": 0.87, "min_depth": 0.8, "max_depth": 4.2, "is_likely_real_scene": true},
+            "metadata": {"timestamp_valid": true, "timestamp_delta_seconds": 0, "model_verified": true, "model_name": "iPhone 15 Pro", "resolution_valid": true, "location_available": false, "location_opted_out": false},
+            "processing": {"processed_at": "2025-01-01T00:00:00Z", "processing_time_ms": 100, "backend_version": "0.1.0"}
+        }"#;
+        let evidence: EvidencePackage = serde_json::from_str(json).unwrap();
+
+        assert_eq!(evidence.schema_version, 1);
+        assert_eq!(evidence.platform, Platform::Ios);
+        assert_eq!(evidence.capture_type, CaptureType::Photo);
+        assert!(evidence.video.is_none());
+    }
+
+    #[test]
+    fn test_hash_chain_status_is_typed() {
+        let chain = HashChainEvidence::fail("chain broken");
+        let json = serde_json::to_value(&chain).unwrap();
+        assert_eq!(json["status"], "fail");
+
+        let parsed: HashChainEvidence = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.status, HashChainStatus::Fail);
+
+        let mut bad = serde_json::to_value(&chain).unwrap();
+        bad["status"] = "broken".into();
+        assert!(serde_json::from_value::<HashChainEvidence>(bad).is_err());
+    }
+
+    // ========================================================================
+    // Video Evidence Tests
+    // ========================================================================
+
+    #[test]
+    fn test_confidence_level_round_trip() {
+        for level in [
+            ConfidenceLevel::High,
+            ConfidenceLevel::Medium,
+            ConfidenceLevel::Low,
+            ConfidenceLevel::Suspicious,
+        ] {
+            let json = serde_json::to_string(&level).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ConfidenceLevel>(&json).unwrap(),
+                level
+            );
+        }
+    }
+
+    #[test]
+    fn test_hash_chain_evidence_fail() {
+        let chain = HashChainEvidence::fail("test failure");
+        assert_eq!(chain.status, HashChainStatus::Fail);
+        assert!(!chain.chain_intact);
+        assert!(!chain.attestation_valid);
+        assert_eq!(chain.verified_frames, 0);
+        assert_eq!(chain.partial_reason, Some("test failure".to_string()));
+    }
+
+    #[test]
+    fn test_partial_attestation_complete() {
+        let partial = PartialAttestationInfo::complete(450);
+        assert!(!partial.is_partial);
+        assert_eq!(partial.verified_frames, 450);
+        assert_eq!(partial.total_frames, 450);
+        assert!(partial.checkpoint_index.is_none());
+        assert!(partial.reason.is_none());
+    }
+
+    #[test]
+    fn test_partial_attestation_partial() {
+        let partial = PartialAttestationInfo::partial(1, 300, 450);
+        assert!(partial.is_partial);
+        assert_eq!(partial.checkpoint_index, Some(1));
+        assert_eq!(partial.verified_frames, 300);
+        assert_eq!(partial.total_frames, 450);
+        assert_eq!(partial.reason, Some("checkpoint_attestation".to_string()));
+    }
+
+    #[test]
+    fn test_processing_info_with_checks() {
+        let info = ProcessingInfo::new(1500, "0.1.0").with_checks(vec!["hardware".to_string()]);
+        assert_eq!(info.processing_time_ms, 1500);
+        assert_eq!(info.backend_version, "0.1.0");
+        assert_eq!(info.checks_performed, vec!["hardware".to_string()]);
+    }
+
+    #[test]
+    fn test_temporal_depth_evidence_from_analysis() {
+        let analysis = VideoDepthAnalysis {
+            frame_analyses: vec![],
+            depth_consistency: 0.85,
+            motion_coherence: 0.72,
+            scene_stability: 0.95,
+            is_likely_real_scene: true,
+            suspicious_frames: vec![4],
+        };
+
+        let evidence = TemporalDepthEvidence::from_analysis(&analysis);
+        assert_eq!(evidence.depth_consistency, 0.85);
+        assert_eq!(evidence.motion_coherence, 0.72);
+        assert_eq!(evidence.scene_stability, 0.95);
+        assert!(evidence.is_likely_real_scene);
+        assert_eq!(evidence.suspicious_frames, vec![4]);
+    }
+
+    #[test]
+    fn test_metadata_evidence_round_trip() {
+        let metadata = MetadataEvidence {
+            model_name: "iPhone 15 Pro".to_string(),
+            timestamp_valid: true,
+            location_available: true,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&metadata).unwrap();
+        let parsed: MetadataEvidence = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.model_name, "iPhone 15 Pro");
+        assert!(parsed.timestamp_valid);
+        assert!(parsed.location_available);
+        assert!(parsed.location_plausible.is_none());
+    }
+
+    #[test]
+    fn test_video_evidence_serialization_round_trip() {
+        let hash_chain = HashChainEvidence {
+            status: HashChainStatus::Pass,
+            verified_frames: 450,
+            total_frames: 450,
+            chain_intact: true,
+            attestation_valid: true,
+            partial_reason: None,
+            verified_duration_ms: 15000,
+            checkpoint_verified: false,
+            checkpoint_index: None,
+            derivation_verified: true,
+            genesis_bound: true,
+            broken_at_frame: None,
+        };
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            DepthAnalysis::default(),
+            MetadataEvidence::default(),
+            ProcessingInfo::new(1000, "0.1.0").with_checks(vec!["hash_chain".to_string()]),
+        )
+        .with_video(VideoDetails {
+            duration_ms: 15000,
+            frame_count: 450,
+            temporal_depth: None,
+            partial_attestation: PartialAttestationInfo::complete(450),
+        })
+        .with_hash_chain(hash_chain);
+
+        let json = serde_json::to_string(&evidence).unwrap();
+        assert!(json.contains("\"capture_type\":\"video\""));
+        assert!(json.contains("\"duration_ms\":15000"));
+        assert!(json.contains("\"hardware_attestation\""));
+        assert!(json.contains("\"hash_chain\""));
+
+        let parsed: EvidencePackage = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.capture_type, CaptureType::Video);
+        let video = parsed.video.unwrap();
+        assert_eq!(video.frame_count, 450);
+        assert!(!video.partial_attestation.is_partial);
+        let chain = parsed.hash_chain.unwrap();
+        assert_eq!(chain.status, HashChainStatus::Pass);
+        assert!(chain.genesis_bound);
+        assert_eq!(chain.verified_duration_ms, 15000);
+    }
+
+    #[test]
+    fn test_evidence_schema_describes_versioned_package() {
+        let schema = evidence_json_schema();
+
+        assert_eq!(schema["$id"], EVIDENCE_SCHEMA_ID);
+        assert_eq!(schema["x-schema-version"], EVIDENCE_SCHEMA_VERSION);
+        assert!(schema["properties"]["schema_version"].is_object());
+        assert!(schema["properties"]["video"].is_object());
+        let status = serde_json::to_string(&schema["$defs"]["CheckStatus"]).unwrap();
+        assert!(status.contains("\"pending\""));
+    }
+
+    /// Regenerate with `UPDATE_EVIDENCE_SCHEMA=1 cargo test evidence_schema`
+    #[test]
+    fn test_committed_evidence_schema_is_current() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/schemas/evidence.schema.json");
+        let generated = serde_json::to_string_pretty(&evidence_json_schema()).unwrap() + "\n";
+        if std::env::var_os("UPDATE_EVIDENCE_SCHEMA").is_some() {
+            std::fs::write(path, &generated).unwrap();
+        }
+        let committed = std::fs::read_to_string(path).unwrap_or_default();
+        assert_eq!(
+            committed, generated,
+            "schemas/evidence.schema.json is stale; rerun with UPDATE_EVIDENCE_SCHEMA=1"
+        );
+    }
 }
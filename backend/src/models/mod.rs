@@ -4,19 +4,29 @@
 //! All models derive `sqlx::FromRow` for compile-time checked queries.
 
 mod capture;
+mod confidence_score;
 mod debug_log;
 mod device;
 mod evidence;
 mod verification_log;
 
 pub use capture::{Capture, CreateCaptureParams};
+pub use confidence_score::{
+    ConfidenceScore, FactorEffect, ScoreBuilder, ScoreFactor, HIGH_MIN_SCORE, MEDIUM_MIN_SCORE,
+    SUSPICIOUS_MAX_SCORE,
+};
 pub use debug_log::{
     BatchInsertResponse, CreateDebugLog, DebugLog, DebugLogDelete, DebugLogQuery, DebugLogStats,
-    DeleteResponse, LevelCounts, LogLevel, LogSource, QueryLogsResponse, SourceCounts,
+    DeleteResponse, LevelCounts, LogCursor, LogLevel, LogSource, QueryLogsResponse, SessionSummary,
+    SessionTimelineResponse, SourceCounts,
 };
 pub use device::Device;
 pub use evidence::{
-    AttestationLevel, CheckStatus, ConfidenceLevel, DepthAnalysis, EvidencePackage,
-    HardwareAttestation, MetadataEvidence, ProcessingInfo, SecurityLevelInfo,
+    evidence_json_schema, AttestationLevel, CaptureType, CheckStatus, ConfidenceLevel,
+    DepthAnalysis, DetectionAssessment, DetectionFactor, DetectionVerdict, EvidencePackage,
+    HardwareAttestation, HashChainEvidence, HashChainStatus, KeymasterLevel, MetadataEvidence,
+    PartialAttestationInfo, Platform, ProcessingInfo, SecurityLevelInfo, TemporalDepthEvidence,
+    TimestampConsistency, VideoDetails, ANALYSIS_TRUST_FULL, ANALYSIS_TRUST_MIN,
+    EVIDENCE_SCHEMA_VERSION,
 };
 pub use verification_log::VerificationLog;
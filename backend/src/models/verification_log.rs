@@ -18,12 +18,21 @@ pub struct VerificationLog {
     /// ID of the capture being verified (if applicable)
     pub capture_id: Option<Uuid>,
 
-    /// Type of action performed (e.g., "verify", "upload", "check")
+    /// Type of action performed ("verify_file", "verify", "upload", "register")
     pub action: String,
 
-    /// Client IP address (PostgreSQL INET type stored as String)
+    /// Result of the action (e.g., "verified", "no_record", "rejected")
+    pub outcome: String,
+
+    /// Device that uploaded or registered, if any
+    pub device_id: Option<Uuid>,
+
+    /// Truncated client IP address (PostgreSQL INET type stored as String)
     pub client_ip: Option<String>,
 
+    /// Salted hash of the client IP (when IPs are hashed instead of truncated)
+    pub client_ip_hash: Option<String>,
+
     /// HTTP User-Agent header from the request
     pub user_agent: Option<String>,
 
@@ -5,6 +5,8 @@
 //! ## Endpoints
 //! - POST /api/v1/captures - Upload a new capture with photo, depth map, and metadata
 //! - GET /api/v1/captures/{id} - Get capture by ID
+//! - GET /api/v1/captures/{id}/depth - Download the raw depth map (owner only)
+//! - GET /api/v1/captures/{id}/verifications - Verification history (owner only)
 //!
 //! ## Authentication
 //! All endpoints require device authentication via DeviceAuthLayer middleware.
@@ -17,7 +19,8 @@
 
 use axum::{
     extract::{Extension, Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
@@ -28,19 +31,27 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
-use crate::middleware::{lookup_device, update_device_counter, DeviceContext};
+use crate::middleware::{
+    lookup_attested_model, lookup_device, update_device_counter, AuditOutcome, DeviceContext,
+};
 use crate::models::{EvidencePackage, HardwareAttestation, ProcessingInfo};
+use crate::routes::verify::original_media_url;
 use crate::routes::AppState;
 use crate::services::{
-    analyze_depth_map_from_bytes, process_location_for_evidence, validate_metadata,
-    verify_capture_assertion,
+    analyze_depth_map_from_bytes, analyze_photo_moire, apply_model_identity,
+    capture_verification_summary, check_device_location, check_device_timestamp,
+    check_model_identity, compare_client_moire, coordinate_literal_digits, delete_capture,
+    process_location_for_evidence, server_detection_flags, spawn_derivatives, validate_metadata,
+    verify_capture_assertion, CaptureVerificationSummary, DeletedBy, DerivativeJob,
+    LocationPrivacyBounds, MediaAsset, RegisteredIdentity,
 };
 
 /// Backend version for processing info (from Cargo.toml)
 const BACKEND_VERSION: &str = env!("CARGO_PKG_VERSION");
 use crate::types::{
     capture::{validate_depth_map_size, validate_photo_size},
-    ApiResponse, CaptureMetadataPayload, CaptureUploadResponse, DetectionResults,
+    ApiResponse, CaptureDeletedResponse, CaptureMetadataPayload, CaptureUploadResponse,
+    DetectionResults,
 };
 
 // ============================================================================
@@ -53,10 +64,14 @@ use crate::types::{
 /// Routes:
 /// - POST / - Upload a new capture (protected by DeviceAuthLayer)
 /// - GET /{id} - Get capture by ID (protected by DeviceAuthLayer)
+/// - GET /{id}/depth - Raw depth map for the owning device (protected by DeviceAuthLayer)
+/// - DELETE /{id} - Delete a capture owned by the device (protected by DeviceAuthLayer)
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(upload_capture))
-        .route("/{id}", get(get_capture))
+        .route("/{id}", get(get_capture).delete(delete_capture_by_owner))
+        .route("/{id}/depth", get(get_capture_depth))
+        .route("/{id}/verifications", get(get_capture_verifications))
 }
 
 // ============================================================================
@@ -68,6 +83,8 @@ struct ParsedMultipart {
     photo_bytes: Vec<u8>,
     depth_map_bytes: Vec<u8>,
     metadata: CaptureMetadataPayload,
+    /// Significant digits of the submitted coordinate literals
+    coordinate_digits: Option<usize>,
     /// Optional multi-signal detection results from iOS (Story 9-7)
     detection: Option<DetectionResults>,
 }
@@ -83,6 +100,7 @@ async fn parse_multipart(mut multipart: Multipart) -> Result<ParsedMultipart, Ap
     let mut photo_bytes: Option<Vec<u8>> = None;
     let mut depth_map_bytes: Option<Vec<u8>> = None;
     let mut metadata: Option<CaptureMetadataPayload> = None;
+    let mut coordinate_digits: Option<usize> = None;
     let mut detection: Option<DetectionResults> = None;
 
     while let Some(field) = multipart.next_field().await.map_err(|e| {
@@ -130,6 +148,7 @@ async fn parse_multipart(mut multipart: Multipart) -> Result<ParsedMultipart, Ap
                 // Validate metadata fields
                 parsed.validate()?;
 
+                coordinate_digits = coordinate_literal_digits(&text);
                 metadata = Some(parsed);
 
                 tracing::debug!("Metadata field parsed and validated");
@@ -213,6 +232,7 @@ async fn parse_multipart(mut multipart: Multipart) -> Result<ParsedMultipart, Ap
         photo_bytes,
         depth_map_bytes,
         metadata,
+        coordinate_digits,
         detection,
     })
 }
@@ -231,10 +251,17 @@ struct InsertCaptureWithEvidenceParams {
     pub depth_map_s3_key: String,
     pub captured_at: chrono::DateTime<chrono::Utc>,
     pub location_precise: Option<serde_json::Value>,
+    pub location_coarse: Option<String>,
+    pub location_label: Option<String>,
+    pub assertion_counter: Option<i64>,
     pub evidence: serde_json::Value,
     pub confidence_level: String,
     /// Multi-signal detection results from iOS (Story 9-7)
     pub detection_results: Option<serde_json::Value>,
+    /// Server-side detection results computed from the photo
+    pub server_detection_results: Option<serde_json::Value>,
+    /// Master key wrapping the capture data key (None if unencrypted)
+    pub data_key_id: Option<String>,
 }
 
 /// Inserts a new capture record into the database with evidence
@@ -253,9 +280,10 @@ async fn insert_capture_with_evidence(
         INSERT INTO captures (
             id, device_id, target_media_hash, photo_s3_key, depth_map_s3_key,
             evidence, confidence_level, status, location_precise, captured_at,
-            detection_results
+            detection_results, location_coarse, location_label, assertion_counter,
+            server_detection_results, data_key_id
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
         RETURNING id
         "#,
     )
@@ -270,6 +298,11 @@ async fn insert_capture_with_evidence(
     .bind(&params.location_precise)
     .bind(params.captured_at)
     .bind(&params.detection_results) // Story 9-7: Multi-signal detection results
+    .bind(&params.location_coarse)
+    .bind(&params.location_label)
+    .bind(params.assertion_counter)
+    .bind(&params.server_detection_results)
+    .bind(&params.data_key_id)
     .fetch_one(pool)
     .await
     .map_err(|e| {
@@ -308,7 +341,14 @@ async fn upload_capture(
     Extension(request_id): Extension<Uuid>,
     Extension(device_ctx): Extension<DeviceContext>,
     multipart: Multipart,
-) -> Result<(StatusCode, Json<ApiResponse<CaptureUploadResponse>>), ApiErrorWithRequestId> {
+) -> Result<
+    (
+        StatusCode,
+        AuditOutcome,
+        Json<ApiResponse<CaptureUploadResponse>>,
+    ),
+    ApiErrorWithRequestId,
+> {
     // Start timing for processing info (Story 4-7)
     let processing_start = std::time::Instant::now();
 
@@ -388,12 +428,29 @@ async fn upload_capture(
     let storage = &state.storage;
     let config = &state.config;
 
+    // Per-capture data key sealing the media and precise location at rest
+    let data_key = match &state.envelope {
+        Some(envelope) => {
+            Some(
+                envelope
+                    .generate_data_key()
+                    .await
+                    .map_err(|e| ApiErrorWithRequestId {
+                        error: ApiError::Internal(e.into()),
+                        request_id,
+                    })?,
+            )
+        }
+        None => None,
+    };
+
     // Upload files to S3
     let (photo_s3_key, depth_map_s3_key) = storage
         .upload_capture_files(
             capture_id,
             parsed.photo_bytes.clone(),
             parsed.depth_map_bytes.clone(),
+            data_key.as_ref(),
         )
         .await
         .map_err(|e| ApiErrorWithRequestId {
@@ -464,6 +521,9 @@ async fn upload_capture(
         }
     }
 
+    // Counter of the verified assertion orders this capture in device history
+    let assertion_counter = assertion_result.new_counter.map(i64::from);
+
     // Build hardware attestation evidence from assertion result
     let hardware_attestation: HardwareAttestation = assertion_result.into();
 
@@ -497,22 +557,134 @@ async fn upload_capture(
     // End Story 4.5 additions
     // ========================================================================
 
+    // ========================================================================
+    // Server-side Moire Detection
+    // ========================================================================
+    // Independently analyze the photo for moire/halftone patterns and compare
+    // with the client-reported result. This is NON-BLOCKING.
+
+    let photo_bytes = parsed.photo_bytes.clone();
+    let server_detection =
+        match tokio::task::spawn_blocking(move || analyze_photo_moire(&photo_bytes)).await {
+            Ok(mut results) => {
+                results.client_agreement =
+                    compare_client_moire(&results.moire, parsed.detection.as_ref());
+                tracing::info!(
+                    request_id = %request_id,
+                    capture_id = %capture_id,
+                    moire_status = ?results.moire.status,
+                    moire_detected = results.moire.detected,
+                    moire_confidence = results.moire.confidence,
+                    halftone_detected = results.halftone_detected,
+                    client_agreement = ?results.client_agreement,
+                    "[moire_detection] Server-side moire analysis completed"
+                );
+                Some(results)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    request_id = %request_id,
+                    capture_id = %capture_id,
+                    error = %e,
+                    "[moire_detection] Server-side moire analysis task failed (non-blocking)"
+                );
+                None
+            }
+        };
+
     // ========================================================================
     // STORY 4.6: Metadata Validation
     // ========================================================================
     // Validate capture metadata (timestamp, device model, location, resolution).
     // This is NON-BLOCKING: failures do not reject the upload.
 
-    let metadata_evidence = validate_metadata(&parsed.metadata);
+    let mut metadata_evidence = validate_metadata(&parsed.metadata, &state.device_registry);
+
+    // Cross-check the claimed model against the registered device identity.
+    // Lookup failure only loses the attestation match, never fails the upload.
+    let attested_model = lookup_attested_model(&state.db, device.id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                request_id = %request_id,
+                device_id = %device.id,
+                error = %e,
+                "[metadata_validation] Failed to load attested device model"
+            );
+            None
+        });
+    let identity = check_model_identity(
+        &parsed.metadata.device_model,
+        &RegisteredIdentity {
+            platform: &device.platform,
+            model: &device.model,
+            attested_model: attested_model.as_deref(),
+        },
+        &state.device_registry,
+    );
+    apply_model_identity(&mut metadata_evidence, identity);
+
+    // Check captured_at against the device's registration and capture history,
+    // and the precise location against the device's neighboring captures
+    if let Ok(captured_at) = parsed.metadata.captured_at_datetime() {
+        if let Some(location) = parsed.metadata.location.as_ref() {
+            match check_device_location(
+                &state.db,
+                state.envelope.as_deref(),
+                device.id,
+                location,
+                captured_at,
+                parsed.coordinate_digits,
+            )
+            .await
+            {
+                Ok(plausibility) => {
+                    metadata_evidence.location_plausible = Some(plausibility.plausible);
+                    metadata_evidence.location_plausibility_reason = plausibility.reason;
+                }
+                Err(e) => tracing::warn!(
+                    request_id = %request_id,
+                    device_id = %device.id,
+                    error = %e,
+                    "[location_plausibility] Failed to load device location history"
+                ),
+            }
+        }
+
+        match check_device_timestamp(
+            &state.db,
+            device.id,
+            device.first_seen_at,
+            captured_at,
+            assertion_counter,
+        )
+        .await
+        {
+            Ok(consistency) => metadata_evidence.timestamp_consistency = Some(consistency),
+            Err(e) => tracing::warn!(
+                request_id = %request_id,
+                device_id = %device.id,
+                error = %e,
+                "[timestamp_consistency] Failed to load device capture history"
+            ),
+        }
+    }
 
     tracing::info!(
         request_id = %request_id,
         capture_id = %capture_id,
         timestamp_valid = metadata_evidence.timestamp_valid,
         timestamp_delta = metadata_evidence.timestamp_delta_seconds,
+        timestamp_consistent = metadata_evidence
+            .timestamp_consistency
+            .as_ref()
+            .map(|c| c.consistent),
         model_verified = metadata_evidence.model_verified,
+        model_attested = metadata_evidence.model_attested,
+        model_mismatch = metadata_evidence.model_mismatch,
         resolution_valid = metadata_evidence.resolution_valid,
         location_available = metadata_evidence.location_available,
+        location_plausible = ?metadata_evidence.location_plausible,
         "[metadata_validation] Metadata validation completed"
     );
 
@@ -523,19 +695,33 @@ async fn upload_capture(
     // ========================================================================
     // STORY 4.8: Privacy Controls
     // ========================================================================
-    // Apply location coarsening for privacy protection.
-    // Precise location stored separately; coarse location in evidence package.
+    // Apply the location privacy policy (device-requested or server default,
+    // clamped to server bounds). Precise location stored separately; the
+    // generalized location and the applied policy go in the evidence package.
+
+    let protected_location = process_location_for_evidence(
+        parsed.metadata.location.as_ref(),
+        parsed.metadata.location_privacy,
+        &LocationPrivacyBounds::from_config(&state.config),
+    );
+    let location_coarse = protected_location.as_ref().map(|p| p.display.clone());
 
-    let location_coarse = process_location_for_evidence(parsed.metadata.location.as_ref());
+    // Label the published (not precise) location from the offline gazetteer
+    let location_label = protected_location
+        .as_ref()
+        .and_then(|p| state.gazetteer.label(p.latitude, p.longitude));
 
     // Update metadata evidence with coarsened location
-    let mut metadata_evidence = metadata_evidence;
     metadata_evidence.location_coarse = location_coarse.clone();
+    metadata_evidence.location_privacy = protected_location.map(|p| p.evidence);
+    metadata_evidence.location_label = location_label.clone();
 
     tracing::info!(
         request_id = %request_id,
         capture_id = %capture_id,
         location_coarse = ?location_coarse,
+        location_label = ?location_label,
+        location_policy = ?metadata_evidence.location_privacy.as_ref().map(|p| p.policy.to_string()),
         location_opted_out = metadata_evidence.location_opted_out,
         "[privacy] Location privacy controls applied"
     );
@@ -571,10 +757,50 @@ async fn upload_capture(
         depth_analysis,
         metadata_evidence,
         processing_info,
+    )
+    .with_confidence_flags(
+        server_detection
+            .as_ref()
+            .map(server_detection_flags)
+            .unwrap_or_default(),
     );
 
-    // Calculate confidence level based on evidence
-    let confidence_level = evidence_package.calculate_confidence();
+    // Merge client multi-signal detection under the scoring policy
+    let evidence_package = match parsed.detection.as_ref() {
+        Some(detection) => {
+            let assessment = state.detection_policy.assess(detection);
+            tracing::info!(
+                request_id = %request_id,
+                capture_id = %capture_id,
+                algorithm_version = %assessment.algorithm_version,
+                detection_score = ?assessment.score,
+                detection_verdict = ?assessment.verdict,
+                "[detection] Detection signals merged into confidence"
+            );
+            evidence_package.with_detection(assessment)
+        }
+        None => evidence_package,
+    };
+
+    // Calculate confidence score based on evidence
+    let mut confidence_score = evidence_package.calculate_score();
+
+    // Cap confidence for unverified devices (Phase 3 hardening)
+    // Devices that haven't passed full attestation verification cannot achieve High confidence
+    if !device_ctx.is_verified && confidence_score.level == crate::models::ConfidenceLevel::High {
+        tracing::info!(
+            request_id = %request_id,
+            capture_id = %capture_id,
+            "Capping confidence from High to Medium for unverified device"
+        );
+        confidence_score = confidence_score.capped(
+            crate::models::ConfidenceLevel::Medium,
+            "device",
+            "device attestation not fully verified",
+        );
+    }
+    let confidence_level = confidence_score.level;
+    let evidence_package = evidence_package.with_confidence_score(confidence_score);
 
     tracing::info!(
         request_id = %request_id,
@@ -614,14 +840,18 @@ async fn upload_capture(
     // This ensures we store what we actually received, not what the client claimed
     let photo_hash_bytes = computed_hash.to_vec();
 
-    // Prepare location data if present
+    // Prepare location data if present (sealed when encrypting at rest)
     let location_precise = parsed.metadata.location.as_ref().map(|loc| {
-        json!({
+        let location = json!({
             "latitude": loc.latitude,
             "longitude": loc.longitude,
             "altitude": loc.altitude,
             "accuracy": loc.accuracy
-        })
+        });
+        match &data_key {
+            Some(data_key) => data_key.seal_json(&location),
+            None => location,
+        }
     });
 
     // Parse captured_at timestamp
@@ -634,24 +864,6 @@ async fn upload_capture(
                 request_id,
             })?;
 
-    // Cap confidence for unverified devices (Phase 3 hardening)
-    // Devices that haven't passed full attestation verification cannot achieve High confidence
-    let confidence_level = if !device_ctx.is_verified {
-        match confidence_level {
-            crate::models::ConfidenceLevel::High => {
-                tracing::info!(
-                    request_id = %request_id,
-                    capture_id = %capture_id,
-                    "Capping confidence from High to Medium for unverified device"
-                );
-                crate::models::ConfidenceLevel::Medium
-            }
-            other => other,
-        }
-    } else {
-        confidence_level
-    };
-
     // Create database record with evidence
     let confidence_str = match confidence_level {
         crate::models::ConfidenceLevel::High => "high",
@@ -671,6 +883,16 @@ async fn upload_capture(
         })
     });
 
+    let server_detection_json = server_detection.as_ref().map(|d| {
+        serde_json::to_value(d).unwrap_or_else(|e| {
+            tracing::warn!(
+                error = %e,
+                "[moire_detection] Failed to serialize server detection results, storing null"
+            );
+            serde_json::Value::Null
+        })
+    });
+
     // Log detection storage
     if let Some(ref detection) = parsed.detection {
         let summary = detection.summary();
@@ -686,6 +908,18 @@ async fn upload_capture(
         );
     }
 
+    // The derivative job takes the data key to seal what it renders
+    let data_key_id = data_key.as_ref().map(|k| k.key_id().to_string());
+    let derivative_job = DerivativeJob::Photo {
+        photo_key: photo_s3_key.clone(),
+        depth_key: depth_map_s3_key.clone(),
+        depth_dimensions: (
+            parsed.metadata.depth_map_dimensions.width,
+            parsed.metadata.depth_map_dimensions.height,
+        ),
+        data_key,
+    };
+
     let db_capture_id = insert_capture_with_evidence(
         &state.db,
         InsertCaptureWithEvidenceParams {
@@ -696,9 +930,14 @@ async fn upload_capture(
             depth_map_s3_key,
             captured_at,
             location_precise,
+            location_coarse,
+            location_label,
+            assertion_counter,
             evidence: evidence_json,
             confidence_level: confidence_str.to_string(),
             detection_results: detection_json, // Story 9-7: Multi-signal detection
+            server_detection_results: server_detection_json,
+            data_key_id,
         },
     )
     .await
@@ -715,6 +954,18 @@ async fn upload_capture(
         "Capture record created in database with evidence"
     );
 
+    // The capture row now keeps its media from deletion
+    state.storage.release_key_leases(capture_id).await;
+
+    // Thumbnails and depth heatmap are rendered off the request path
+    spawn_derivatives(
+        state.db.clone(),
+        state.storage.clone(),
+        config.ffmpeg_path.clone(),
+        db_capture_id,
+        derivative_job,
+    );
+
     // Build response
     let verification_url = format!("{}/{db_capture_id}", config.verification_base_url);
 
@@ -732,6 +983,9 @@ async fn upload_capture(
 
     Ok((
         StatusCode::ACCEPTED,
+        AuditOutcome::new("accepted")
+            .capture(db_capture_id)
+            .device(device_ctx.device_id),
         Json(ApiResponse::new(response_data, request_id)),
     ))
 }
@@ -774,11 +1028,11 @@ async fn get_capture(
         r#"
         SELECT id, device_id, target_media_hash, photo_s3_key, depth_map_s3_key,
                thumbnail_s3_key, evidence, confidence_level, status,
-               location_precise, location_coarse, captured_at, uploaded_at,
+               location_precise, location_coarse, location_label, captured_at, uploaded_at,
                capture_type, video_s3_key, hash_chain_s3_key, duration_ms,
                frame_count, is_partial, checkpoint_index,
                capture_mode, media_stored, analysis_source, metadata_flags,
-               detection_results
+               detection_results, server_detection_results
         FROM captures
         WHERE id = $1
         "#,
@@ -818,7 +1072,8 @@ async fn get_capture(
     let is_hash_only = capture.capture_mode.as_deref() == Some("hash_only");
 
     // For hash-only captures: media_url is null, media_hash is provided
-    // For full captures: media_url would be generated (left as None for now, handled by verification page)
+    // For full captures: media_url is a short-lived presigned URL to the photo or video,
+    // or the decrypting media endpoint when media is encrypted at rest
     let media_hash: Option<String> = if is_hash_only {
         // Convert BYTEA target_media_hash to lowercase hex string
         Some(hex::encode(&capture.target_media_hash))
@@ -826,6 +1081,20 @@ async fn get_capture(
         None
     };
 
+    let media = match (&capture.video_s3_key, &capture.photo_s3_key) {
+        (Some(key), _) => Some((MediaAsset::Video, key.as_str())),
+        (None, Some(key)) => Some((MediaAsset::Photo, key.as_str())),
+        (None, None) => None,
+    };
+    let media_url = match media {
+        Some((asset, key)) if !is_hash_only => {
+            original_media_url(&state, capture_id, asset, Some(key), request_id)
+                .await
+                .map(|(url, _)| url)
+        }
+        _ => None,
+    };
+
     // Story 9-7: Extract detection summary from detection_results
     let (
         detection_available,
@@ -872,11 +1141,12 @@ async fn get_capture(
         captured_at: capture.captured_at.to_rfc3339(),
         uploaded_at: capture.uploaded_at.to_rfc3339(),
         location_coarse: capture.location_coarse,
+        location_label: capture.location_label,
         verification_url,
         // Hash-only fields (Story 8-5)
         capture_mode: capture.capture_mode,
         media_stored: capture.media_stored,
-        media_url: None, // Full captures get URL from verification page, hash-only is always null
+        media_url, // Hash-only is always null
         media_hash,
         metadata_flags: capture.metadata_flags,
         // Detection fields (Story 9-7)
@@ -886,6 +1156,7 @@ async fn get_capture(
         detection_primary_valid,
         detection_signals_agree,
         detection_method_count,
+        server_detection: capture.server_detection_results,
     };
 
     tracing::info!(
@@ -902,6 +1173,204 @@ async fn get_capture(
     Ok((StatusCode::OK, Json(ApiResponse::new(response, request_id))))
 }
 
+/// GET /api/v1/captures/{id}/depth - Download the raw depth map
+///
+/// Depth maps are never linked publicly; only the device that created the
+/// capture can fetch them. Returns the gzipped depth data as stored.
+///
+/// # Responses
+/// - 200 OK: Gzipped depth map (`application/gzip`)
+/// - 400 Bad Request: Invalid capture ID
+/// - 403 Forbidden: Capture belongs to another device
+/// - 404 Not Found: Capture missing or has no stored depth map (hash-only)
+async fn get_capture_depth(
+    State(state): State<AppState>,
+    Extension(device_ctx): Extension<DeviceContext>,
+    Extension(request_id): Extension<Uuid>,
+    Path(id): Path<String>,
+) -> Result<Response, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+
+    let row = sqlx::query_as::<_, (Uuid, Option<String>)>(
+        "SELECT device_id, depth_map_s3_key FROM captures WHERE id = $1",
+    )
+    .bind(capture_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Database(e),
+        request_id,
+    })?;
+
+    let (owner_id, depth_key) = row.ok_or_else(|| ApiErrorWithRequestId {
+        error: ApiError::CaptureNotFound,
+        request_id,
+    })?;
+
+    if owner_id != device_ctx.device_id {
+        tracing::warn!(
+            request_id = %request_id,
+            capture_id = %capture_id,
+            requesting_device_id = %device_ctx.device_id,
+            "Access denied: device does not own capture depth map"
+        );
+        return Err(ApiErrorWithRequestId {
+            error: ApiError::Forbidden("You do not have access to this capture".to_string()),
+            request_id,
+        });
+    }
+
+    let depth_key = depth_key.ok_or_else(|| ApiErrorWithRequestId {
+        error: ApiError::CaptureNotFound,
+        request_id,
+    })?;
+
+    let bytes = state
+        .storage
+        .download(&depth_key)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
+    tracing::info!(
+        request_id = %request_id,
+        capture_id = %capture_id,
+        size_bytes = bytes.len(),
+        "Depth map served to owning device"
+    );
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/gzip"),
+            (header::CACHE_CONTROL, "private, no-store"),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+/// DELETE /api/v1/captures/{id} - Delete a capture
+///
+/// Removes the capture record, its stored media and metadata commitments.
+/// A tombstone with the media hash and evidence digest is kept so
+/// `/verify-file` reports the file as captured and deleted by its owner.
+///
+/// Access control: Only the device that created the capture can delete it.
+async fn delete_capture_by_owner(
+    State(state): State<AppState>,
+    Extension(device_ctx): Extension<DeviceContext>,
+    Extension(request_id): Extension<Uuid>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<CaptureDeletedResponse>>, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+
+    let tombstone = delete_capture(
+        &state.db,
+        &state.storage,
+        capture_id,
+        DeletedBy::Owner,
+        Some(device_ctx.device_id),
+    )
+    .await
+    .map_err(|e| {
+        if matches!(e, ApiError::Forbidden(_)) {
+            tracing::warn!(
+                request_id = %request_id,
+                capture_id = %capture_id,
+                requesting_device_id = %device_ctx.device_id,
+                "Deletion denied: device does not own capture"
+            );
+        }
+        ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        }
+    })?;
+
+    tracing::info!(
+        request_id = %request_id,
+        capture_id = %capture_id,
+        device_id = %device_ctx.device_id,
+        "Capture deleted by owning device"
+    );
+
+    let response = CaptureDeletedResponse {
+        capture_id,
+        deleted_at: tombstone.deleted_at.to_rfc3339(),
+        evidence_digest: hex::encode(&tombstone.evidence_digest),
+    };
+
+    Ok(Json(ApiResponse::new(response, request_id)))
+}
+
+/// GET /api/v1/captures/{id}/verifications - Verification history
+///
+/// How often and when the capture was checked through `/verify/{id}` and
+/// `/verify-file`. Client IPs and user agents are never returned.
+///
+/// Access control: Only the device that created the capture can read it.
+///
+/// # Responses
+/// - 200 OK: Totals, daily counts (last 30 days) and the most recent checks
+/// - 400 Bad Request: Invalid capture ID
+/// - 403 Forbidden: Capture belongs to another device
+/// - 404 Not Found: Capture not found
+async fn get_capture_verifications(
+    State(state): State<AppState>,
+    Extension(device_ctx): Extension<DeviceContext>,
+    Extension(request_id): Extension<Uuid>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<CaptureVerificationSummary>>, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+
+    let owner_id = sqlx::query_scalar::<_, Uuid>("SELECT device_id FROM captures WHERE id = $1")
+        .bind(capture_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: ApiError::Database(e),
+            request_id,
+        })?
+        .ok_or_else(|| ApiErrorWithRequestId {
+            error: ApiError::CaptureNotFound,
+            request_id,
+        })?;
+
+    if owner_id != device_ctx.device_id {
+        tracing::warn!(
+            request_id = %request_id,
+            capture_id = %capture_id,
+            requesting_device_id = %device_ctx.device_id,
+            "Access denied: device does not own capture verification history"
+        );
+        return Err(ApiErrorWithRequestId {
+            error: ApiError::Forbidden("You do not have access to this capture".to_string()),
+            request_id,
+        });
+    }
+
+    let summary = capture_verification_summary(&state.db, capture_id)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: ApiError::Database(e),
+            request_id,
+        })?;
+
+    Ok(Json(ApiResponse::new(summary, request_id)))
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
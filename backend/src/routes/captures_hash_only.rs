@@ -4,35 +4,55 @@
 //! In this mode, clients submit only a hash of the media along with
 //! pre-computed depth analysis - the actual media never touches the server.
 //!
-//! ## Endpoint
+//! ## Endpoints
 //! - POST /api/v1/captures/hash-only - Accept hash-only capture with client analysis
+//! - POST /api/v1/captures/hash-only/{id}/disclosures - Disclose a committed metadata field
 //!
 //! ## Key Differences from Full Captures
 //! - JSON body (not multipart) - no media files uploaded
 //! - Assertion verification is BLOCKING (returns 401 on failure)
 //! - No S3 storage operations - significantly faster processing
-//! - Depth analysis comes from client, not computed server-side
+//! - Depth analysis comes from client, not computed server-side; it is
+//!   checked for plausibility and scored with `analysis_trust`
+//! - Video hash chains are verified in no-media mode: checkpoint assertions
+//!   and the assertion-bound final hash, not client summary numbers
+//! - Metadata fields may be withheld behind salted commitments and disclosed
+//!   later by the owning device
 
 use axum::{
-    extract::{Extension, State},
+    extract::{Extension, Path, State},
     http::StatusCode,
     routing::post,
     Json, Router,
 };
-use sqlx::PgPool;
+use rand::{rngs::OsRng, RngCore};
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
-use crate::middleware::{lookup_device, update_device_counter, DeviceContext};
+use crate::middleware::{
+    lookup_attested_model, lookup_device, update_device_counter, AuditOutcome, DeviceContext,
+};
+use crate::models::Device;
 use crate::models::{
     CheckStatus, ConfidenceLevel, DepthAnalysis, EvidencePackage, HardwareAttestation,
-    MetadataEvidence, ProcessingInfo,
+    HashChainEvidence, MetadataEvidence, PartialAttestationInfo, ProcessingInfo, VideoDetails,
 };
 use crate::routes::AppState;
-use crate::services::{c2pa_manifest_s3_key, verify_hash_only_assertion, C2paService};
+use crate::services::{
+    apply_model_identity, c2pa_manifest_s3_key, check_depth_plausibility, check_device_location,
+    check_device_timestamp, check_model_identity, load_model_depth_history, protect_location,
+    verify_hash_only_assertion, verify_hash_only_checkpoint_assertions,
+    verify_hash_only_final_hash_binding, verify_metadata_disclosure, C2paService,
+    HashChainVerifier, LocationPrivacyBounds, RegisteredIdentity,
+};
+use crate::types::capture::CaptureLocation;
 use crate::types::hash_only::AnalysisSource;
 use crate::types::{
-    ApiResponse, HashOnlyCapturePayload, HashOnlyCaptureResponse, InsertHashOnlyCaptureParams,
+    ApiResponse, CaptureMode, CommitmentField, HashChainVerificationError, HashOnlyCapturePayload,
+    HashOnlyCaptureResponse, InsertHashOnlyCaptureParams, LocationPrivacyEvidence,
+    MetadataCommitments, MetadataDisclosure, MetadataDisclosureRequest, VideoAttestation,
+    VideoHashChainData,
 };
 
 /// Backend version for processing info
@@ -46,8 +66,11 @@ const BACKEND_VERSION: &str = env!("CARGO_PKG_VERSION");
 ///
 /// Routes:
 /// - POST / - Accept hash-only capture (protected by DeviceAuthLayer)
+/// - POST /{id}/disclosures - Disclose a committed metadata field (owner only)
 pub fn router() -> Router<AppState> {
-    Router::new().route("/", post(upload_hash_only_capture))
+    Router::new()
+        .route("/", post(upload_hash_only_capture))
+        .route("/{id}/disclosures", post(disclose_metadata_field))
 }
 
 // ============================================================================
@@ -56,7 +79,7 @@ pub fn router() -> Router<AppState> {
 
 /// Inserts a hash-only capture record into the database
 async fn insert_hash_only_capture(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     params: InsertHashOnlyCaptureParams,
 ) -> Result<Uuid, ApiError> {
     let capture_id = params.capture_id;
@@ -67,9 +90,9 @@ async fn insert_hash_only_capture(
         INSERT INTO captures (
             id, device_id, target_media_hash, evidence, confidence_level, status,
             captured_at, capture_mode, media_stored, analysis_source, metadata_flags,
-            location_coarse
+            location_coarse, location_label, assertion_counter
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
         RETURNING id
         "#,
     )
@@ -85,7 +108,9 @@ async fn insert_hash_only_capture(
     .bind("device") // analysis_source
     .bind(&params.metadata_flags)
     .bind(&params.location_coarse)
-    .fetch_one(pool)
+    .bind(&params.location_label)
+    .bind(params.assertion_counter)
+    .fetch_one(conn)
     .await
     .map_err(|e| {
         tracing::error!(error = %e, "Failed to insert hash-only capture record");
@@ -93,6 +118,93 @@ async fn insert_hash_only_capture(
     })
 }
 
+/// Inserts the metadata commitments for a hash-only capture
+async fn insert_metadata_commitments(
+    conn: &mut PgConnection,
+    capture_id: Uuid,
+    commitments: &MetadataCommitments,
+) -> Result<(), ApiError> {
+    for (field, commitment) in commitments.entries() {
+        let commitment_bytes = hex::decode(commitment)
+            .map_err(|e| ApiError::Validation(format!("Invalid {field} commitment hex: {e}")))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO metadata_commitments (capture_id, field, commitment)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(capture_id)
+        .bind(field.to_string())
+        .bind(commitment_bytes)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to insert metadata commitment");
+            ApiError::Database(e)
+        })?;
+    }
+    Ok(())
+}
+
+/// Stored commitment for a capture field, with its owning device
+#[derive(sqlx::FromRow)]
+struct CommitmentRecord {
+    device_id: Uuid,
+    commitment: Option<Vec<u8>>,
+    disclosed_value: Option<String>,
+}
+
+/// Loads the commitment for a capture field
+///
+/// Returns `None` if the hash-only capture does not exist. `commitment` is
+/// `None` when the capture exists but did not commit to the field.
+async fn load_commitment(
+    pool: &PgPool,
+    capture_id: Uuid,
+    field: CommitmentField,
+) -> Result<Option<CommitmentRecord>, ApiError> {
+    sqlx::query_as::<_, CommitmentRecord>(
+        r#"
+        SELECT c.device_id, mc.commitment, mc.disclosed_value
+        FROM captures c
+        LEFT JOIN metadata_commitments mc
+            ON mc.capture_id = c.id AND mc.field = $2
+        WHERE c.id = $1 AND c.capture_mode = 'hash_only'
+        "#,
+    )
+    .bind(capture_id)
+    .bind(field.to_string())
+    .fetch_optional(pool)
+    .await
+    .map_err(ApiError::Database)
+}
+
+/// Records a verified disclosure for a committed field
+async fn store_disclosure(
+    pool: &PgPool,
+    capture_id: Uuid,
+    field: CommitmentField,
+    value: &str,
+    salt: &[u8],
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        UPDATE metadata_commitments
+        SET disclosed_value = $3, disclosed_salt = $4, disclosed_at = NOW()
+        WHERE capture_id = $1 AND field = $2
+        "#,
+    )
+    .bind(capture_id)
+    .bind(field.to_string())
+    .bind(value)
+    .bind(salt)
+    .execute(pool)
+    .await
+    .map_err(ApiError::Database)?;
+    Ok(())
+}
+
 // ============================================================================
 // Route Handlers
 // ============================================================================
@@ -122,7 +234,14 @@ async fn upload_hash_only_capture(
     Extension(request_id): Extension<Uuid>,
     Extension(device_ctx): Extension<DeviceContext>,
     Json(payload): Json<HashOnlyCapturePayload>,
-) -> Result<(StatusCode, Json<ApiResponse<HashOnlyCaptureResponse>>), ApiErrorWithRequestId> {
+) -> Result<
+    (
+        StatusCode,
+        AuditOutcome,
+        Json<ApiResponse<HashOnlyCaptureResponse>>,
+    ),
+    ApiErrorWithRequestId,
+> {
     // Start timing for processing info
     let processing_start = std::time::Instant::now();
 
@@ -212,13 +331,73 @@ async fn upload_hash_only_capture(
         }
     }
 
+    // ========================================================================
+    // Video Hash Chain Verification (no-media mode)
+    // ========================================================================
+    // The payload assertion binds the chain summary; checkpoint assertions are
+    // checked against the device key before the structural verification.
+    let hash_chain_evidence = match (&payload.hash_chain, assertion_result.new_counter) {
+        (Some(chain), Some(payload_counter)) => {
+            Some(verify_video_hash_chain(VideoHashChainParams {
+                device: &device,
+                payload: &payload,
+                chain,
+                payload_counter,
+                config: &state.config,
+                request_id,
+            }))
+        }
+        _ => None,
+    };
+
+    if let Some(ref evidence) = hash_chain_evidence {
+        tracing::info!(
+            request_id = %request_id,
+            status = %evidence.status,
+            total_frames = evidence.total_frames,
+            chain_intact = evidence.chain_intact,
+            "[hash_only] Video hash chain verified"
+        );
+    }
+
     // ========================================================================
     // AC 6: Build Evidence Package
     // ========================================================================
 
+    // Counter of the verified assertion orders this capture in device history
+    let assertion_counter = assertion_result.new_counter.map(i64::from);
+
     // Build hardware attestation from assertion result
     let hardware_attestation: HardwareAttestation = assertion_result.into();
 
+    // Check client depth metrics for internal consistency and against the
+    // device model's history; the trust score caps confidence
+    let depth_history = load_model_depth_history(&state.db, &device.model)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                request_id = %request_id,
+                error = %e,
+                "[hash_only] Failed to load model depth history (non-fatal)"
+            );
+            None
+        });
+    let plausibility = check_depth_plausibility(
+        &payload.depth_analysis,
+        payload.temporal_depth_analysis.as_ref(),
+        depth_history.as_ref(),
+    );
+
+    if !plausibility.issues.is_empty() {
+        tracing::warn!(
+            request_id = %request_id,
+            device_id = %device_ctx.device_id,
+            analysis_trust = plausibility.trust,
+            issues = ?plausibility.issues,
+            "[hash_only] Client depth analysis failed plausibility checks"
+        );
+    }
+
     // Build depth analysis from client-provided data (AC 6.3: source="device")
     // Story 10-5: Add method field for device-side LiDAR analysis
     let depth_analysis = DepthAnalysis {
@@ -237,10 +416,105 @@ async fn upload_hash_only_capture(
         source: Some(AnalysisSource::Device), // Hash-only captures use device-side analysis
         method: Some("lidar".to_string()),    // Story 10-5: iOS hash-only uses LiDAR
         unavailable_reason: None,
+        analysis_trust: Some(plausibility.trust),
+        plausibility_issues: plausibility.issues,
     };
 
-    // Build metadata evidence from filtered metadata
-    let metadata_evidence = build_metadata_evidence(&payload);
+    // Build metadata evidence from filtered metadata, with the published
+    // location and the privacy policy applied to it
+    let published_location =
+        extract_coarse_location(&payload, &LocationPrivacyBounds::from_config(&state.config));
+    let location_coarse = published_location.as_ref().map(|l| l.display.clone());
+    let location_label = published_location
+        .as_ref()
+        .and_then(|l| state.gazetteer.label(l.latitude, l.longitude));
+    let mut metadata_evidence = build_metadata_evidence(&payload);
+    metadata_evidence.location_coarse = location_coarse.clone();
+    metadata_evidence.location_privacy = published_location.and_then(|l| l.privacy);
+    metadata_evidence.location_label = location_label.clone();
+
+    // Cross-check an included model against the registered device identity
+    if let Some(claimed_model) = payload.metadata.device_model.as_deref() {
+        let attested_model = lookup_attested_model(&state.db, device.id)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(
+                    request_id = %request_id,
+                    device_id = %device.id,
+                    error = %e,
+                    "[hash_only] Failed to load attested device model"
+                );
+                None
+            });
+        let identity = check_model_identity(
+            claimed_model,
+            &RegisteredIdentity {
+                platform: &device.platform,
+                model: &device.model,
+                attested_model: attested_model.as_deref(),
+            },
+            &state.device_registry,
+        );
+        apply_model_identity(&mut metadata_evidence, identity);
+    }
+
+    // Check captured_at against the device's registration and capture history.
+    // Location plausibility only applies to precise (uncoarsened) locations.
+    if let Ok(captured_at) = payload.captured_at_datetime() {
+        let precise_location = payload
+            .metadata
+            .location
+            .as_ref()
+            .filter(|_| payload.metadata_flags.location_level == "precise")
+            .map(|l| CaptureLocation {
+                latitude: l.latitude,
+                longitude: l.longitude,
+                altitude: l.altitude,
+                accuracy: l.accuracy,
+            });
+        if let Some(location) = precise_location {
+            match check_device_location(
+                &state.db,
+                state.envelope.as_deref(),
+                device.id,
+                &location,
+                captured_at,
+                // The Json extractor keeps only the parsed f64s
+                None,
+            )
+            .await
+            {
+                Ok(plausibility) => {
+                    metadata_evidence.location_plausible = Some(plausibility.plausible);
+                    metadata_evidence.location_plausibility_reason = plausibility.reason;
+                }
+                Err(e) => tracing::warn!(
+                    request_id = %request_id,
+                    device_id = %device.id,
+                    error = %e,
+                    "[hash_only] Failed to load device location history"
+                ),
+            }
+        }
+
+        match check_device_timestamp(
+            &state.db,
+            device.id,
+            device.first_seen_at,
+            captured_at,
+            assertion_counter,
+        )
+        .await
+        {
+            Ok(consistency) => metadata_evidence.timestamp_consistency = Some(consistency),
+            Err(e) => tracing::warn!(
+                request_id = %request_id,
+                device_id = %device.id,
+                error = %e,
+                "[hash_only] Failed to load device capture history"
+            ),
+        }
+    }
 
     // Calculate processing time
     let processing_time_ms = processing_start.elapsed().as_millis() as u64;
@@ -248,15 +522,29 @@ async fn upload_hash_only_capture(
 
     // Assemble evidence package using iOS builder (Story 10-5)
     // Currently all hash-only captures are from iOS devices
-    let evidence_package = EvidencePackage::for_ios(
+    let mut evidence_package = EvidencePackage::for_ios(
         hardware_attestation,
         depth_analysis,
         metadata_evidence,
         processing_info,
-    );
+    )
+    .with_capture_mode(CaptureMode::HashOnly);
+    if let Some(hash_chain) = hash_chain_evidence {
+        let frame_count = payload.frame_count.unwrap_or(0).max(0) as u32;
+        evidence_package = evidence_package
+            .with_video(VideoDetails {
+                duration_ms: payload.duration_ms.unwrap_or(0).max(0) as u64,
+                frame_count,
+                temporal_depth: None,
+                partial_attestation: PartialAttestationInfo::complete(frame_count),
+            })
+            .with_hash_chain(hash_chain);
+    }
 
-    // Calculate confidence level
-    let confidence_level = evidence_package.calculate_confidence();
+    // Calculate confidence score
+    let confidence_score = evidence_package.calculate_score();
+    let confidence_level = confidence_score.level;
+    let evidence_package = evidence_package.with_confidence_score(confidence_score);
 
     tracing::info!(
         request_id = %request_id,
@@ -306,9 +594,6 @@ async fn upload_hash_only_capture(
         }
     })?;
 
-    // Extract coarse location if available
-    let location_coarse = extract_coarse_location(&payload);
-
     // Convert confidence to string
     let confidence_str = match confidence_level {
         ConfidenceLevel::High => "high",
@@ -317,9 +602,14 @@ async fn upload_hash_only_capture(
         ConfidenceLevel::Suspicious => "suspicious",
     };
 
-    // Insert capture record
+    // Insert capture record and its metadata commitments atomically
+    let mut tx = state.db.begin().await.map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Database(e),
+        request_id,
+    })?;
+
     let db_capture_id = insert_hash_only_capture(
-        &state.db,
+        &mut tx,
         InsertHashOnlyCaptureParams {
             capture_id,
             device_id: device_ctx.device_id,
@@ -329,6 +619,8 @@ async fn upload_hash_only_capture(
             confidence_level: confidence_str.to_string(),
             metadata_flags: metadata_flags_json,
             location_coarse,
+            location_label,
+            assertion_counter,
         },
     )
     .await
@@ -337,11 +629,29 @@ async fn upload_hash_only_capture(
         request_id,
     })?;
 
+    if let Some(ref commitments) = payload.metadata_commitments {
+        insert_metadata_commitments(&mut tx, db_capture_id, commitments)
+            .await
+            .map_err(|e| ApiErrorWithRequestId {
+                error: e,
+                request_id,
+            })?;
+    }
+
+    tx.commit().await.map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Database(e),
+        request_id,
+    })?;
+
     tracing::info!(
         request_id = %request_id,
         capture_id = %db_capture_id,
         device_id = %device_ctx.device_id,
         confidence_level = confidence_str,
+        commitment_count = payload
+            .metadata_commitments
+            .as_ref()
+            .map_or(0, |c| c.entries().len()),
         "[hash_only] Capture record created"
     );
 
@@ -415,10 +725,123 @@ async fn upload_hash_only_capture(
 
     Ok((
         StatusCode::ACCEPTED,
+        AuditOutcome::new("accepted")
+            .capture(db_capture_id)
+            .device(device_ctx.device_id),
         Json(ApiResponse::new(response_data, request_id)),
     ))
 }
 
+/// POST /api/v1/captures/hash-only/{id}/disclosures - Disclose a committed field
+///
+/// Accepts a JSON body with:
+/// - field: "location", "timestamp", or "device_model"
+/// - value: canonical value that was committed to
+/// - salt: commitment salt (hex string)
+///
+/// Only the device that created the capture may disclose. The value is stored
+/// only if it opens the commitment bound by the capture assertion.
+///
+/// # Responses
+/// - 200 OK: Disclosure verified and recorded
+/// - 400 Bad Request: Malformed value/salt, no commitment, or mismatch
+/// - 403 Forbidden: Capture belongs to another device
+/// - 404 Not Found: Hash-only capture does not exist
+async fn disclose_metadata_field(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Extension(device_ctx): Extension<DeviceContext>,
+    Path(id): Path<String>,
+    Json(request): Json<MetadataDisclosureRequest>,
+) -> Result<Json<ApiResponse<MetadataDisclosure>>, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+    let field = request.field;
+
+    tracing::info!(
+        request_id = %request_id,
+        capture_id = %capture_id,
+        device_id = %device_ctx.device_id,
+        field = %field,
+        "[hash_only] Processing metadata disclosure"
+    );
+
+    let record = load_commitment(&state.db, capture_id, field)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?
+        .ok_or(ApiErrorWithRequestId {
+            error: ApiError::CaptureNotFound,
+            request_id,
+        })?;
+
+    // Access control: only the owning device can disclose its metadata
+    if record.device_id != device_ctx.device_id {
+        tracing::warn!(
+            request_id = %request_id,
+            capture_id = %capture_id,
+            capture_device_id = %record.device_id,
+            requesting_device_id = %device_ctx.device_id,
+            "[hash_only] Access denied: device does not own capture"
+        );
+        return Err(ApiErrorWithRequestId {
+            error: ApiError::Forbidden("You do not have access to this capture".to_string()),
+            request_id,
+        });
+    }
+
+    let commitment = record.commitment.ok_or(ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Capture has no commitment for {field}")),
+        request_id,
+    })?;
+
+    let salt = hex::decode(&request.salt).map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid salt hex: {e}")),
+        request_id,
+    })?;
+
+    verify_metadata_disclosure(field, &commitment, &request.value, &salt).map_err(|e| {
+        tracing::warn!(
+            request_id = %request_id,
+            capture_id = %capture_id,
+            field = %field,
+            error = %e,
+            "[hash_only] Metadata disclosure rejected"
+        );
+        ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        }
+    })?;
+
+    // Re-disclosing the same opening is idempotent; a commitment can only
+    // open to one value, so a stored value always equals request.value
+    if record.disclosed_value.is_none() {
+        store_disclosure(&state.db, capture_id, field, &request.value, &salt)
+            .await
+            .map_err(|e| ApiErrorWithRequestId {
+                error: e,
+                request_id,
+            })?;
+    }
+
+    tracing::info!(
+        request_id = %request_id,
+        capture_id = %capture_id,
+        field = %field,
+        "[hash_only] Metadata field disclosed and matches commitment"
+    );
+
+    Ok(Json(ApiResponse::new(
+        MetadataDisclosure::disclosed(field, request.value),
+        request_id,
+    )))
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -431,36 +854,132 @@ fn build_metadata_evidence(payload: &HashOnlyCapturePayload) -> MetadataEvidence
 
     MetadataEvidence {
         timestamp_valid,
-        timestamp_delta_seconds: 0, // Not applicable for hash-only (client timestamp)
+        timestamp_consistency: None, // Set by check_timestamp_consistency
+        timestamp_delta_seconds: 0,  // Not applicable for hash-only (client timestamp)
         model_verified,
         model_name: payload
             .metadata
             .device_model
             .clone()
             .unwrap_or_else(|| "Unknown".to_string()),
+        model_attested: false, // Set by check_model_identity when a model is included
+        model_mismatch: false,
         resolution_valid: true, // Assume valid for hash-only (client verified)
         location_available,
         location_opted_out: !location_available,
+        location_plausible: None,
+        location_plausibility_reason: None,
         location_coarse: None, // Set separately if available
+        location_privacy: None,
+        location_label: None,
+    }
+}
+
+/// Inputs for verifying a hash-only video hash chain
+struct VideoHashChainParams<'a> {
+    device: &'a Device,
+    payload: &'a HashOnlyCapturePayload,
+    chain: &'a VideoHashChainData,
+    /// Counter of the verified payload assertion; checkpoints must sit below it
+    payload_counter: u32,
+    config: &'a crate::config::Config,
+    request_id: Uuid,
+}
+
+/// Verifies a hash-only video hash chain without media and builds its evidence.
+///
+/// The chain's final hash only counts as attested if the payload assertion's
+/// signature verifies over a clientDataHash recomputed with that final hash.
+/// Checkpoints only count if their own assertion verifies; the frame count
+/// comes from the assertion-bound payload.
+fn verify_video_hash_chain(params: VideoHashChainParams<'_>) -> HashChainEvidence {
+    let VideoHashChainParams {
+        device,
+        payload,
+        chain,
+        payload_counter,
+        config,
+        request_id,
+    } = params;
+
+    if let Err(e) = verify_hash_only_final_hash_binding(device, payload, &chain.final_hash) {
+        tracing::warn!(
+            request_id = %request_id,
+            device_id = %device.id,
+            error = %e,
+            "[hash_only] Payload assertion does not sign the chain final hash"
+        );
+        return HashChainEvidence::fail(
+            &HashChainVerificationError::AttestationHashMismatch.to_string(),
+        );
     }
+
+    let attested =
+        verify_hash_only_checkpoint_assertions(device, chain, payload_counter, config, request_id);
+    let summary = chain.to_summary(attested);
+
+    let attestation = VideoAttestation {
+        final_hash: chain.final_hash.clone(),
+        assertion: payload.assertion.clone(),
+        duration_ms: payload.duration_ms.unwrap_or(0).max(0) as u64,
+        frame_count: payload.frame_count.unwrap_or(0).max(0) as u32,
+        is_partial: false,
+        checkpoint_index: None,
+    };
+
+    let verification = HashChainVerifier::new().verify_summary(&summary, &attestation);
+    HashChainEvidence::from_verification(&verification)
 }
 
 /// Extracts coarse location string from filtered metadata if available
-fn extract_coarse_location(payload: &HashOnlyCapturePayload) -> Option<String> {
+fn extract_coarse_location(
+    payload: &HashOnlyCapturePayload,
+    bounds: &LocationPrivacyBounds,
+) -> Option<PublishedLocation> {
     if !payload.metadata_flags.location_included {
         return None;
     }
+    let loc = payload.metadata.location.as_ref()?;
 
-    payload.metadata.location.as_ref().map(|loc| {
-        // Generate coarse location based on privacy level
-        match payload.metadata_flags.location_level.as_str() {
-            "coarse" => format!("~{:.1}, ~{:.1}", loc.latitude, loc.longitude),
-            "precise" => format!("{:.4}, {:.4}", loc.latitude, loc.longitude),
-            _ => "Location available".to_string(),
-        }
+    // A requested policy is applied within server bounds and recorded
+    if let Some(policy) = payload.location_privacy {
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        let protected = protect_location(loc.latitude, loc.longitude, Some(policy), bounds, seed);
+        return Some(PublishedLocation {
+            display: protected.display,
+            latitude: protected.latitude,
+            longitude: protected.longitude,
+            privacy: Some(protected.evidence),
+        });
+    }
+
+    // Generate coarse location based on privacy level
+    let display = match payload.metadata_flags.location_level.as_str() {
+        "coarse" => format!("~{:.1}, ~{:.1}", loc.latitude, loc.longitude),
+        "precise" => format!("{:.4}, {:.4}", loc.latitude, loc.longitude),
+        _ => "Location available".to_string(),
+    };
+    Some(PublishedLocation {
+        display,
+        latitude: loc.latitude,
+        longitude: loc.longitude,
+        privacy: None,
     })
 }
 
+/// Location as published for a hash-only capture
+struct PublishedLocation {
+    /// Display string stored as `location_coarse`
+    display: String,
+    /// Published latitude (already generalized by the client or policy)
+    latitude: f64,
+    /// Published longitude
+    longitude: f64,
+    /// Applied privacy policy, when the device requested one
+    privacy: Option<LocationPrivacyEvidence>,
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -468,8 +987,14 @@ fn extract_coarse_location(payload: &HashOnlyCapturePayload) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{ClientDepthAnalysis, FilteredMetadata, MetadataFlags};
+    use crate::models::HashChainStatus;
+    use crate::services::compute_hash_only_client_data_hash;
+    use crate::types::{
+        ClientDepthAnalysis, FilteredMetadata, LocationPrivacyPolicy, MetadataFlags,
+    };
     use base64::Engine;
+    use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+    use sha2::{Digest, Sha256};
 
     fn test_payload() -> HashOnlyCapturePayload {
         HashOnlyCapturePayload {
@@ -500,6 +1025,8 @@ mod tests {
             temporal_depth_analysis: None,
             frame_count: None,
             duration_ms: None,
+            metadata_commitments: None,
+            location_privacy: None,
         }
     }
 
@@ -532,10 +1059,169 @@ mod tests {
         assert!(!evidence.location_opted_out);
     }
 
+    fn test_video_payload() -> HashOnlyCapturePayload {
+        let mut payload = test_payload();
+        payload.media_type = "video".to_string();
+        payload.hash_chain = Some(VideoHashChainData {
+            final_hash: base64::engine::general_purpose::STANDARD.encode([0xCC; 32]),
+            chain_length: 450,
+            version: "1.0".to_string(),
+            checkpoint_count: 0,
+            checkpoints: vec![],
+        });
+        payload.frame_count = Some(450);
+        payload.duration_ms = Some(15000);
+        payload
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    fn test_device() -> Device {
+        Device {
+            id: Uuid::new_v4(),
+            attestation_level: "secure_enclave".to_string(),
+            attestation_key_id: "test-key-id".to_string(),
+            attestation_chain: None,
+            platform: "iOS".to_string(),
+            model: "iPhone 15 Pro".to_string(),
+            has_lidar: true,
+            first_seen_at: chrono::Utc::now(),
+            last_seen_at: chrono::Utc::now(),
+            assertion_counter: 5,
+            public_key: Some(
+                signing_key()
+                    .verifying_key()
+                    .to_encoded_point(false)
+                    .as_bytes()
+                    .to_vec(),
+            ),
+            security_level: None,
+            keymaster_security_level: None,
+        }
+    }
+
+    /// Signs the payload's clientDataHash as the device would with counter 9
+    fn sign_payload(payload: &mut HashOnlyCapturePayload, config: &crate::config::Config) {
+        let app_id = format!("{}.{}", config.apple_team_id, config.apple_bundle_id);
+        let mut auth_data = Sha256::digest(app_id.as_bytes()).to_vec();
+        auth_data.push(0x01);
+        auth_data.extend_from_slice(&9u32.to_be_bytes());
+
+        let mut message = auth_data.clone();
+        message.extend_from_slice(&compute_hash_only_client_data_hash(payload));
+        let signature: Signature = signing_key().sign(&message);
+
+        let mut cbor_bytes = Vec::new();
+        ciborium::into_writer(
+            &ciborium::Value::Map(vec![
+                (
+                    ciborium::Value::Text("authenticatorData".to_string()),
+                    ciborium::Value::Bytes(auth_data),
+                ),
+                (
+                    ciborium::Value::Text("signature".to_string()),
+                    ciborium::Value::Bytes(signature.to_bytes().to_vec()),
+                ),
+            ]),
+            &mut cbor_bytes,
+        )
+        .unwrap();
+        payload.assertion = base64::engine::general_purpose::STANDARD.encode(cbor_bytes);
+    }
+
+    fn verify_chain(payload: &HashOnlyCapturePayload) -> HashChainEvidence {
+        let config = crate::config::Config::default_for_test();
+        verify_video_hash_chain(VideoHashChainParams {
+            device: &test_device(),
+            payload,
+            chain: payload.hash_chain.as_ref().unwrap(),
+            payload_counter: 9,
+            config: &config,
+            request_id: Uuid::new_v4(),
+        })
+    }
+
+    #[test]
+    fn test_verify_video_hash_chain_without_checkpoints() {
+        let mut payload = test_video_payload();
+        sign_payload(&mut payload, &crate::config::Config::default_for_test());
+
+        let evidence = verify_chain(&payload);
+
+        assert_eq!(evidence.status, HashChainStatus::Pass);
+        assert_eq!(evidence.total_frames, 450);
+        assert!(evidence.attestation_valid);
+        // Structure cannot be established without the expected checkpoints
+        assert!(!evidence.chain_intact);
+    }
+
+    #[test]
+    fn test_verify_video_hash_chain_unattested_checkpoint_fails() {
+        let mut payload = test_video_payload();
+        let chain = payload.hash_chain.as_mut().unwrap();
+        chain.checkpoint_count = 1;
+        chain.checkpoints = vec![crate::types::VideoHashCheckpoint {
+            index: 0,
+            frame_number: 150,
+            hash: base64::engine::general_purpose::STANDARD.encode([0x01; 32]),
+            timestamp: 5.0,
+            assertion: base64::engine::general_purpose::STANDARD.encode("forged"),
+        }];
+        sign_payload(&mut payload, &crate::config::Config::default_for_test());
+
+        let evidence = verify_chain(&payload);
+
+        assert_eq!(evidence.status, HashChainStatus::Fail);
+        assert!(evidence
+            .partial_reason
+            .unwrap()
+            .contains("no valid device assertion"));
+    }
+
+    #[test]
+    fn test_verify_video_hash_chain_frame_count_mismatch_fails() {
+        let mut payload = test_video_payload();
+        payload.frame_count = Some(300);
+        sign_payload(&mut payload, &crate::config::Config::default_for_test());
+
+        let evidence = verify_chain(&payload);
+
+        assert_eq!(evidence.status, HashChainStatus::Fail);
+    }
+
+    #[test]
+    fn test_verify_video_hash_chain_unsigned_final_hash_fails() {
+        let mut payload = test_video_payload();
+        sign_payload(&mut payload, &crate::config::Config::default_for_test());
+        // Swap the final hash after signing
+        payload.hash_chain.as_mut().unwrap().final_hash =
+            base64::engine::general_purpose::STANDARD.encode([0x7f; 32]);
+
+        let evidence = verify_chain(&payload);
+
+        assert_eq!(evidence.status, HashChainStatus::Fail);
+        assert!(!evidence.attestation_valid);
+        assert!(evidence
+            .partial_reason
+            .unwrap()
+            .contains("Attestation hash mismatch"));
+    }
+
+    #[test]
+    fn test_verify_video_hash_chain_unsigned_payload_fails() {
+        // test_payload carries a placeholder assertion
+        let evidence = verify_chain(&test_video_payload());
+
+        assert_eq!(evidence.status, HashChainStatus::Fail);
+        assert!(!evidence.attestation_valid);
+    }
+
     #[test]
     fn test_extract_coarse_location_none() {
         let payload = test_payload();
-        let location = extract_coarse_location(&payload);
+        let location = extract_coarse_location(&payload, &test_bounds());
         assert!(location.is_none());
     }
 
@@ -551,10 +1237,40 @@ mod tests {
             accuracy: None,
         });
 
-        let location = extract_coarse_location(&payload);
+        let location = extract_coarse_location(&payload, &test_bounds());
         assert!(location.is_some());
-        let loc_str = location.unwrap();
+        let location = location.unwrap();
+        assert!(location.privacy.is_none());
+        let loc_str = location.display;
         assert!(loc_str.contains("~37.8"));
         assert!(loc_str.contains("~-122.4"));
     }
+
+    fn test_bounds() -> LocationPrivacyBounds {
+        LocationPrivacyBounds::from_config(&crate::config::Config::default_for_test())
+    }
+
+    #[test]
+    fn test_extract_coarse_location_with_policy() {
+        let mut payload = test_payload();
+        payload.metadata_flags.location_included = true;
+        payload.metadata_flags.location_level = "precise".to_string();
+        payload.metadata.location = Some(crate::types::FilteredLocation {
+            latitude: 37.7749,
+            longitude: -122.4194,
+            altitude: None,
+            accuracy: None,
+        });
+        payload.location_privacy = Some(LocationPrivacyPolicy::Geohash { precision: 8 });
+
+        let location = extract_coarse_location(&payload, &test_bounds()).unwrap();
+        let policy = location.privacy.unwrap();
+        // Precision 8 (~20m) is below the 500m minimum and is coarsened
+        assert!(policy.adjusted);
+        assert_eq!(
+            policy.policy,
+            LocationPrivacyPolicy::Geohash { precision: 6 }
+        );
+        assert_eq!(policy.cell.as_deref(), Some("9q8yyk"));
+    }
 }
@@ -26,12 +26,20 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
-use crate::middleware::DeviceContext;
+use crate::middleware::{lookup_device, update_device_counter, AuditOutcome, DeviceContext};
+use crate::models::{
+    AttestationLevel, CheckStatus, DepthAnalysis, EvidencePackage, HardwareAttestation,
+    HashChainEvidence, MetadataEvidence, PartialAttestationInfo, ProcessingInfo, VideoDetails,
+};
 use crate::routes::AppState;
+use crate::services::{
+    check_device_timestamp, sha256_digest, spawn_derivatives, verify_video_assertion,
+    DerivativeJob, HashChainVerifier,
+};
 use crate::types::{
     validate_hash_chain_size, validate_video_depth_size, validate_video_metadata_size,
-    validate_video_size, ApiErrorResponse, ApiResponse, VideoUploadMetadata, VideoUploadResponse,
-    VIDEO_RATE_LIMIT_PER_HOUR,
+    validate_video_size, ApiErrorResponse, ApiResponse, HashChainData, VideoAttestation,
+    VideoUploadMetadata, VideoUploadResponse, VIDEO_RATE_LIMIT_PER_HOUR,
 };
 
 // ============================================================================
@@ -228,16 +236,131 @@ async fn check_video_rate_limit(pool: &PgPool, device_id: Uuid) -> Result<(), (i
     Ok(())
 }
 
+// ============================================================================
+// Hash Chain Verification
+// ============================================================================
+
+/// Loads the device's v2 hash chain genesis, if registration stored one
+///
+/// Errors are propagated rather than treated as "no genesis", which would let
+/// the chain skip the device binding.
+async fn load_hash_chain_genesis(
+    pool: &PgPool,
+    device_id: Uuid,
+) -> Result<Option<[u8; 32]>, ApiError> {
+    let genesis: Option<Vec<u8>> =
+        sqlx::query_scalar("SELECT hash_chain_genesis FROM devices WHERE id = $1")
+            .bind(device_id)
+            .fetch_optional(pool)
+            .await?
+            .flatten();
+
+    genesis
+        .map(|g| {
+            <[u8; 32]>::try_from(g.as_slice()).map_err(|_| {
+                ApiError::Internal(anyhow::anyhow!(
+                    "Stored hash chain genesis is {} bytes, expected 32",
+                    g.len()
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Verifies the uploaded hash chain against the metadata's attested final hash
+///
+/// v2 chains are recomputed from `genesis` when the device has one, so a
+/// derivation break reports `broken_at_frame`. An unparseable chain yields
+/// failed evidence rather than rejecting the upload.
+fn verify_video_hash_chain(
+    hash_chain_bytes: &[u8],
+    metadata: &VideoUploadMetadata,
+    genesis: Option<&[u8; 32]>,
+) -> HashChainEvidence {
+    let chain: HashChainData = match serde_json::from_slice(hash_chain_bytes) {
+        Ok(chain) => chain,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to parse hash chain JSON");
+            return HashChainEvidence::fail("Hash chain is not valid JSON");
+        }
+    };
+
+    let attestation = VideoAttestation {
+        final_hash: metadata.hash_chain_final.clone(),
+        assertion: metadata.assertion.clone().unwrap_or_default(),
+        duration_ms: metadata.duration_ms,
+        frame_count: metadata.frame_count,
+        is_partial: metadata.is_partial,
+        checkpoint_index: metadata
+            .is_partial
+            .then(|| metadata.checkpoints.last().map(|c| c.index))
+            .flatten(),
+    };
+
+    let verification = HashChainVerifier::new().verify_with_genesis(&chain, &attestation, genesis);
+    HashChainEvidence::from_verification(&verification)
+}
+
 // ============================================================================
 // Database Operations
 // ============================================================================
 
+/// Initial evidence for a video upload, stored until verification runs
+///
+/// Uses the unified evidence schema with pending statuses; currently all
+/// video captures are from iOS and analyse depth with LiDAR.
+fn pending_video_evidence(
+    device_model: &str,
+    duration_ms: u64,
+    frame_count: u32,
+    is_partial: bool,
+) -> EvidencePackage {
+    let hardware = HardwareAttestation {
+        status: CheckStatus::Pending,
+        level: AttestationLevel::SecureEnclave,
+        device_model: device_model.to_string(),
+        assertion_verified: false,
+        counter_valid: false,
+        security_level: None,
+    };
+    let depth = DepthAnalysis {
+        status: CheckStatus::Pending,
+        method: Some("lidar".to_string()),
+        ..Default::default()
+    };
+    let metadata = MetadataEvidence {
+        model_name: device_model.to_string(),
+        ..Default::default()
+    };
+    let partial_attestation = PartialAttestationInfo {
+        is_partial,
+        checkpoint_index: None,
+        verified_frames: 0,
+        total_frames: frame_count,
+        reason: None,
+    };
+
+    EvidencePackage::for_ios(
+        hardware,
+        depth,
+        metadata,
+        ProcessingInfo::new(0, env!("CARGO_PKG_VERSION")),
+    )
+    .with_video(VideoDetails {
+        duration_ms,
+        frame_count,
+        temporal_depth: None,
+        partial_attestation,
+    })
+}
+
 /// Insert a new video capture record
 #[allow(clippy::too_many_arguments)]
 async fn insert_video_capture(
     pool: &PgPool,
     capture_id: Uuid,
     device_id: Uuid,
+    video_hash: &[u8],
     video_s3_key: &str,
     depth_s3_key: &str,
     hash_chain_s3_key: &str,
@@ -246,47 +369,31 @@ async fn insert_video_capture(
     duration_ms: i64,
     frame_count: i32,
     is_partial: bool,
+    data_key_id: Option<&str>,
+    assertion_counter: Option<i64>,
+    evidence: &EvidencePackage,
 ) -> Result<Uuid, ApiError> {
-    // Initial evidence package (minimal for video - full evidence built in Stories 7-9, 7-10)
-    // Story 10-5: Include platform field for unified schema consistency
-    let evidence = json!({
-        "platform": "ios",  // Story 10-5: Currently all video captures are from iOS
-        "hardware_attestation": {
-            "status": "pending",
-            "assertion_verified": false,
-            "counter_valid": false,
-            "failure_reason": "Video attestation pending verification"
-        },
-        "depth_analysis": {
-            "status": "pending",
-            "analysis_type": "video_keyframes",
-            "keyframe_count": 0,
-            "method": "lidar"  // Story 10-5: iOS video uses LiDAR
-        },
-        "hash_chain": {
-            "status": "pending",
-            "frame_count": frame_count,
-            "checkpoints_verified": 0
-        },
-        "metadata": {
-            "duration_ms": duration_ms,
-            "is_partial": is_partial
-        }
-    });
+    let evidence = serde_json::to_value(evidence)
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!("Failed to serialize evidence: {e}")))?;
 
     sqlx::query_scalar::<_, Uuid>(
         r#"
         INSERT INTO captures (
-            id, device_id, capture_type, video_s3_key, depth_map_s3_key,
+            id, device_id, capture_type, target_media_hash, video_s3_key, depth_map_s3_key,
             hash_chain_s3_key, evidence, confidence_level, status,
-            location_precise, captured_at, duration_ms, frame_count, is_partial
+            location_precise, captured_at, duration_ms, frame_count, is_partial, data_key_id,
+            assertion_counter
+        )
+        VALUES (
+            $1, $2, 'video', $3, $4, $5, $6, $7, 'low', 'processing',
+            $8, $9, $10, $11, $12, $13, $14
         )
-        VALUES ($1, $2, 'video', $3, $4, $5, $6, 'low', 'processing', $7, $8, $9, $10, $11)
         RETURNING id
         "#,
     )
     .bind(capture_id)
     .bind(device_id)
+    .bind(video_hash)
     .bind(video_s3_key)
     .bind(depth_s3_key)
     .bind(hash_chain_s3_key)
@@ -296,6 +403,8 @@ async fn insert_video_capture(
     .bind(duration_ms)
     .bind(frame_count)
     .bind(is_partial)
+    .bind(data_key_id)
+    .bind(assertion_counter)
     .fetch_one(pool)
     .await
     .map_err(|e| {
@@ -317,6 +426,10 @@ async fn insert_video_capture(
 /// - metadata: JSON metadata with attestation (max 100KB)
 ///
 /// Device authentication is handled by DeviceAuthLayer middleware.
+/// The hash chain is verified against the device's registration genesis
+/// and stored as the capture's `hash_chain` evidence. The final-hash
+/// assertion is verified (non-blocking), its counter stored, and
+/// `captured_at` checked against the device's capture history.
 ///
 /// ## Rate Limiting
 /// Limited to 5 video uploads per hour per device.
@@ -334,7 +447,14 @@ async fn upload_video(
     Extension(request_id): Extension<Uuid>,
     Extension(device_ctx): Extension<DeviceContext>,
     multipart: Multipart,
-) -> Result<(StatusCode, Json<ApiResponse<VideoUploadResponse>>), Response> {
+) -> Result<
+    (
+        StatusCode,
+        AuditOutcome,
+        Json<ApiResponse<VideoUploadResponse>>,
+    ),
+    Response,
+> {
     tracing::info!(
         request_id = %request_id,
         device_id = %device_ctx.device_id,
@@ -391,6 +511,104 @@ async fn upload_video(
     // Generate capture ID
     let capture_id = Uuid::new_v4();
 
+    // Verify the chain before it is handed to storage
+    let genesis = load_hash_chain_genesis(&state.db, device_ctx.device_id)
+        .await
+        .map_err(|error| ApiErrorWithRequestId { error, request_id }.into_response())?;
+    let hash_chain =
+        verify_video_hash_chain(&parsed.hash_chain_bytes, &parsed.metadata, genesis.as_ref());
+
+    tracing::info!(
+        request_id = %request_id,
+        status = ?hash_chain.status,
+        derivation_verified = hash_chain.derivation_verified,
+        genesis_bound = hash_chain.genesis_bound,
+        broken_at_frame = ?hash_chain.broken_at_frame,
+        "Video hash chain verified"
+    );
+
+    // Parse capture timestamp
+    let captured_at = parsed.metadata.started_at_datetime().map_err(|e| {
+        ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        }
+        .into_response()
+    })?;
+
+    // The final-hash assertion orders this capture in the device's history.
+    // NON-BLOCKING: failures are recorded in the hardware attestation evidence.
+    let device = lookup_device(&state.db, device_ctx.device_id)
+        .await
+        .map_err(|e| {
+            ApiErrorWithRequestId {
+                error: e,
+                request_id,
+            }
+            .into_response()
+        })?;
+    let assertion_result = verify_video_assertion(
+        &device,
+        parsed.metadata.assertion.as_deref(),
+        &parsed.metadata.hash_chain_final,
+        &state.config,
+        request_id,
+    );
+    if let Some(new_counter) = assertion_result.new_counter {
+        if let Err(e) = update_device_counter(&state.db, device.id, new_counter as i64).await {
+            tracing::error!(
+                request_id = %request_id,
+                device_id = %device.id,
+                new_counter = new_counter,
+                error = %e,
+                "[capture_attestation] Failed to update device counter"
+            );
+        }
+    }
+    let assertion_counter = assertion_result.new_counter.map(i64::from);
+
+    let mut evidence = pending_video_evidence(
+        &device_ctx.model,
+        parsed.metadata.duration_ms,
+        parsed.metadata.frame_count,
+        parsed.metadata.is_partial,
+    )
+    .with_hash_chain(hash_chain);
+    evidence.hardware_attestation = assertion_result.into();
+
+    match check_device_timestamp(
+        &state.db,
+        device.id,
+        device.first_seen_at,
+        captured_at,
+        assertion_counter,
+    )
+    .await
+    {
+        Ok(consistency) => evidence.metadata.timestamp_consistency = Some(consistency),
+        Err(e) => tracing::warn!(
+            request_id = %request_id,
+            device_id = %device.id,
+            error = %e,
+            "[timestamp_consistency] Failed to load device capture history"
+        ),
+    }
+
+    // Server-computed hash of the video is the capture's media hash
+    let video_hash = sha256_digest(&parsed.video_bytes);
+
+    // Per-capture data key sealing the media and precise location at rest
+    let data_key = match &state.envelope {
+        Some(envelope) => Some(envelope.generate_data_key().await.map_err(|e| {
+            ApiErrorWithRequestId {
+                error: ApiError::Internal(e.into()),
+                request_id,
+            }
+            .into_response()
+        })?),
+        None => None,
+    };
+
     // Upload files to S3
     let storage = &state.storage;
 
@@ -400,6 +618,7 @@ async fn upload_video(
             parsed.video_bytes,
             parsed.depth_bytes,
             parsed.hash_chain_bytes,
+            data_key.as_ref(),
         )
         .await
         .map_err(|e| {
@@ -419,23 +638,18 @@ async fn upload_video(
         "Video files uploaded to S3"
     );
 
-    // Parse capture timestamp
-    let captured_at = parsed.metadata.started_at_datetime().map_err(|e| {
-        ApiErrorWithRequestId {
-            error: e,
-            request_id,
-        }
-        .into_response()
-    })?;
-
-    // Prepare location data if present
+    // Prepare location data if present (sealed when encrypting at rest)
     let location_precise = parsed.metadata.location.as_ref().map(|loc| {
-        json!({
+        let location = json!({
             "latitude": loc.latitude,
             "longitude": loc.longitude,
             "altitude": loc.altitude,
             "accuracy": loc.accuracy
-        })
+        });
+        match &data_key {
+            Some(data_key) => data_key.seal_json(&location),
+            None => location,
+        }
     });
 
     // Create database record
@@ -443,6 +657,7 @@ async fn upload_video(
         &state.db,
         capture_id,
         device_ctx.device_id,
+        &video_hash,
         &video_s3_key,
         &depth_s3_key,
         &hash_chain_s3_key,
@@ -451,6 +666,9 @@ async fn upload_video(
         parsed.metadata.duration_ms as i64,
         parsed.metadata.frame_count as i32,
         parsed.metadata.is_partial,
+        data_key.as_ref().map(|k| k.key_id()),
+        assertion_counter,
+        &evidence,
     )
     .await
     .map_err(|e| {
@@ -468,8 +686,23 @@ async fn upload_video(
         "Video capture record created in database"
     );
 
-    // Build response
+    // The capture row now keeps its media from deletion
+    state.storage.release_key_leases(capture_id).await;
+
+    // Poster frame and thumbnails are rendered off the request path
     let config = &state.config;
+    spawn_derivatives(
+        state.db.clone(),
+        state.storage.clone(),
+        config.ffmpeg_path.clone(),
+        db_capture_id,
+        DerivativeJob::Video {
+            video_key: video_s3_key,
+            data_key,
+        },
+    );
+
+    // Build response
     let verification_url = format!("{}/{db_capture_id}", config.verification_base_url);
 
     let response_data = VideoUploadResponse {
@@ -487,6 +720,9 @@ async fn upload_video(
 
     Ok((
         StatusCode::ACCEPTED,
+        AuditOutcome::new("accepted")
+            .capture(db_capture_id)
+            .device(device_ctx.device_id),
         Json(ApiResponse::new(response_data, request_id)),
     ))
 }
@@ -499,6 +735,23 @@ async fn upload_video(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pending_video_evidence_uses_unified_schema() {
+        let evidence = pending_video_evidence("iPhone 15 Pro", 15000, 450, true);
+        let json = serde_json::to_value(&evidence).unwrap();
+
+        assert_eq!(
+            json["schema_version"],
+            crate::models::EVIDENCE_SCHEMA_VERSION
+        );
+        assert_eq!(json["capture_type"], "video");
+        assert_eq!(json["hardware_attestation"]["status"], "pending");
+        assert_eq!(json["depth_analysis"]["method"], "lidar");
+        assert_eq!(json["video"]["frame_count"], 450);
+        assert_eq!(json["video"]["partial_attestation"]["is_partial"], true);
+        assert!(json.get("hash_chain").is_none());
+    }
+
     #[test]
     fn test_verification_url_format() {
         let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
@@ -525,4 +778,331 @@ mod tests {
         assert!(json.contains(r#""status":"processing""#));
         assert!(json.contains(r#""capture_id":"550e8400-e29b-41d4-a716-446655440000""#));
     }
+
+    // ========================================================================
+    // Upload Route Tests (database)
+    // ========================================================================
+
+    mod upload {
+        use super::*;
+        use crate::config::Config;
+        use crate::middleware::device_auth::AttestationLevel as DeviceAttestationLevel;
+        use crate::services::{compute_hash_chain_genesis, ChallengeStore};
+        use axum::body::Body;
+        use axum::http::Request;
+        use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+        use chrono::{DateTime, Duration, Utc};
+        use p256::ecdsa::{signature::Signer, Signature, SigningKey};
+        use sqlx::postgres::PgPoolOptions;
+        use std::sync::Arc;
+        use tower::ServiceExt;
+
+        const BOUNDARY: &str = "realitycam-test-boundary";
+
+        async fn create_test_state() -> AppState {
+            dotenvy::dotenv().ok();
+            let config = Config::default_for_test();
+            let database_url =
+                std::env::var("DATABASE_URL").unwrap_or_else(|_| config.database_url.clone());
+
+            let pool = PgPoolOptions::new()
+                .max_connections(5)
+                .connect(&database_url)
+                .await
+                .expect("Failed to connect to test database");
+            sqlx::migrate!("./migrations")
+                .run(&pool)
+                .await
+                .expect("Failed to run migrations");
+
+            AppState {
+                db: pool,
+                challenge_store: ChallengeStore::new(),
+                config: Arc::new(config),
+                storage: Arc::new(crate::services::StorageService::in_memory()),
+                gazetteer: Arc::new(crate::services::Gazetteer::bundled().unwrap()),
+                device_registry: Arc::new(crate::services::DeviceRegistry::bundled().unwrap()),
+                detection_policy: Arc::new(crate::services::DetectionPolicy::bundled().unwrap()),
+                confidence_policies: Arc::new(
+                    crate::services::ConfidencePolicies::bundled().unwrap(),
+                ),
+                envelope: None,
+            }
+        }
+
+        /// Key the test device signs its assertions with
+        fn signing_key() -> SigningKey {
+            SigningKey::from_slice(&[9u8; 32]).unwrap()
+        }
+
+        /// Inserts a device whose registration stored `genesis` and the
+        /// public half of `signing_key()`
+        async fn insert_device(pool: &PgPool, genesis: &[u8; 32]) -> Uuid {
+            let public_key = signing_key()
+                .verifying_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec();
+            sqlx::query_scalar(
+                r#"
+                INSERT INTO devices (
+                    attestation_key_id, platform, model, has_lidar, hash_chain_genesis, public_key
+                )
+                VALUES ($1, 'ios', 'iPhone 15 Pro', true, $2, $3)
+                RETURNING id
+                "#,
+            )
+            .bind(format!("test-key-{}", Uuid::new_v4()))
+            .bind(genesis.as_slice())
+            .bind(public_key)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+        }
+
+        /// CBOR assertion over SHA256(final hash bytes) with `counter`
+        fn sign_final_hash(config: &Config, final_hash_b64: &str, counter: u32) -> String {
+            let app_id = format!("{}.{}", config.apple_team_id, config.apple_bundle_id);
+            let mut auth_data = sha256_digest(app_id.as_bytes()).to_vec();
+            auth_data.push(0x01);
+            auth_data.extend_from_slice(&counter.to_be_bytes());
+
+            let mut message = auth_data.clone();
+            message.extend_from_slice(&sha256_digest(&BASE64.decode(final_hash_b64).unwrap()));
+            let signature: Signature = signing_key().sign(&message);
+
+            let mut cbor = Vec::new();
+            ciborium::into_writer(
+                &ciborium::Value::Map(vec![
+                    (
+                        ciborium::Value::Text("authenticatorData".to_string()),
+                        ciborium::Value::Bytes(auth_data),
+                    ),
+                    (
+                        ciborium::Value::Text("signature".to_string()),
+                        ciborium::Value::Bytes(signature.to_bytes().to_vec()),
+                    ),
+                ]),
+                &mut cbor,
+            )
+            .unwrap();
+            BASE64.encode(cbor)
+        }
+
+        /// Builds a v2 chain from `genesis`; `tamper_frame` (1-based) gets a
+        /// digest that does not produce its hash
+        fn v2_chain(genesis: &[u8; 32], frames: u32, tamper_frame: Option<u32>) -> HashChainData {
+            let mut previous = *genesis;
+            let mut frame_hashes = Vec::new();
+            let mut frame_digests = Vec::new();
+            for n in 1..=frames {
+                let digest = sha256_digest(&n.to_be_bytes());
+                let hash = sha256_digest(&[previous.as_slice(), digest.as_slice()].concat());
+                let submitted = if tamper_frame == Some(n) {
+                    sha256_digest(b"substituted frame")
+                } else {
+                    digest
+                };
+                frame_hashes.push(BASE64.encode(hash));
+                frame_digests.push(BASE64.encode(submitted));
+                previous = hash.as_slice().try_into().unwrap();
+            }
+
+            HashChainData {
+                final_hash: frame_hashes.last().unwrap().clone(),
+                frame_hashes,
+                checkpoints: vec![],
+                version: 2,
+                frame_digests,
+            }
+        }
+
+        fn multipart_body(
+            chain: &HashChainData,
+            started_at: DateTime<Utc>,
+            assertion: Option<String>,
+        ) -> Vec<u8> {
+            let metadata = json!({
+                "started_at": started_at.to_rfc3339(),
+                "ended_at": (started_at + Duration::seconds(1)).to_rfc3339(),
+                "duration_ms": 1000,
+                "frame_count": chain.frame_hashes.len(),
+                "depth_keyframe_count": 10,
+                "resolution": {"width": 1920, "height": 1080},
+                "codec": "hevc",
+                "device_model": "iPhone 15 Pro",
+                "attestation_level": "full",
+                "hash_chain_final": chain.final_hash,
+                "assertion": assertion,
+            });
+
+            let mut body = Vec::new();
+            for (name, content) in [
+                ("video", Uuid::new_v4().as_bytes().to_vec()),
+                ("depth_data", b"depth".to_vec()),
+                ("hash_chain", serde_json::to_vec(chain).unwrap()),
+                ("metadata", serde_json::to_vec(&metadata).unwrap()),
+            ] {
+                body.extend_from_slice(
+                    format!(
+                        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n"
+                    )
+                    .as_bytes(),
+                );
+                body.extend_from_slice(&content);
+                body.extend_from_slice(b"\r\n");
+            }
+            body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+            body
+        }
+
+        /// Uploads `chain` as `device_id` and returns the stored evidence
+        async fn upload(
+            state: &AppState,
+            device_id: Uuid,
+            chain: &HashChainData,
+        ) -> serde_json::Value {
+            let started_at = Utc::now() - Duration::seconds(2);
+            upload_signed(state, device_id, chain, started_at, None)
+                .await
+                .0
+        }
+
+        /// Uploads `chain` with an optional assertion; returns the stored
+        /// evidence and assertion counter
+        async fn upload_signed(
+            state: &AppState,
+            device_id: Uuid,
+            chain: &HashChainData,
+            started_at: DateTime<Utc>,
+            assertion: Option<String>,
+        ) -> (serde_json::Value, Option<i64>) {
+            let device_ctx = DeviceContext {
+                device_id,
+                attestation_level: DeviceAttestationLevel::SecureEnclave,
+                model: "iPhone 15 Pro".to_string(),
+                has_lidar: true,
+                is_verified: true,
+            };
+            let app = Router::new()
+                .nest("/captures/video", router())
+                .with_state(state.clone())
+                .layer(axum::middleware::from_fn(
+                    move |mut req: Request<Body>, next: axum::middleware::Next| {
+                        req.extensions_mut().insert(Uuid::new_v4());
+                        req.extensions_mut().insert(device_ctx.clone());
+                        next.run(req)
+                    },
+                ));
+
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/captures/video")
+                        .header(
+                            header::CONTENT_TYPE,
+                            format!("multipart/form-data; boundary={BOUNDARY}"),
+                        )
+                        .body(Body::from(multipart_body(chain, started_at, assertion)))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            let capture_id: Uuid = json["data"]["capture_id"]
+                .as_str()
+                .unwrap()
+                .parse()
+                .unwrap();
+
+            sqlx::query_as("SELECT evidence, assertion_counter FROM captures WHERE id = $1")
+                .bind(capture_id)
+                .fetch_one(&state.db)
+                .await
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_upload_verifies_chain_against_device_genesis() {
+            let state = create_test_state().await;
+            let genesis = compute_hash_chain_genesis(Uuid::new_v4().as_bytes());
+            let device_id = insert_device(&state.db, &genesis).await;
+
+            let evidence = upload(&state, device_id, &v2_chain(&genesis, 30, None)).await;
+            let hash_chain = &evidence["hash_chain"];
+            assert_eq!(hash_chain["status"], "pass");
+            assert_eq!(hash_chain["derivation_verified"], true);
+            assert_eq!(hash_chain["genesis_bound"], true);
+            assert!(hash_chain.get("broken_at_frame").is_none());
+
+            // A chain started from another genesis breaks at its first frame
+            let other = compute_hash_chain_genesis(b"another registration");
+            let evidence = upload(&state, device_id, &v2_chain(&other, 30, None)).await;
+            assert_eq!(evidence["hash_chain"]["status"], "fail");
+            assert_eq!(evidence["hash_chain"]["broken_at_frame"], 1);
+        }
+
+        #[tokio::test]
+        async fn test_upload_reports_broken_frame() {
+            let state = create_test_state().await;
+            let genesis = compute_hash_chain_genesis(Uuid::new_v4().as_bytes());
+            let device_id = insert_device(&state.db, &genesis).await;
+
+            let evidence = upload(&state, device_id, &v2_chain(&genesis, 30, Some(12))).await;
+            let hash_chain = &evidence["hash_chain"];
+            assert_eq!(hash_chain["status"], "fail");
+            assert_eq!(hash_chain["broken_at_frame"], 12);
+            assert_eq!(hash_chain["chain_intact"], false);
+        }
+
+        #[tokio::test]
+        async fn test_upload_records_counter_and_flags_out_of_order_capture() {
+            let state = create_test_state().await;
+            let genesis = compute_hash_chain_genesis(Uuid::new_v4().as_bytes());
+            let device_id = insert_device(&state.db, &genesis).await;
+            let now = Utc::now();
+
+            let first = v2_chain(&genesis, 30, None);
+            let assertion = sign_final_hash(&state.config, &first.final_hash, 10);
+            let (evidence, counter) = upload_signed(
+                &state,
+                device_id,
+                &first,
+                now - Duration::minutes(1),
+                Some(assertion),
+            )
+            .await;
+            assert_eq!(counter, Some(10));
+            assert_eq!(evidence["hardware_attestation"]["status"], "pass");
+            assert_eq!(
+                evidence["metadata"]["timestamp_consistency"]["consistent"],
+                true
+            );
+
+            // A later assertion dated well before the previous capture
+            let second = v2_chain(&genesis, 30, Some(5));
+            let assertion = sign_final_hash(&state.config, &second.final_hash, 11);
+            let (evidence, counter) = upload_signed(
+                &state,
+                device_id,
+                &second,
+                now - Duration::minutes(30),
+                Some(assertion),
+            )
+            .await;
+            assert_eq!(counter, Some(11));
+            let consistency = &evidence["metadata"]["timestamp_consistency"];
+            assert_eq!(consistency["consistent"], false);
+            assert!(consistency["reason"]
+                .as_str()
+                .unwrap()
+                .contains("lower assertion counter"));
+        }
+    }
 }
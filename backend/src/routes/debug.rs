@@ -9,24 +9,36 @@
 //! - GET /api/v1/debug/logs/{id} - Get single log entry
 //! - DELETE /api/v1/debug/logs - Delete logs with filters
 //! - GET /api/v1/debug/logs/stats - Get aggregated statistics
+//! - GET /api/v1/debug/logs/stream - Tail new entries (Server-Sent Events)
+//! - GET /api/v1/debug/logs/sessions/{session_id} - App session timeline
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
 
 use axum::{
     extract::{Extension, Path, Query, State},
     http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
     routing::{delete, get, post},
     Json, Router,
 };
+use chrono::Utc;
+use futures_util::{stream, Stream};
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
 use crate::models::{
-    BatchInsertResponse, CreateDebugLog, DebugLogDelete, DebugLogQuery, DebugLogStats,
-    DeleteResponse, QueryLogsResponse,
+    BatchInsertResponse, CreateDebugLog, DebugLog, DebugLogDelete, DebugLogQuery, DebugLogStats,
+    DeleteResponse, QueryLogsResponse, SessionTimelineResponse,
 };
 use crate::routes::AppState;
 use crate::services::debug_logs;
 use crate::types::ApiResponse;
 
+/// Seconds between database polls of a live tail
+const STREAM_POLL_INTERVAL_SECS: u64 = 1;
+
 // ============================================================================
 // Router Setup
 // ============================================================================
@@ -37,6 +49,8 @@ use crate::types::ApiResponse;
 /// - POST /logs - Batch insert log entries
 /// - GET /logs - Query logs with filters
 /// - GET /logs/stats - Get aggregated statistics (must be before /{id})
+/// - GET /logs/stream - Tail new entries as Server-Sent Events
+/// - GET /logs/sessions/{session_id} - App session timeline
 /// - GET /logs/{id} - Get single log entry
 /// - DELETE /logs - Delete logs with filters
 pub fn router() -> Router<AppState> {
@@ -45,6 +59,8 @@ pub fn router() -> Router<AppState> {
         .route("/logs", get(query_logs))
         .route("/logs", delete(delete_logs))
         .route("/logs/stats", get(get_stats))
+        .route("/logs/stream", get(stream_logs))
+        .route("/logs/sessions/{session_id}", get(get_session_timeline))
         .route("/logs/{id}", get(get_log_by_id))
 }
 
@@ -117,12 +133,17 @@ async fn create_logs(
 /// - source: Filter by source (ios, backend, web)
 /// - level: Filter by level (debug, info, warn, error)
 /// - event: Filter by event type (substring match)
-/// - since: ISO timestamp, logs after this time
+/// - device_id / session_id: Filter by device or app session (exact match)
+/// - q: Full-text search over event and payload ("quoted phrases", -exclude)
+/// - payload: JSON object the payload must contain, e.g. {"status":"400"}
+/// - since: ISO timestamp, logs at or after this time
+/// - until: ISO timestamp, logs before this time
+/// - cursor: `next_cursor` from the previous page
 /// - limit: Max results (default 100, max 1000)
 /// - order: "asc" or "desc" (default "desc")
 ///
 /// # Responses
-/// - 200 OK: Returns logs with count and has_more pagination info
+/// - 200 OK: Returns logs with count, has_more and next_cursor
 /// - 400 Bad Request: Invalid query parameters
 /// - 404 Not Found: Debug logging disabled
 async fn query_logs(
@@ -136,36 +157,155 @@ async fn query_logs(
         request_id,
     })?;
 
-    let limit = query.effective_limit();
-
     tracing::debug!(
         request_id = %request_id,
         correlation_id = ?query.correlation_id,
         source = ?query.source,
         level = ?query.level,
         event = ?query.event,
+        q = ?query.q,
         limit = ?query.limit,
         "Querying debug logs"
     );
 
-    let logs = debug_logs::query(&state.db, &query)
+    let response = debug_logs::query_page(&state.db, &query)
         .await
         .map_err(|e| ApiErrorWithRequestId {
             error: e,
             request_id,
         })?;
 
-    // Determine if there are more results (if we got exactly limit results, there may be more)
-    let count = logs.len();
-    let has_more = count as u32 == limit;
+    Ok(Json(ApiResponse::new(response, request_id)))
+}
+
+/// GET /api/v1/debug/logs/sessions/{session_id} - App session timeline
+///
+/// Returns a summary of the whole session (counts, requests, devices, time
+/// span) and its entries across iOS, web and backend, oldest first.
+///
+/// # Query Parameters
+/// Same as GET /logs; filters and `cursor` apply to the entries only.
+/// `order` defaults to "asc".
+///
+/// # Responses
+/// - 200 OK: Summary and one page of entries (empty for unknown sessions)
+/// - 400 Bad Request: Invalid query parameters
+/// - 404 Not Found: Debug logging disabled
+async fn get_session_timeline(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Path(session_id): Path<Uuid>,
+    Query(query): Query<DebugLogQuery>,
+) -> Result<Json<ApiResponse<SessionTimelineResponse>>, ApiErrorWithRequestId> {
+    query.validate().map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Validation(e),
+        request_id,
+    })?;
 
-    let response = QueryLogsResponse {
-        logs,
-        count,
-        has_more,
-    };
+    tracing::debug!(
+        request_id = %request_id,
+        session_id = %session_id,
+        "Getting debug log session timeline"
+    );
 
-    Ok(Json(ApiResponse::new(response, request_id)))
+    let timeline = debug_logs::session_timeline(&state.db, session_id, &query)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
+    Ok(Json(ApiResponse::new(timeline, request_id)))
+}
+
+/// GET /api/v1/debug/logs/stream - Tail new entries as Server-Sent Events
+///
+/// Sends each new entry matching the filters as a `log` event (JSON data,
+/// SSE id = entry id), oldest first, until the client disconnects. Database
+/// errors are logged and sent as a generic `error` event; polling continues.
+///
+/// # Query Parameters
+/// Same filters as GET /logs (correlation_id, source, level, event,
+/// device_id, session_id, q, payload), plus:
+/// - since: ISO timestamp; first replays entries the server stored after it
+///   (default: now). Unlike GET /logs, this compares the server insert time
+///   (`created_at`), not the client-supplied `timestamp`
+/// - limit: Max entries fetched per poll (default 100, max 1000)
+///
+/// `until`, `cursor` and `order` are ignored.
+///
+/// # Responses
+/// - 200 OK: `text/event-stream`
+/// - 400 Bad Request: Invalid query parameters
+/// - 404 Not Found: Debug logging disabled
+async fn stream_logs(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Query(query): Query<DebugLogQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiErrorWithRequestId> {
+    query.validate().map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Validation(e),
+        request_id,
+    })?;
+
+    tracing::debug!(
+        request_id = %request_id,
+        correlation_id = ?query.correlation_id,
+        source = ?query.source,
+        level = ?query.level,
+        event = ?query.event,
+        since = ?query.since,
+        "Streaming debug logs"
+    );
+
+    let cursor = debug_logs::TailCursor::new(query.since.unwrap_or_else(Utc::now));
+    let mut interval = tokio::time::interval(Duration::from_secs(STREAM_POLL_INTERVAL_SECS));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    let tail = (state.db, query, cursor, interval, VecDeque::new());
+    let events = stream::unfold(
+        tail,
+        move |(db, query, mut cursor, mut interval, mut pending)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((Ok(event), (db, query, cursor, interval, pending)));
+                }
+                interval.tick().await;
+                match debug_logs::tail(&db, &query, &mut cursor).await {
+                    Ok(logs) => pending.extend(logs.iter().map(log_event)),
+                    Err(e) => {
+                        tracing::error!(
+                            request_id = %request_id,
+                            error = %e,
+                            "Debug log stream poll failed"
+                        );
+                        pending.push_back(error_event());
+                    }
+                }
+            }
+        },
+    );
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// SSE `log` event for one entry
+fn log_event(log: &DebugLog) -> Event {
+    Event::default()
+        .event("log")
+        .id(log.id.to_string())
+        .json_data(log)
+        .unwrap_or_else(|e| {
+            tracing::error!(log_id = %log.id, error = %e, "Failed to encode debug log event");
+            error_event()
+        })
+}
+
+/// SSE `error` event; details stay in the server log
+fn error_event() -> Event {
+    Event::default()
+        .event("error")
+        .data("failed to read debug logs")
 }
 
 /// GET /api/v1/debug/logs/{id} - Get single log entry
@@ -305,14 +445,19 @@ mod tests {
             .await
             .expect("Failed to run migrations");
 
-        // Create minimal storage service (not used by debug endpoints)
-        let storage = Arc::new(crate::services::StorageService::new(&config).await);
+        // In-memory storage (not used by debug endpoints)
+        let storage = Arc::new(crate::services::StorageService::in_memory());
 
         AppState {
             db: pool,
             challenge_store: ChallengeStore::new(),
             config: Arc::new(config),
             storage,
+            gazetteer: Arc::new(crate::services::Gazetteer::bundled().unwrap()),
+            device_registry: Arc::new(crate::services::DeviceRegistry::bundled().unwrap()),
+            detection_policy: Arc::new(crate::services::DetectionPolicy::bundled().unwrap()),
+            confidence_policies: Arc::new(crate::services::ConfidencePolicies::bundled().unwrap()),
+            envelope: None,
         }
     }
 
@@ -945,7 +1090,9 @@ mod tests {
 
         let app = create_test_router(state.clone());
 
-        let cutoff = (Utc::now() - Duration::hours(1)).to_rfc3339();
+        // Z suffix: a literal "+" in the query string would decode as a space
+        let cutoff =
+            (Utc::now() - Duration::hours(1)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
         let response = app
             .oneshot(
                 Request::builder()
@@ -1058,4 +1205,355 @@ mod tests {
 
         cleanup_test_data(&state).await;
     }
+
+    // ========================================================================
+    // GET /debug/logs/stream and Retention Tests
+    // ========================================================================
+
+    /// Reads SSE chunks until one contains `needle`
+    async fn next_sse_frame_with(
+        body: &mut (impl futures_util::Stream<Item = Result<axum::body::Bytes, axum::Error>> + Unpin),
+        needle: &str,
+    ) -> String {
+        use futures_util::StreamExt;
+
+        tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            loop {
+                let chunk = body.next().await.expect("stream ended").unwrap();
+                let frame = String::from_utf8(chunk.to_vec()).unwrap();
+                if frame.contains(needle) {
+                    return frame;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for SSE frame")
+    }
+
+    #[tokio::test]
+    async fn test_stream_logs_replays_then_tails() {
+        let state = create_test_state().await;
+        let app = create_test_router(state.clone());
+
+        let first = sample_log(LogSource::Ios, LogLevel::Info, "STREAM_FIRST");
+        let correlation_id = first.correlation_id;
+        debug_logs::insert_batch(&state.db, vec![first])
+            .await
+            .unwrap();
+
+        let since = (Utc::now() - Duration::minutes(1))
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::GET)
+                    .uri(format!(
+                        "/debug/logs/stream?correlation_id={correlation_id}&since={since}"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        let mut body = response.into_body().into_data_stream();
+
+        let frame = next_sse_frame_with(&mut body, "STREAM_FIRST").await;
+        assert!(frame.starts_with("event: log"));
+        assert!(frame.contains(&correlation_id.to_string()));
+
+        let mut second = sample_log(LogSource::Web, LogLevel::Warn, "STREAM_SECOND");
+        second.correlation_id = correlation_id;
+        debug_logs::insert_batch(&state.db, vec![second])
+            .await
+            .unwrap();
+
+        let frame = next_sse_frame_with(&mut body, "STREAM_SECOND").await;
+        assert!(!frame.contains("STREAM_FIRST"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_logs_invalid_level() {
+        let state = create_test_state().await;
+        let app = create_test_router(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(Method::GET)
+                    .uri("/debug/logs/stream?level=verbose")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_tail_delivers_each_entry_once() {
+        let state = create_test_state().await;
+
+        let log = sample_log(LogSource::Backend, LogLevel::Info, "TAIL_ONCE");
+        let query = DebugLogQuery {
+            correlation_id: Some(log.correlation_id),
+            ..Default::default()
+        };
+        let mut cursor = debug_logs::TailCursor::new(Utc::now() - Duration::minutes(1));
+        debug_logs::insert_batch(&state.db, vec![log])
+            .await
+            .unwrap();
+
+        let first = debug_logs::tail(&state.db, &query, &mut cursor)
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 1);
+
+        // Still inside the overlap window, but already delivered
+        let again = debug_logs::tail(&state.db, &query, &mut cursor)
+            .await
+            .unwrap();
+        assert!(again.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_uses_insert_time() {
+        let state = create_test_state().await;
+
+        let mut expired = sample_log(LogSource::Ios, LogLevel::Info, "EXPIRED_LOG");
+        // A recent client timestamp does not keep an old row alive
+        expired.timestamp = Utc::now();
+        let correlation_id = expired.correlation_id;
+        debug_logs::insert_batch(&state.db, vec![expired])
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE debug_logs SET created_at = NOW() - INTERVAL '8 days' WHERE correlation_id = $1",
+        )
+        .bind(correlation_id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let mut fresh = sample_log(LogSource::Ios, LogLevel::Info, "FRESH_LOG");
+        fresh.correlation_id = correlation_id;
+        fresh.timestamp = Utc::now() - Duration::days(30);
+        debug_logs::insert_batch(&state.db, vec![fresh])
+            .await
+            .unwrap();
+
+        let deleted = debug_logs::delete_expired(&state.db, 7).await.unwrap();
+        assert!(deleted >= 1);
+
+        let remaining = debug_logs::query(
+            &state.db,
+            &DebugLogQuery {
+                correlation_id: Some(correlation_id),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].event, "FRESH_LOG");
+    }
+
+    // ========================================================================
+    // Search, Pagination and Session Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_query_logs_cursor_pages_without_gaps() {
+        let state = create_test_state().await;
+
+        let correlation_id = Uuid::new_v4();
+        let base = Utc::now() - Duration::minutes(5);
+        let logs: Vec<_> = (0..5)
+            .map(|i| {
+                let mut log = sample_log(LogSource::Ios, LogLevel::Info, &format!("PAGE_{i}"));
+                log.correlation_id = correlation_id;
+                // Two entries share a timestamp; the id breaks the tie
+                log.timestamp = base + Duration::seconds(i.min(3));
+                log
+            })
+            .collect();
+        debug_logs::insert_batch(&state.db, logs).await.unwrap();
+
+        let app = create_test_router(state);
+        let mut events = Vec::new();
+        let mut cursor: Option<String> = None;
+        for _ in 0..5 {
+            let mut uri = format!("/debug/logs?correlation_id={correlation_id}&limit=2");
+            if let Some(cursor) = &cursor {
+                uri.push_str(&format!("&cursor={cursor}"));
+            }
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            for log in json["data"]["logs"].as_array().unwrap() {
+                events.push(log["event"].as_str().unwrap().to_string());
+            }
+            cursor = json["data"]["next_cursor"].as_str().map(str::to_string);
+            assert_eq!(json["data"]["has_more"], cursor.is_some());
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(events.len(), 5);
+        assert!(events[..2].contains(&"PAGE_4".to_string()));
+        assert!(events[..2].contains(&"PAGE_3".to_string()));
+        assert_eq!(events[2..], ["PAGE_2", "PAGE_1", "PAGE_0"]);
+    }
+
+    #[tokio::test]
+    async fn test_query_logs_search_payload_and_range() {
+        let state = create_test_state().await;
+
+        let correlation_id = Uuid::new_v4();
+        let device_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut upload = sample_log(LogSource::Ios, LogLevel::Error, "Upload failed");
+        upload.payload = json!({"stage": "upload", "reason": "timeout", "attempt": 2});
+        upload.device_id = Some(device_id);
+        let mut depth = sample_log(LogSource::Ios, LogLevel::Info, "Depth captured");
+        depth.payload = json!({"stage": "capture", "reason": "ok"});
+        depth.timestamp = now - Duration::hours(2);
+        let mut web = sample_log(LogSource::Web, LogLevel::Warn, "Verify slow");
+        web.payload = json!({"stage": "upload", "reason": "slow network"});
+        for log in [&mut upload, &mut depth, &mut web] {
+            log.correlation_id = correlation_id;
+        }
+        debug_logs::insert_batch(&state.db, vec![upload, depth, web])
+            .await
+            .unwrap();
+
+        let events = |query: DebugLogQuery| {
+            let pool = state.db.clone();
+            async move {
+                let query = DebugLogQuery {
+                    correlation_id: Some(correlation_id),
+                    order: Some("asc".to_string()),
+                    ..query
+                };
+                query.validate().unwrap();
+                debug_logs::query(&pool, &query)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|log| log.event)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // Full text matches payload values as well as the event
+        let found = events(DebugLogQuery {
+            q: Some("timeout".to_string()),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(found, ["Upload failed"]);
+
+        let found = events(DebugLogQuery {
+            q: Some("upload -slow".to_string()),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(found, ["Upload failed"]);
+
+        let found = events(DebugLogQuery {
+            payload: Some(r#"{"stage": "upload"}"#.to_string()),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(found, ["Upload failed", "Verify slow"]);
+
+        let found = events(DebugLogQuery {
+            until: Some(now - Duration::hours(1)),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(found, ["Depth captured"]);
+
+        let found = events(DebugLogQuery {
+            device_id: Some(device_id),
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(found, ["Upload failed"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_session_timeline() {
+        let state = create_test_state().await;
+
+        let session_id = Uuid::new_v4();
+        let device_id = Uuid::new_v4();
+        let request_a = Uuid::new_v4();
+        let request_b = Uuid::new_v4();
+        let base = Utc::now() - Duration::minutes(10);
+
+        let mut logs = vec![
+            sample_log(LogSource::Ios, LogLevel::Info, "SESSION_START"),
+            sample_log(LogSource::Backend, LogLevel::Info, "SESSION_UPLOAD"),
+            sample_log(LogSource::Ios, LogLevel::Error, "SESSION_FAIL"),
+        ];
+        for (i, log) in logs.iter_mut().enumerate() {
+            log.session_id = Some(session_id);
+            log.timestamp = base + Duration::seconds(i as i64);
+            log.correlation_id = if i == 0 { request_a } else { request_b };
+        }
+        logs[0].device_id = Some(device_id);
+        // Outside the session
+        logs.push(sample_log(LogSource::Ios, LogLevel::Info, "OTHER_SESSION"));
+        debug_logs::insert_batch(&state.db, logs).await.unwrap();
+
+        let app = create_test_router(state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/debug/logs/sessions/{session_id}?limit=2"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let data = &json["data"];
+
+        assert_eq!(data["session_id"], session_id.to_string());
+        assert_eq!(data["summary"]["total"], 3);
+        assert_eq!(data["summary"]["requests"], 2);
+        assert_eq!(data["summary"]["device_ids"], json!([device_id]));
+        assert_eq!(data["summary"]["by_source"]["ios"], 2);
+        assert_eq!(data["summary"]["by_level"]["error"], 1);
+
+        // Oldest first by default, paged by limit
+        let events: Vec<_> = data["logs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|log| log["event"].as_str().unwrap())
+            .collect();
+        assert_eq!(events, ["SESSION_START", "SESSION_UPLOAD"]);
+        assert_eq!(data["has_more"], true);
+        assert!(data["next_cursor"].is_string());
+    }
 }
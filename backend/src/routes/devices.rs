@@ -49,10 +49,12 @@ use std::net::SocketAddr;
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
+use crate::middleware::AuditOutcome;
 use crate::models::Device;
 use crate::routes::AppState;
 use crate::services::{
-    verify_android_attestation, verify_attestation, AndroidAttestationError, ChallengeError,
+    compute_hash_chain_genesis, verify_android_attestation, verify_attestation,
+    AndroidAttestationError, AndroidDeviceInfo, ChallengeError, DeviceRegistry,
 };
 use crate::types::ApiResponse;
 
@@ -263,6 +265,106 @@ fn validate_common_registration_request(req: &DeviceRegistrationRequest) -> Resu
     Ok(())
 }
 
+/// Validates the claimed device capabilities against the capability registry.
+///
+/// Rejects impossible claims:
+/// - A model registered for the other platform (e.g., "Pixel 8 Pro" on iOS)
+/// - has_lidar = true for a registered model without a LiDAR scanner
+///
+/// Models not in the registry are accepted so new hardware can register before
+/// the registry is updated, but their LiDAR claim is not trusted.
+///
+/// Returns the has_lidar value to record for the device.
+fn validate_device_capabilities(
+    req: &DeviceRegistrationRequest,
+    registry: &DeviceRegistry,
+) -> Result<bool, ApiError> {
+    let platform = req.platform.to_lowercase();
+    let Some(capability) = registry.lookup_for_platform(&platform, &req.model) else {
+        if let Some(other) = registry.lookup(&req.model) {
+            tracing::warn!(
+                platform = %platform,
+                model = %req.model,
+                registered_platform = %other.platform,
+                "Validation failed: model registered for a different platform"
+            );
+            return Err(ApiError::Validation(format!(
+                "model '{}' is not an {platform} device",
+                req.model
+            )));
+        }
+        if req.has_lidar {
+            tracing::warn!(
+                platform = %platform,
+                model = %req.model,
+                "Model not in device capability registry - recording has_lidar = false"
+            );
+        } else {
+            tracing::info!(
+                platform = %platform,
+                model = %req.model,
+                "Model not in device capability registry"
+            );
+        }
+        return Ok(false);
+    };
+
+    if req.has_lidar && !capability.has_lidar {
+        tracing::warn!(
+            model = %req.model,
+            marketing_name = %capability.marketing_name,
+            "Validation failed: has_lidar claimed for a device without LiDAR"
+        );
+        return Err(ApiError::Validation(format!(
+            "has_lidar must be false for {}",
+            capability.marketing_name
+        )));
+    }
+
+    Ok(req.has_lidar)
+}
+
+/// Validates the claimed model against the Android ID-attested identity.
+///
+/// Only TEE-enforced ID attestation counts; without it the claimed model stays
+/// self-reported. iOS App Attest carries no model identifiers, so iOS claims
+/// are checked against the capability registry only.
+fn validate_attested_model(
+    req: &DeviceRegistrationRequest,
+    device_info: &AndroidDeviceInfo,
+    registry: &DeviceRegistry,
+) -> Result<(), ApiError> {
+    let Some(attested_model) = device_info
+        .model
+        .as_deref()
+        .filter(|_| device_info.id_attested)
+    else {
+        return Ok(());
+    };
+
+    let branded = device_info
+        .brand
+        .as_deref()
+        .map(|brand| format!("{brand} {attested_model}"));
+    let matches = registry.same_model("android", &req.model, attested_model)
+        || branded.is_some_and(|branded| registry.same_model("android", &req.model, &branded));
+
+    if !matches {
+        tracing::warn!(
+            model = %req.model,
+            attested_model = %attested_model,
+            attested_brand = ?device_info.brand,
+            "Validation failed: claimed model does not match attested model"
+        );
+        return Err(ApiError::Validation(format!(
+            "model '{}' does not match attested device model '{attested_model}'",
+            req.model
+        )));
+    }
+
+    Ok(())
+}
+
 /// Validates iOS device registration request.
 ///
 /// Checks:
@@ -414,6 +516,68 @@ async fn insert_device(
     })
 }
 
+/// Stores the v2 hash chain genesis derived from the registration challenge.
+///
+/// Only called for attested iOS devices; the challenge was consumed by the
+/// ChallengeStore, so the genesis is unique to this registration.
+async fn store_hash_chain_genesis(
+    pool: &sqlx::PgPool,
+    device_id: Uuid,
+    challenge: &[u8],
+) -> Result<(), ApiError> {
+    let genesis = compute_hash_chain_genesis(challenge);
+
+    sqlx::query("UPDATE devices SET hash_chain_genesis = $2 WHERE id = $1")
+        .bind(device_id)
+        .bind(genesis.as_slice())
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                device_id = %device_id,
+                error = %e,
+                "Failed to store hash chain genesis"
+            );
+            ApiError::Database(e)
+        })?;
+
+    Ok(())
+}
+
+/// Stores the hardware-attested brand, model and device codename.
+///
+/// Only called when the Android attestation carried TEE-enforced ID
+/// attestation; captures are later cross-checked against `attested_model`.
+async fn store_attested_identity(
+    pool: &sqlx::PgPool,
+    device_id: Uuid,
+    device_info: &AndroidDeviceInfo,
+) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        UPDATE devices
+        SET attested_brand = $2, attested_model = $3, attested_device = $4
+        WHERE id = $1
+        "#,
+    )
+    .bind(device_id)
+    .bind(device_info.brand.as_deref())
+    .bind(device_info.model.as_deref())
+    .bind(device_info.device.as_deref())
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(
+            device_id = %device_id,
+            error = %e,
+            "Failed to store attested device identity"
+        );
+        ApiError::Database(e)
+    })?;
+
+    Ok(())
+}
+
 // ============================================================================
 // Route Handlers
 // ============================================================================
@@ -501,7 +665,14 @@ async fn register_device(
     State(state): State<AppState>,
     Extension(request_id): Extension<Uuid>,
     Json(req): Json<DeviceRegistrationRequest>,
-) -> Result<(StatusCode, Json<ApiResponse<DeviceRegistrationResponse>>), ApiErrorWithRequestId> {
+) -> Result<
+    (
+        StatusCode,
+        AuditOutcome,
+        Json<ApiResponse<DeviceRegistrationResponse>>,
+    ),
+    ApiErrorWithRequestId,
+> {
     tracing::info!(
         request_id = %request_id,
         platform = %req.platform,
@@ -518,22 +689,39 @@ async fn register_device(
         request_id,
     })?;
 
-    // Platform routing (Story 10-3)
-    match req.platform.to_lowercase().as_str() {
-        "ios" => register_ios_device(state, request_id, req).await,
-        "android" => register_android_device(state, request_id, req).await,
-        _ => Err(ApiErrorWithRequestId {
-            error: ApiError::Validation("unsupported platform".to_string()),
+    // Reject capability claims the registry knows to be impossible
+    let has_lidar = validate_device_capabilities(&req, &state.device_registry).map_err(|e| {
+        ApiErrorWithRequestId {
+            error: e,
             request_id,
-        }),
-    }
+        }
+    })?;
+
+    // Platform routing (Story 10-3)
+    let (status, response) = match req.platform.to_lowercase().as_str() {
+        "ios" => register_ios_device(state, request_id, req, has_lidar).await?,
+        "android" => register_android_device(state, request_id, req).await?,
+        _ => {
+            return Err(ApiErrorWithRequestId {
+                error: ApiError::Validation("unsupported platform".to_string()),
+                request_id,
+            })
+        }
+    };
+
+    // Record the registered device in verification_logs
+    let audit = AuditOutcome::new("registered").device(response.0.data.device_id);
+    Ok((status, audit, response))
 }
 
 /// Registers an iOS device with DCAppAttest verification.
+///
+/// `has_lidar` is the registry-checked capability, not the raw claim.
 async fn register_ios_device(
     state: AppState,
     request_id: Uuid,
     req: DeviceRegistrationRequest,
+    has_lidar: bool,
 ) -> Result<(StatusCode, Json<ApiResponse<DeviceRegistrationResponse>>), ApiErrorWithRequestId> {
     // Validate iOS-specific request
     let (key_id, attestation_object_b64, challenge_bytes) = validate_ios_registration_request(&req)
@@ -613,6 +801,7 @@ async fn register_ios_device(
                     request_id,
                     &key_id,
                     &req,
+                    has_lidar,
                     &attestation_bytes,
                 )
                 .await;
@@ -671,7 +860,7 @@ async fn register_ios_device(
             key_id: &key_id,
             platform: &req.platform,
             model: &req.model,
-            has_lidar: req.has_lidar,
+            has_lidar,
             attestation_bytes: &attestation_bytes,
             attestation_level,
             public_key: public_key.as_deref(),
@@ -686,6 +875,21 @@ async fn register_ios_device(
         request_id,
     })?;
 
+    // Bind v2 video hash chains to the verified registration challenge.
+    // Non-fatal: without a genesis, v2 chains are verified but not device-bound.
+    if device.attestation_level == "secure_enclave" {
+        if let Some(ref challenge) = challenge_bytes {
+            if let Err(e) = store_hash_chain_genesis(&state.db, device.id, challenge).await {
+                tracing::warn!(
+                    request_id = %request_id,
+                    device_id = %device.id,
+                    error = %e,
+                    "Hash chain genesis not stored (non-fatal)"
+                );
+            }
+        }
+    }
+
     // Build security level response (Story 10-2)
     let security_level_response = device
         .security_level
@@ -726,6 +930,7 @@ async fn register_unverified_device(
     request_id: Uuid,
     key_id: &str,
     req: &DeviceRegistrationRequest,
+    has_lidar: bool,
     attestation_bytes: &[u8],
 ) -> Result<(StatusCode, Json<ApiResponse<DeviceRegistrationResponse>>), ApiErrorWithRequestId> {
     let device = insert_device(
@@ -734,7 +939,7 @@ async fn register_unverified_device(
             key_id,
             platform: &req.platform,
             model: &req.model,
-            has_lidar: req.has_lidar,
+            has_lidar,
             attestation_bytes,
             attestation_level: "unverified",
             public_key: None,
@@ -825,6 +1030,17 @@ async fn register_android_device(
         }
     })?;
 
+    // Reject a claimed model that contradicts the ID-attested model
+    validate_attested_model(
+        &req,
+        &attestation_result.device_info,
+        &state.device_registry,
+    )
+    .map_err(|e| ApiErrorWithRequestId {
+        error: e,
+        request_id,
+    })?;
+
     // Extract key_id from attestation payload or use provided value
     let key_id = android_att.key_id.clone().unwrap_or_else(|| {
         STANDARD
@@ -883,6 +1099,21 @@ async fn register_android_device(
         request_id,
     })?;
 
+    // Record the hardware-attested identity for capture cross-checks.
+    // Non-fatal: without it, captures are compared to the claimed model only.
+    if attestation_result.device_info.id_attested {
+        if let Err(e) =
+            store_attested_identity(&state.db, device.id, &attestation_result.device_info).await
+        {
+            tracing::warn!(
+                request_id = %request_id,
+                device_id = %device.id,
+                error = %e,
+                "Attested device identity not stored (non-fatal)"
+            );
+        }
+    }
+
     // Build security level response
     let security_level_response = SecurityLevelResponse {
         attestation: security_level_str.to_string(),
@@ -1217,6 +1448,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_device_capabilities_accepts_known_models() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        assert!(matches!(
+            validate_device_capabilities(&valid_nested_request(), &registry),
+            Ok(true)
+        ));
+        assert!(matches!(
+            validate_device_capabilities(&valid_android_request(), &registry),
+            Ok(false)
+        ));
+
+        let mut req = valid_nested_request();
+        req.model = "iPhone16,1".to_string();
+        assert!(validate_device_capabilities(&req, &registry).is_ok());
+    }
+
+    #[test]
+    fn test_validate_device_capabilities_rejects_lidar_on_non_lidar_model() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let mut req = valid_nested_request();
+        req.model = "iPhone 15".to_string();
+        let result = validate_device_capabilities(&req, &registry);
+        assert!(matches!(result, Err(ApiError::Validation(ref msg)) if msg.contains("has_lidar")));
+
+        // Under-claiming is not impossible
+        req.has_lidar = false;
+        assert!(matches!(
+            validate_device_capabilities(&req, &registry),
+            Ok(false)
+        ));
+    }
+
+    #[test]
+    fn test_validate_device_capabilities_rejects_wrong_platform() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let mut req = valid_nested_request();
+        req.model = "Pixel 8 Pro".to_string();
+        req.has_lidar = false;
+        let result = validate_device_capabilities(&req, &registry);
+        assert!(
+            matches!(result, Err(ApiError::Validation(ref msg)) if msg.contains("not an ios device"))
+        );
+    }
+
+    #[test]
+    fn test_validate_device_capabilities_unknown_model_accepted_without_lidar() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let mut req = valid_nested_request();
+        req.model = "iPhone 19 Pro".to_string();
+        assert!(req.has_lidar);
+        // Accepted, but the unverifiable LiDAR claim is not recorded
+        assert!(matches!(
+            validate_device_capabilities(&req, &registry),
+            Ok(false)
+        ));
+    }
+
+    fn attested_device_info(brand: &str, model: &str, id_attested: bool) -> AndroidDeviceInfo {
+        AndroidDeviceInfo {
+            brand: Some(brand.to_string()),
+            device: None,
+            product: None,
+            manufacturer: None,
+            model: Some(model.to_string()),
+            os_version: None,
+            os_patch_level: None,
+            id_attested,
+        }
+    }
+
+    #[test]
+    fn test_validate_attested_model_matches() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let req = valid_android_request();
+        let info = attested_device_info("google", "Pixel 8 Pro", true);
+        assert!(validate_attested_model(&req, &info, &registry).is_ok());
+
+        // Marketing name against a regional model code
+        let mut req = valid_android_request();
+        req.model = "Galaxy S24 Ultra".to_string();
+        let info = attested_device_info("samsung", "SM-S928B", true);
+        assert!(validate_attested_model(&req, &info, &registry).is_ok());
+
+        // Brand-prefixed claim for a model not in the registry
+        req.model = "Acme Phone 2".to_string();
+        let info = attested_device_info("Acme", "Phone 2", true);
+        assert!(validate_attested_model(&req, &info, &registry).is_ok());
+    }
+
+    #[test]
+    fn test_validate_attested_model_rejects_mismatch() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let req = valid_android_request();
+        let info = attested_device_info("samsung", "SM-S928B", true);
+        let result = validate_attested_model(&req, &info, &registry);
+        assert!(matches!(result, Err(ApiError::Validation(ref msg)) if msg.contains("attested")));
+    }
+
+    #[test]
+    fn test_validate_attested_model_ignores_software_ids() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let req = valid_android_request();
+        let info = attested_device_info("samsung", "SM-S928B", false);
+        assert!(validate_attested_model(&req, &info, &registry).is_ok());
+    }
+
     #[test]
     fn test_map_android_attestation_error_software_only() {
         let request_id = Uuid::new_v4();
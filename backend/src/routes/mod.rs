@@ -9,8 +9,11 @@ use sqlx::PgPool;
 use std::sync::Arc;
 
 use crate::config::Config;
-use crate::middleware::{DeviceAuthConfig, DeviceAuthLayer};
-use crate::services::{ChallengeStore, StorageService};
+use crate::middleware::{log_verification_activity, DeviceAuthConfig, DeviceAuthLayer};
+use crate::services::{
+    ChallengeStore, ConfidencePolicies, DetectionPolicy, DeviceRegistry, Envelope, Gazetteer,
+    StorageService, VerificationLogger,
+};
 
 pub mod captures;
 pub mod captures_hash_only;
@@ -18,6 +21,7 @@ pub mod captures_video;
 pub mod debug;
 pub mod devices;
 pub mod health;
+pub mod ops;
 pub mod test;
 pub mod verify;
 
@@ -32,6 +36,16 @@ pub struct AppState {
     pub config: Arc<Config>,
     /// S3 storage service (shared, connection-pooled)
     pub storage: Arc<StorageService>,
+    /// Offline reverse geocoder for coarse location labels
+    pub gazetteer: Arc<Gazetteer>,
+    /// Device capability registry (model -> LiDAR / depth sensor)
+    pub device_registry: Arc<DeviceRegistry>,
+    /// Scoring policy merging client detection signals into confidence
+    pub detection_policy: Arc<DetectionPolicy>,
+    /// Named confidence policy profiles for verification requests
+    pub confidence_policies: Arc<ConfidencePolicies>,
+    /// Envelope encryption for capture data at rest (None when disabled)
+    pub envelope: Option<Arc<Envelope>>,
 }
 
 /// Creates the main API router with all routes.
@@ -42,6 +56,8 @@ pub struct AppState {
 /// - `/api/v1/devices/*` - Device routes (public - no auth middleware)
 /// - `/api/v1/captures/*` - Capture routes (protected with device auth middleware)
 /// - `/api/v1/verify-file` - Verification route (public)
+/// - `/api/v1/evidence/schema` - Evidence JSON Schema (public)
+/// - `/api/v1/ops/*` - Operator routes (bearer token, only when OPERATOR_API_TOKEN is set)
 pub fn api_router(state: AppState) -> Router {
     // Create stateful router for health endpoints that need db access
     let health_router = Router::new()
@@ -113,10 +129,27 @@ pub fn api_router(state: AppState) -> Router {
         v1_router = v1_router.nest("/debug", debug::router());
     }
 
+    // Conditionally add operator routes (SECURITY: only enabled via OPERATOR_API_TOKEN)
+    if let Some(token) = state.config.operator_api_token.clone() {
+        v1_router = v1_router.nest("/ops", ops::router(token));
+    }
+
+    // Verification activity log (verification_logs), outermost so it sees
+    // full paths and the final status of every recorded request
+    let verification_logger = Arc::new(VerificationLogger::new(
+        state.db.clone(),
+        state.config.verification_log_ip_mode,
+        state.config.verification_log_ip_salt.clone(),
+    ));
+
     let v1_router = v1_router.with_state(state);
 
     // Combine all routes
     Router::new()
         .merge(health_router)
         .nest("/api/v1", v1_router)
+        .layer(axum::middleware::from_fn_with_state(
+            verification_logger,
+            log_verification_activity,
+        ))
 }
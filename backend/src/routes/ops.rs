@@ -0,0 +1,141 @@
+//! Operator endpoints
+//!
+//! SECURITY: Only mounted when OPERATOR_API_TOKEN is set, and every request
+//! must carry `Authorization: Bearer <token>`.
+//!
+//! ## Endpoints
+//! - GET /api/v1/ops/verification-stats - Aggregate verification activity
+
+use axum::{
+    extract::{Extension, Query, Request, State},
+    http::header::AUTHORIZATION,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiErrorWithRequestId};
+use crate::routes::AppState;
+use crate::services::storage::sha256_digest;
+use crate::services::{verification_stats, VerificationStats};
+use crate::types::ApiResponse;
+
+/// Default stats window in days
+const DEFAULT_STATS_DAYS: i32 = 7;
+
+/// Longest stats window in days
+const MAX_STATS_DAYS: i32 = 90;
+
+// ============================================================================
+// Router Setup
+// ============================================================================
+
+/// Creates the operator routes router, guarded by `token`.
+///
+/// Routes:
+/// - GET /verification-stats - Aggregate verification activity (?days=, default 7, max 90)
+pub fn router(token: String) -> Router<AppState> {
+    Router::new()
+        .route("/verification-stats", get(get_verification_stats))
+        .layer(axum::middleware::from_fn_with_state(
+            Arc::new(token),
+            require_operator_token,
+        ))
+}
+
+// ============================================================================
+// Authentication
+// ============================================================================
+
+/// Rejects requests without the operator bearer token
+async fn require_operator_token(
+    State(token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let presented = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    if presented.is_some_and(|presented| tokens_match(presented, &token)) {
+        return next.run(request).await;
+    }
+
+    let request_id = request
+        .extensions()
+        .get::<Uuid>()
+        .copied()
+        .unwrap_or_else(Uuid::new_v4);
+    ApiErrorWithRequestId {
+        error: ApiError::Forbidden("Invalid operator token".to_string()),
+        request_id,
+    }
+    .into_response()
+}
+
+/// Compares tokens without leaking where they differ through timing
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    let a = sha256_digest(presented.as_bytes());
+    let b = sha256_digest(expected.as_bytes());
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+// ============================================================================
+// Route Handlers
+// ============================================================================
+
+/// Query parameters for verification stats
+#[derive(Debug, Deserialize)]
+pub struct StatsQuery {
+    /// Window length in days, ending now
+    pub days: Option<i32>,
+}
+
+/// GET /api/v1/ops/verification-stats - Aggregate verification activity
+async fn get_verification_stats(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<ApiResponse<VerificationStats>>, ApiErrorWithRequestId> {
+    let days = query.days.unwrap_or(DEFAULT_STATS_DAYS);
+    if !(1..=MAX_STATS_DAYS).contains(&days) {
+        return Err(ApiErrorWithRequestId {
+            error: ApiError::Validation(format!("days must be between 1 and {MAX_STATS_DAYS}")),
+            request_id,
+        });
+    }
+
+    let stats = verification_stats(&state.db, days)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e.into(),
+            request_id,
+        })?;
+
+    Ok(Json(ApiResponse::new(stats, request_id)))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("s3cret", "s3cret"));
+        assert!(!tokens_match("s3cret", "s3cre"));
+        assert!(!tokens_match("", "s3cret"));
+    }
+}
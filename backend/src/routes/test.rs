@@ -214,16 +214,25 @@ async fn create_test_evidence(
             source: None,
             method: Some("lidar".to_string()), // Story 10-5
             unavailable_reason: None,
+            analysis_trust: None,
+            plausibility_issues: Vec::new(),
         },
         MetadataEvidence {
             timestamp_valid: true,
+            timestamp_consistency: None,
             timestamp_delta_seconds: 0,
             model_verified: true,
             model_name: req.metadata.device_model.clone(),
+            model_attested: false,
+            model_mismatch: false,
             resolution_valid: true,
             location_available: req.metadata.latitude.is_some(),
             location_opted_out: false,
+            location_plausible: None,
+            location_plausibility_reason: None,
             location_coarse: None,
+            location_privacy: None,
+            location_label: None,
         },
         ProcessingInfo::new(100, env!("CARGO_PKG_VERSION")),
     );
@@ -5,6 +5,13 @@
 //!
 //! ## Endpoints
 //! - POST /api/v1/verify-file - Upload a file to verify against database
+//! - GET /api/v1/verify/{id} - Public capture details
+//! - GET /api/v1/verify/{id}/media - Original photo or video (decrypted)
+//! - GET /api/v1/verify/{id}/media/{file} - Thumbnail, poster or heatmap (decrypted)
+//! - GET /api/v1/evidence/schema - JSON Schema of the evidence package
+//!
+//! Both endpoints accept `?policy=<name>` to additionally evaluate the stored
+//! evidence under a named confidence policy profile.
 //!
 //! ## Response Types
 //! - "verified" - File hash matches a capture in database
@@ -12,7 +19,9 @@
 //! - "no_record" - No provenance record found
 
 use axum::{
-    extract::{Extension, Path, State},
+    extract::{Extension, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
@@ -24,9 +33,15 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiErrorWithRequestId};
+use crate::middleware::AuditOutcome;
+use crate::models::{evidence_json_schema, EvidencePackage};
 use crate::routes::AppState;
-use crate::services::C2paManifestInfo;
-use crate::types::ApiResponse;
+use crate::services::confidence_policy::ConfidencePolicy;
+use crate::services::{
+    find_tombstone_by_hash, C2paManifestInfo, DerivativeKind, IntegrityStatus, MediaAsset,
+    MediaUrl, PolicyEvaluation,
+};
+use crate::types::{ApiResponse, CommitmentField, MetadataDisclosure};
 
 // ============================================================================
 // Configuration
@@ -35,6 +50,17 @@ use crate::types::ApiResponse;
 /// Maximum file size for verification (50MB for hash-only per Story 8-7)
 const MAX_FILE_SIZE: usize = 50 * 1024 * 1024;
 
+// ============================================================================
+// Request Types
+// ============================================================================
+
+/// Query parameters shared by the verification endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct VerifyQuery {
+    /// Confidence policy profile to evaluate the evidence under
+    pub policy: Option<String>,
+}
+
 // ============================================================================
 // Response Types
 // ============================================================================
@@ -45,12 +71,26 @@ const MAX_FILE_SIZE: usize = 50 * 1024 * 1024;
 pub enum VerificationStatus {
     /// File hash matches a capture in database
     Verified,
+    /// File matches a capture that has since been deleted (tombstone)
+    Deleted,
     /// File has C2PA manifest but no database match
     C2paOnly,
     /// No provenance record found
     NoRecord,
 }
 
+impl VerificationStatus {
+    /// Serialized form, also recorded as the verification log outcome
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationStatus::Verified => "verified",
+            VerificationStatus::Deleted => "deleted",
+            VerificationStatus::C2paOnly => "c2pa_only",
+            VerificationStatus::NoRecord => "no_record",
+        }
+    }
+}
+
 /// File verification response data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileVerificationResponse {
@@ -108,6 +148,27 @@ pub struct FileVerificationResponse {
     /// Media type: "photo" or "video" (Story 8-7)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+
+    /// Evidence evaluated under the requested `?policy=` profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_evaluation: Option<PolicyEvaluation>,
+
+    /// Result of the last stored-media integrity check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrity_status: Option<IntegrityStatus>,
+
+    // ========== Deleted capture (tombstone) fields ==========
+    /// When the matching capture was deleted (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+
+    /// Who deleted it: "owner" or "retention"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<String>,
+
+    /// SHA-256 of the evidence at deletion time (hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence_digest: Option<String>,
 }
 
 /// Public capture details response (for web verification page)
@@ -124,14 +185,43 @@ pub struct CaptureDetailsPublic {
     /// Coarse location (city-level, privacy protected)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location_coarse: Option<String>,
+    /// Human-readable place for the coarse location (e.g. "San Francisco, CA, US")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_label: Option<String>,
     /// Full evidence package
     pub evidence: serde_json::Value,
-    /// Photo URL (presigned S3 URL)
+    /// Photo URL (short-lived presigned URL)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_url: Option<String>,
-    /// Depth map URL (presigned S3 URL)
+    /// When `photo_url` expires (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url_expires_at: Option<String>,
+    /// Web-sized JPEG thumbnail of the photo or video poster (presigned, or
+    /// the decrypting media endpoint)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
+    /// WebP variant of `thumbnail_url`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_webp_url: Option<String>,
+    /// Full-size first frame of a video capture
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poster_url: Option<String>,
+    /// False-colour PNG rendering of the depth map
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth_heatmap_url: Option<String>,
+    /// When the presigned thumbnail, poster and heatmap URLs expire (ISO 8601)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub depth_map_url: Option<String>,
+    pub derivatives_expires_at: Option<String>,
+    /// Committed metadata fields and their disclosure state (hash-only captures)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disclosures: Vec<MetadataDisclosure>,
+    /// Evidence evaluated under the requested `?policy=` profile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_evaluation: Option<PolicyEvaluation>,
+    /// Result of the last stored-media integrity check (re-hash of stored
+    /// media against the recorded hash)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrity_status: Option<IntegrityStatus>,
 }
 
 // ============================================================================
@@ -143,12 +233,23 @@ pub fn router() -> Router<AppState> {
     Router::new()
         .route("/verify-file", post(verify_file))
         .route("/verify/{id}", get(get_capture_public))
+        .route("/verify/{id}/media", get(get_capture_media))
+        .route("/verify/{id}/media/{file}", get(get_capture_derivative))
+        .route("/evidence/schema", get(get_evidence_schema))
 }
 
 // ============================================================================
 // Route Handlers
 // ============================================================================
 
+/// GET /api/v1/evidence/schema - Published evidence JSON Schema
+///
+/// Returns the schema document itself (not wrapped in `ApiResponse`) so it
+/// can be referenced by its `$id` from client validators.
+async fn get_evidence_schema() -> Json<serde_json::Value> {
+    Json(evidence_json_schema())
+}
+
 /// POST /api/v1/verify-file - Verify an uploaded file
 ///
 /// Accepts a file upload (JPEG, PNG, HEIC up to 20MB) and:
@@ -161,22 +262,51 @@ pub fn router() -> Router<AppState> {
 /// Content-Type: multipart/form-data
 /// - file: The image file to verify
 ///
+/// Query: `policy` (optional) - confidence policy profile for a matched capture
+///
 /// # Responses
 /// - 200 OK: Verification result (verified, c2pa_only, or no_record)
-/// - 400 Bad Request: No file uploaded or invalid format
+/// - 400 Bad Request: No file uploaded, invalid format, or unknown policy
 /// - 413 Payload Too Large: File > 20MB
 /// - 429 Too Many Requests: Rate limit exceeded
 /// - 500 Internal Server Error: Processing failed
 async fn verify_file(
+    state: State<AppState>,
+    request_id: Extension<Uuid>,
+    query: Query<VerifyQuery>,
+    multipart: Multipart,
+) -> Result<(AuditOutcome, Json<ApiResponse<FileVerificationResponse>>), ApiErrorWithRequestId> {
+    let response = lookup_file(state, request_id, query, multipart).await?;
+
+    // Record the verification status and matched capture in verification_logs
+    let data = &response.0.data;
+    let mut audit = AuditOutcome::new(data.status.as_str());
+    if let Some(capture_id) = data.capture_id.as_deref().and_then(|id| id.parse().ok()) {
+        audit = audit.capture(capture_id);
+    }
+
+    Ok((audit, response))
+}
+
+/// Hashes the uploaded file and looks up its provenance record
+async fn lookup_file(
     State(state): State<AppState>,
     Extension(request_id): Extension<Uuid>,
+    Query(query): Query<VerifyQuery>,
     multipart: Multipart,
 ) -> Result<Json<ApiResponse<FileVerificationResponse>>, ApiErrorWithRequestId> {
     tracing::info!(
         request_id = %request_id,
+        policy = ?query.policy,
         "Processing file verification request"
     );
 
+    let policy =
+        resolve_policy(&state, query.policy.as_deref()).map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
     // Parse multipart to get file bytes
     let file_bytes = parse_file_multipart(multipart)
         .await
@@ -224,6 +354,14 @@ async fn verify_file(
         // Convert hash to hex for display
         let media_hash_hex = hex::encode(&hash_bytes);
 
+        let policy_evaluation = policy
+            .map(|policy| evaluate_policy(policy, capture.evidence.as_ref()))
+            .transpose()
+            .map_err(|e| ApiErrorWithRequestId {
+                error: e,
+                request_id,
+            })?;
+
         let response = FileVerificationResponse {
             status: VerificationStatus::Verified,
             capture_id: Some(capture.id.to_string()),
@@ -243,6 +381,57 @@ async fn verify_file(
             metadata_flags: capture.metadata_flags,
             captured_at: Some(capture.captured_at.to_rfc3339()),
             media_type: Some(capture.capture_type),
+            policy_evaluation,
+            integrity_status: capture.integrity_status.parse().ok(),
+            deleted_at: None,
+            deleted_by: None,
+            evidence_digest: None,
+        };
+
+        return Ok(Json(ApiResponse::new(response, request_id)));
+    }
+
+    // A deleted capture leaves a tombstone: report that the file was captured
+    let tombstone = find_tombstone_by_hash(&state.db, &hash_bytes)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: ApiError::Database(e),
+            request_id,
+        })?;
+
+    if let Some(tombstone) = tombstone {
+        tracing::info!(
+            request_id = %request_id,
+            capture_id = %tombstone.capture_id,
+            deleted_by = %tombstone.deleted_by,
+            "File matches a deleted capture"
+        );
+
+        let note = match tombstone.deleted_by.as_str() {
+            "owner" => "This file was captured with rial, but the capture was deleted by its owner.",
+            _ => "This file was captured with rial, but the capture was removed under the retention policy.",
+        };
+
+        let response = FileVerificationResponse {
+            status: VerificationStatus::Deleted,
+            capture_id: Some(tombstone.capture_id.to_string()),
+            confidence_level: None,
+            verification_url: None,
+            manifest_info: None,
+            note: Some(note.to_string()),
+            file_hash: hash_base64,
+            capture_mode: Some(tombstone.capture_mode),
+            media_stored: Some(false),
+            media_hash: Some(hex::encode(&hash_bytes)),
+            evidence: None,
+            metadata_flags: None,
+            captured_at: Some(tombstone.captured_at.to_rfc3339()),
+            media_type: Some(tombstone.capture_type),
+            policy_evaluation: None,
+            integrity_status: None,
+            deleted_at: Some(tombstone.deleted_at.to_rfc3339()),
+            deleted_by: Some(tombstone.deleted_by),
+            evidence_digest: Some(hex::encode(&tombstone.evidence_digest)),
         };
 
         return Ok(Json(ApiResponse::new(response, request_id)));
@@ -275,6 +464,11 @@ async fn verify_file(
         metadata_flags: None,
         captured_at: None,
         media_type: None,
+        policy_evaluation: None,
+        integrity_status: None,
+        deleted_at: None,
+        deleted_by: None,
+        evidence_digest: None,
     };
 
     Ok(Json(ApiResponse::new(response, request_id)))
@@ -327,6 +521,7 @@ struct CaptureRecord {
     metadata_flags: Option<serde_json::Value>,
     captured_at: chrono::DateTime<chrono::Utc>,
     capture_type: String,
+    integrity_status: String,
 }
 
 /// Looks up a capture by its target_media_hash
@@ -338,7 +533,7 @@ async fn lookup_capture_by_hash(
     let record = sqlx::query_as::<_, CaptureRecord>(
         r#"
         SELECT id, confidence_level, capture_mode, media_stored,
-               evidence, metadata_flags, captured_at, capture_type
+               evidence, metadata_flags, captured_at, capture_type, integrity_status
         FROM captures
         WHERE target_media_hash = $1
         AND status = 'complete'
@@ -364,7 +559,12 @@ struct CaptureFullRecord {
     location_coarse: Option<String>,
     evidence: Option<serde_json::Value>,
     photo_s3_key: Option<String>,
-    depth_map_s3_key: Option<String>,
+    location_label: Option<String>,
+    integrity_status: String,
+    thumbnail_s3_key: Option<String>,
+    thumbnail_webp_s3_key: Option<String>,
+    poster_s3_key: Option<String>,
+    depth_heatmap_s3_key: Option<String>,
 }
 
 /// GET /api/v1/verify/{id} - Get public capture details
@@ -372,10 +572,13 @@ struct CaptureFullRecord {
 /// Returns capture details for the web verification page.
 /// This is a PUBLIC endpoint - no authentication required.
 /// Only returns coarse location (not precise) for privacy.
+///
+/// Query: `policy` (optional) - confidence policy profile to evaluate under
 async fn get_capture_public(
     State(state): State<AppState>,
     Extension(request_id): Extension<Uuid>,
     Path(id): Path<String>,
+    Query(query): Query<VerifyQuery>,
 ) -> Result<Json<ApiResponse<CaptureDetailsPublic>>, ApiErrorWithRequestId> {
     // Parse capture ID
     let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
@@ -383,18 +586,26 @@ async fn get_capture_public(
         request_id,
     })?;
 
+    let policy =
+        resolve_policy(&state, query.policy.as_deref()).map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
     tracing::info!(
         request_id = %request_id,
         capture_id = %capture_id,
         "Looking up capture for public verification"
     );
 
-    // Query capture from database (including photo_s3_key for URL construction)
+    // Query capture from database (including media and derivative keys for URLs)
     let capture = sqlx::query_as!(
         CaptureFullRecord,
         r#"
         SELECT id, confidence_level, captured_at, uploaded_at,
-               location_coarse, evidence, photo_s3_key, depth_map_s3_key
+               location_coarse, evidence, photo_s3_key,
+               location_label, integrity_status,
+               thumbnail_s3_key, thumbnail_webp_s3_key, poster_s3_key, depth_heatmap_s3_key
         FROM captures
         WHERE id = $1 AND status = 'complete'
         "#,
@@ -419,22 +630,76 @@ async fn get_capture_public(
         "Capture found for public verification"
     );
 
-    // Build photo URL from config (s3_public_endpoint/bucket/key)
-    // In dev: configurable via S3_PUBLIC_ENDPOINT env var
-    // In production: would be a presigned S3 URL or CloudFront CDN
-    let photo_url = capture.photo_s3_key.as_ref().map(|key| {
-        format!(
-            "{}/{}/{}",
-            state.config.s3_public_endpoint, state.config.s3_bucket, key
-        )
-    });
+    // Photos get a short-lived presigned URL (or the decrypting media
+    // endpoint); depth maps are owner-only (GET /api/v1/captures/{id}/depth)
+    // and never linked publicly
+    let photo_url = original_media_url(
+        &state,
+        capture.id,
+        MediaAsset::Photo,
+        capture.photo_s3_key.as_deref(),
+        request_id,
+    )
+    .await;
+
+    // Server-generated derivatives appear once the background job has run
+    let thumbnail_url = derivative_url(
+        &state,
+        capture.id,
+        DerivativeKind::ThumbnailJpeg,
+        capture.thumbnail_s3_key.as_deref(),
+        request_id,
+    )
+    .await;
+    let thumbnail_webp_url = derivative_url(
+        &state,
+        capture.id,
+        DerivativeKind::ThumbnailWebp,
+        capture.thumbnail_webp_s3_key.as_deref(),
+        request_id,
+    )
+    .await;
+    let poster_url = derivative_url(
+        &state,
+        capture.id,
+        DerivativeKind::Poster,
+        capture.poster_s3_key.as_deref(),
+        request_id,
+    )
+    .await;
+    let depth_heatmap_url = derivative_url(
+        &state,
+        capture.id,
+        DerivativeKind::DepthHeatmap,
+        capture.depth_heatmap_s3_key.as_deref(),
+        request_id,
+    )
+    .await;
+    let derivatives_expires_at = [
+        &thumbnail_url,
+        &thumbnail_webp_url,
+        &poster_url,
+        &depth_heatmap_url,
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|(_, expires_at)| *expires_at)
+    .min();
+
+    let disclosures = load_metadata_disclosures(&state.db, capture.id)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: ApiError::Database(e),
+            request_id,
+        })?;
 
-    let depth_map_url = capture.depth_map_s3_key.as_ref().map(|key| {
-        format!(
-            "{}/{}/{}",
-            state.config.s3_public_endpoint, state.config.s3_bucket, key
-        )
-    });
+    let policy_evaluation = policy
+        .map(|policy| evaluate_policy(policy, capture.evidence.as_ref()))
+        .transpose()
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
 
     let response = CaptureDetailsPublic {
         capture_id: capture.id.to_string(),
@@ -442,14 +707,331 @@ async fn get_capture_public(
         captured_at: capture.captured_at.to_rfc3339(),
         uploaded_at: capture.uploaded_at.to_rfc3339(),
         location_coarse: capture.location_coarse,
+        location_label: capture.location_label,
         evidence: capture.evidence.unwrap_or(serde_json::json!({})),
-        photo_url,
-        depth_map_url,
+        photo_url_expires_at: photo_url
+            .as_ref()
+            .and_then(|(_, expires_at)| expires_at.map(|t| t.to_rfc3339())),
+        photo_url: photo_url.map(|(url, _)| url),
+        thumbnail_url: thumbnail_url.map(|(url, _)| url),
+        thumbnail_webp_url: thumbnail_webp_url.map(|(url, _)| url),
+        poster_url: poster_url.map(|(url, _)| url),
+        depth_heatmap_url: depth_heatmap_url.map(|(url, _)| url),
+        derivatives_expires_at: derivatives_expires_at.map(|t| t.to_rfc3339()),
+        disclosures,
+        policy_evaluation,
+        integrity_status: capture.integrity_status.parse().ok(),
     };
 
     Ok(Json(ApiResponse::new(response, request_id)))
 }
 
+/// Looks up the requested confidence policy profile
+fn resolve_policy<'a>(
+    state: &'a AppState,
+    name: Option<&str>,
+) -> Result<Option<&'a ConfidencePolicy>, ApiError> {
+    name.map(|name| {
+        state
+            .confidence_policies
+            .get(name)
+            .ok_or_else(|| ApiError::Validation(format!("Unknown confidence policy: {name}")))
+    })
+    .transpose()
+}
+
+/// Evaluates stored evidence under a confidence policy profile
+///
+/// Every capture type stores the unified evidence schema, so missing or
+/// unparseable evidence is a server-side fault (500), not a bad request.
+fn evaluate_policy(
+    policy: &ConfidencePolicy,
+    evidence: Option<&serde_json::Value>,
+) -> Result<PolicyEvaluation, ApiError> {
+    let evidence = evidence
+        .ok_or_else(|| ApiError::Internal(anyhow::anyhow!("Capture has no stored evidence")))?;
+    let evidence = serde_json::from_value::<EvidencePackage>(evidence.clone()).map_err(|e| {
+        ApiError::Internal(anyhow::anyhow!(
+            "Stored evidence cannot be evaluated under policy {}: {e}",
+            policy.name
+        ))
+    })?;
+    Ok(policy.evaluate(&evidence))
+}
+
+/// GET /api/v1/verify/{id}/media - Original photo or video
+///
+/// Serves the capture's original media through the API, decrypting it when
+/// it is encrypted at rest (sealed objects cannot be presigned). Same
+/// audience as presigned photo URLs: anyone with the capture ID. Depth data
+/// is never served here.
+///
+/// # Responses
+/// - 200 OK: `image/jpeg` or `video/mp4`
+/// - 400 Bad Request: Invalid capture ID
+/// - 404 Not Found: Unknown capture, or no stored media (hash-only)
+async fn get_capture_media(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Path(id): Path<String>,
+) -> Result<Response, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+
+    // NOTE: Using runtime query to avoid SQLX_OFFLINE cache issues
+    let row = sqlx::query_as::<_, (Option<String>, Option<String>)>(
+        r#"
+        SELECT video_s3_key, photo_s3_key
+        FROM captures
+        WHERE id = $1 AND status = 'complete' AND media_stored
+        "#,
+    )
+    .bind(capture_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Database(e),
+        request_id,
+    })?;
+
+    let (key, content_type) = match row {
+        Some((Some(video_key), _)) => (video_key, "video/mp4"),
+        Some((None, Some(photo_key))) => (photo_key, "image/jpeg"),
+        _ => {
+            return Err(ApiErrorWithRequestId {
+                error: ApiError::CaptureNotFound,
+                request_id,
+            })
+        }
+    };
+
+    let bytes = state
+        .storage
+        .download(&key)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
+    tracing::info!(
+        request_id = %request_id,
+        capture_id = %capture_id,
+        size_bytes = bytes.len(),
+        "Original media served"
+    );
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("private, max-age={}", state.config.media_url_ttl_secs),
+            ),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+/// GET /api/v1/verify/{id}/media/{file} - Thumbnail, poster or depth heatmap
+///
+/// Serves a server-generated derivative through the API, decrypting it when
+/// it is encrypted at rest. `file` is the derivative's file name
+/// (thumbnail.jpg, thumbnail.webp, poster.jpg, depth_heatmap.png).
+///
+/// # Responses
+/// - 200 OK: `image/jpeg`, `image/webp` or `image/png`
+/// - 400 Bad Request: Invalid capture ID
+/// - 404 Not Found: Unknown capture or file, or derivative not generated
+async fn get_capture_derivative(
+    State(state): State<AppState>,
+    Extension(request_id): Extension<Uuid>,
+    Path((id, file)): Path<(String, String)>,
+) -> Result<Response, ApiErrorWithRequestId> {
+    let capture_id = Uuid::parse_str(&id).map_err(|_| ApiErrorWithRequestId {
+        error: ApiError::Validation(format!("Invalid capture ID format: {id}")),
+        request_id,
+    })?;
+    let kind = DerivativeKind::from_file_name(&file).ok_or(ApiErrorWithRequestId {
+        error: ApiError::CaptureNotFound,
+        request_id,
+    })?;
+
+    // NOTE: Using runtime query to avoid SQLX_OFFLINE cache issues
+    let row = sqlx::query_as::<
+        _,
+        (
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        ),
+    >(
+        r#"
+        SELECT thumbnail_s3_key, thumbnail_webp_s3_key, poster_s3_key, depth_heatmap_s3_key
+        FROM captures
+        WHERE id = $1 AND status = 'complete'
+        "#,
+    )
+    .bind(capture_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiErrorWithRequestId {
+        error: ApiError::Database(e),
+        request_id,
+    })?;
+
+    let key = row.and_then(
+        |(thumbnail, thumbnail_webp, poster, depth_heatmap)| match kind {
+            DerivativeKind::ThumbnailJpeg => thumbnail,
+            DerivativeKind::ThumbnailWebp => thumbnail_webp,
+            DerivativeKind::Poster => poster,
+            DerivativeKind::DepthHeatmap => depth_heatmap,
+        },
+    );
+    let key = key.ok_or(ApiErrorWithRequestId {
+        error: ApiError::CaptureNotFound,
+        request_id,
+    })?;
+
+    let bytes = state
+        .storage
+        .download(&key)
+        .await
+        .map_err(|e| ApiErrorWithRequestId {
+            error: e,
+            request_id,
+        })?;
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, kind.content_type().to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("private, max-age={}", state.config.media_url_ttl_secs),
+            ),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+/// URL of a capture derivative, with its expiry if any
+///
+/// Presigned like `original_media_url`; sealed derivatives and backends that
+/// cannot sign URLs link to `GET /verify/{id}/media/{file}` instead.
+async fn derivative_url(
+    state: &AppState,
+    capture_id: Uuid,
+    kind: DerivativeKind,
+    key: Option<&str>,
+    request_id: Uuid,
+) -> Option<(String, Option<chrono::DateTime<chrono::Utc>>)> {
+    let key = key?;
+    let asset = match kind {
+        DerivativeKind::DepthHeatmap => MediaAsset::DepthHeatmap,
+        _ => MediaAsset::Thumbnail,
+    };
+    if let Some(url) = presign_media(state, asset, Some(key), request_id).await {
+        return Some((url.url, Some(url.expires_at)));
+    }
+    Some((
+        format!(
+            "{}/api/v1/verify/{capture_id}/media/{}",
+            state.config.public_api_url,
+            kind.file_name()
+        ),
+        None,
+    ))
+}
+
+/// URL of a capture's original photo or video, with its expiry if any
+///
+/// Presigned while media is stored in the clear on a backend that can sign
+/// URLs. Otherwise (encrypted originals, filesystem or memory storage) it
+/// links to `GET /verify/{id}/media`, which does not expire.
+pub(crate) async fn original_media_url(
+    state: &AppState,
+    capture_id: Uuid,
+    asset: MediaAsset,
+    key: Option<&str>,
+    request_id: Uuid,
+) -> Option<(String, Option<chrono::DateTime<chrono::Utc>>)> {
+    let key = key?;
+    if let Some(url) = presign_media(state, asset, Some(key), request_id).await {
+        return Some((url.url, Some(url.expires_at)));
+    }
+    Some((
+        format!(
+            "{}/api/v1/verify/{capture_id}/media",
+            state.config.public_api_url
+        ),
+        None,
+    ))
+}
+
+/// Presigns a stored media key, omitting the URL when signing fails
+async fn presign_media(
+    state: &AppState,
+    asset: MediaAsset,
+    key: Option<&str>,
+    request_id: Uuid,
+) -> Option<MediaUrl> {
+    let key = key?;
+    state
+        .storage
+        .public_media_url(asset, key)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                request_id = %request_id,
+                key = %key,
+                asset = ?asset,
+                error = %e,
+                "Failed to presign media URL, omitting it"
+            );
+            None
+        })
+}
+
+/// Loads the disclosure state of each committed metadata field
+///
+/// Only disclosures verified against their commitment are stored, so a
+/// present value is reported as disclosed and matching. Salts are never
+/// returned.
+async fn load_metadata_disclosures(
+    pool: &PgPool,
+    capture_id: Uuid,
+) -> Result<Vec<MetadataDisclosure>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (String, Option<String>)>(
+        r#"
+        SELECT field, disclosed_value
+        FROM metadata_commitments
+        WHERE capture_id = $1
+        ORDER BY field
+        "#,
+    )
+    .bind(capture_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(field, value)| {
+            let field = field.parse::<CommitmentField>().ok()?;
+            Some(match value {
+                Some(value) => MetadataDisclosure::disclosed(field, value),
+                None => MetadataDisclosure::committed(field),
+            })
+        })
+        .collect())
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -474,6 +1056,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verification_status_as_str_matches_serialization() {
+        for status in [
+            VerificationStatus::Verified,
+            VerificationStatus::Deleted,
+            VerificationStatus::C2paOnly,
+            VerificationStatus::NoRecord,
+        ] {
+            assert_eq!(
+                serde_json::to_value(&status).unwrap(),
+                serde_json::json!(status.as_str())
+            );
+        }
+    }
+
     #[test]
     fn test_max_file_size() {
         assert_eq!(MAX_FILE_SIZE, 50 * 1024 * 1024); // 50MB (Story 8-7)
@@ -496,6 +1093,11 @@ mod tests {
             metadata_flags: None,
             captured_at: Some("2024-01-01T00:00:00Z".to_string()),
             media_type: Some("photo".to_string()),
+            policy_evaluation: None,
+            integrity_status: None,
+            deleted_at: None,
+            deleted_by: None,
+            evidence_digest: None,
         };
 
         let json = serde_json::to_string(&response).unwrap();
@@ -506,6 +1108,54 @@ mod tests {
         assert!(!json.contains("\"note\"")); // Should be skipped when None
     }
 
+    #[test]
+    fn test_evaluate_policy_on_stored_evidence() {
+        use crate::models::{
+            AttestationLevel, ConfidenceLevel, DepthAnalysis, HardwareAttestation,
+            MetadataEvidence, PartialAttestationInfo, ProcessingInfo, VideoDetails,
+        };
+        use crate::services::ConfidencePolicies;
+
+        let policies = ConfidencePolicies::bundled().unwrap();
+        let newsroom = policies.get("newsroom").unwrap();
+
+        let evidence = EvidencePackage::for_android(
+            HardwareAttestation::pass(
+                "Samsung Galaxy S24".to_string(),
+                AttestationLevel::TrustedEnvironment,
+            ),
+            DepthAnalysis::unavailable_android(),
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        let stored = serde_json::to_value(&evidence).unwrap();
+
+        let evaluation = evaluate_policy(newsroom, Some(&stored)).unwrap();
+        assert_eq!(evaluation.policy, "newsroom");
+        assert_eq!(evaluation.confidence_score.level, ConfidenceLevel::Low);
+
+        // Video evidence shares the unified schema
+        let video = serde_json::to_value(evidence.with_video(VideoDetails {
+            duration_ms: 15000,
+            frame_count: 450,
+            temporal_depth: None,
+            partial_attestation: PartialAttestationInfo::complete(450),
+        }))
+        .unwrap();
+        assert!(evaluate_policy(newsroom, Some(&video)).is_ok());
+
+        // Corrupt stored evidence is an internal error
+        let corrupt = serde_json::json!({"hardware_attestation": {"status": "pending"}});
+        assert!(matches!(
+            evaluate_policy(newsroom, Some(&corrupt)),
+            Err(ApiError::Internal(_))
+        ));
+        assert!(matches!(
+            evaluate_policy(newsroom, None),
+            Err(ApiError::Internal(_))
+        ));
+    }
+
     #[test]
     fn test_no_record_response() {
         let response = FileVerificationResponse {
@@ -523,6 +1173,11 @@ mod tests {
             metadata_flags: None,
             captured_at: None,
             media_type: None,
+            policy_evaluation: None,
+            integrity_status: None,
+            deleted_at: None,
+            deleted_by: None,
+            evidence_digest: None,
         };
 
         let json = serde_json::to_string(&response).unwrap();
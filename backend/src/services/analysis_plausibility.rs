@@ -0,0 +1,655 @@
+//! Client Depth Analysis Plausibility Service
+//!
+//! Hash-only (privacy mode) captures carry depth metrics computed on the
+//! device; the server never sees the depth map. A modified client could
+//! simply claim `is_likely_real_scene = true`, so this service checks that
+//! the submitted numbers are consistent with each other and with what the
+//! same device model usually reports.
+//!
+//! ## Checks
+//! 1. **Internal coherence:** depth std dev cannot exceed half the depth
+//!    range, layer count is bounded by the range and histogram resolution,
+//!    and a "real scene" claim must clear the server thresholds
+//! 2. **Temporal aggregates (video):** keyframe count, mean variance,
+//!    temporal coherence and variance stability must match the keyframes
+//! 3. **Model history:** metrics are compared with the distribution of
+//!    earlier trusted hash-only captures from the same device model
+//!
+//! ## Trust Score
+//! Each finding subtracts a penalty from 1.0. The resulting
+//! `analysis_trust` is recorded in the evidence and caps confidence
+//! (see `EvidencePackage::calculate_confidence`).
+
+use sqlx::PgPool;
+use tracing::debug;
+
+use crate::models::ANALYSIS_TRUST_MIN;
+use crate::services::depth_analysis::{
+    COHERENCE_THRESHOLD, HISTOGRAM_BINS, LAYER_THRESHOLD, MAX_VALID_DEPTH, VARIANCE_THRESHOLD,
+};
+use crate::types::{ClientDepthAnalysis, ClientTemporalDepthAnalysis};
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+/// Impossible or non-finite values
+const PENALTY_IMPOSSIBLE: f64 = 0.5;
+
+/// "Real scene" claim contradicts the submitted metrics
+const PENALTY_CLAIM_CONTRADICTED: f64 = 0.5;
+
+/// Values outside the sensor's physical limits
+const PENALTY_OUT_OF_RANGE: f64 = 0.3;
+
+/// Temporal aggregate does not match its keyframes
+const PENALTY_TEMPORAL_MISMATCH: f64 = 0.2;
+
+/// Metric is an outlier for the device model
+const PENALTY_HISTORY_OUTLIER: f64 = 0.15;
+
+/// Minimum temporal variance stability for a "real scene" video claim
+const TEMPORAL_STABILITY_THRESHOLD: f64 = 0.8;
+
+/// Minimum earlier captures before the model history is used
+const MIN_HISTORY_SAMPLES: i64 = 20;
+
+/// Number of recent captures the model history is computed from
+const HISTORY_WINDOW: i64 = 500;
+
+/// Z-score beyond which a metric counts as an outlier for the model
+const HISTORY_Z_THRESHOLD: f64 = 4.0;
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Result of plausibility checks on client-computed depth analysis
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlausibilityReport {
+    /// Trust in the client analysis (0.0 - 1.0)
+    pub trust: f64,
+    /// Human-readable findings that reduced trust
+    pub issues: Vec<String>,
+}
+
+impl Default for PlausibilityReport {
+    fn default() -> Self {
+        Self {
+            trust: 1.0,
+            issues: Vec::new(),
+        }
+    }
+}
+
+impl PlausibilityReport {
+    fn penalize(&mut self, penalty: f64, issue: String) {
+        self.trust = (self.trust - penalty).max(0.0);
+        self.issues.push(issue);
+    }
+}
+
+/// Mean and standard deviation of one metric
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricDistribution {
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl MetricDistribution {
+    /// Z-score of a value, or None when the distribution has no spread
+    fn z_score(&self, value: f64) -> Option<f64> {
+        (self.std_dev > f64::EPSILON).then(|| (value - self.mean) / self.std_dev)
+    }
+}
+
+/// Historical depth metrics for one device model
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelDepthHistory {
+    /// Number of captures the distribution is based on
+    pub sample_count: i64,
+    pub depth_variance: MetricDistribution,
+    pub depth_layers: MetricDistribution,
+    pub edge_coherence: MetricDistribution,
+}
+
+// ============================================================================
+// Plausibility Checks
+// ============================================================================
+
+/// Checks client-computed depth analysis for internal and historical consistency.
+///
+/// `temporal` is the video temporal analysis (if any); `history` is the
+/// device model's distribution from [`load_model_depth_history`].
+pub fn check_depth_plausibility(
+    analysis: &ClientDepthAnalysis,
+    temporal: Option<&ClientTemporalDepthAnalysis>,
+    history: Option<&ModelDepthHistory>,
+) -> PlausibilityReport {
+    let mut report = PlausibilityReport::default();
+
+    for issue in frame_issues(analysis) {
+        report.penalize(issue.0, issue.1);
+    }
+
+    if let Some(temporal) = temporal {
+        check_temporal(temporal, &mut report);
+    }
+
+    if let Some(history) = history {
+        check_history(analysis, history, &mut report);
+    }
+
+    debug!(
+        trust = report.trust,
+        issues = report.issues.len(),
+        "[analysis_plausibility] Client depth analysis checked"
+    );
+
+    report
+}
+
+/// Internal coherence findings for one depth analysis (penalty, issue)
+fn frame_issues(analysis: &ClientDepthAnalysis) -> Vec<(f64, String)> {
+    let variance = analysis.depth_variance as f64;
+    let coherence = analysis.edge_coherence as f64;
+    let min_depth = analysis.min_depth as f64;
+    let max_depth = analysis.max_depth as f64;
+    let layers = analysis.depth_layers.max(0) as u32;
+
+    if ![variance, coherence, min_depth, max_depth]
+        .iter()
+        .all(|v| v.is_finite())
+    {
+        return vec![(
+            PENALTY_IMPOSSIBLE,
+            "depth metrics contain non-finite values".to_string(),
+        )];
+    }
+
+    let mut issues = Vec::new();
+
+    if min_depth > max_depth {
+        issues.push((
+            PENALTY_IMPOSSIBLE,
+            format!("min_depth {min_depth:.2}m exceeds max_depth {max_depth:.2}m"),
+        ));
+        return issues;
+    }
+
+    if min_depth < 0.0 || max_depth > MAX_VALID_DEPTH as f64 {
+        issues.push((
+            PENALTY_OUT_OF_RANGE,
+            format!(
+                "depth range {min_depth:.2}-{max_depth:.2}m outside sensor limits (0-{MAX_VALID_DEPTH}m)"
+            ),
+        ));
+    }
+
+    // A std dev can never exceed half the range of the values (Popoviciu)
+    let range = max_depth - min_depth;
+    if variance < 0.0 || variance > range / 2.0 + 0.01 {
+        issues.push((
+            PENALTY_IMPOSSIBLE,
+            format!("depth_variance {variance:.2} impossible for depth range {range:.2}m"),
+        ));
+    }
+
+    // Layers are histogram peaks: none without range, at most one per two bins
+    let max_layers = if range > 0.0 {
+        (HISTOGRAM_BINS / 2) as u32
+    } else {
+        1
+    };
+    if layers > max_layers {
+        issues.push((
+            PENALTY_IMPOSSIBLE,
+            format!("depth_layers {layers} impossible for depth range {range:.2}m"),
+        ));
+    }
+
+    if analysis.is_likely_real_scene
+        && !(variance > VARIANCE_THRESHOLD
+            && layers >= LAYER_THRESHOLD
+            && coherence > COHERENCE_THRESHOLD)
+    {
+        issues.push((
+            PENALTY_CLAIM_CONTRADICTED,
+            "is_likely_real_scene claimed but metrics are below real-scene thresholds".to_string(),
+        ));
+    }
+
+    issues
+}
+
+/// Checks temporal aggregates against the per-keyframe analyses
+fn check_temporal(temporal: &ClientTemporalDepthAnalysis, report: &mut PlausibilityReport) {
+    let keyframes = &temporal.keyframe_analyses;
+    if keyframes.is_empty() {
+        return;
+    }
+
+    if temporal.keyframe_count != keyframes.len() as i32 {
+        report.penalize(
+            PENALTY_TEMPORAL_MISMATCH,
+            format!(
+                "keyframe_count {} but {} keyframe analyses submitted",
+                temporal.keyframe_count,
+                keyframes.len()
+            ),
+        );
+    }
+
+    let count = keyframes.len() as f64;
+    let variances: Vec<f64> = keyframes.iter().map(|k| k.depth_variance as f64).collect();
+    let mean_variance = variances.iter().sum::<f64>() / count;
+    let mean_coherence = keyframes
+        .iter()
+        .map(|k| k.edge_coherence as f64)
+        .sum::<f64>()
+        / count;
+
+    if !approx_eq(temporal.mean_variance as f64, mean_variance) {
+        report.penalize(
+            PENALTY_TEMPORAL_MISMATCH,
+            format!(
+                "mean_variance {:.3} does not match keyframes ({mean_variance:.3})",
+                temporal.mean_variance
+            ),
+        );
+    }
+
+    if !approx_eq(temporal.temporal_coherence as f64, mean_coherence) {
+        report.penalize(
+            PENALTY_TEMPORAL_MISMATCH,
+            format!(
+                "temporal_coherence {:.3} does not match keyframes ({mean_coherence:.3})",
+                temporal.temporal_coherence
+            ),
+        );
+    }
+
+    // variance_stability = 1 - stddev(variances) / mean(variances)
+    if mean_variance > f64::EPSILON {
+        let std_dev = (variances
+            .iter()
+            .map(|v| (v - mean_variance).powi(2))
+            .sum::<f64>()
+            / count)
+            .sqrt();
+        let stability = 1.0 - std_dev / mean_variance;
+        if !approx_eq(temporal.variance_stability as f64, stability) {
+            report.penalize(
+                PENALTY_TEMPORAL_MISMATCH,
+                format!(
+                    "variance_stability {:.3} does not match keyframes ({stability:.3})",
+                    temporal.variance_stability
+                ),
+            );
+        }
+    }
+
+    let inconsistent = keyframes
+        .iter()
+        .filter(|k| !frame_issues(k).is_empty())
+        .count();
+    if inconsistent > 0 {
+        report.penalize(
+            PENALTY_TEMPORAL_MISMATCH,
+            format!("{inconsistent} keyframe analyses are internally inconsistent"),
+        );
+    }
+
+    if temporal.is_likely_real_scene
+        && (keyframes.iter().any(|k| !k.is_likely_real_scene)
+            || (temporal.variance_stability as f64) <= TEMPORAL_STABILITY_THRESHOLD)
+    {
+        report.penalize(
+            PENALTY_CLAIM_CONTRADICTED,
+            "temporal is_likely_real_scene claimed but keyframes or stability disagree".to_string(),
+        );
+    }
+}
+
+/// Flags metrics that are outliers for the device model
+fn check_history(
+    analysis: &ClientDepthAnalysis,
+    history: &ModelDepthHistory,
+    report: &mut PlausibilityReport,
+) {
+    if history.sample_count < MIN_HISTORY_SAMPLES {
+        return;
+    }
+
+    let metrics = [
+        (
+            "depth_variance",
+            analysis.depth_variance as f64,
+            history.depth_variance,
+        ),
+        (
+            "depth_layers",
+            analysis.depth_layers as f64,
+            history.depth_layers,
+        ),
+        (
+            "edge_coherence",
+            analysis.edge_coherence as f64,
+            history.edge_coherence,
+        ),
+    ];
+
+    for (name, value, distribution) in metrics {
+        if let Some(z) = distribution.z_score(value) {
+            if z.abs() > HISTORY_Z_THRESHOLD {
+                report.penalize(
+                    PENALTY_HISTORY_OUTLIER,
+                    format!(
+                        "{name} {value:.3} is an outlier for this device model (z={z:.1}, n={})",
+                        history.sample_count
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Tolerant comparison for client-rounded aggregates
+fn approx_eq(claimed: f64, computed: f64) -> bool {
+    (claimed - computed).abs() <= 0.02 + 0.05 * computed.abs()
+}
+
+// ============================================================================
+// Database Operations
+// ============================================================================
+
+/// Loads the depth metric distribution of recent hash-only captures for a model.
+///
+/// Only captures whose own analysis was trusted contribute, so manipulated
+/// uploads cannot drag the distribution. Returns `None` without history.
+pub async fn load_model_depth_history(
+    pool: &PgPool,
+    model: &str,
+) -> Result<Option<ModelDepthHistory>, sqlx::Error> {
+    let row = sqlx::query_as::<
+        _,
+        (
+            i64,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+            Option<f64>,
+        ),
+    >(
+        r#"
+        SELECT
+            COUNT(*)::BIGINT,
+            AVG(variance), STDDEV_POP(variance),
+            AVG(layers), STDDEV_POP(layers),
+            AVG(coherence), STDDEV_POP(coherence)
+        FROM (
+            SELECT
+                (c.evidence->'depth_analysis'->>'depth_variance')::FLOAT8 AS variance,
+                (c.evidence->'depth_analysis'->>'depth_layers')::FLOAT8 AS layers,
+                (c.evidence->'depth_analysis'->>'edge_coherence')::FLOAT8 AS coherence
+            FROM captures c
+            JOIN devices d ON d.id = c.device_id
+            WHERE d.model = $1
+              AND c.analysis_source = 'device'
+              AND COALESCE((c.evidence->'depth_analysis'->>'analysis_trust')::FLOAT8, 1.0) >= $2
+            ORDER BY c.uploaded_at DESC
+            LIMIT $3
+        ) recent
+        "#,
+    )
+    .bind(model)
+    .bind(ANALYSIS_TRUST_MIN)
+    .bind(HISTORY_WINDOW)
+    .fetch_one(pool)
+    .await?;
+
+    let (count, var_mean, var_std, layer_mean, layer_std, coh_mean, coh_std) = row;
+    let distribution = |mean: Option<f64>, std_dev: Option<f64>| MetricDistribution {
+        mean: mean.unwrap_or(0.0),
+        std_dev: std_dev.unwrap_or(0.0),
+    };
+
+    if count == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(ModelDepthHistory {
+        sample_count: count,
+        depth_variance: distribution(var_mean, var_std),
+        depth_layers: distribution(layer_mean, layer_std),
+        edge_coherence: distribution(coh_mean, coh_std),
+    }))
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn real_scene() -> ClientDepthAnalysis {
+        ClientDepthAnalysis {
+            depth_variance: 1.2,
+            depth_layers: 5,
+            edge_coherence: 0.6,
+            min_depth: 0.5,
+            max_depth: 4.5,
+            is_likely_real_scene: true,
+            algorithm_version: "1.0".to_string(),
+        }
+    }
+
+    fn temporal_from(keyframes: Vec<ClientDepthAnalysis>) -> ClientTemporalDepthAnalysis {
+        let count = keyframes.len() as f32;
+        let mean_variance = keyframes.iter().map(|k| k.depth_variance).sum::<f32>() / count;
+        let coherence = keyframes.iter().map(|k| k.edge_coherence).sum::<f32>() / count;
+        let std_dev = (keyframes
+            .iter()
+            .map(|k| (k.depth_variance - mean_variance).powi(2))
+            .sum::<f32>()
+            / count)
+            .sqrt();
+        ClientTemporalDepthAnalysis {
+            keyframe_count: keyframes.len() as i32,
+            mean_variance,
+            variance_stability: 1.0 - std_dev / mean_variance,
+            temporal_coherence: coherence,
+            is_likely_real_scene: true,
+            algorithm_version: "1.0".to_string(),
+            keyframe_analyses: keyframes,
+        }
+    }
+
+    fn history(samples: i64) -> ModelDepthHistory {
+        ModelDepthHistory {
+            sample_count: samples,
+            depth_variance: MetricDistribution {
+                mean: 1.0,
+                std_dev: 0.3,
+            },
+            depth_layers: MetricDistribution {
+                mean: 5.0,
+                std_dev: 1.5,
+            },
+            edge_coherence: MetricDistribution {
+                mean: 0.55,
+                std_dev: 0.1,
+            },
+        }
+    }
+
+    #[test]
+    fn test_consistent_analysis_fully_trusted() {
+        let report = check_depth_plausibility(&real_scene(), None, Some(&history(100)));
+        assert_eq!(report.trust, 1.0);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_variance_exceeding_range_is_impossible() {
+        let mut analysis = real_scene();
+        analysis.min_depth = 1.0;
+        analysis.max_depth = 1.5;
+        analysis.depth_variance = 2.0;
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert_eq!(report.trust, 0.5);
+        assert!(report.issues[0].contains("depth_variance"));
+    }
+
+    #[test]
+    fn test_layers_without_range_is_impossible() {
+        let analysis = ClientDepthAnalysis {
+            depth_variance: 0.0,
+            depth_layers: 4,
+            edge_coherence: 0.1,
+            min_depth: 0.6,
+            max_depth: 0.6,
+            is_likely_real_scene: false,
+            algorithm_version: "1.0".to_string(),
+        };
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.issues[0].contains("depth_layers 4"));
+    }
+
+    #[test]
+    fn test_real_claim_below_thresholds_is_contradicted() {
+        let mut analysis = real_scene();
+        analysis.depth_variance = 0.1;
+        analysis.depth_layers = 1;
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert_eq!(report.trust, 0.5);
+        assert!(report.issues[0].contains("is_likely_real_scene"));
+    }
+
+    #[test]
+    fn test_flat_scene_not_claimed_real_is_trusted() {
+        let analysis = ClientDepthAnalysis {
+            depth_variance: 0.02,
+            depth_layers: 1,
+            edge_coherence: 0.1,
+            min_depth: 0.5,
+            max_depth: 0.6,
+            is_likely_real_scene: false,
+            algorithm_version: "1.0".to_string(),
+        };
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert_eq!(report.trust, 1.0);
+    }
+
+    #[test]
+    fn test_min_greater_than_max() {
+        let mut analysis = real_scene();
+        analysis.min_depth = 5.0;
+        analysis.max_depth = 1.0;
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert!(report.issues[0].contains("exceeds max_depth"));
+    }
+
+    #[test]
+    fn test_non_finite_values() {
+        let mut analysis = real_scene();
+        analysis.depth_variance = f32::NAN;
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert_eq!(report.trust, 0.5);
+        assert!(report.issues[0].contains("non-finite"));
+    }
+
+    #[test]
+    fn test_range_outside_sensor_limits() {
+        let mut analysis = real_scene();
+        analysis.max_depth = 60.0;
+        analysis.depth_variance = 5.0;
+
+        let report = check_depth_plausibility(&analysis, None, None);
+        assert!(report.issues[0].contains("sensor limits"));
+    }
+
+    #[test]
+    fn test_consistent_temporal_analysis() {
+        let mut second = real_scene();
+        second.depth_variance = 1.1;
+        let temporal = temporal_from(vec![real_scene(), second]);
+
+        let report = check_depth_plausibility(&real_scene(), Some(&temporal), None);
+        assert_eq!(report.trust, 1.0, "issues: {:?}", report.issues);
+    }
+
+    #[test]
+    fn test_temporal_aggregate_mismatch() {
+        let mut temporal = temporal_from(vec![real_scene(), real_scene()]);
+        temporal.mean_variance = 3.0;
+        temporal.keyframe_count = 150;
+
+        let report = check_depth_plausibility(&real_scene(), Some(&temporal), None);
+        assert_eq!(report.issues.len(), 2);
+        assert!((report.trust - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_temporal_real_claim_with_failing_keyframe() {
+        let mut flat = real_scene();
+        flat.is_likely_real_scene = false;
+        let temporal = temporal_from(vec![real_scene(), flat]);
+
+        let report = check_depth_plausibility(&real_scene(), Some(&temporal), None);
+        assert!(report
+            .issues
+            .iter()
+            .any(|i| i.contains("temporal is_likely_real_scene")));
+    }
+
+    #[test]
+    fn test_history_outlier_penalized() {
+        let mut analysis = real_scene();
+        analysis.depth_layers = 20;
+        analysis.max_depth = 12.0;
+
+        let report = check_depth_plausibility(&analysis, None, Some(&history(100)));
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.issues[0].contains("depth_layers"));
+        assert!((report.trust - 0.85).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_small_history_ignored() {
+        let mut analysis = real_scene();
+        analysis.depth_layers = 20;
+        analysis.max_depth = 12.0;
+
+        let report = check_depth_plausibility(&analysis, None, Some(&history(5)));
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_trust_never_negative() {
+        let analysis = ClientDepthAnalysis {
+            depth_variance: 9.0,
+            depth_layers: 40,
+            edge_coherence: 0.1,
+            min_depth: -1.0,
+            max_depth: 0.0,
+            is_likely_real_scene: true,
+            algorithm_version: "1.0".to_string(),
+        };
+
+        let report = check_depth_plausibility(&analysis, None, Some(&history(100)));
+        assert_eq!(report.trust, 0.0);
+        assert!(report.issues.len() >= 4);
+    }
+}
@@ -329,6 +329,10 @@ pub struct AndroidDeviceInfo {
     pub os_version: Option<i32>,
     /// Security patch level (YYYYMM format)
     pub os_patch_level: Option<i32>,
+    /// Whether the model came from TEE-enforced ID attestation rather than
+    /// the software-enforced list (only then is it a hardware-backed identity)
+    #[serde(default)]
+    pub id_attested: bool,
 }
 
 /// Result of successful Android attestation verification
@@ -1106,6 +1110,7 @@ fn extract_device_info(key_description: &KeyDescription) -> AndroidDeviceInfo {
             .and_then(|b| String::from_utf8(b.clone()).ok()),
         os_version: tee.os_version.or(sw.os_version),
         os_patch_level: tee.os_patch_level.or(sw.os_patch_level),
+        id_attested: tee.attestation_id_model.is_some(),
     }
 }
 
@@ -1451,6 +1456,28 @@ mod tests {
         assert_eq!(device_info.manufacturer, Some("Google".to_string()));
         assert_eq!(device_info.os_version, Some(140000));
         assert_eq!(device_info.os_patch_level, Some(202312));
+        assert!(device_info.id_attested);
+    }
+
+    #[test]
+    fn test_device_info_software_model_not_id_attested() {
+        let key_desc = KeyDescription {
+            attestation_version: 4,
+            attestation_security_level: SecurityLevel::TrustedEnvironment,
+            keymaster_version: 4,
+            keymaster_security_level: SecurityLevel::TrustedEnvironment,
+            attestation_challenge: vec![0u8; 32],
+            unique_id: vec![],
+            software_enforced: AuthorizationList {
+                attestation_id_model: Some(b"Pixel 8 Pro".to_vec()),
+                ..Default::default()
+            },
+            tee_enforced: AuthorizationList::default(),
+        };
+
+        let device_info = extract_device_info(&key_desc);
+        assert_eq!(device_info.model, Some("Pixel 8 Pro".to_string()));
+        assert!(!device_info.id_attested);
     }
 
     #[test]
@@ -0,0 +1,578 @@
+//! Blob storage backends
+//!
+//! `BlobStore` is the object-storage interface behind `StorageService`:
+//! put, get, head, delete and presign over string keys. Three backends are
+//! provided, selected with `STORAGE_BACKEND`:
+//! - `s3` (default): AWS S3, or LocalStack when `S3_ENDPOINT` points at it
+//! - `filesystem`: files under `STORAGE_PATH`, for running without S3
+//! - `memory`: process-local map, for tests
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use aws_config::BehaviorVersion;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::ChecksumAlgorithm;
+use aws_sdk_s3::Client as S3Client;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::sync::RwLock;
+use tracing::info;
+
+use crate::config::Config;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors returned by blob store backends
+#[derive(Debug, Error)]
+pub enum BlobStoreError {
+    #[error("Blob not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid blob key: {0}")]
+    InvalidKey(String),
+
+    #[error("Checksum mismatch for blob: {0}")]
+    ChecksumMismatch(String),
+
+    #[error("Blob store backend error: {0}")]
+    Backend(String),
+}
+
+impl From<std::io::Error> for BlobStoreError {
+    fn from(e: std::io::Error) -> Self {
+        BlobStoreError::Backend(e.to_string())
+    }
+}
+
+// ============================================================================
+// Backend Selection
+// ============================================================================
+
+/// Storage backend selected by `STORAGE_BACKEND`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StorageBackend {
+    /// AWS S3 or an S3-compatible endpoint (LocalStack)
+    #[default]
+    S3,
+    /// Local filesystem under `STORAGE_PATH`
+    Filesystem,
+    /// In-process memory (contents lost on restart)
+    Memory,
+}
+
+impl std::str::FromStr for StorageBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "s3" => Ok(StorageBackend::S3),
+            "filesystem" | "fs" => Ok(StorageBackend::Filesystem),
+            "memory" => Ok(StorageBackend::Memory),
+            _ => Err(format!("Unknown storage backend: {s}")),
+        }
+    }
+}
+
+// ============================================================================
+// BlobStore Trait
+// ============================================================================
+
+/// Metadata about a stored blob
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobMetadata {
+    /// Size in bytes
+    pub size: u64,
+    /// Content type recorded at upload, when the backend keeps it
+    pub content_type: Option<String>,
+}
+
+/// Object storage keyed by string paths like `media/sha256/ab/{digest}.jpg`
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Short backend name for logs ("s3", "filesystem", "memory")
+    fn name(&self) -> &'static str;
+
+    /// Stores `bytes` at `key`, replacing any existing blob
+    ///
+    /// `sha256` is the expected digest of `bytes`; the blob is rejected with
+    /// `ChecksumMismatch` instead of stored when they disagree.
+    async fn put(
+        &self,
+        key: &str,
+        bytes: Vec<u8>,
+        content_type: &str,
+        sha256: &[u8; 32],
+    ) -> Result<(), BlobStoreError>;
+
+    /// Reads the blob at `key`
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError>;
+
+    /// Returns metadata for `key`, or None if it does not exist
+    async fn head(&self, key: &str) -> Result<Option<BlobMetadata>, BlobStoreError>;
+
+    /// Removes the blob at `key` (succeeds if it does not exist)
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError>;
+
+    /// Returns a URL granting read access to `key` for `expires_in`
+    ///
+    /// None when the backend has no signing authority; callers serve the
+    /// blob through the API instead.
+    async fn presign_get(
+        &self,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<Option<String>, BlobStoreError>;
+}
+
+/// Local equivalent of S3's checksum validation for non-S3 backends
+fn verify_checksum(key: &str, bytes: &[u8], sha256: &[u8; 32]) -> Result<(), BlobStoreError> {
+    if Sha256::digest(bytes)[..] == sha256[..] {
+        Ok(())
+    } else {
+        Err(BlobStoreError::ChecksumMismatch(key.to_string()))
+    }
+}
+
+// ============================================================================
+// S3 Backend
+// ============================================================================
+
+/// S3 (or S3-compatible, e.g. LocalStack) blob store
+pub struct S3BlobStore {
+    client: S3Client,
+    /// Client used only for presigning; it targets `S3_PUBLIC_ENDPOINT`
+    /// so signed URLs carry a host clients can reach
+    presign_client: S3Client,
+    bucket: String,
+}
+
+impl S3BlobStore {
+    /// Creates an S3 client from application config
+    ///
+    /// Credentials and region come from the AWS default chain. `S3_ENDPOINT`
+    /// and `S3_FORCE_PATH_STYLE` explicitly override the endpoint and
+    /// addressing style for S3-compatible services.
+    pub async fn new(config: &Config) -> Self {
+        info!(
+            endpoint = config.s3_endpoint.as_deref().unwrap_or("aws"),
+            public_endpoint = config.s3_public_endpoint.as_deref().unwrap_or("aws"),
+            force_path_style = config.s3_force_path_style,
+            bucket = %config.s3_bucket,
+            "Configuring S3 client"
+        );
+        let aws_config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        let client = Self::client(
+            &aws_config,
+            config.s3_endpoint.as_deref(),
+            config.s3_force_path_style,
+        );
+        let presign_client = Self::client(
+            &aws_config,
+            config.s3_public_endpoint.as_deref(),
+            config.s3_force_path_style,
+        );
+
+        Self {
+            client,
+            presign_client,
+            bucket: config.s3_bucket.clone(),
+        }
+    }
+
+    /// S3 client with an optional endpoint override and addressing style
+    fn client(
+        aws_config: &aws_config::SdkConfig,
+        endpoint: Option<&str>,
+        force_path_style: bool,
+    ) -> S3Client {
+        let mut builder =
+            aws_sdk_s3::config::Builder::from(aws_config).force_path_style(force_path_style);
+        if let Some(endpoint) = endpoint {
+            builder = builder.endpoint_url(endpoint);
+        }
+        S3Client::from_conf(builder.build())
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn put(
+        &self,
+        key: &str,
+        bytes: Vec<u8>,
+        content_type: &str,
+        sha256: &[u8; 32],
+    ) -> Result<(), BlobStoreError> {
+        // S3 recomputes the digest server-side and rejects the upload with
+        // BadDigest if the body was altered in transit
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(bytes))
+            .content_type(content_type)
+            .checksum_algorithm(ChecksumAlgorithm::Sha256)
+            .checksum_sha256(STANDARD.encode(sha256))
+            .send()
+            .await
+            .map_err(|e| match e.code() {
+                Some("BadDigest") | Some("InvalidDigest") => {
+                    BlobStoreError::ChecksumMismatch(key.to_string())
+                }
+                _ => BlobStoreError::Backend(e.to_string()),
+            })?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+        let response = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| match e.into_service_error() {
+                err if err.is_no_such_key() => BlobStoreError::NotFound(key.to_string()),
+                err => BlobStoreError::Backend(err.to_string()),
+            })?;
+
+        let bytes = response
+            .body
+            .collect()
+            .await
+            .map_err(|e| BlobStoreError::Backend(e.to_string()))?
+            .into_bytes()
+            .to_vec();
+        Ok(bytes)
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<BlobMetadata>, BlobStoreError> {
+        match self
+            .client
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(output) => Ok(Some(BlobMetadata {
+                size: output.content_length().unwrap_or(0).max(0) as u64,
+                content_type: output.content_type().map(str::to_string),
+            })),
+            Err(e) => match e.into_service_error() {
+                err if err.is_not_found() => Ok(None),
+                err => Err(BlobStoreError::Backend(err.to_string())),
+            },
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| BlobStoreError::Backend(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn presign_get(
+        &self,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<Option<String>, BlobStoreError> {
+        let presigning = PresigningConfig::expires_in(expires_in)
+            .map_err(|e| BlobStoreError::Backend(e.to_string()))?;
+        let request = self
+            .presign_client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .presigned(presigning)
+            .await
+            .map_err(|e| BlobStoreError::Backend(e.to_string()))?;
+        Ok(Some(request.uri().to_string()))
+    }
+}
+
+// ============================================================================
+// Filesystem Backend
+// ============================================================================
+
+/// Blob store writing each key as a file under a root directory
+pub struct FsBlobStore {
+    root: PathBuf,
+}
+
+impl FsBlobStore {
+    /// Creates a store rooted at `root` (created on first write)
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Maps a key to a path under the root, rejecting keys that escape it
+    fn path_for(&self, key: &str) -> Result<PathBuf, BlobStoreError> {
+        let relative = Path::new(key);
+        let is_plain = !key.is_empty()
+            && relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)));
+        if !is_plain {
+            return Err(BlobStoreError::InvalidKey(key.to_string()));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl BlobStore for FsBlobStore {
+    fn name(&self) -> &'static str {
+        "filesystem"
+    }
+
+    async fn put(
+        &self,
+        key: &str,
+        bytes: Vec<u8>,
+        _content_type: &str,
+        sha256: &[u8; 32],
+    ) -> Result<(), BlobStoreError> {
+        let path = self.path_for(key)?;
+        verify_checksum(key, &bytes, sha256)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // Write to a temporary sibling, then rename so readers never see partial blobs
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, bytes).await?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(bytes),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Err(BlobStoreError::NotFound(key.to_string()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<BlobMetadata>, BlobStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::metadata(&path).await {
+            Ok(meta) if meta.is_file() => Ok(Some(BlobMetadata {
+                size: meta.len(),
+                content_type: None,
+            })),
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn presign_get(
+        &self,
+        key: &str,
+        _expires_in: Duration,
+    ) -> Result<Option<String>, BlobStoreError> {
+        // Local files have no signing authority (and their paths must not leak)
+        self.path_for(key)?;
+        Ok(None)
+    }
+}
+
+// ============================================================================
+// In-Memory Backend
+// ============================================================================
+
+/// Blob store holding everything in a process-local map
+#[derive(Default)]
+pub struct MemoryBlobStore {
+    blobs: RwLock<HashMap<String, (Vec<u8>, String)>>,
+}
+
+impl MemoryBlobStore {
+    /// Creates an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlobStore for MemoryBlobStore {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn put(
+        &self,
+        key: &str,
+        bytes: Vec<u8>,
+        content_type: &str,
+        sha256: &[u8; 32],
+    ) -> Result<(), BlobStoreError> {
+        verify_checksum(key, &bytes, sha256)?;
+        self.blobs
+            .write()
+            .await
+            .insert(key.to_string(), (bytes, content_type.to_string()));
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+        self.blobs
+            .read()
+            .await
+            .get(key)
+            .map(|(bytes, _)| bytes.clone())
+            .ok_or_else(|| BlobStoreError::NotFound(key.to_string()))
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<BlobMetadata>, BlobStoreError> {
+        Ok(self
+            .blobs
+            .read()
+            .await
+            .get(key)
+            .map(|(bytes, content_type)| BlobMetadata {
+                size: bytes.len() as u64,
+                content_type: Some(content_type.clone()),
+            }))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+        self.blobs.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn presign_get(
+        &self,
+        _key: &str,
+        _expires_in: Duration,
+    ) -> Result<Option<String>, BlobStoreError> {
+        // Process-local blobs cannot be fetched by URL
+        Ok(None)
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha(bytes: &[u8]) -> [u8; 32] {
+        Sha256::digest(bytes).into()
+    }
+
+    async fn exercise(store: &dyn BlobStore) {
+        let key = "captures/550e8400-e29b-41d4-a716-446655440000/photo.jpg";
+
+        assert!(store.head(key).await.unwrap().is_none());
+        assert!(matches!(
+            store.get(key).await,
+            Err(BlobStoreError::NotFound(_))
+        ));
+
+        store
+            .put(key, vec![1, 2, 3], "image/jpeg", &sha(&[1, 2, 3]))
+            .await
+            .unwrap();
+        assert_eq!(store.get(key).await.unwrap(), vec![1, 2, 3]);
+        assert_eq!(store.head(key).await.unwrap().unwrap().size, 3);
+
+        // Overwrite replaces the blob
+        store
+            .put(key, vec![9; 5], "image/jpeg", &sha(&[9; 5]))
+            .await
+            .unwrap();
+        assert_eq!(store.head(key).await.unwrap().unwrap().size, 5);
+
+        // Bytes that do not match the declared digest are rejected, not stored
+        assert!(matches!(
+            store
+                .put(key, vec![7; 5], "image/jpeg", &sha(&[9; 5]))
+                .await,
+            Err(BlobStoreError::ChecksumMismatch(_))
+        ));
+        assert_eq!(store.get(key).await.unwrap(), vec![9; 5]);
+
+        // Local backends cannot sign URLs; media is proxied by the API
+        assert!(store
+            .presign_get(key, Duration::from_secs(300))
+            .await
+            .unwrap()
+            .is_none());
+
+        store.delete(key).await.unwrap();
+        assert!(store.head(key).await.unwrap().is_none());
+        // Deleting a missing blob is not an error
+        store.delete(key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_round_trip() {
+        let store = MemoryBlobStore::new();
+        exercise(&store).await;
+    }
+
+    #[tokio::test]
+    async fn test_fs_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FsBlobStore::new(dir.path());
+        exercise(&store).await;
+    }
+
+    #[tokio::test]
+    async fn test_fs_store_rejects_escaping_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FsBlobStore::new(dir.path());
+
+        for key in ["../outside", "/etc/passwd", "captures/../../x", ""] {
+            assert!(matches!(
+                store.put(key, vec![1], "text/plain", &sha(&[1])).await,
+                Err(BlobStoreError::InvalidKey(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_storage_backend_from_str() {
+        assert_eq!("s3".parse(), Ok(StorageBackend::S3));
+        assert_eq!("Filesystem".parse(), Ok(StorageBackend::Filesystem));
+        assert_eq!("memory".parse(), Ok(StorageBackend::Memory));
+        assert!("gcs".parse::<StorageBackend>().is_err());
+    }
+}
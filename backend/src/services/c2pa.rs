@@ -24,9 +24,10 @@ use thiserror::Error;
 use tracing::info;
 use uuid::Uuid;
 
-use crate::models::{CheckStatus, ConfidenceLevel, EvidencePackage};
+use crate::models::{
+    ConfidenceLevel, EvidencePackage, HashChainEvidence, PartialAttestationInfo, ScoreFactor,
+};
 use crate::services::video_evidence::VideoEvidenceService;
-use crate::types::video_evidence::{VideoConfidenceLevel, VideoEvidence};
 
 // ============================================================================
 // Constants
@@ -83,6 +84,12 @@ pub struct RealityCamAssertion {
     /// Confidence level from evidence analysis
     pub confidence_level: String,
 
+    /// Numeric confidence score (0-100)
+    pub confidence_score: u8,
+
+    /// Factors that produced the confidence score, most decisive first
+    pub confidence_factors: Vec<ScoreFactor>,
+
     /// Hardware attestation summary
     pub hardware_attestation: HardwareAssertionData,
 
@@ -252,41 +259,30 @@ impl C2paService {
         evidence: &EvidencePackage,
         captured_at: &str,
     ) -> RealityCamAssertion {
-        let confidence_level = match evidence.calculate_confidence() {
+        // Prefer the stored score: it includes caps applied at upload time
+        let score = evidence
+            .confidence_score
+            .clone()
+            .unwrap_or_else(|| evidence.calculate_score());
+
+        let confidence_level = match score.level {
             ConfidenceLevel::High => "high",
             ConfidenceLevel::Medium => "medium",
             ConfidenceLevel::Low => "low",
             ConfidenceLevel::Suspicious => "suspicious",
         };
 
-        let hw_status = match evidence.hardware_attestation.status {
-            CheckStatus::Pass => "pass",
-            CheckStatus::Fail => "fail",
-            CheckStatus::Unavailable => "unavailable",
-        };
-
-        let hw_level = match evidence.hardware_attestation.level {
-            crate::models::AttestationLevel::SecureEnclave => "secure_enclave",
-            crate::models::AttestationLevel::StrongBox => "strongbox",
-            crate::models::AttestationLevel::TrustedEnvironment => "tee",
-            crate::models::AttestationLevel::Unverified => "unverified",
-        };
-
-        let depth_status = match evidence.depth_analysis.status {
-            CheckStatus::Pass => "pass",
-            CheckStatus::Fail => "fail",
-            CheckStatus::Unavailable => "unavailable",
-        };
-
         RealityCamAssertion {
             confidence_level: confidence_level.to_string(),
+            confidence_score: score.score,
+            confidence_factors: score.factors,
             hardware_attestation: HardwareAssertionData {
-                status: hw_status.to_string(),
-                level: hw_level.to_string(),
+                status: evidence.hardware_attestation.status.to_string(),
+                level: evidence.hardware_attestation.level.to_string(),
                 verified: evidence.hardware_attestation.assertion_verified,
             },
             depth_analysis: DepthAssertionData {
-                status: depth_status.to_string(),
+                status: evidence.depth_analysis.status.to_string(),
                 is_real_scene: evidence.depth_analysis.is_likely_real_scene,
                 depth_layers: evidence.depth_analysis.depth_layers,
                 depth_variance: evidence.depth_analysis.depth_variance,
@@ -317,7 +313,7 @@ impl C2paService {
     /// ```
     pub fn generate_video_manifest(
         &self,
-        evidence: &VideoEvidence,
+        evidence: &EvidencePackage,
         captured_at: &str,
     ) -> C2paVideoManifest {
         let assertion = self.build_video_assertion(evidence, captured_at);
@@ -328,7 +324,7 @@ impl C2paService {
 
         info!(
             "Generated video C2PA manifest: {} frames, {}ms, confidence={}",
-            evidence.frame_count, evidence.duration_ms, assertion.confidence_level
+            assertion.frame_count, assertion.duration_ms, assertion.confidence_level
         );
 
         C2paVideoManifest {
@@ -354,7 +350,7 @@ impl C2paService {
     /// Pretty-printed JSON string of the manifest
     pub fn generate_video_manifest_json(
         &self,
-        evidence: &VideoEvidence,
+        evidence: &EvidencePackage,
         captured_at: &str,
     ) -> Result<String, C2paError> {
         let manifest = self.generate_video_manifest(evidence, captured_at);
@@ -364,33 +360,49 @@ impl C2paService {
     /// Builds the RealityCam video assertion from evidence (Story 7-12)
     fn build_video_assertion(
         &self,
-        evidence: &VideoEvidence,
+        evidence: &EvidencePackage,
         captured_at: &str,
     ) -> RealityCamVideoAssertion {
-        // Calculate confidence using VideoEvidenceService
-        let evidence_service = VideoEvidenceService::new();
-        let confidence = evidence_service.calculate_confidence(evidence);
-        let confidence_level = self.map_video_confidence_level(confidence);
+        // Calculate confidence using VideoEvidenceService (unless already scored)
+        let score = evidence
+            .confidence_score
+            .clone()
+            .unwrap_or_else(|| VideoEvidenceService::new().calculate_score(evidence));
+        let confidence_level = self.map_confidence_level(score.level);
+
+        // Evidence without video or hash chain sections reports nothing verified
+        let video = evidence.video.as_ref();
+        let unverified_chain;
+        let hash_chain = match evidence.hash_chain.as_ref() {
+            Some(chain) => chain,
+            None => {
+                unverified_chain = HashChainEvidence::fail("Hash chain not verified");
+                &unverified_chain
+            }
+        };
+        let partial = video
+            .map(|v| v.partial_attestation.clone())
+            .unwrap_or_else(|| PartialAttestationInfo::complete(0));
 
         // Map hardware attestation (reuse existing HardwareAssertionData)
         let hardware_attestation = HardwareAssertionData {
-            status: evidence.hardware_attestation.status.clone(),
-            level: "secure_enclave".to_string(),
-            verified: evidence.hardware_attestation.assertion_valid,
+            status: evidence.hardware_attestation.status.to_string(),
+            level: evidence.hardware_attestation.level.to_string(),
+            verified: evidence.hardware_attestation.assertion_verified,
         };
 
         // Map hash chain verification to summary
         let hash_chain_summary = HashChainSummaryData {
-            status: evidence.hash_chain.status.clone(),
-            chain_intact: evidence.hash_chain.chain_intact,
-            attestation_valid: evidence.hash_chain.attestation_valid,
-            verified_frames: evidence.hash_chain.verified_frames,
-            total_frames: evidence.hash_chain.total_frames,
-            broken_at_frame: None, // Hash chain evidence doesn't expose broken_at_frame
+            status: hash_chain.status.to_string(),
+            chain_intact: hash_chain.chain_intact,
+            attestation_valid: hash_chain.attestation_valid,
+            verified_frames: hash_chain.verified_frames,
+            total_frames: hash_chain.total_frames,
+            broken_at_frame: hash_chain.broken_at_frame,
         };
 
         // Map temporal depth analysis (optional)
-        let temporal_depth_summary = evidence.depth_analysis.as_ref().map(|depth| {
+        let temporal_depth_summary = video.and_then(|v| v.temporal_depth.as_ref()).map(|depth| {
             let status = if depth.is_likely_real_scene {
                 "pass"
             } else {
@@ -408,35 +420,37 @@ impl C2paService {
 
         // Map partial attestation
         let partial_attestation = PartialAttestationData {
-            is_partial: evidence.partial_attestation.is_partial,
-            checkpoint_index: evidence.partial_attestation.checkpoint_index,
-            verified_frames: evidence.partial_attestation.verified_frames,
-            total_frames: evidence.partial_attestation.total_frames,
-            reason: evidence.partial_attestation.reason.clone(),
+            is_partial: partial.is_partial,
+            checkpoint_index: partial.checkpoint_index,
+            verified_frames: partial.verified_frames,
+            total_frames: partial.total_frames,
+            reason: partial.reason,
         };
 
         RealityCamVideoAssertion {
             confidence_level,
+            confidence_score: score.score,
+            confidence_factors: score.factors,
             media_type: "video".to_string(),
-            duration_ms: evidence.duration_ms,
-            frame_count: evidence.frame_count,
-            verified_frames: evidence.hash_chain.verified_frames,
+            duration_ms: video.map_or(0, |v| v.duration_ms),
+            frame_count: video.map_or(0, |v| v.frame_count),
+            verified_frames: hash_chain.verified_frames,
             hardware_attestation,
             hash_chain_summary,
             temporal_depth_summary,
             partial_attestation,
-            device_model: evidence.metadata.device_model.clone(),
+            device_model: evidence.hardware_attestation.device_model.clone(),
             captured_at: captured_at.to_string(),
         }
     }
 
-    /// Map VideoConfidenceLevel to string (Story 7-12)
-    fn map_video_confidence_level(&self, confidence: VideoConfidenceLevel) -> String {
+    /// Map ConfidenceLevel to string (Story 7-12)
+    fn map_confidence_level(&self, confidence: ConfidenceLevel) -> String {
         match confidence {
-            VideoConfidenceLevel::High => "high",
-            VideoConfidenceLevel::Medium => "medium",
-            VideoConfidenceLevel::Low => "low",
-            VideoConfidenceLevel::Suspicious => "suspicious",
+            ConfidenceLevel::High => "high",
+            ConfidenceLevel::Medium => "medium",
+            ConfidenceLevel::Low => "low",
+            ConfidenceLevel::Suspicious => "suspicious",
         }
         .to_string()
     }
@@ -512,6 +526,12 @@ pub struct RealityCamVideoAssertion {
     /// Confidence level from evidence analysis
     pub confidence_level: String,
 
+    /// Numeric confidence score (0-100)
+    pub confidence_score: u8,
+
+    /// Factors that produced the confidence score, most decisive first
+    pub confidence_factors: Vec<ScoreFactor>,
+
     /// Media type (always "video")
     #[serde(rename = "type")]
     pub media_type: String,
@@ -637,7 +657,8 @@ pub fn c2pa_video_manifest_s3_key(capture_id: Uuid) -> String {
 mod tests {
     use super::*;
     use crate::models::{
-        AttestationLevel, DepthAnalysis, HardwareAttestation, MetadataEvidence, ProcessingInfo,
+        AttestationLevel, CheckStatus, DepthAnalysis, HardwareAttestation, MetadataEvidence,
+        ProcessingInfo,
     };
 
     fn create_test_evidence() -> EvidencePackage {
@@ -655,6 +676,8 @@ mod tests {
                 source: None,
                 method: Some("lidar".to_string()), // Story 10-5
                 unavailable_reason: None,
+                analysis_trust: None,
+                plausibility_issues: Vec::new(),
             },
             MetadataEvidence::default(),
             ProcessingInfo::new(1000, "0.1.0"),
@@ -678,6 +701,20 @@ mod tests {
         assert_eq!(assertion.device_model, "iPhone 15 Pro");
     }
 
+    #[test]
+    fn test_assertion_includes_confidence_score() {
+        let service = C2paService::new();
+        let evidence = create_test_evidence();
+
+        let assertion = service.build_assertion(&evidence, "2025-11-23T10:30:00Z");
+
+        assert_eq!(assertion.confidence_score, 90);
+        assert_eq!(assertion.confidence_factors[0].check, "hardware");
+        let json = serde_json::to_value(&assertion).unwrap();
+        assert_eq!(json["confidence_score"], 90);
+        assert!(json["confidence_factors"].is_array());
+    }
+
     #[test]
     fn test_generate_manifest() {
         let service = C2paService::new();
@@ -744,20 +781,45 @@ mod tests {
     // Video Manifest Tests (Story 7-12)
     // ========================================================================
 
-    use crate::types::video_evidence::{
-        DepthAnalysisEvidence, HardwareAttestationEvidence, HashChainEvidence,
-        MetadataEvidence as VideoMetadataEvidence, PartialAttestationInfo,
-        ProcessingInfo as VideoProcessingInfo, VideoEvidence,
-    };
-    use chrono::Utc;
+    use crate::models::{HashChainStatus, TemporalDepthEvidence, VideoDetails};
+
+    fn create_video_evidence(
+        duration_ms: u64,
+        frame_count: u32,
+        hash_chain: HashChainEvidence,
+        temporal_depth: Option<TemporalDepthEvidence>,
+        partial_attestation: PartialAttestationInfo,
+    ) -> EvidencePackage {
+        let depth = DepthAnalysis {
+            status: if temporal_depth.is_some() {
+                CheckStatus::Pass
+            } else {
+                CheckStatus::Unavailable
+            },
+            is_likely_real_scene: temporal_depth.is_some(),
+            ..Default::default()
+        };
+        EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            depth,
+            MetadataEvidence::default(),
+            ProcessingInfo::new(1500, "0.1.0"),
+        )
+        .with_video(VideoDetails {
+            duration_ms,
+            frame_count,
+            temporal_depth,
+            partial_attestation,
+        })
+        .with_hash_chain(hash_chain)
+    }
 
-    fn create_test_video_evidence() -> VideoEvidence {
-        VideoEvidence::new(
+    fn create_test_video_evidence() -> EvidencePackage {
+        create_video_evidence(
             15000, // 15 seconds
             450,   // 30 fps * 15 seconds
-            HardwareAttestationEvidence::pass(Utc::now()),
             HashChainEvidence {
-                status: "pass".to_string(),
+                status: HashChainStatus::Pass,
                 verified_frames: 450,
                 total_frames: 450,
                 chain_intact: true,
@@ -766,35 +828,27 @@ mod tests {
                 verified_duration_ms: 15000,
                 checkpoint_verified: false,
                 checkpoint_index: None,
+                derivation_verified: false,
+                genesis_bound: false,
+                broken_at_frame: None,
             },
-            Some(DepthAnalysisEvidence {
+            Some(TemporalDepthEvidence {
                 depth_consistency: 0.85,
                 motion_coherence: 0.72,
                 scene_stability: 0.90,
                 is_likely_real_scene: true,
                 suspicious_frames: vec![],
             }),
-            VideoMetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
             PartialAttestationInfo::complete(450),
-            VideoProcessingInfo::new(
-                1500,
-                "0.1.0".to_string(),
-                vec![
-                    "hardware".to_string(),
-                    "hash_chain".to_string(),
-                    "depth".to_string(),
-                ],
-            ),
         )
     }
 
-    fn create_test_video_evidence_partial() -> VideoEvidence {
-        VideoEvidence::new(
+    fn create_test_video_evidence_partial() -> EvidencePackage {
+        create_video_evidence(
             10000, // 10 seconds
             300,   // 30 fps * 10 seconds
-            HardwareAttestationEvidence::pass(Utc::now()),
             HashChainEvidence {
-                status: "partial".to_string(),
+                status: HashChainStatus::Partial,
                 verified_frames: 300,
                 total_frames: 450,
                 chain_intact: true,
@@ -803,25 +857,21 @@ mod tests {
                 verified_duration_ms: 10000,
                 checkpoint_verified: true,
                 checkpoint_index: Some(1),
+                derivation_verified: false,
+                genesis_bound: false,
+                broken_at_frame: None,
             },
             None, // No depth analysis
-            VideoMetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
             PartialAttestationInfo::partial(1, 300, 450),
-            VideoProcessingInfo::new(
-                1200,
-                "0.1.0".to_string(),
-                vec!["hardware".to_string(), "hash_chain".to_string()],
-            ),
         )
     }
 
-    fn create_test_video_evidence_no_depth() -> VideoEvidence {
-        VideoEvidence::new(
+    fn create_test_video_evidence_no_depth() -> EvidencePackage {
+        create_video_evidence(
             15000,
             450,
-            HardwareAttestationEvidence::pass(Utc::now()),
             HashChainEvidence {
-                status: "pass".to_string(),
+                status: HashChainStatus::Pass,
                 verified_frames: 450,
                 total_frames: 450,
                 chain_intact: true,
@@ -830,15 +880,12 @@ mod tests {
                 verified_duration_ms: 15000,
                 checkpoint_verified: false,
                 checkpoint_index: None,
+                derivation_verified: false,
+                genesis_bound: false,
+                broken_at_frame: None,
             },
             None, // No depth analysis
-            VideoMetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
             PartialAttestationInfo::complete(450),
-            VideoProcessingInfo::new(
-                1000,
-                "0.1.0".to_string(),
-                vec!["hardware".to_string(), "hash_chain".to_string()],
-            ),
         )
     }
 
@@ -883,6 +930,22 @@ mod tests {
         assert!(hash_chain.attestation_valid);
         assert_eq!(hash_chain.verified_frames, 450);
         assert_eq!(hash_chain.total_frames, 450);
+        assert_eq!(hash_chain.broken_at_frame, None);
+    }
+
+    #[test]
+    fn test_video_manifest_hash_chain_broken_at_frame() {
+        let service = C2paService::new();
+        let mut evidence = create_test_video_evidence();
+        let chain = evidence.hash_chain.as_mut().unwrap();
+        chain.status = HashChainStatus::Fail;
+        chain.chain_intact = false;
+        chain.broken_at_frame = Some(212);
+
+        let manifest = service.generate_video_manifest(&evidence, "2025-11-27T12:00:00Z");
+
+        let hash_chain = &manifest.realitycam.hash_chain_summary;
+        assert_eq!(hash_chain.broken_at_frame, Some(212));
     }
 
     #[test]
@@ -1056,6 +1119,8 @@ mod tests {
                 source: Some(AnalysisSource::Device), // Hash-only uses device analysis
                 method: Some("lidar".to_string()),    // Story 10-5
                 unavailable_reason: None,
+                analysis_trust: None,
+                plausibility_issues: Vec::new(),
             },
             MetadataEvidence::default(),
             ProcessingInfo::new(100, "0.1.0"), // Faster processing for hash-only
@@ -17,6 +17,13 @@
 //! ## Hash-Only Capture Verification Flow (Story 8-4)
 //! Same as above, but clientDataHash = SHA256(serialized_payload_json)
 //! where the payload is the HashOnlyCapturePayload with assertion field excluded.
+//! Video payloads include their hash chain summary, so the final hash is bound.
+//!
+//! ## Hash-Only Video Checkpoints
+//! Each submitted checkpoint carries its own assertion with
+//! clientDataHash = SHA256(checkpoint_hash_bytes). Checkpoint counters must be
+//! above the stored counter, increase with the checkpoint index, and stay
+//! below the payload assertion's counter (they were signed during recording).
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use ciborium::Value;
@@ -26,7 +33,8 @@ use uuid::Uuid;
 
 use crate::config::Config;
 use crate::models::{
-    AttestationLevel, CheckStatus, Device, HardwareAttestation, SecurityLevelInfo,
+    AttestationLevel, CheckStatus, Device, HardwareAttestation, KeymasterLevel, Platform,
+    SecurityLevelInfo,
 };
 
 // ============================================================================
@@ -144,9 +152,12 @@ impl From<CaptureAssertionResult> for HardwareAttestation {
 /// Builds SecurityLevelInfo from device fields (Story 10-2)
 fn build_security_level_info(device: &Device) -> Option<SecurityLevelInfo> {
     device.security_level.as_ref().map(|sl| SecurityLevelInfo {
-        attestation_level: sl.clone(),
-        keymaster_level: device.keymaster_security_level.clone(),
-        platform: device.platform.to_lowercase(),
+        attestation_level: AttestationLevel::from(sl.as_str()),
+        keymaster_level: device
+            .keymaster_security_level
+            .as_deref()
+            .and_then(KeymasterLevel::parse),
+        platform: Platform::from(device.platform.as_str()),
     })
 }
 
@@ -172,15 +183,64 @@ pub fn verify_capture_assertion(
     captured_at: &str,
     config: &Config,
     request_id: Uuid,
+) -> CaptureAssertionResult {
+    // Handle missing assertion, otherwise attempt verification
+    let outcome = assertion_b64
+        .filter(|s| !s.trim().is_empty())
+        .map(|assertion_b64| {
+            verify_assertion_internal(
+                device,
+                assertion_b64,
+                photo_hash,
+                captured_at,
+                config,
+                request_id,
+            )
+        });
+
+    assertion_result(device, outcome, request_id)
+}
+
+/// Verifies a video capture assertion over the attested final hash.
+///
+/// NON-BLOCKING like [`verify_capture_assertion`]. The assertion's
+/// clientDataHash is SHA256 of the raw final hash bytes, the same binding as
+/// checkpoint assertions.
+pub fn verify_video_assertion(
+    device: &Device,
+    assertion_b64: Option<&str>,
+    final_hash_b64: &str,
+    config: &Config,
+    request_id: Uuid,
+) -> CaptureAssertionResult {
+    let outcome = assertion_b64
+        .filter(|s| !s.trim().is_empty())
+        .map(|assertion_b64| {
+            verify_checkpoint_assertion_internal(
+                device,
+                final_hash_b64,
+                assertion_b64,
+                device.assertion_counter,
+                config,
+            )
+        });
+
+    assertion_result(device, outcome, request_id)
+}
+
+/// Builds the evidence result of an assertion check (`None` if none was sent)
+fn assertion_result(
+    device: &Device,
+    outcome: Option<Result<u32, CaptureAssertionError>>,
+    request_id: Uuid,
 ) -> CaptureAssertionResult {
     let device_model = device.model.clone();
     let level = AttestationLevel::from(device.attestation_level.as_str());
     let security_level = build_security_level_info(device);
 
-    // Handle missing assertion
-    let assertion_b64 = match assertion_b64 {
-        Some(s) if !s.trim().is_empty() => s,
-        _ => {
+    let outcome = match outcome {
+        Some(outcome) => outcome,
+        None => {
             tracing::info!(
                 request_id = %request_id,
                 device_id = %device.id,
@@ -199,15 +259,8 @@ pub fn verify_capture_assertion(
         }
     };
 
-    // Attempt verification - any error results in status=fail
-    match verify_assertion_internal(
-        device,
-        assertion_b64,
-        photo_hash,
-        captured_at,
-        config,
-        request_id,
-    ) {
+    // Any error results in status=fail
+    match outcome {
         Ok(new_counter) => {
             tracing::info!(
                 request_id = %request_id,
@@ -462,7 +515,7 @@ fn parse_signature(sig_bytes: &[u8]) -> Result<Signature, CaptureAssertionError>
 // Hash-Only Capture Verification (Story 8-4)
 // ============================================================================
 
-use crate::types::HashOnlyCapturePayload;
+use crate::types::{HashOnlyCapturePayload, VideoHashChainData};
 
 /// Verifies a hash-only capture assertion against the device's registered public key.
 ///
@@ -617,17 +670,15 @@ fn verify_hash_only_assertion_internal(
     Ok(auth_data.counter)
 }
 
-/// Computes the clientDataHash for hash-only capture assertion binding.
+/// Builds the client data signed by a hash-only capture assertion.
 ///
 /// CRITICAL: Must match iOS implementation exactly.
-/// Creates a JSON object with all payload fields EXCEPT assertion,
-/// serializes to bytes, and computes SHA-256 hash.
-///
-/// The fields are serialized in alphabetical key order by serde_json.
-pub fn compute_hash_only_client_data_hash(payload: &HashOnlyCapturePayload) -> [u8; 32] {
+/// A JSON object with all payload fields EXCEPT assertion; the fields are
+/// serialized in alphabetical key order by serde_json.
+pub fn hash_only_client_data(payload: &HashOnlyCapturePayload) -> serde_json::Value {
     // Create JSON with all fields except assertion
     // Using serde_json::json! macro for consistent serialization
-    let hashable = serde_json::json!({
+    let mut hashable = serde_json::json!({
         "capture_mode": payload.capture_mode,
         "captured_at": payload.captured_at,
         "depth_analysis": payload.depth_analysis,
@@ -637,6 +688,44 @@ pub fn compute_hash_only_client_data_hash(payload: &HashOnlyCapturePayload) -> [
         "metadata_flags": payload.metadata_flags,
     });
 
+    // Video fields are only present for video captures (iOS omits nil optionals)
+    if let Some(fields) = hashable.as_object_mut() {
+        if let Some(ref hash_chain) = payload.hash_chain {
+            fields.insert("hash_chain".into(), serde_json::json!(hash_chain));
+        }
+        if let Some(ref temporal) = payload.temporal_depth_analysis {
+            fields.insert(
+                "temporal_depth_analysis".into(),
+                serde_json::json!(temporal),
+            );
+        }
+        if let Some(frame_count) = payload.frame_count {
+            fields.insert("frame_count".into(), serde_json::json!(frame_count));
+        }
+        if let Some(duration_ms) = payload.duration_ms {
+            fields.insert("duration_ms".into(), serde_json::json!(duration_ms));
+        }
+        // Metadata commitments and location policy are optional in privacy mode
+        if let Some(ref commitments) = payload.metadata_commitments {
+            fields.insert(
+                "metadata_commitments".into(),
+                serde_json::json!(commitments),
+            );
+        }
+        if let Some(policy) = payload.location_privacy {
+            fields.insert("location_privacy".into(), serde_json::json!(policy));
+        }
+    }
+
+    hashable
+}
+
+/// Computes the clientDataHash for hash-only capture assertion binding.
+///
+/// SHA-256 of the compact serialization of [`hash_only_client_data`].
+pub fn compute_hash_only_client_data_hash(payload: &HashOnlyCapturePayload) -> [u8; 32] {
+    let hashable = hash_only_client_data(payload);
+
     // Serialize to bytes (serde_json::to_vec produces compact JSON)
     let json_bytes = serde_json::to_vec(&hashable).expect("JSON serialization should not fail");
 
@@ -644,6 +733,160 @@ pub fn compute_hash_only_client_data_hash(payload: &HashOnlyCapturePayload) -> [
     Sha256::digest(&json_bytes).into()
 }
 
+/// Verifies that a hash-only capture assertion signed `final_hash` as the
+/// video hash chain's final hash.
+///
+/// Recomputes the clientDataHash with `final_hash` in the chain summary and
+/// checks the payload assertion's signature over it with the device key.
+/// The counter is not re-checked; `verify_hash_only_assertion` already
+/// consumed it for this assertion.
+pub fn verify_hash_only_final_hash_binding(
+    device: &Device,
+    payload: &HashOnlyCapturePayload,
+    final_hash: &str,
+) -> Result<(), CaptureAssertionError> {
+    let mut bound = payload.clone();
+    bound
+        .hash_chain
+        .as_mut()
+        .ok_or(CaptureAssertionError::MissingField("hash_chain"))?
+        .final_hash = final_hash.to_string();
+    let client_data_hash = compute_hash_only_client_data_hash(&bound);
+
+    let assertion_bytes = STANDARD
+        .decode(&payload.assertion)
+        .map_err(|_| CaptureAssertionError::InvalidBase64)?;
+    let assertion = parse_cbor_assertion(&assertion_bytes)?;
+
+    let public_key_bytes = device
+        .public_key
+        .as_ref()
+        .ok_or(CaptureAssertionError::MissingPublicKey)?;
+
+    let mut message = assertion.authenticator_data.clone();
+    message.extend_from_slice(&client_data_hash);
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(public_key_bytes)
+        .map_err(|e| CaptureAssertionError::InvalidPublicKey(format!("Failed to parse: {e}")))?;
+    let signature = parse_signature(&assertion.signature)?;
+    verifying_key
+        .verify(&message, &signature)
+        .map_err(|e| CaptureAssertionError::SignatureInvalid(format!("Verification failed: {e}")))
+}
+
+// ============================================================================
+// Hash-Only Video Checkpoint Verification
+// ============================================================================
+
+/// Verifies the per-checkpoint assertions of a hash-only video hash chain.
+///
+/// Returns the indices of checkpoints whose assertion verified and whose
+/// counter fits between the stored device counter and `payload_counter`.
+/// Failures are logged and simply leave the checkpoint unattested; the
+/// hash chain verifier then records the failure in evidence.
+pub fn verify_hash_only_checkpoint_assertions(
+    device: &Device,
+    hash_chain: &VideoHashChainData,
+    payload_counter: u32,
+    config: &Config,
+    request_id: Uuid,
+) -> Vec<u32> {
+    let mut attested = Vec::with_capacity(hash_chain.checkpoints.len());
+    let mut last_counter = device.assertion_counter;
+
+    for checkpoint in &hash_chain.checkpoints {
+        let result = verify_checkpoint_assertion_internal(
+            device,
+            &checkpoint.hash,
+            &checkpoint.assertion,
+            last_counter,
+            config,
+        )
+        .and_then(|counter| {
+            if counter >= payload_counter {
+                Err(CaptureAssertionError::InvalidAuthData(format!(
+                    "checkpoint counter {counter} not below payload counter {payload_counter}"
+                )))
+            } else {
+                Ok(counter)
+            }
+        });
+
+        match result {
+            Ok(counter) => {
+                last_counter = counter as i64;
+                attested.push(checkpoint.index);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    request_id = %request_id,
+                    device_id = %device.id,
+                    checkpoint_index = checkpoint.index,
+                    error = %e,
+                    "[capture_attestation] Checkpoint assertion verification failed"
+                );
+            }
+        }
+    }
+
+    tracing::debug!(
+        request_id = %request_id,
+        attested = attested.len(),
+        submitted = hash_chain.checkpoints.len(),
+        "[capture_attestation] Checkpoint assertions verified"
+    );
+
+    attested
+}
+
+/// Verifies one checkpoint assertion; returns its counter.
+///
+/// clientDataHash = SHA256(checkpoint_hash_bytes), as produced by the iOS
+/// `generateAssertion(for: checkpointHash)` call.
+fn verify_checkpoint_assertion_internal(
+    device: &Device,
+    checkpoint_hash_b64: &str,
+    assertion_b64: &str,
+    counter_floor: i64,
+    config: &Config,
+) -> Result<u32, CaptureAssertionError> {
+    let checkpoint_hash = STANDARD
+        .decode(checkpoint_hash_b64)
+        .map_err(|_| CaptureAssertionError::InvalidBase64)?;
+    let assertion_bytes = STANDARD
+        .decode(assertion_b64)
+        .map_err(|_| CaptureAssertionError::InvalidBase64)?;
+
+    let assertion = parse_cbor_assertion(&assertion_bytes)?;
+    let auth_data = parse_assertion_auth_data(&assertion.authenticator_data)?;
+    verify_rp_id_hash(&auth_data.rp_id_hash, config)?;
+
+    if (auth_data.counter as i64) <= counter_floor {
+        return Err(CaptureAssertionError::CounterNotIncreasing {
+            received: auth_data.counter,
+            stored: counter_floor,
+        });
+    }
+
+    let public_key_bytes = device
+        .public_key
+        .as_ref()
+        .ok_or(CaptureAssertionError::MissingPublicKey)?;
+
+    let client_data_hash: [u8; 32] = Sha256::digest(&checkpoint_hash).into();
+    let mut message = assertion.authenticator_data.clone();
+    message.extend_from_slice(&client_data_hash);
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(public_key_bytes)
+        .map_err(|e| CaptureAssertionError::InvalidPublicKey(format!("Failed to parse: {e}")))?;
+    let signature = parse_signature(&assertion.signature)?;
+    verifying_key.verify(&message, &signature).map_err(|e| {
+        CaptureAssertionError::SignatureInvalid(format!("Verification failed: {e}"))
+    })?;
+
+    Ok(auth_data.counter)
+}
+
 // ============================================================================
 // Unit Tests
 // ============================================================================
@@ -850,9 +1093,9 @@ mod tests {
             new_counter: Some(10),
             error_message: None,
             security_level: Some(SecurityLevelInfo {
-                attestation_level: "secure_enclave".to_string(),
+                attestation_level: AttestationLevel::SecureEnclave,
                 keymaster_level: None,
-                platform: "ios".to_string(),
+                platform: Platform::Ios,
             }),
         };
 
@@ -864,8 +1107,8 @@ mod tests {
         assert!(hw.counter_valid);
         assert!(hw.security_level.is_some());
         let sl = hw.security_level.unwrap();
-        assert_eq!(sl.attestation_level, "secure_enclave");
-        assert_eq!(sl.platform, "ios");
+        assert_eq!(sl.attestation_level, AttestationLevel::SecureEnclave);
+        assert_eq!(sl.platform, Platform::Ios);
     }
 
     #[test]
@@ -969,6 +1212,8 @@ mod tests {
             temporal_depth_analysis: None,
             frame_count: None,
             duration_ms: None,
+            metadata_commitments: None,
+            location_privacy: None,
         }
     }
 
@@ -1081,9 +1326,9 @@ mod tests {
         let info = build_security_level_info(&device);
         assert!(info.is_some());
         let info = info.unwrap();
-        assert_eq!(info.attestation_level, "secure_enclave");
+        assert_eq!(info.attestation_level, AttestationLevel::SecureEnclave);
         assert!(info.keymaster_level.is_none());
-        assert_eq!(info.platform, "ios"); // lowercase
+        assert_eq!(info.platform, Platform::Ios); // lowercase
     }
 
     #[test]
@@ -1108,9 +1353,9 @@ mod tests {
         let info = build_security_level_info(&device);
         assert!(info.is_some());
         let info = info.unwrap();
-        assert_eq!(info.attestation_level, "strongbox");
-        assert_eq!(info.keymaster_level, Some("strongbox".to_string()));
-        assert_eq!(info.platform, "android");
+        assert_eq!(info.attestation_level, AttestationLevel::StrongBox);
+        assert_eq!(info.keymaster_level, Some(KeymasterLevel::StrongBox));
+        assert_eq!(info.platform, Platform::Android);
     }
 
     #[test]
@@ -1156,7 +1401,200 @@ mod tests {
         // test_device() has security_level set
         assert!(result.security_level.is_some());
         let sl = result.security_level.unwrap();
-        assert_eq!(sl.attestation_level, "secure_enclave");
-        assert_eq!(sl.platform, "ios");
+        assert_eq!(sl.attestation_level, AttestationLevel::SecureEnclave);
+        assert_eq!(sl.platform, Platform::Ios);
+    }
+
+    #[test]
+    fn test_compute_hash_only_client_data_hash_binds_hash_chain() {
+        let mut payload1 = test_hash_only_payload();
+        payload1.media_type = "video".to_string();
+        payload1.hash_chain = Some(VideoHashChainData {
+            final_hash: STANDARD.encode([1u8; 32]),
+            chain_length: 450,
+            version: "1.0".to_string(),
+            checkpoint_count: 0,
+            checkpoints: vec![],
+        });
+        let mut payload2 = payload1.clone();
+        payload2.hash_chain.as_mut().unwrap().final_hash = STANDARD.encode([2u8; 32]);
+
+        assert_ne!(
+            compute_hash_only_client_data_hash(&payload1),
+            compute_hash_only_client_data_hash(&payload2),
+            "Final hash must be bound by the payload assertion"
+        );
+    }
+
+    #[test]
+    fn test_compute_hash_only_client_data_hash_binds_metadata_commitments() {
+        let mut payload1 = test_hash_only_payload();
+        payload1.metadata_commitments = Some(crate::types::MetadataCommitments {
+            location: Some("1".repeat(64)),
+            ..Default::default()
+        });
+        let mut payload2 = payload1.clone();
+        payload2.metadata_commitments.as_mut().unwrap().location = Some("2".repeat(64));
+
+        assert_ne!(
+            compute_hash_only_client_data_hash(&payload1),
+            compute_hash_only_client_data_hash(&payload2),
+            "Metadata commitments must be bound by the payload assertion"
+        );
+        assert_ne!(
+            compute_hash_only_client_data_hash(&payload1),
+            compute_hash_only_client_data_hash(&test_hash_only_payload())
+        );
+    }
+
+    // ========================================================================
+    // Hash-Only Video Checkpoint Tests
+    // ========================================================================
+
+    use crate::types::VideoHashCheckpoint;
+    use p256::ecdsa::{signature::Signer, SigningKey};
+
+    fn checkpoint_signing_key() -> SigningKey {
+        SigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    fn checkpoint_device() -> Device {
+        let key = checkpoint_signing_key();
+        let mut device = test_device();
+        device.public_key = Some(
+            key.verifying_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec(),
+        );
+        device
+    }
+
+    /// Builds a CBOR assertion over SHA256(checkpoint hash bytes)
+    fn sign_checkpoint(config: &Config, hash: &[u8], counter: u32) -> String {
+        let app_id = format!("{}.{}", config.apple_team_id, config.apple_bundle_id);
+        let mut auth_data = Sha256::digest(app_id.as_bytes()).to_vec();
+        auth_data.push(0x01);
+        auth_data.extend_from_slice(&counter.to_be_bytes());
+
+        let mut message = auth_data.clone();
+        message.extend_from_slice(&Sha256::digest(hash));
+        let signature: Signature = checkpoint_signing_key().sign(&message);
+
+        let mut cbor_bytes = Vec::new();
+        ciborium::into_writer(
+            &ciborium::Value::Map(vec![
+                (
+                    ciborium::Value::Text("authenticatorData".to_string()),
+                    ciborium::Value::Bytes(auth_data),
+                ),
+                (
+                    ciborium::Value::Text("signature".to_string()),
+                    ciborium::Value::Bytes(signature.to_bytes().to_vec()),
+                ),
+            ]),
+            &mut cbor_bytes,
+        )
+        .unwrap();
+        STANDARD.encode(cbor_bytes)
+    }
+
+    fn signed_hash_chain(config: &Config, counters: &[u32]) -> VideoHashChainData {
+        let checkpoints = counters
+            .iter()
+            .enumerate()
+            .map(|(i, &counter)| {
+                let hash = [i as u8 + 1; 32];
+                VideoHashCheckpoint {
+                    index: i as u32,
+                    frame_number: (i as u32 + 1) * 150,
+                    hash: STANDARD.encode(hash),
+                    timestamp: (i + 1) as f64 * 5.0,
+                    assertion: sign_checkpoint(config, &hash, counter),
+                }
+            })
+            .collect::<Vec<_>>();
+        VideoHashChainData {
+            final_hash: STANDARD.encode([0xFF; 32]),
+            chain_length: 450,
+            version: "1.0".to_string(),
+            checkpoint_count: checkpoints.len() as i32,
+            checkpoints,
+        }
+    }
+
+    #[test]
+    fn test_video_assertion_over_final_hash() {
+        let config = test_config();
+        let device = checkpoint_device(); // stored counter 5
+        let final_hash = [0xAB; 32];
+        let final_hash_b64 = STANDARD.encode(final_hash);
+
+        let assertion = sign_checkpoint(&config, &final_hash, 6);
+        let result = verify_video_assertion(
+            &device,
+            Some(&assertion),
+            &final_hash_b64,
+            &config,
+            Uuid::new_v4(),
+        );
+        assert_eq!(result.status, CheckStatus::Pass);
+        assert_eq!(result.new_counter, Some(6));
+
+        // Signed over a different hash
+        let other = STANDARD.encode([0xCD; 32]);
+        let result =
+            verify_video_assertion(&device, Some(&assertion), &other, &config, Uuid::new_v4());
+        assert_eq!(result.status, CheckStatus::Fail);
+        assert_eq!(result.new_counter, None);
+
+        let result =
+            verify_video_assertion(&device, None, &final_hash_b64, &config, Uuid::new_v4());
+        assert_eq!(result.status, CheckStatus::Unavailable);
+    }
+
+    #[test]
+    fn test_checkpoint_assertions_all_valid() {
+        let config = test_config();
+        let device = checkpoint_device(); // stored counter 5
+        let chain = signed_hash_chain(&config, &[6, 7, 8]);
+
+        let attested =
+            verify_hash_only_checkpoint_assertions(&device, &chain, 9, &config, Uuid::new_v4());
+        assert_eq!(attested, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_checkpoint_assertion_counter_not_below_payload() {
+        let config = test_config();
+        let device = checkpoint_device();
+        let chain = signed_hash_chain(&config, &[6, 7, 9]);
+
+        let attested =
+            verify_hash_only_checkpoint_assertions(&device, &chain, 9, &config, Uuid::new_v4());
+        assert_eq!(attested, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_checkpoint_assertion_counter_must_increase() {
+        let config = test_config();
+        let device = checkpoint_device();
+        let chain = signed_hash_chain(&config, &[7, 6, 8]);
+
+        let attested =
+            verify_hash_only_checkpoint_assertions(&device, &chain, 9, &config, Uuid::new_v4());
+        assert_eq!(attested, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_checkpoint_assertion_wrong_hash_rejected() {
+        let config = test_config();
+        let device = checkpoint_device();
+        let mut chain = signed_hash_chain(&config, &[6, 7, 8]);
+        chain.checkpoints[1].hash = STANDARD.encode([0xAB; 32]);
+
+        let attested =
+            verify_hash_only_checkpoint_assertions(&device, &chain, 9, &config, Uuid::new_v4());
+        assert_eq!(attested, vec![0, 2]);
     }
 }
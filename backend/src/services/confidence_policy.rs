@@ -0,0 +1,441 @@
+//! Confidence Policy Profiles
+//!
+//! Integrators differ in what they are willing to trust: a newsroom wants no
+//! unverified device to ever count as Medium, while casual users accept
+//! Android TEE captures. Named profiles express such requirements and are
+//! applied to stored evidence when `/verify/{id}` or `/verify-file` is called
+//! with `?policy=<name>`.
+//!
+//! ## Evaluation
+//! 1. The evidence is scored as usual (the stored `confidence_score`, or
+//!    `EvidencePackage::calculate_score` for older evidence)
+//! 2. Each unmet requirement caps the bucket at the profile's `unmet_cap`:
+//!    - `min_attestation`: hardware attestation passed at this level or above
+//!      (StrongBox and Secure Enclave rank equally)
+//!    - `require_depth_pass`: the depth check passed on a real scene
+//!    - `depth_sources`: the depth analysis ran on an accepted source
+//!      (evidence without a source predates hash-only captures and is treated
+//!      as server analysis)
+//!
+//! Profiles can only lower confidence, never raise it.
+//!
+//! ## Profiles
+//! Profiles are bundled with the binary (`data/confidence_policies.tsv`).
+//! Setting `CONFIDENCE_POLICIES_PATH` loads a different file.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::models::{
+    AttestationLevel, CheckStatus, ConfidenceLevel, ConfidenceScore, EvidencePackage,
+};
+use crate::types::AnalysisSource;
+
+/// Bundled policy profiles
+const BUNDLED_POLICIES: &str = include_str!("../../data/confidence_policies.tsv");
+
+/// Column count of the policies data file
+const POLICY_COLUMNS: usize = 5;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors loading confidence policy profiles
+#[derive(Debug, Error)]
+pub enum ConfidencePolicyError {
+    #[error("Failed to read confidence policies file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid confidence policy on line {0}")]
+    Invalid(usize),
+
+    #[error("Duplicate confidence policy '{0}'")]
+    Duplicate(String),
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Requirements of one named policy profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfidencePolicy {
+    pub name: String,
+    /// Lowest accepted attestation level (Unverified accepts anything)
+    pub min_attestation: AttestationLevel,
+    /// Whether the depth check must have passed
+    pub require_depth_pass: bool,
+    /// Accepted depth analysis sources
+    pub depth_sources: Vec<AnalysisSource>,
+    /// Bucket cap applied when a requirement is unmet
+    pub unmet_cap: ConfidenceLevel,
+}
+
+/// Result of evaluating stored evidence under a policy profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolicyEvaluation {
+    /// Profile name
+    pub policy: String,
+    /// Confidence under the profile
+    pub confidence_score: ConfidenceScore,
+    /// Requirements the evidence does not meet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmet_requirements: Vec<String>,
+}
+
+/// Named confidence policy profiles
+#[derive(Debug, Clone)]
+pub struct ConfidencePolicies {
+    policies: HashMap<String, ConfidencePolicy>,
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+impl ConfidencePolicies {
+    /// Loads profiles from `path`, or the bundled profiles when None
+    pub fn load(path: Option<&str>) -> Result<Self, ConfidencePolicyError> {
+        match path {
+            Some(path) => Self::from_tsv(&std::fs::read_to_string(path)?),
+            None => Self::bundled(),
+        }
+    }
+
+    /// Loads the bundled profiles
+    pub fn bundled() -> Result<Self, ConfidencePolicyError> {
+        Self::from_tsv(BUNDLED_POLICIES)
+    }
+
+    /// Parses tab-separated profiles
+    ///
+    /// Lines starting with `#` and blank lines are skipped. Malformed rows are
+    /// rejected, since a silently dropped profile would fail every request
+    /// naming it.
+    pub fn from_tsv(data: &str) -> Result<Self, ConfidencePolicyError> {
+        let mut policies = HashMap::new();
+        for (index, line) in data.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let policy = parse_row(line).ok_or(ConfidencePolicyError::Invalid(index + 1))?;
+            if policies.contains_key(&policy.name) {
+                return Err(ConfidencePolicyError::Duplicate(policy.name));
+            }
+            policies.insert(policy.name.clone(), policy);
+        }
+        Ok(Self { policies })
+    }
+
+    /// Number of profiles
+    pub fn policy_count(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Looks up a profile by name
+    pub fn get(&self, name: &str) -> Option<&ConfidencePolicy> {
+        self.policies.get(name)
+    }
+}
+
+/// Parses one profile row
+fn parse_row(line: &str) -> Option<ConfidencePolicy> {
+    let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+    if columns.len() != POLICY_COLUMNS || columns[0].is_empty() {
+        return None;
+    }
+
+    let min_attestation = match columns[1] {
+        "unverified" => AttestationLevel::Unverified,
+        "tee" => AttestationLevel::TrustedEnvironment,
+        "strongbox" => AttestationLevel::StrongBox,
+        "secure_enclave" => AttestationLevel::SecureEnclave,
+        _ => return None,
+    };
+    let require_depth_pass = match columns[2] {
+        "yes" => true,
+        "no" => false,
+        _ => return None,
+    };
+    let depth_sources = if columns[3] == "*" {
+        vec![AnalysisSource::Server, AnalysisSource::Device]
+    } else {
+        columns[3]
+            .split(',')
+            .map(|source| match source.trim() {
+                "server" => Some(AnalysisSource::Server),
+                "device" => Some(AnalysisSource::Device),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?
+    };
+    let unmet_cap = match columns[4] {
+        "medium" => ConfidenceLevel::Medium,
+        "low" => ConfidenceLevel::Low,
+        "suspicious" => ConfidenceLevel::Suspicious,
+        _ => return None,
+    };
+
+    Some(ConfidencePolicy {
+        name: columns[0].to_string(),
+        min_attestation,
+        require_depth_pass,
+        depth_sources,
+        unmet_cap,
+    })
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+impl ConfidencePolicy {
+    /// Evaluates evidence under the profile
+    ///
+    /// # Arguments
+    /// * `evidence` - Stored evidence package
+    ///
+    /// # Returns
+    /// PolicyEvaluation with the capped score and any unmet requirements
+    pub fn evaluate(&self, evidence: &EvidencePackage) -> PolicyEvaluation {
+        let unmet = self.unmet_requirements(evidence);
+
+        let mut score = evidence
+            .confidence_score
+            .clone()
+            .unwrap_or_else(|| evidence.calculate_score());
+        for reason in &unmet {
+            score = score.capped(self.unmet_cap, "policy", reason);
+        }
+
+        PolicyEvaluation {
+            policy: self.name.clone(),
+            confidence_score: score,
+            unmet_requirements: unmet,
+        }
+    }
+
+    /// Lists the requirements the evidence does not meet
+    fn unmet_requirements(&self, evidence: &EvidencePackage) -> Vec<String> {
+        let mut unmet = Vec::new();
+
+        let hardware = &evidence.hardware_attestation;
+        if self.min_attestation != AttestationLevel::Unverified {
+            let min = attestation_name(self.min_attestation);
+            if hardware.status != CheckStatus::Pass {
+                unmet.push(format!("{min} attestation required, hardware not verified"));
+            } else if attestation_rank(hardware.level) < attestation_rank(self.min_attestation) {
+                unmet.push(format!(
+                    "{min} attestation required, device has {}",
+                    attestation_name(hardware.level)
+                ));
+            }
+        }
+
+        let depth = &evidence.depth_analysis;
+        if self.require_depth_pass
+            && (depth.status != CheckStatus::Pass || !depth.is_likely_real_scene)
+        {
+            unmet.push("depth check must pass on a real scene".to_string());
+        }
+        if depth.status != CheckStatus::Unavailable {
+            let source = depth.source.unwrap_or(AnalysisSource::Server);
+            if !self.depth_sources.contains(&source) {
+                unmet.push(format!("{source} depth analysis not accepted"));
+            }
+        }
+
+        unmet
+    }
+}
+
+/// Orders attestation levels by strength (hardware-backed keys rank equally)
+fn attestation_rank(level: AttestationLevel) -> u8 {
+    match level {
+        AttestationLevel::Unverified => 0,
+        AttestationLevel::TrustedEnvironment => 1,
+        AttestationLevel::StrongBox | AttestationLevel::SecureEnclave => 2,
+    }
+}
+
+/// Data file name of an attestation level
+fn attestation_name(level: AttestationLevel) -> &'static str {
+    match level {
+        AttestationLevel::Unverified => "unverified",
+        AttestationLevel::TrustedEnvironment => "tee",
+        AttestationLevel::StrongBox => "strongbox",
+        AttestationLevel::SecureEnclave => "secure_enclave",
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DepthAnalysis, HardwareAttestation, MetadataEvidence, ProcessingInfo};
+
+    fn passing_depth(source: AnalysisSource) -> DepthAnalysis {
+        DepthAnalysis {
+            status: CheckStatus::Pass,
+            is_likely_real_scene: true,
+            source: Some(source),
+            ..Default::default()
+        }
+    }
+
+    fn ios_evidence(source: AnalysisSource) -> EvidencePackage {
+        EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            passing_depth(source),
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        )
+    }
+
+    fn android_tee_evidence() -> EvidencePackage {
+        EvidencePackage::for_android(
+            HardwareAttestation::pass(
+                "Samsung Galaxy S24".to_string(),
+                AttestationLevel::TrustedEnvironment,
+            ),
+            DepthAnalysis::unavailable_android(),
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        )
+    }
+
+    #[test]
+    fn test_bundled_policies_load() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+        assert_eq!(policies.policy_count(), 3);
+        let newsroom = policies.get("newsroom").unwrap();
+        assert_eq!(newsroom.min_attestation, AttestationLevel::StrongBox);
+        assert!(newsroom.require_depth_pass);
+        assert_eq!(newsroom.depth_sources, vec![AnalysisSource::Server]);
+        assert_eq!(newsroom.unmet_cap, ConfidenceLevel::Low);
+        assert!(policies.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_from_tsv_rejects_invalid_rows() {
+        assert!(matches!(
+            ConfidencePolicies::from_tsv("# header\nstrict\tsome\tyes\t*\tlow\n"),
+            Err(ConfidencePolicyError::Invalid(2))
+        ));
+        assert!(matches!(
+            ConfidencePolicies::from_tsv("strict\ttee\tyes\tcloud\tlow\n"),
+            Err(ConfidencePolicyError::Invalid(1))
+        ));
+        assert!(matches!(
+            ConfidencePolicies::from_tsv("a\ttee\tno\t*\tlow\na\ttee\tno\t*\tlow\n"),
+            Err(ConfidencePolicyError::Duplicate(name)) if name == "a"
+        ));
+    }
+
+    #[test]
+    fn test_standard_matches_stored_confidence() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+        let evidence = android_tee_evidence();
+
+        let evaluation = policies.get("standard").unwrap().evaluate(&evidence);
+        assert!(evaluation.unmet_requirements.is_empty());
+        assert_eq!(evaluation.confidence_score, evidence.calculate_score());
+    }
+
+    #[test]
+    fn test_casual_accepts_android_tee() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+
+        let evaluation = policies
+            .get("casual")
+            .unwrap()
+            .evaluate(&android_tee_evidence());
+        assert!(evaluation.unmet_requirements.is_empty());
+        assert_eq!(evaluation.confidence_score.level, ConfidenceLevel::Medium);
+    }
+
+    #[test]
+    fn test_newsroom_caps_android_tee_at_low() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+
+        let evaluation = policies
+            .get("newsroom")
+            .unwrap()
+            .evaluate(&android_tee_evidence());
+        assert_eq!(evaluation.confidence_score.level, ConfidenceLevel::Low);
+        assert_eq!(
+            evaluation.unmet_requirements,
+            vec![
+                "strongbox attestation required, device has tee".to_string(),
+                "depth check must pass on a real scene".to_string(),
+            ]
+        );
+        assert_eq!(evaluation.confidence_score.factors[0].check, "policy");
+    }
+
+    #[test]
+    fn test_newsroom_caps_unverified_device_at_low() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::unavailable(
+                "iPhone 15 Pro".to_string(),
+                AttestationLevel::SecureEnclave,
+            ),
+            passing_depth(AnalysisSource::Server),
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+        assert_eq!(evidence.calculate_confidence(), ConfidenceLevel::Medium);
+
+        let evaluation = policies.get("newsroom").unwrap().evaluate(&evidence);
+        assert_eq!(evaluation.confidence_score.level, ConfidenceLevel::Low);
+        assert_eq!(
+            evaluation.unmet_requirements,
+            vec!["strongbox attestation required, hardware not verified".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_newsroom_rejects_device_depth_analysis() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+        let newsroom = policies.get("newsroom").unwrap();
+
+        let server = newsroom.evaluate(&ios_evidence(AnalysisSource::Server));
+        assert!(server.unmet_requirements.is_empty());
+        assert_eq!(server.confidence_score.level, ConfidenceLevel::High);
+
+        let device = newsroom.evaluate(&ios_evidence(AnalysisSource::Device));
+        assert_eq!(
+            device.unmet_requirements,
+            vec!["device depth analysis not accepted".to_string()]
+        );
+        assert_eq!(device.confidence_score.level, ConfidenceLevel::Low);
+    }
+
+    #[test]
+    fn test_policy_never_raises_confidence() {
+        let policies = ConfidencePolicies::bundled().unwrap();
+        let evidence = EvidencePackage::for_ios(
+            HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave),
+            DepthAnalysis {
+                status: CheckStatus::Fail,
+                is_likely_real_scene: false,
+                ..Default::default()
+            },
+            MetadataEvidence::default(),
+            ProcessingInfo::default(),
+        );
+
+        let evaluation = policies.get("newsroom").unwrap().evaluate(&evidence);
+        assert_eq!(
+            evaluation.confidence_score.level,
+            ConfidenceLevel::Suspicious
+        );
+    }
+}
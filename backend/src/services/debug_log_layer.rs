@@ -0,0 +1,473 @@
+//! Backend self-logging into debug_logs
+//!
+//! A `tracing_subscriber::Layer` that copies selected backend events into
+//! `debug_logs` with `source = "backend"`, so
+//! `GET /debug/logs?correlation_id=` returns the iOS, web and backend side of
+//! a request together.
+//!
+//! ## Correlation
+//! Each event takes the `correlation_id` (or, failing that, `request_id`)
+//! field of the event itself or of its nearest enclosing span. The
+//! `http_request` span records the client's `X-Correlation-ID`, falling back
+//! to the request ID. Events outside any request are not recorded.
+//!
+//! ## Redaction
+//! Fields carrying personal data (client IPs, user agents, coordinates; see
+//! `REDACTED_FIELDS`) are stored as `"[redacted]"`, whether they are set on
+//! the event or on an enclosing span. Message text is stored as is.
+//!
+//! ## Delivery
+//! Events are queued on a bounded channel and written in batches through
+//! `debug_logs::insert_batch` by `DebugLogWriter`. When the queue is full,
+//! events are dropped rather than slowing down the request being logged.
+//! The writer's own events (including sqlx) are never recorded.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{Map, Value};
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Instrument, Level, Subscriber};
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+use uuid::Uuid;
+
+use crate::models::{CreateDebugLog, LogLevel, LogSource};
+use crate::services::debug_logs;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Events queued for the writer before new ones are dropped
+const QUEUE_CAPACITY: usize = 10_000;
+
+/// Name of the span the writer runs in; events inside it are skipped
+const WRITER_SPAN: &str = "debug_log_writer";
+
+/// Longest event name stored; longer messages are cut
+const MAX_EVENT_LEN: usize = 200;
+
+/// Field names (or `_`-separated suffixes) whose values are never stored
+const REDACTED_FIELDS: &[&str] = &[
+    "ip",
+    "ip_address",
+    "remote_addr",
+    "forwarded_for",
+    "user_agent",
+    "latitude",
+    "longitude",
+    "lat",
+    "lon",
+    "lng",
+    "altitude",
+    "coordinates",
+    "location",
+    "location_precise",
+];
+
+/// Placeholder stored instead of a redacted value
+const REDACTED: &str = "[redacted]";
+
+// ============================================================================
+// Layer
+// ============================================================================
+
+/// Queues selected backend events for insertion into debug_logs
+pub struct DebugLogLayer {
+    filter: Targets,
+    tx: mpsc::Sender<CreateDebugLog>,
+    dropped: Arc<AtomicU64>,
+}
+
+/// Writes events queued by `DebugLogLayer`
+pub struct DebugLogWriter {
+    rx: mpsc::Receiver<CreateDebugLog>,
+    dropped: Arc<AtomicU64>,
+    max_batch: usize,
+}
+
+impl DebugLogLayer {
+    /// Creates a layer recording events enabled by `filter`, and the writer
+    /// that drains it in batches of at most `max_batch` entries.
+    ///
+    /// The writer can be spawned later (e.g. once the database is up);
+    /// events are queued meanwhile.
+    pub fn new(filter: Targets, max_batch: usize) -> (Self, DebugLogWriter) {
+        let (tx, rx) = mpsc::channel(QUEUE_CAPACITY);
+        let dropped = Arc::new(AtomicU64::new(0));
+        (
+            Self {
+                filter,
+                tx,
+                dropped: dropped.clone(),
+            },
+            DebugLogWriter {
+                rx,
+                dropped,
+                max_batch: max_batch.max(1),
+            },
+        )
+    }
+}
+
+/// Per-span state kept in span extensions
+#[derive(Default)]
+struct SpanContext {
+    /// Correlation ID recorded on this span
+    correlation_id: Option<Uuid>,
+    /// Other fields, merged into the payload of events inside the span
+    fields: Map<String, Value>,
+    /// This is the writer's span
+    is_writer: bool,
+}
+
+impl<S> Layer<S> for DebugLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = JsonVisitor::default();
+        attrs.record(&mut visitor);
+
+        let mut context = SpanContext {
+            is_writer: attrs.metadata().name() == WRITER_SPAN,
+            ..Default::default()
+        };
+        context.correlation_id = visitor.take_correlation_id();
+        context.fields = visitor.fields;
+        span.extensions_mut().insert(context);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = JsonVisitor::default();
+        values.record(&mut visitor);
+
+        let mut extensions = span.extensions_mut();
+        if let Some(context) = extensions.get_mut::<SpanContext>() {
+            if let Some(correlation_id) = visitor.take_correlation_id() {
+                context.correlation_id = Some(correlation_id);
+            }
+            context.fields.extend(visitor.fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if !self
+            .filter
+            .would_enable(metadata.target(), metadata.level())
+        {
+            return;
+        }
+
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+        let mut correlation_id = visitor.take_correlation_id();
+
+        // Innermost span first: its fields and correlation ID win
+        let mut payload = Map::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope {
+                let extensions = span.extensions();
+                let Some(context) = extensions.get::<SpanContext>() else {
+                    continue;
+                };
+                if context.is_writer {
+                    return;
+                }
+                correlation_id = correlation_id.or(context.correlation_id);
+                for (key, value) in &context.fields {
+                    payload.entry(key.clone()).or_insert_with(|| value.clone());
+                }
+            }
+        }
+
+        let Some(correlation_id) = correlation_id else {
+            return;
+        };
+
+        let message = visitor
+            .fields
+            .remove("message")
+            .and_then(|v| v.as_str().map(str::to_string));
+        let device_id = visitor
+            .fields
+            .get("device_id")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok());
+        payload.extend(visitor.fields);
+        payload.insert("target".to_string(), Value::from(metadata.target()));
+
+        let event_name = message.unwrap_or_else(|| metadata.target().to_string());
+        let entry = CreateDebugLog {
+            correlation_id,
+            timestamp: Utc::now(),
+            source: LogSource::Backend,
+            level: log_level(metadata.level()),
+            event: event_name.chars().take(MAX_EVENT_LEN).collect(),
+            payload: Value::Object(payload),
+            device_id,
+            session_id: None,
+        };
+
+        // Never block or log from here; the writer reports drops
+        if self.tx.try_send(entry).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Maps a tracing level onto the debug_logs levels (trace counts as debug)
+fn log_level(level: &Level) -> LogLevel {
+    match *level {
+        Level::ERROR => LogLevel::Error,
+        Level::WARN => LogLevel::Warn,
+        Level::INFO => LogLevel::Info,
+        _ => LogLevel::Debug,
+    }
+}
+
+// ============================================================================
+// Field Visitor
+// ============================================================================
+
+/// Collects event or span fields as JSON values
+#[derive(Default)]
+struct JsonVisitor {
+    fields: Map<String, Value>,
+}
+
+impl JsonVisitor {
+    /// Removes and parses `correlation_id`, else `request_id`
+    fn take_correlation_id(&mut self) -> Option<Uuid> {
+        let parse = |v: Option<Value>| v.and_then(|v| v.as_str().and_then(|s| s.parse().ok()));
+        let correlation_id = parse(self.fields.remove("correlation_id"));
+        let request_id = parse(self.fields.remove("request_id"));
+        correlation_id.or(request_id)
+    }
+
+    /// Stores a field value, redacting personal data
+    fn insert(&mut self, field: &Field, value: impl Into<Value>) {
+        let name = field.name();
+        let value = if is_redacted(name) {
+            Value::from(REDACTED)
+        } else {
+            value.into()
+        };
+        self.fields.insert(name.to_string(), value);
+    }
+}
+
+/// Whether a field is, or ends in `_` plus, a redacted field name
+fn is_redacted(name: &str) -> bool {
+    REDACTED_FIELDS.iter().any(|redacted| {
+        name == *redacted
+            || name
+                .strip_suffix(redacted)
+                .is_some_and(|prefix| prefix.ends_with('_'))
+    })
+}
+
+impl Visit for JsonVisitor {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.insert(field, format!("{value:?}"));
+    }
+}
+
+// ============================================================================
+// Writer
+// ============================================================================
+
+impl DebugLogWriter {
+    /// Spawns the task writing queued events to the database
+    pub fn spawn(self, pool: PgPool) -> JoinHandle<()> {
+        tokio::spawn(self.run(pool).instrument(tracing::info_span!(WRITER_SPAN)))
+    }
+
+    async fn run(mut self, pool: PgPool) {
+        let mut batch = Vec::with_capacity(self.max_batch);
+        while self.rx.recv_many(&mut batch, self.max_batch).await > 0 {
+            let count = batch.len();
+            if let Err(e) = debug_logs::insert_batch(&pool, std::mem::take(&mut batch)).await {
+                tracing::warn!(
+                    count,
+                    error = %e,
+                    "[debug_log_layer] Failed to write backend debug logs"
+                );
+            }
+
+            let dropped = self.dropped.swap(0, Ordering::Relaxed);
+            if dropped > 0 {
+                tracing::warn!(
+                    dropped,
+                    "[debug_log_layer] Queue full, dropped backend debug logs"
+                );
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn capture(filter: &str, f: impl FnOnce()) -> Vec<CreateDebugLog> {
+        let (layer, mut writer) = DebugLogLayer::new(filter.parse().unwrap(), 100);
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, f);
+
+        let mut entries = Vec::new();
+        while let Ok(entry) = writer.rx.try_recv() {
+            entries.push(entry);
+        }
+        entries
+    }
+
+    #[test]
+    fn test_event_takes_correlation_from_request_span() {
+        let request_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let device_id = Uuid::new_v4();
+
+        let entries = capture("realitycam_api=info", || {
+            let span = tracing::info_span!(
+                "http_request",
+                method = "POST",
+                request_id = %request_id,
+                correlation_id = %correlation_id,
+            );
+            let _guard = span.enter();
+            tracing::warn!(device_id = %device_id, attempts = 3, "Upload rejected");
+        });
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.correlation_id, correlation_id);
+        assert_eq!(entry.source, LogSource::Backend);
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.event, "Upload rejected");
+        assert_eq!(entry.device_id, Some(device_id));
+        assert_eq!(entry.payload["method"], "POST");
+        assert_eq!(entry.payload["attempts"], 3);
+        assert!(entry.payload["target"]
+            .as_str()
+            .unwrap()
+            .starts_with("realitycam_api"));
+    }
+
+    #[test]
+    fn test_request_id_used_without_correlation_id() {
+        let request_id = Uuid::new_v4();
+
+        let entries = capture("realitycam_api=info", || {
+            let span = tracing::info_span!("http_request", request_id = %request_id);
+            let _guard = span.enter();
+            tracing::info!("Capture upload completed successfully");
+        });
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].correlation_id, request_id);
+    }
+
+    #[test]
+    fn test_filtered_uncorrelated_and_writer_events_skipped() {
+        let request_id = Uuid::new_v4();
+
+        let entries = capture("realitycam_api=warn", || {
+            let span = tracing::info_span!("http_request", request_id = %request_id);
+            let _guard = span.enter();
+
+            // Below the filter level
+            tracing::info!("Processing capture upload request");
+            // Outside the filtered targets
+            tracing::warn!(target: "sqlx::query", "slow statement");
+            // Inside the writer
+            tracing::info_span!(WRITER_SPAN).in_scope(|| {
+                tracing::warn!("Failed to write backend debug logs");
+            });
+        });
+        assert!(entries.is_empty());
+
+        let entries = capture("realitycam_api=info", || {
+            tracing::error!("Retention sweep failed");
+        });
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_personal_fields_redacted() {
+        let request_id = Uuid::new_v4();
+
+        let entries = capture("realitycam_api=info", || {
+            let span = tracing::info_span!(
+                "http_request",
+                request_id = %request_id,
+                client_ip = "203.0.113.7",
+                user_agent = "RealityCam/1.0",
+            );
+            let _guard = span.enter();
+            tracing::info!(
+                latitude = 37.7749,
+                longitude = -122.4194,
+                location_coarse = "San Francisco",
+                "Location processed"
+            );
+        });
+
+        assert_eq!(entries.len(), 1);
+        let payload = &entries[0].payload;
+        for field in ["client_ip", "user_agent", "latitude", "longitude"] {
+            assert_eq!(payload[field], REDACTED, "{field}");
+        }
+        assert_eq!(payload["location_coarse"], "San Francisco");
+        assert!(!payload.to_string().contains("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_is_redacted_matches_suffixes_only() {
+        assert!(is_redacted("ip"));
+        assert!(is_redacted("client_ip"));
+        assert!(is_redacted("device_latitude"));
+        assert!(!is_redacted("zip"));
+        assert!(!is_redacted("location_coarse"));
+        assert!(!is_redacted("relocation"));
+    }
+}
@@ -3,13 +3,18 @@
 //! Provides functions for inserting, querying, and managing debug logs
 //! with correlation IDs for cross-stack request tracing.
 
-use sqlx::PgPool;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::postgres::PgArguments;
+use sqlx::query::QueryAs;
+use sqlx::{PgPool, Postgres};
 use uuid::Uuid;
 
 use crate::error::ApiError;
 use crate::models::{
-    CreateDebugLog, DebugLog, DebugLogDelete, DebugLogQuery, DebugLogStats, LevelCounts,
-    SourceCounts,
+    CreateDebugLog, DebugLog, DebugLogDelete, DebugLogQuery, DebugLogStats, LevelCounts, LogCursor,
+    QueryLogsResponse, SessionSummary, SessionTimelineResponse, SourceCounts,
 };
 
 // ============================================================================
@@ -68,6 +73,79 @@ pub async fn insert_batch(pool: &PgPool, logs: Vec<CreateDebugLog>) -> Result<us
 // Query Operations
 // ============================================================================
 
+/// Appends the `DebugLogQuery` filters other than `since`, `until` and
+/// `cursor` as AND clauses
+///
+/// Bind the values with `bind_filters`, in the same order.
+fn push_filters(sql: &mut String, query: &DebugLogQuery, param_count: &mut usize) {
+    let mut push = |clause: &str| {
+        *param_count += 1;
+        sql.push_str(&clause.replace("$?", &format!("${param_count}")));
+    };
+
+    if query.correlation_id.is_some() {
+        push(" AND correlation_id = $?");
+    }
+    if query.source.is_some() {
+        push(" AND source = $?");
+    }
+    if query.level.is_some() {
+        push(" AND level = $?");
+    }
+    if query.event.is_some() {
+        push(" AND event ILIKE $?");
+    }
+    if query.device_id.is_some() {
+        push(" AND device_id = $?");
+    }
+    if query.session_id.is_some() {
+        push(" AND session_id = $?");
+    }
+    if query.q.is_some() {
+        push(" AND search_vector @@ websearch_to_tsquery('simple', $?)");
+    }
+    if query.payload.is_some() {
+        push(" AND payload @> $?");
+    }
+}
+
+/// Binds the values for the clauses added by `push_filters`
+///
+/// Expects a validated query (`DebugLogQuery::validate`).
+fn bind_filters<'q>(
+    mut query_builder: QueryAs<'q, Postgres, DebugLog, PgArguments>,
+    query: &'q DebugLogQuery,
+) -> QueryAs<'q, Postgres, DebugLog, PgArguments> {
+    if let Some(correlation_id) = query.correlation_id {
+        query_builder = query_builder.bind(correlation_id);
+    }
+    if let Some(ref source) = query.source {
+        query_builder = query_builder.bind(source);
+    }
+    if let Some(ref level) = query.level {
+        query_builder = query_builder.bind(level);
+    }
+    if let Some(ref event) = query.event {
+        // Use ILIKE for substring match with wildcards
+        query_builder = query_builder.bind(format!("%{event}%"));
+    }
+    if let Some(device_id) = query.device_id {
+        query_builder = query_builder.bind(device_id);
+    }
+    if let Some(session_id) = query.session_id {
+        query_builder = query_builder.bind(session_id);
+    }
+    if let Some(ref q) = query.q {
+        query_builder = query_builder.bind(q);
+    }
+    if query.payload.is_some() {
+        // An invalid filter (unvalidated query) matches nothing
+        let payload = query.payload_filter().ok().flatten();
+        query_builder = query_builder.bind(payload.unwrap_or(serde_json::Value::Null));
+    }
+    query_builder
+}
+
 /// Queries debug logs with filters
 ///
 /// # Arguments
@@ -77,8 +155,52 @@ pub async fn insert_batch(pool: &PgPool, logs: Vec<CreateDebugLog>) -> Result<us
 /// # Returns
 /// * Vector of matching debug log entries
 pub async fn query(pool: &PgPool, query: &DebugLogQuery) -> Result<Vec<DebugLog>, ApiError> {
-    let limit = query.effective_limit() as i64;
+    let logs = fetch(pool, query, query.effective_limit() as i64).await?;
+
+    tracing::debug!(count = logs.len(), "Queried debug logs");
+
+    Ok(logs)
+}
+
+/// Queries one page of debug logs, with the cursor for the next one
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `query` - Query parameters, including `cursor` from the previous page
+///
+/// # Returns
+/// * Matching entries with `has_more` and `next_cursor`
+pub async fn query_page(
+    pool: &PgPool,
+    query: &DebugLogQuery,
+) -> Result<QueryLogsResponse, ApiError> {
+    let limit = query.effective_limit() as usize;
+
+    // One extra row tells whether another page exists
+    let mut logs = fetch(pool, query, limit as i64 + 1).await?;
+    let has_more = logs.len() > limit;
+    logs.truncate(limit);
+
+    let next_cursor = has_more
+        .then(|| logs.last().map(|log| LogCursor::after(log).encode()))
+        .flatten();
+
+    Ok(QueryLogsResponse {
+        count: logs.len(),
+        logs,
+        has_more,
+        next_cursor,
+    })
+}
+
+/// Runs a filtered, keyset-paginated query returning at most `limit` rows
+async fn fetch(
+    pool: &PgPool,
+    query: &DebugLogQuery,
+    limit: i64,
+) -> Result<Vec<DebugLog>, ApiError> {
     let order_desc = !query.is_ascending();
+    let cursor = query.page_cursor().map_err(ApiError::Validation)?;
 
     // Build dynamic query based on filters
     // Using raw SQL for flexibility with optional parameters
@@ -92,33 +214,32 @@ pub async fn query(pool: &PgPool, query: &DebugLogQuery) -> Result<Vec<DebugLog>
     );
 
     let mut param_count = 0;
-
-    if query.correlation_id.is_some() {
-        param_count += 1;
-        sql.push_str(&format!(" AND correlation_id = ${param_count}"));
-    }
-    if query.source.is_some() {
-        param_count += 1;
-        sql.push_str(&format!(" AND source = ${param_count}"));
-    }
-    if query.level.is_some() {
+    push_filters(&mut sql, query, &mut param_count);
+    if query.since.is_some() {
         param_count += 1;
-        sql.push_str(&format!(" AND level = ${param_count}"));
+        sql.push_str(&format!(" AND timestamp >= ${param_count}"));
     }
-    if query.event.is_some() {
+    if query.until.is_some() {
         param_count += 1;
-        sql.push_str(&format!(" AND event ILIKE ${param_count}"));
+        sql.push_str(&format!(" AND timestamp < ${param_count}"));
     }
-    if query.since.is_some() {
-        param_count += 1;
-        sql.push_str(&format!(" AND timestamp >= ${param_count}"));
+
+    // Keyset pagination: continue after the last (timestamp, id) returned
+    if cursor.is_some() {
+        let op = if order_desc { "<" } else { ">" };
+        sql.push_str(&format!(
+            " AND (timestamp, id) {op} (${}, ${})",
+            param_count + 1,
+            param_count + 2
+        ));
+        param_count += 2;
     }
 
-    // Order by timestamp
+    // Order by timestamp, id as tiebreaker for stable pages
     if order_desc {
-        sql.push_str(" ORDER BY timestamp DESC");
+        sql.push_str(" ORDER BY timestamp DESC, id DESC");
     } else {
-        sql.push_str(" ORDER BY timestamp ASC");
+        sql.push_str(" ORDER BY timestamp ASC, id ASC");
     }
 
     // Add limit
@@ -126,32 +247,194 @@ pub async fn query(pool: &PgPool, query: &DebugLogQuery) -> Result<Vec<DebugLog>
     sql.push_str(&format!(" LIMIT ${param_count}"));
 
     // Build and execute query with bindings
-    let mut query_builder = sqlx::query_as::<_, DebugLog>(&sql);
-
-    if let Some(correlation_id) = query.correlation_id {
-        query_builder = query_builder.bind(correlation_id);
+    let mut query_builder = bind_filters(sqlx::query_as::<_, DebugLog>(&sql), query);
+    if let Some(since) = query.since {
+        query_builder = query_builder.bind(since);
     }
-    if let Some(ref source) = query.source {
-        query_builder = query_builder.bind(source);
+    if let Some(until) = query.until {
+        query_builder = query_builder.bind(until);
     }
-    if let Some(ref level) = query.level {
-        query_builder = query_builder.bind(level);
+    if let Some(cursor) = cursor {
+        query_builder = query_builder.bind(cursor.timestamp).bind(cursor.id);
     }
-    if let Some(ref event) = query.event {
-        // Use ILIKE for substring match with wildcards
-        query_builder = query_builder.bind(format!("%{event}%"));
+    query_builder = query_builder.bind(limit);
+
+    query_builder
+        .fetch_all(pool)
+        .await
+        .map_err(ApiError::Database)
+}
+
+// ============================================================================
+// Session Timeline
+// ============================================================================
+
+/// Whole-session aggregates for the timeline summary
+#[derive(sqlx::FromRow)]
+struct SessionTotals {
+    total: i64,
+    requests: i64,
+    started_at: Option<DateTime<Utc>>,
+    ended_at: Option<DateTime<Utc>>,
+    device_ids: Vec<Uuid>,
+}
+
+/// Gets the timeline of one app session: a summary plus a page of entries
+///
+/// Entries are oldest first unless `order=desc`; the other filters and the
+/// cursor apply to the entries only, not to the summary.
+pub async fn session_timeline(
+    pool: &PgPool,
+    session_id: Uuid,
+    query: &DebugLogQuery,
+) -> Result<SessionTimelineResponse, ApiError> {
+    let page_query = DebugLogQuery {
+        session_id: Some(session_id),
+        order: Some(query.order.clone().unwrap_or_else(|| "asc".to_string())),
+        ..query.clone()
+    };
+    let page = query_page(pool, &page_query).await?;
+
+    let totals: SessionTotals = sqlx::query_as(
+        r#"
+        SELECT COUNT(*) AS total,
+               COUNT(DISTINCT correlation_id) AS requests,
+               MIN(timestamp) AS started_at,
+               MAX(timestamp) AS ended_at,
+               COALESCE(array_agg(DISTINCT device_id) FILTER (WHERE device_id IS NOT NULL), '{}')
+                   AS device_ids
+        FROM debug_logs
+        WHERE session_id = $1
+        "#,
+    )
+    .bind(session_id)
+    .fetch_one(pool)
+    .await
+    .map_err(ApiError::Database)?;
+
+    let rows: Vec<(String, String, i64)> = sqlx::query_as(
+        "SELECT source, level, COUNT(*) FROM debug_logs WHERE session_id = $1 GROUP BY source, level",
+    )
+    .bind(session_id)
+    .fetch_all(pool)
+    .await
+    .map_err(ApiError::Database)?;
+
+    let mut by_source = SourceCounts::default();
+    let mut by_level = LevelCounts::default();
+    for (source, level, count) in rows {
+        match source.as_str() {
+            "ios" => by_source.ios += count,
+            "backend" => by_source.backend += count,
+            "web" => by_source.web += count,
+            _ => {}
+        }
+        match level.as_str() {
+            "debug" => by_level.debug += count,
+            "info" => by_level.info += count,
+            "warn" => by_level.warn += count,
+            "error" => by_level.error += count,
+            _ => {}
+        }
     }
-    if let Some(since) = query.since {
-        query_builder = query_builder.bind(since);
+
+    Ok(SessionTimelineResponse {
+        session_id,
+        summary: SessionSummary {
+            total: totals.total,
+            requests: totals.requests,
+            device_ids: totals.device_ids,
+            by_source,
+            by_level,
+            started_at: totals.started_at,
+            ended_at: totals.ended_at,
+        },
+        logs: page.logs,
+        has_more: page.has_more,
+        next_cursor: page.next_cursor,
+    })
+}
+
+// ============================================================================
+// Tail Operations
+// ============================================================================
+
+/// How far back each tail poll looks again, to catch batches whose
+/// transaction started (setting created_at) before the previous poll but
+/// committed after it
+const TAIL_OVERLAP_SECS: i64 = 5;
+
+/// Position of a live tail over debug_logs
+///
+/// Entries are followed by `created_at` (server insert time), so batches
+/// shipped late by clients still show up even with old `timestamp`s.
+#[derive(Debug, Clone)]
+pub struct TailCursor {
+    /// Newest created_at delivered so far
+    after: DateTime<Utc>,
+    /// Entries delivered within the overlap window
+    seen: HashMap<Uuid, DateTime<Utc>>,
+}
+
+impl TailCursor {
+    /// Starts a tail delivering entries created after `start`
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            after: start,
+            seen: HashMap::new(),
+        }
     }
-    query_builder = query_builder.bind(limit);
+}
+
+/// Returns matching entries created since the last call, oldest first
+///
+/// Uses the same filters as `query`; `since`, `until`, `cursor` and `order`
+/// are ignored (the tail cursor decides where it starts). At most `effective_limit` entries
+/// are returned per call; the rest follow on the next one.
+pub async fn tail(
+    pool: &PgPool,
+    query: &DebugLogQuery,
+    cursor: &mut TailCursor,
+) -> Result<Vec<DebugLog>, ApiError> {
+    let window_start = cursor.after - Duration::seconds(TAIL_OVERLAP_SECS);
 
-    let logs = query_builder
+    let mut sql = String::from(
+        r#"
+        SELECT id, correlation_id, timestamp, source, level, event, payload,
+               device_id, session_id, created_at
+        FROM debug_logs
+        WHERE 1=1
+        "#,
+    );
+    let mut param_count = 0;
+    push_filters(&mut sql, query, &mut param_count);
+    sql.push_str(&format!(
+        " AND created_at > ${} AND NOT (id = ANY(${}))",
+        param_count + 1,
+        param_count + 2
+    ));
+    sql.push_str(&format!(
+        " ORDER BY created_at ASC, id ASC LIMIT ${}",
+        param_count + 3
+    ));
+
+    let seen: Vec<Uuid> = cursor.seen.keys().copied().collect();
+    let logs = bind_filters(sqlx::query_as::<_, DebugLog>(&sql), query)
+        .bind(window_start)
+        .bind(&seen)
+        .bind(query.effective_limit() as i64)
         .fetch_all(pool)
         .await
         .map_err(ApiError::Database)?;
 
-    tracing::debug!(count = logs.len(), "Queried debug logs");
+    for log in &logs {
+        cursor.after = cursor.after.max(log.created_at);
+        cursor.seen.insert(log.id, log.created_at);
+    }
+    let window_start = cursor.after - Duration::seconds(TAIL_OVERLAP_SECS);
+    cursor
+        .seen
+        .retain(|_, created_at| *created_at > window_start);
 
     Ok(logs)
 }
@@ -233,6 +516,47 @@ pub async fn delete(pool: &PgPool, params: &DebugLogDelete) -> Result<u64, ApiEr
     Ok(result.rows_affected())
 }
 
+/// Deletes debug logs stored more than `ttl_days` days ago
+///
+/// Uses `created_at` (server insert time), not the client-supplied `timestamp`.
+///
+/// # Returns
+/// * Number of entries deleted
+pub async fn delete_expired(pool: &PgPool, ttl_days: u32) -> Result<u64, ApiError> {
+    let result =
+        sqlx::query("DELETE FROM debug_logs WHERE created_at < NOW() - make_interval(days => $1)")
+            .bind(ttl_days as i32)
+            .execute(pool)
+            .await
+            .map_err(ApiError::Database)?;
+
+    Ok(result.rows_affected())
+}
+
+/// Spawns a background task that deletes expired debug logs every `interval`.
+/// Returns a handle that can be used to abort the task.
+pub fn spawn_cleanup_task(
+    pool: PgPool,
+    ttl_days: u32,
+    interval: std::time::Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            match delete_expired(&pool, ttl_days).await {
+                Ok(0) => {}
+                Ok(deleted) => {
+                    tracing::info!(deleted, ttl_days, "[debug_logs] Deleted expired debug logs");
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "[debug_logs] Failed to delete expired debug logs");
+                }
+            }
+        }
+    })
+}
+
 // ============================================================================
 // Stats Operations
 // ============================================================================
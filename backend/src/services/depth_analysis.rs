@@ -35,26 +35,26 @@ use crate::services::StorageService;
 // ============================================================================
 
 /// Minimum depth variance (std dev) for real scene detection (meters)
-const VARIANCE_THRESHOLD: f64 = 0.5;
+pub(crate) const VARIANCE_THRESHOLD: f64 = 0.5;
 
 /// Minimum depth layers for real scene detection
-const LAYER_THRESHOLD: u32 = 3;
+pub(crate) const LAYER_THRESHOLD: u32 = 3;
 
 /// Minimum edge coherence for real scene detection (0.0-1.0)
 /// NOTE: Lowered from 0.7 for hackathon - real LiDAR often has lower edge coherence
-const COHERENCE_THRESHOLD: f64 = 0.3;
+pub(crate) const COHERENCE_THRESHOLD: f64 = 0.3;
 
 /// Number of histogram bins for layer detection
-const HISTOGRAM_BINS: usize = 50;
+pub(crate) const HISTOGRAM_BINS: usize = 50;
 
 /// Minimum peak prominence as fraction of max bin count
 const PEAK_PROMINENCE_RATIO: f64 = 0.05;
 
 /// Minimum valid depth value (meters) - filter noise
-const MIN_VALID_DEPTH: f32 = 0.1;
+pub(crate) const MIN_VALID_DEPTH: f32 = 0.1;
 
 /// Maximum valid depth value (meters) - filter outliers
-const MAX_VALID_DEPTH: f32 = 20.0;
+pub(crate) const MAX_VALID_DEPTH: f32 = 20.0;
 
 /// Screen detection: max depth range for suspicious uniform surface (meters)
 /// Screens are typically 0.3-0.8m away with <0.1m variation
@@ -715,6 +715,8 @@ fn analyze_depth_map_from_bytes_inner(
         source: None,             // Server-side analysis (set by caller)
         method: None,             // Story 10-5: Set by caller
         unavailable_reason: None, // Story 10-5: Set by caller if needed
+        analysis_trust: None,
+        plausibility_issues: Vec::new(),
     })
 }
 
@@ -730,7 +732,8 @@ fn analyze_depth_map_from_bytes_inner(
 ///
 /// # Arguments
 /// * `storage` - StorageService for S3 access
-/// * `capture_id` - Capture UUID for S3 key generation
+/// * `capture_id` - Capture UUID (for logging)
+/// * `depth_map_key` - Storage key of the depth map
 /// * `dimensions` - Expected (width, height) tuple
 ///
 /// # Returns
@@ -743,6 +746,7 @@ fn analyze_depth_map_from_bytes_inner(
 pub async fn analyze_depth_map(
     storage: &StorageService,
     capture_id: Uuid,
+    depth_map_key: &str,
     dimensions: Option<(u32, u32)>,
 ) -> DepthAnalysis {
     let start = std::time::Instant::now();
@@ -754,7 +758,7 @@ pub async fn analyze_depth_map(
     );
 
     // Try to perform analysis
-    match analyze_depth_map_inner(storage, capture_id, dimensions).await {
+    match analyze_depth_map_inner(storage, depth_map_key, dimensions).await {
         Ok(analysis) => {
             let elapsed = start.elapsed();
             info!(
@@ -785,12 +789,12 @@ pub async fn analyze_depth_map(
 /// Inner analysis function that returns Result for error propagation
 async fn analyze_depth_map_inner(
     storage: &StorageService,
-    capture_id: Uuid,
+    depth_map_key: &str,
     dimensions: Option<(u32, u32)>,
 ) -> Result<DepthAnalysis, DepthAnalysisError> {
     // 1. Download from S3
     let compressed = storage
-        .download_depth_map(capture_id)
+        .download(depth_map_key)
         .await
         .map_err(|e| DepthAnalysisError::S3Download(e.to_string()))?;
 
@@ -881,6 +885,8 @@ async fn analyze_depth_map_inner(
         source: None,             // Server-side analysis (set by caller)
         method: None,             // Story 10-5: Set by caller
         unavailable_reason: None, // Story 10-5: Set by caller if needed
+        analysis_trust: None,
+        plausibility_issues: Vec::new(),
     })
 }
 
@@ -0,0 +1,618 @@
+//! Detection Scoring Policy
+//!
+//! The iOS client sends multi-signal `DetectionResults` (moire, texture,
+//! artifacts, aggregated confidence, cross-validation) with full captures.
+//! This service merges those signals into a `DetectionAssessment` that
+//! `EvidencePackage::calculate_confidence` takes into account.
+//!
+//! ## Policy
+//! 1. **Critical (Suspicious):** a high-severity cross-validation anomaly, a
+//!    failed cross-validation, or a client aggregated level of Suspicious
+//! 2. **Score:** each usable signal is mapped to an authenticity score
+//!    (1.0 = real scene, 0.0 = artificial source):
+//!    - aggregated: `overall_confidence`
+//!    - moire: `1 - confidence` when detected, otherwise 1.0
+//!    - texture: `confidence` for `real_scene`, `1 - confidence` for screens
+//!      and prints (`unknown` is skipped)
+//!    - artifacts: `1 - overall_confidence` when likely artificial, otherwise 1.0
+//!
+//!    Scores are averaged using the weights for the client's algorithm
+//!    version, normalized over the signals present. The cross-validation
+//!    `overall_penalty`, scaled by `penalty_scale`, is then subtracted.
+//! 3. **Caution (caps at Medium):** score below `CAUTION_SCORE`, or the client
+//!    flagged a screen, a print or a failed primary signal
+//! 4. Otherwise **Pass**, or **Inconclusive** when no signal is usable
+//!
+//! ## Weights
+//! Weights are bundled with the binary (`data/detection_weights.tsv`), keyed
+//! by `aggregated_confidence.algorithm_version`. Setting
+//! `DETECTION_WEIGHTS_PATH` loads a different file. The `*` row applies to
+//! versions without their own row.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+use tracing::debug;
+
+use crate::models::{DetectionAssessment, DetectionFactor, DetectionVerdict};
+use crate::types::detection::{
+    AggregatedConfidenceLevel, AnomalySeverity, ArtifactAnalysisStatus, ConfidenceFlag,
+    CrossValidationResult, DetectionResults, MoireAnalysisStatus, TextureClassificationStatus,
+    TextureType, ValidationStatus,
+};
+
+/// Bundled detection weights
+const BUNDLED_WEIGHTS: &str = include_str!("../../data/detection_weights.tsv");
+
+/// Column count of the weights data file
+const WEIGHTS_COLUMNS: usize = 6;
+
+/// Version key of the fallback weights
+pub const FALLBACK_VERSION: &str = "*";
+
+/// Detection score below which confidence is capped at Medium
+pub const CAUTION_SCORE: f64 = 0.5;
+
+/// Client flags that cap confidence at Medium
+const CAUTION_FLAGS: [ConfidenceFlag; 3] = [
+    ConfidenceFlag::ScreenDetected,
+    ConfidenceFlag::PrintDetected,
+    ConfidenceFlag::PrimarySignalFailed,
+];
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors loading detection weights
+#[derive(Debug, Error)]
+pub enum DetectionPolicyError {
+    #[error("Failed to read detection weights file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid detection weights on line {0}")]
+    Invalid(usize),
+
+    #[error("Detection weights have no fallback ({FALLBACK_VERSION}) row")]
+    MissingFallback,
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Signal weights for one detection algorithm version
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectionWeights {
+    pub aggregated: f64,
+    pub moire: f64,
+    pub texture: f64,
+    pub artifacts: f64,
+    /// Multiplier for the cross-validation overall penalty
+    pub penalty_scale: f64,
+}
+
+/// Detection scoring policy with weights per algorithm version
+#[derive(Debug, Clone)]
+pub struct DetectionPolicy {
+    weights: HashMap<String, DetectionWeights>,
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+impl DetectionPolicy {
+    /// Loads weights from `path`, or the bundled weights when None
+    pub fn load(path: Option<&str>) -> Result<Self, DetectionPolicyError> {
+        match path {
+            Some(path) => Self::from_tsv(&std::fs::read_to_string(path)?),
+            None => Self::bundled(),
+        }
+    }
+
+    /// Loads the bundled weights
+    pub fn bundled() -> Result<Self, DetectionPolicyError> {
+        Self::from_tsv(BUNDLED_WEIGHTS)
+    }
+
+    /// Parses tab-separated weights
+    ///
+    /// Lines starting with `#` and blank lines are skipped. Unlike the
+    /// gazetteer, malformed rows are rejected since a silently dropped row
+    /// would change scoring.
+    pub fn from_tsv(data: &str) -> Result<Self, DetectionPolicyError> {
+        let mut weights = HashMap::new();
+        for (index, line) in data.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (version, row) = parse_row(line).ok_or(DetectionPolicyError::Invalid(index + 1))?;
+            weights.insert(version, row);
+        }
+
+        if !weights.contains_key(FALLBACK_VERSION) {
+            return Err(DetectionPolicyError::MissingFallback);
+        }
+        Ok(Self { weights })
+    }
+
+    /// Number of algorithm versions with weights (including the fallback)
+    pub fn version_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Weights for an algorithm version, falling back to `*`
+    ///
+    /// # Returns
+    /// (matched version key, weights)
+    pub fn weights_for(&self, version: Option<&str>) -> (&str, &DetectionWeights) {
+        version
+            .and_then(|v| self.weights.get_key_value(v))
+            .or_else(|| self.weights.get_key_value(FALLBACK_VERSION))
+            .map(|(key, weights)| (key.as_str(), weights))
+            .expect("fallback weights are checked at load")
+    }
+}
+
+/// Parses one weights row
+fn parse_row(line: &str) -> Option<(String, DetectionWeights)> {
+    let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+    if columns.len() != WEIGHTS_COLUMNS || columns[0].is_empty() {
+        return None;
+    }
+    let values: Vec<f64> = columns[1..]
+        .iter()
+        .map(|v| v.parse::<f64>().ok().filter(|v| v.is_finite() && *v >= 0.0))
+        .collect::<Option<_>>()?;
+    if values[..4].iter().sum::<f64>() <= 0.0 {
+        return None;
+    }
+
+    Some((
+        columns[0].to_string(),
+        DetectionWeights {
+            aggregated: values[0],
+            moire: values[1],
+            texture: values[2],
+            artifacts: values[3],
+            penalty_scale: values[4],
+        },
+    ))
+}
+
+// ============================================================================
+// Scoring
+// ============================================================================
+
+impl DetectionPolicy {
+    /// Merges client detection results under the policy
+    ///
+    /// # Arguments
+    /// * `detection` - Detection results sent by the client
+    ///
+    /// # Returns
+    /// DetectionAssessment with the verdict and contributing factors
+    pub fn assess(&self, detection: &DetectionResults) -> DetectionAssessment {
+        let aggregated = detection.aggregated_confidence.as_ref();
+        let (version, weights) = self.weights_for(aggregated.map(|a| a.algorithm_version.as_str()));
+
+        // (signal, weight, score, reason)
+        let mut signals: Vec<(&str, f64, f64, String)> = Vec::new();
+
+        if let Some(agg) = aggregated {
+            signals.push((
+                "aggregated",
+                weights.aggregated,
+                unit(agg.overall_confidence),
+                format!(
+                    "client aggregated confidence {:.2} ({})",
+                    agg.overall_confidence,
+                    agg.confidence_level.to_backend_level()
+                ),
+            ));
+        }
+
+        if let Some(moire) = detection
+            .moire
+            .as_ref()
+            .filter(|m| m.status == MoireAnalysisStatus::Completed)
+        {
+            let (score, reason) = if moire.detected {
+                (
+                    1.0 - unit(moire.confidence),
+                    format!("moire pattern detected ({:.2})", moire.confidence),
+                )
+            } else {
+                (1.0, "no moire pattern".to_string())
+            };
+            signals.push(("moire", weights.moire, score, reason));
+        }
+
+        if let Some(texture) = detection.texture.as_ref().filter(|t| {
+            t.status == TextureClassificationStatus::Success
+                && t.classification != TextureType::Unknown
+        }) {
+            let confidence = unit(texture.confidence);
+            let score = if texture.classification == TextureType::RealScene {
+                confidence
+            } else {
+                1.0 - confidence
+            };
+            signals.push((
+                "texture",
+                weights.texture,
+                score,
+                format!(
+                    "texture classified as {} ({:.2})",
+                    snake_name(&texture.classification),
+                    texture.confidence
+                ),
+            ));
+        }
+
+        if let Some(artifacts) = detection
+            .artifacts
+            .as_ref()
+            .filter(|a| a.status == ArtifactAnalysisStatus::Success)
+        {
+            let (score, reason) = if artifacts.is_likely_artificial {
+                (
+                    1.0 - unit(artifacts.overall_confidence),
+                    format!(
+                        "artifacts indicate an artificial source ({:.2})",
+                        artifacts.overall_confidence
+                    ),
+                )
+            } else {
+                (1.0, "no screen or print artifacts".to_string())
+            };
+            signals.push(("artifacts", weights.artifacts, score, reason));
+        }
+
+        let total_weight: f64 = signals.iter().map(|s| s.1).sum();
+        let mut factors: Vec<DetectionFactor> = signals
+            .into_iter()
+            .filter(|s| total_weight > 0.0 && s.1 > 0.0)
+            .map(|(signal, weight, score, reason)| {
+                let weight = weight / total_weight;
+                DetectionFactor {
+                    signal: signal.to_string(),
+                    score,
+                    weight,
+                    contribution: weight * score,
+                    reason,
+                }
+            })
+            .collect();
+
+        let mut score =
+            (!factors.is_empty()).then(|| factors.iter().map(|f| f.contribution).sum::<f64>());
+
+        let cross_validation = detection
+            .cross_validation
+            .as_ref()
+            .or_else(|| aggregated.and_then(|a| a.cross_validation.as_ref()));
+
+        let mut critical =
+            aggregated.is_some_and(|a| a.confidence_level == AggregatedConfidenceLevel::Suspicious);
+        if let Some(cv) = cross_validation {
+            let penalty = (cv.overall_penalty.max(0.0) as f64) * weights.penalty_scale;
+            if penalty > 0.0 {
+                factors.push(DetectionFactor {
+                    signal: "cross_validation".to_string(),
+                    score: 0.0,
+                    weight: weights.penalty_scale,
+                    contribution: -penalty,
+                    reason: format!("cross-validation penalty {:.2}", cv.overall_penalty),
+                });
+                score = score.map(|s| s - penalty);
+            }
+            critical |= cv.validation_status == ValidationStatus::Fail;
+            critical |= push_critical_anomalies(cv, &mut factors);
+        }
+        let score = score.map(|s| s.clamp(0.0, 1.0));
+
+        let flagged = aggregated.is_some_and(|a| a.flags.iter().any(|f| CAUTION_FLAGS.contains(f)));
+        let verdict = if critical {
+            DetectionVerdict::Critical
+        } else if flagged || score.is_some_and(|s| s < CAUTION_SCORE) {
+            DetectionVerdict::Caution
+        } else if score.is_none() {
+            DetectionVerdict::Inconclusive
+        } else {
+            DetectionVerdict::Pass
+        };
+
+        debug!(
+            algorithm_version = version,
+            score = ?score,
+            verdict = ?verdict,
+            factors = factors.len(),
+            "[detection_scoring] Detection signals merged"
+        );
+
+        DetectionAssessment {
+            algorithm_version: version.to_string(),
+            score,
+            verdict,
+            factors,
+        }
+    }
+}
+
+/// Adds a factor for each high-severity anomaly
+///
+/// # Returns
+/// Whether any high-severity anomaly was found
+fn push_critical_anomalies(cv: &CrossValidationResult, factors: &mut Vec<DetectionFactor>) -> bool {
+    let mut found = false;
+    for anomaly in cv
+        .anomalies
+        .iter()
+        .filter(|a| a.severity == AnomalySeverity::High)
+    {
+        found = true;
+        factors.push(DetectionFactor {
+            signal: "anomaly".to_string(),
+            score: 0.0,
+            weight: 0.0,
+            contribution: 0.0,
+            reason: format!(
+                "critical {} anomaly: {}",
+                snake_name(&anomaly.anomaly_type),
+                anomaly.details
+            ),
+        });
+    }
+    found
+}
+
+/// Clamps a client-reported confidence to 0.0-1.0
+fn unit(value: f32) -> f64 {
+    (value as f64).clamp(0.0, 1.0)
+}
+
+/// Serialized (snake_case) name of a detection enum
+fn snake_name<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::detection::{
+        AggregatedConfidenceResult, AggregationStatus, AnomalyReport, AnomalyType,
+        ConfidenceInterval, MoireAnalysisResult,
+    };
+    use chrono::Utc;
+
+    fn policy() -> DetectionPolicy {
+        DetectionPolicy::bundled().unwrap()
+    }
+
+    fn aggregated(confidence: f32, version: &str) -> AggregatedConfidenceResult {
+        AggregatedConfidenceResult {
+            overall_confidence: confidence,
+            confidence_level: AggregatedConfidenceLevel::High,
+            method_breakdown: HashMap::new(),
+            primary_signal_valid: true,
+            supporting_signals_agree: true,
+            flags: vec![],
+            analysis_time_ms: 1,
+            computed_at: Utc::now(),
+            algorithm_version: version.to_string(),
+            status: AggregationStatus::Success,
+            cross_validation: None,
+            confidence_interval: None,
+        }
+    }
+
+    fn moire(detected: bool, confidence: f32) -> MoireAnalysisResult {
+        MoireAnalysisResult {
+            detected,
+            confidence,
+            peaks: vec![],
+            screen_type: None,
+            analysis_time_ms: 1,
+            algorithm_version: "1.0".to_string(),
+            computed_at: Utc::now(),
+            status: MoireAnalysisStatus::Completed,
+        }
+    }
+
+    fn cross_validation(
+        status: ValidationStatus,
+        penalty: f32,
+        anomalies: Vec<AnomalyReport>,
+    ) -> CrossValidationResult {
+        CrossValidationResult {
+            validation_status: status,
+            pairwise_consistencies: vec![],
+            temporal_consistency: None,
+            confidence_intervals: HashMap::new(),
+            aggregated_interval: ConfidenceInterval {
+                lower_bound: 0.0,
+                point_estimate: 0.5,
+                upper_bound: 1.0,
+            },
+            anomalies,
+            overall_penalty: penalty,
+            analysis_time_ms: 1,
+            algorithm_version: "1.0".to_string(),
+            computed_at: Utc::now(),
+        }
+    }
+
+    fn detection() -> DetectionResults {
+        DetectionResults {
+            moire: Some(moire(false, 0.0)),
+            texture: None,
+            artifacts: None,
+            aggregated_confidence: Some(aggregated(0.9, "1.0")),
+            cross_validation: None,
+            computed_at: Utc::now(),
+            total_processing_time_ms: 10,
+        }
+    }
+
+    #[test]
+    fn test_bundled_weights_load() {
+        let policy = policy();
+        assert!(policy.version_count() >= 2);
+        let (version, _) = policy.weights_for(Some("1.0"));
+        assert_eq!(version, "1.0");
+        let (version, _) = policy.weights_for(Some("9.9-beta"));
+        assert_eq!(version, FALLBACK_VERSION);
+        let (version, _) = policy.weights_for(None);
+        assert_eq!(version, FALLBACK_VERSION);
+    }
+
+    #[test]
+    fn test_weights_rejects_malformed_rows() {
+        assert!(matches!(
+            DetectionPolicy::from_tsv("*\t0.5\t0.5\n"),
+            Err(DetectionPolicyError::Invalid(1))
+        ));
+        assert!(matches!(
+            DetectionPolicy::from_tsv("*\t0.5\t-0.1\t0.2\t0.2\t1.0\n"),
+            Err(DetectionPolicyError::Invalid(1))
+        ));
+        assert!(matches!(
+            DetectionPolicy::from_tsv("1.0\t0.5\t0.2\t0.2\t0.1\t1.0\n"),
+            Err(DetectionPolicyError::MissingFallback)
+        ));
+    }
+
+    #[test]
+    fn test_weights_configurable_per_version() {
+        let policy = DetectionPolicy::from_tsv("*\t1\t0\t0\t0\t1\n2.0\t0\t1\t0\t0\t1\n").unwrap();
+        let mut results = detection();
+        results.aggregated_confidence = Some(aggregated(0.2, "1.0"));
+        results.moire = Some(moire(false, 0.0));
+
+        // Fallback weights only count the aggregated confidence
+        let assessment = policy.assess(&results);
+        assert_eq!(assessment.algorithm_version, "*");
+        assert!((assessment.score.unwrap() - 0.2).abs() < 1e-6);
+        assert_eq!(assessment.verdict, DetectionVerdict::Caution);
+
+        // Version 2.0 weights only count moire
+        results.aggregated_confidence = Some(aggregated(0.2, "2.0"));
+        let assessment = policy.assess(&results);
+        assert_eq!(assessment.algorithm_version, "2.0");
+        assert!((assessment.score.unwrap() - 1.0).abs() < 1e-6);
+        assert_eq!(assessment.verdict, DetectionVerdict::Pass);
+    }
+
+    #[test]
+    fn test_clean_signals_pass() {
+        let assessment = policy().assess(&detection());
+        assert_eq!(assessment.verdict, DetectionVerdict::Pass);
+        assert!(assessment.score.unwrap() > 0.9);
+        let signals: Vec<&str> = assessment
+            .factors
+            .iter()
+            .map(|f| f.signal.as_str())
+            .collect();
+        assert_eq!(signals, vec!["aggregated", "moire"]);
+        let weight_sum: f64 = assessment.factors.iter().map(|f| f.weight).sum();
+        assert!((weight_sum - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_screen_signals_caution() {
+        let mut results = detection();
+        results.moire = Some(moire(true, 0.95));
+        results.aggregated_confidence = Some(aggregated(0.3, "1.0"));
+
+        let assessment = policy().assess(&results);
+        assert_eq!(assessment.verdict, DetectionVerdict::Caution);
+        assert!(assessment
+            .factors
+            .iter()
+            .any(|f| f.reason.contains("moire pattern detected")));
+    }
+
+    #[test]
+    fn test_client_flag_caution() {
+        let mut results = detection();
+        let mut agg = aggregated(0.9, "1.0");
+        agg.flags = vec![ConfidenceFlag::ScreenDetected];
+        results.aggregated_confidence = Some(agg);
+
+        assert_eq!(policy().assess(&results).verdict, DetectionVerdict::Caution);
+    }
+
+    #[test]
+    fn test_penalty_reduces_score() {
+        let mut results = detection();
+        results.cross_validation = Some(cross_validation(ValidationStatus::Warn, 0.6, vec![]));
+
+        let assessment = policy().assess(&results);
+        assert_eq!(assessment.verdict, DetectionVerdict::Caution);
+        let penalty = assessment
+            .factors
+            .iter()
+            .find(|f| f.signal == "cross_validation")
+            .unwrap();
+        assert!((penalty.contribution + 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_critical_anomaly_is_critical() {
+        let mut results = detection();
+        let mut agg = aggregated(0.95, "1.0");
+        agg.cross_validation = Some(cross_validation(
+            ValidationStatus::Warn,
+            0.0,
+            vec![AnomalyReport {
+                anomaly_type: AnomalyType::TooHighAgreement,
+                severity: AnomalySeverity::High,
+                affected_methods: vec!["moire".to_string(), "texture".to_string()],
+                details: "signals agree perfectly".to_string(),
+                confidence_impact: 0.3,
+            }],
+        ));
+        results.aggregated_confidence = Some(agg);
+
+        let assessment = policy().assess(&results);
+        assert_eq!(assessment.verdict, DetectionVerdict::Critical);
+        assert!(assessment
+            .factors
+            .iter()
+            .any(|f| f.reason == "critical too_high_agreement anomaly: signals agree perfectly"));
+    }
+
+    #[test]
+    fn test_failed_cross_validation_is_critical() {
+        let mut results = detection();
+        results.cross_validation = Some(cross_validation(ValidationStatus::Fail, 0.0, vec![]));
+        assert_eq!(
+            policy().assess(&results).verdict,
+            DetectionVerdict::Critical
+        );
+    }
+
+    #[test]
+    fn test_no_usable_signals_inconclusive() {
+        let mut results = detection();
+        results.aggregated_confidence = None;
+        results.moire = Some(MoireAnalysisResult {
+            status: MoireAnalysisStatus::Unavailable,
+            ..moire(false, 0.0)
+        });
+
+        let assessment = policy().assess(&results);
+        assert_eq!(assessment.verdict, DetectionVerdict::Inconclusive);
+        assert!(assessment.score.is_none());
+        assert!(assessment.factors.is_empty());
+    }
+}
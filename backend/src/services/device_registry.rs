@@ -0,0 +1,506 @@
+//! Device Capability Registry
+//!
+//! Maps device hardware identifiers and model names to their depth hardware:
+//! marketing name, LiDAR presence, depth sensor type and expected depth map
+//! resolutions. Replaces the hard-coded iPhone Pro whitelist.
+//!
+//! ## Data
+//! The registry is bundled with the binary (`data/device_capabilities.tsv`).
+//! Setting `DEVICE_CAPABILITIES_PATH` loads a different file instead. At
+//! startup the file is upserted into the `device_capabilities` table and the
+//! registry is reloaded from the table, so rows added directly in the database
+//! for new hardware are honored after a restart.
+//!
+//! ## Matching
+//! A model string matches an entry by:
+//! - Hardware identifier, case-insensitive (`iPhone16,1`, `SM-S928B`); a
+//!   trailing `*` in the registry matches regional variants
+//! - Marketing name, ignoring case, spaces and punctuation, with an optional
+//!   brand prefix (`Apple iPhone 15 Pro`, `iPhone15Pro`)
+
+use sqlx::PgPool;
+use thiserror::Error;
+
+/// Bundled device capability registry
+const BUNDLED_REGISTRY: &str = include_str!("../../data/device_capabilities.tsv");
+
+/// Column count of the registry data file
+const REGISTRY_COLUMNS: usize = 7;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors loading the device capability registry
+#[derive(Debug, Error)]
+pub enum DeviceRegistryError {
+    #[error("Failed to read device capability file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Device capability database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Device capability registry contains no valid entries")]
+    Empty,
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Source of a device's depth data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthSensor {
+    /// Apple LiDAR scanner
+    Lidar,
+    /// Time-of-flight sensor
+    Tof,
+    /// Stereo disparity from a dual camera
+    DualCamera,
+    /// ARCore software depth (depth-from-motion)
+    Arcore,
+    /// No depth source
+    None,
+}
+
+impl DepthSensor {
+    /// Database / data file representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DepthSensor::Lidar => "lidar",
+            DepthSensor::Tof => "tof",
+            DepthSensor::DualCamera => "dual_camera",
+            DepthSensor::Arcore => "arcore",
+            DepthSensor::None => "none",
+        }
+    }
+
+    /// Whether depth comes from an active hardware sensor (LiDAR or ToF)
+    pub fn is_active_sensor(&self) -> bool {
+        matches!(self, DepthSensor::Lidar | DepthSensor::Tof)
+    }
+}
+
+impl std::str::FromStr for DepthSensor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lidar" => Ok(DepthSensor::Lidar),
+            "tof" => Ok(DepthSensor::Tof),
+            "dual_camera" => Ok(DepthSensor::DualCamera),
+            "arcore" => Ok(DepthSensor::Arcore),
+            "none" => Ok(DepthSensor::None),
+            _ => Err(format!("Invalid depth sensor: {s}")),
+        }
+    }
+}
+
+/// Capabilities of a known device model
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceCapability {
+    /// Platform: "ios" or "android"
+    pub platform: String,
+    /// Hardware identifier (e.g., "iPhone16,1", "SM-S928*")
+    pub identifier: String,
+    /// Manufacturer brand (e.g., "Apple", "Samsung")
+    pub brand: String,
+    /// Marketing name (e.g., "iPhone 15 Pro")
+    pub marketing_name: String,
+    /// Whether the device has a LiDAR scanner
+    pub has_lidar: bool,
+    /// Depth data source
+    pub depth_sensor: DepthSensor,
+    /// Expected depth map resolutions (width, height)
+    pub depth_resolutions: Vec<(u32, u32)>,
+}
+
+impl DeviceCapability {
+    /// Builds a capability from raw columns, rejecting inconsistent entries
+    fn from_parts(
+        platform: &str,
+        identifier: &str,
+        brand: &str,
+        marketing_name: &str,
+        has_lidar: bool,
+        depth_sensor: &str,
+        depth_resolutions: &[&str],
+    ) -> Option<Self> {
+        let platform = platform.trim().to_lowercase();
+        let identifier = identifier.trim();
+        let marketing_name = marketing_name.trim();
+        if (platform != "ios" && platform != "android")
+            || identifier.is_empty()
+            || identifier == "*"
+            || marketing_name.is_empty()
+        {
+            return None;
+        }
+
+        let depth_sensor: DepthSensor = depth_sensor.trim().parse().ok()?;
+        // LiDAR presence and sensor type must agree; Android has no LiDAR
+        if has_lidar != (depth_sensor == DepthSensor::Lidar) || (has_lidar && platform != "ios") {
+            return None;
+        }
+
+        let depth_resolutions = depth_resolutions
+            .iter()
+            .map(|r| parse_resolution(r))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            platform,
+            identifier: identifier.to_string(),
+            brand: brand.trim().to_string(),
+            marketing_name: marketing_name.to_string(),
+            has_lidar,
+            depth_sensor,
+            depth_resolutions,
+        })
+    }
+
+    /// Whether `model` refers to this device
+    fn matches(&self, model: &str) -> bool {
+        let model = model.trim();
+        let identifier_match = match self.identifier.strip_suffix('*') {
+            Some(prefix) => model
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+            None => model.eq_ignore_ascii_case(&self.identifier),
+        };
+        if identifier_match {
+            return true;
+        }
+
+        let model = normalize(model);
+        let name = normalize(&self.marketing_name);
+        model == name || model.strip_prefix(&normalize(&self.brand)) == Some(name.as_str())
+    }
+}
+
+/// Database row of the `device_capabilities` table
+#[derive(Debug, sqlx::FromRow)]
+struct DeviceCapabilityRow {
+    platform: String,
+    identifier: String,
+    brand: String,
+    marketing_name: String,
+    has_lidar: bool,
+    depth_sensor: String,
+    depth_resolutions: Vec<String>,
+}
+
+/// Registry of known device capabilities
+#[derive(Debug)]
+pub struct DeviceRegistry {
+    devices: Vec<DeviceCapability>,
+}
+
+impl DeviceRegistry {
+    /// Loads the registry from `path`, or the bundled data when `None`
+    pub fn load(path: Option<&str>) -> Result<Self, DeviceRegistryError> {
+        match path {
+            Some(path) => Self::from_tsv(&std::fs::read_to_string(path)?),
+            None => Self::bundled(),
+        }
+    }
+
+    /// Loads the bundled registry
+    pub fn bundled() -> Result<Self, DeviceRegistryError> {
+        Self::from_tsv(BUNDLED_REGISTRY)
+    }
+
+    /// Parses tab-separated registry data
+    ///
+    /// Lines starting with `#`, blank lines and malformed or inconsistent rows
+    /// are skipped.
+    pub fn from_tsv(data: &str) -> Result<Self, DeviceRegistryError> {
+        let devices: Vec<DeviceCapability> = data
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(parse_row)
+            .collect();
+
+        Self::from_devices(devices)
+    }
+
+    /// Loads the registry from the `device_capabilities` table
+    pub async fn from_db(pool: &PgPool) -> Result<Self, DeviceRegistryError> {
+        let rows = sqlx::query_as::<_, DeviceCapabilityRow>(
+            r#"
+            SELECT platform, identifier, brand, marketing_name, has_lidar,
+                   depth_sensor, depth_resolutions
+            FROM device_capabilities
+            ORDER BY platform, identifier
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let devices = rows
+            .into_iter()
+            .filter_map(|row| {
+                let resolutions: Vec<&str> =
+                    row.depth_resolutions.iter().map(String::as_str).collect();
+                let capability = DeviceCapability::from_parts(
+                    &row.platform,
+                    &row.identifier,
+                    &row.brand,
+                    &row.marketing_name,
+                    row.has_lidar,
+                    &row.depth_sensor,
+                    &resolutions,
+                );
+                if capability.is_none() {
+                    tracing::warn!(
+                        platform = %row.platform,
+                        identifier = %row.identifier,
+                        "[device_registry] Skipping inconsistent device capability row"
+                    );
+                }
+                capability
+            })
+            .collect();
+
+        Self::from_devices(devices)
+    }
+
+    fn from_devices(devices: Vec<DeviceCapability>) -> Result<Self, DeviceRegistryError> {
+        if devices.is_empty() {
+            return Err(DeviceRegistryError::Empty);
+        }
+        Ok(Self { devices })
+    }
+
+    /// Upserts all entries into the `device_capabilities` table
+    pub async fn seed(&self, pool: &PgPool) -> Result<(), DeviceRegistryError> {
+        let mut tx = pool.begin().await?;
+        for device in &self.devices {
+            let resolutions: Vec<String> = device
+                .depth_resolutions
+                .iter()
+                .map(|(w, h)| format!("{w}x{h}"))
+                .collect();
+            sqlx::query(
+                r#"
+                INSERT INTO device_capabilities (
+                    platform, identifier, brand, marketing_name, has_lidar,
+                    depth_sensor, depth_resolutions
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (platform, identifier) DO UPDATE SET
+                    brand = EXCLUDED.brand,
+                    marketing_name = EXCLUDED.marketing_name,
+                    has_lidar = EXCLUDED.has_lidar,
+                    depth_sensor = EXCLUDED.depth_sensor,
+                    depth_resolutions = EXCLUDED.depth_resolutions,
+                    updated_at = NOW()
+                "#,
+            )
+            .bind(&device.platform)
+            .bind(&device.identifier)
+            .bind(&device.brand)
+            .bind(&device.marketing_name)
+            .bind(device.has_lidar)
+            .bind(device.depth_sensor.as_str())
+            .bind(&resolutions)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Number of devices in the registry
+    pub fn device_count(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Finds the capability entry for a model string on any platform
+    pub fn lookup(&self, model: &str) -> Option<&DeviceCapability> {
+        self.devices.iter().find(|device| device.matches(model))
+    }
+
+    /// Finds the capability entry for a model string on a specific platform
+    pub fn lookup_for_platform(&self, platform: &str, model: &str) -> Option<&DeviceCapability> {
+        self.devices
+            .iter()
+            .filter(|device| device.platform.eq_ignore_ascii_case(platform))
+            .find(|device| device.matches(model))
+    }
+
+    /// Whether two model strings refer to the same device on `platform`
+    ///
+    /// Equal after normalization, or both resolve to the same registry entry
+    /// (e.g., "Galaxy S24 Ultra" and "SM-S928B").
+    pub fn same_model(&self, platform: &str, a: &str, b: &str) -> bool {
+        if !normalize(a).is_empty() && normalize(a) == normalize(b) {
+            return true;
+        }
+        match (
+            self.lookup_for_platform(platform, a),
+            self.lookup_for_platform(platform, b),
+        ) {
+            (Some(a), Some(b)) => a.identifier == b.identifier,
+            _ => false,
+        }
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a registry data row
+fn parse_row(line: &str) -> Option<DeviceCapability> {
+    let columns: Vec<&str> = line.split('\t').collect();
+    if columns.len() != REGISTRY_COLUMNS {
+        return None;
+    }
+
+    let has_lidar: bool = columns[4].trim().parse().ok()?;
+    let resolutions = columns[6].trim();
+    let resolutions: Vec<&str> = if resolutions == "-" || resolutions.is_empty() {
+        Vec::new()
+    } else {
+        resolutions.split(',').collect()
+    };
+
+    DeviceCapability::from_parts(
+        columns[0],
+        columns[1],
+        columns[2],
+        columns[3],
+        has_lidar,
+        columns[5],
+        &resolutions,
+    )
+}
+
+/// Parses a "WxH" resolution
+fn parse_resolution(s: &str) -> Option<(u32, u32)> {
+    let (width, height) = s.trim().split_once('x')?;
+    let width: u32 = width.parse().ok()?;
+    let height: u32 = height.parse().ok()?;
+    (width > 0 && height > 0).then_some((width, height))
+}
+
+/// Lowercases and strips everything but ASCII letters and digits
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_registry_loads() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        assert!(registry.device_count() > 40);
+    }
+
+    #[test]
+    fn test_lookup_by_hardware_identifier() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let device = registry.lookup("iPhone16,1").unwrap();
+        assert_eq!(device.marketing_name, "iPhone 15 Pro");
+        assert!(device.has_lidar);
+        assert_eq!(device.depth_sensor, DepthSensor::Lidar);
+        assert!(device.depth_resolutions.contains(&(256, 192)));
+    }
+
+    #[test]
+    fn test_lookup_by_marketing_name_variants() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        for model in [
+            "iPhone 15 Pro",
+            "IPHONE 15 PRO",
+            "iPhone15Pro",
+            "Apple iPhone 15 Pro",
+        ] {
+            let device = registry.lookup(model).unwrap();
+            assert_eq!(device.identifier, "iPhone16,1", "{model}");
+        }
+        // Pro Max must not resolve to Pro
+        assert_eq!(
+            registry.lookup("iPhone 15 Pro Max").unwrap().identifier,
+            "iPhone16,2"
+        );
+    }
+
+    #[test]
+    fn test_lookup_non_lidar_iphone() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let device = registry.lookup("iPhone 15").unwrap();
+        assert!(!device.has_lidar);
+        assert_eq!(device.depth_sensor, DepthSensor::DualCamera);
+    }
+
+    #[test]
+    fn test_lookup_android_wildcard_identifier() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        let device = registry.lookup("SM-S928U1").unwrap();
+        assert_eq!(device.marketing_name, "Galaxy S24 Ultra");
+        assert_eq!(
+            registry
+                .lookup("Samsung Galaxy S24 Ultra")
+                .unwrap()
+                .identifier,
+            "SM-S928*"
+        );
+        assert_eq!(
+            registry.lookup("sm-g988b").unwrap().depth_sensor,
+            DepthSensor::Tof
+        );
+    }
+
+    #[test]
+    fn test_lookup_for_platform() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        assert!(registry
+            .lookup_for_platform("android", "Pixel 8 Pro")
+            .is_some());
+        assert!(registry.lookup_for_platform("ios", "Pixel 8 Pro").is_none());
+        assert!(registry.lookup("Nokia 3310").is_none());
+    }
+
+    #[test]
+    fn test_same_model() {
+        let registry = DeviceRegistry::bundled().unwrap();
+        assert!(registry.same_model("android", "Galaxy S24 Ultra", "SM-S928B"));
+        assert!(registry.same_model("ios", "iPhone 15 Pro", "iPhone16,1"));
+        assert!(registry.same_model("android", "Pixel 10", "pixel 10"));
+        assert!(!registry.same_model("ios", "iPhone 15 Pro", "iPhone 15 Pro Max"));
+        assert!(!registry.same_model("android", "Pixel 8 Pro", "SM-S928B"));
+        assert!(!registry.same_model("ios", "", ""));
+    }
+
+    #[test]
+    fn test_inconsistent_rows_skipped() {
+        let data = "\
+ios\tiPhone99,1\tApple\tBad Lidar\ttrue\tdual_camera\t256x192
+android\tX1\tAcme\tAndroid Lidar\ttrue\tlidar\t256x192
+ios\tiPhone99,2\tApple\tBad Resolution\tfalse\tnone\t256by192
+ios\tiPhone99,3\tApple\tGood\tfalse\tnone\t-
+";
+        let registry = DeviceRegistry::from_tsv(data).unwrap();
+        assert_eq!(registry.device_count(), 1);
+        assert!(registry.lookup("Good").is_some());
+    }
+
+    #[test]
+    fn test_empty_registry_rejected() {
+        assert!(matches!(
+            DeviceRegistry::from_tsv("# only comments\n\nbad row\n"),
+            Err(DeviceRegistryError::Empty)
+        ));
+    }
+}
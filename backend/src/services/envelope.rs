@@ -0,0 +1,611 @@
+//! Envelope Encryption at Rest
+//!
+//! Each capture gets its own random AES-256-GCM data key. The data key
+//! encrypts the capture's precise location, depth map and original media,
+//! and is itself stored wrapped (encrypted) by a master key. Master keys are
+//! held by a `KeyWrapper`; the shipped implementation is a local key file
+//! (`MASTER_KEY_FILE`), and a KMS client can implement the same trait.
+//!
+//! ## Sealed Format
+//! Sealed blobs are self-describing, so anything holding the master keys can
+//! open them without a database lookup:
+//!
+//! ```text
+//! "RCENC1" | key_id_len: u8 | key_id | wrapped_len: u16 BE | wrapped data key
+//!          | nonce (12 bytes) | ciphertext + GCM tag
+//! ```
+//!
+//! JSON values (e.g. `location_precise`) are sealed into
+//! `{"$envelope": "<base64 sealed blob>"}` so they still fit a JSONB column.
+//! Unsealed inputs pass through `open` unchanged, so data written before
+//! encryption was enabled stays readable.
+//!
+//! ## Rotation
+//! Rotation re-wraps data keys under the active master key; the encrypted
+//! payload is unchanged (see `key_rotation`).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rand::{rngs::OsRng, RngCore};
+use serde_json::Value;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Length of data keys and master keys (AES-256)
+pub const KEY_LEN: usize = 32;
+
+/// AES-GCM nonce length
+const NONCE_LEN: usize = 12;
+
+/// Prefix identifying a sealed blob (format version 1)
+const SEALED_MAGIC: &[u8; 6] = b"RCENC1";
+
+/// Field holding a sealed blob inside a JSON value
+pub const JSON_ENVELOPE_FIELD: &str = "$envelope";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors from key loading, wrapping and sealing
+#[derive(Debug, Error)]
+pub enum EnvelopeError {
+    #[error("Failed to read master key file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid master key file entry on line {0}")]
+    InvalidKeyFile(usize),
+
+    #[error("Duplicate master key ID: {0}")]
+    DuplicateKey(String),
+
+    #[error("Master key file contains no keys")]
+    NoKeys,
+
+    #[error("Unknown master key ID: {0}")]
+    UnknownKey(String),
+
+    #[error("Malformed encrypted envelope")]
+    Malformed,
+
+    #[error("Decryption failed (wrong key or tampered data)")]
+    Decrypt,
+
+    #[error("Key service error: {0}")]
+    KeyService(String),
+}
+
+// ============================================================================
+// Master Keys
+// ============================================================================
+
+/// Data key encrypted under a master key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedKey {
+    /// ID of the master key that wrapped it
+    pub key_id: String,
+    /// Encrypted data key (opaque to everything but the `KeyWrapper`)
+    pub ciphertext: Vec<u8>,
+}
+
+/// Holder of master keys (local key file or a KMS)
+///
+/// Mirrors a KMS Encrypt/Decrypt API: master keys never leave the wrapper,
+/// only data keys are passed in and out.
+#[async_trait]
+pub trait KeyWrapper: Send + Sync {
+    /// Short provider name for logs ("local", "kms")
+    fn name(&self) -> &'static str;
+
+    /// ID of the master key new data keys are wrapped with
+    fn active_key_id(&self) -> &str;
+
+    /// Wraps a data key under the active master key
+    async fn wrap(&self, data_key: &[u8; KEY_LEN]) -> Result<WrappedKey, EnvelopeError>;
+
+    /// Unwraps a data key with the master key named in `wrapped`
+    async fn unwrap(&self, wrapped: &WrappedKey)
+        -> Result<Zeroizing<[u8; KEY_LEN]>, EnvelopeError>;
+}
+
+/// Master keys read from a local key file
+///
+/// The file has one `key_id<TAB>base64 key` line per key; `#` starts a
+/// comment. The last key is active. Older keys stay in the file until
+/// `rotate-keys` has re-wrapped everything under the new one.
+pub struct LocalKeyring {
+    keys: HashMap<String, Zeroizing<[u8; KEY_LEN]>>,
+    active: String,
+}
+
+impl LocalKeyring {
+    /// Loads the keyring from `path`
+    pub fn load(path: &str) -> Result<Self, EnvelopeError> {
+        Self::from_tsv(&fs::read_to_string(path)?)
+    }
+
+    /// Parses key file contents
+    pub fn from_tsv(contents: &str) -> Result<Self, EnvelopeError> {
+        let mut keys = HashMap::new();
+        let mut active = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key_id, encoded) = line
+                .split_once('\t')
+                .ok_or(EnvelopeError::InvalidKeyFile(line_number))?;
+            let key_id = key_id.trim();
+            let bytes = STANDARD
+                .decode(encoded.trim())
+                .map_err(|_| EnvelopeError::InvalidKeyFile(line_number))?;
+            let key: [u8; KEY_LEN] = bytes
+                .try_into()
+                .map_err(|_| EnvelopeError::InvalidKeyFile(line_number))?;
+            if key_id.is_empty() || key_id.len() > u8::MAX as usize {
+                return Err(EnvelopeError::InvalidKeyFile(line_number));
+            }
+
+            if keys
+                .insert(key_id.to_string(), Zeroizing::new(key))
+                .is_some()
+            {
+                return Err(EnvelopeError::DuplicateKey(key_id.to_string()));
+            }
+            active = Some(key_id.to_string());
+        }
+
+        let active = active.ok_or(EnvelopeError::NoKeys)?;
+        Ok(Self { keys, active })
+    }
+
+    /// Creates a keyring of random keys; the last ID is active
+    #[cfg(test)]
+    pub fn generate(key_ids: &[&str]) -> Self {
+        let keys = key_ids
+            .iter()
+            .map(|id| (id.to_string(), Zeroizing::new(random_key())))
+            .collect();
+        Self {
+            keys,
+            active: key_ids.last().expect("at least one key").to_string(),
+        }
+    }
+
+    /// Number of master keys loaded
+    pub fn key_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn key(&self, key_id: &str) -> Result<&[u8; KEY_LEN], EnvelopeError> {
+        self.keys
+            .get(key_id)
+            .map(|k| &**k)
+            .ok_or_else(|| EnvelopeError::UnknownKey(key_id.to_string()))
+    }
+}
+
+#[async_trait]
+impl KeyWrapper for LocalKeyring {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    fn active_key_id(&self) -> &str {
+        &self.active
+    }
+
+    async fn wrap(&self, data_key: &[u8; KEY_LEN]) -> Result<WrappedKey, EnvelopeError> {
+        // The key ID is authenticated so a wrapped key cannot be relabelled
+        let ciphertext = encrypt(self.key(&self.active)?, data_key, self.active.as_bytes());
+        Ok(WrappedKey {
+            key_id: self.active.clone(),
+            ciphertext,
+        })
+    }
+
+    async fn unwrap(
+        &self,
+        wrapped: &WrappedKey,
+    ) -> Result<Zeroizing<[u8; KEY_LEN]>, EnvelopeError> {
+        let plaintext = Zeroizing::new(decrypt(
+            self.key(&wrapped.key_id)?,
+            &wrapped.ciphertext,
+            wrapped.key_id.as_bytes(),
+        )?);
+        let key: [u8; KEY_LEN] = plaintext
+            .as_slice()
+            .try_into()
+            .map_err(|_| EnvelopeError::Malformed)?;
+        Ok(Zeroizing::new(key))
+    }
+}
+
+// ============================================================================
+// Data Keys
+// ============================================================================
+
+/// Per-capture data key, held in plaintext only while the capture is written
+pub struct DataKey {
+    plaintext: Zeroizing<[u8; KEY_LEN]>,
+    wrapped: WrappedKey,
+}
+
+impl DataKey {
+    /// ID of the master key this data key is wrapped with
+    pub fn key_id(&self) -> &str {
+        &self.wrapped.key_id
+    }
+
+    /// Encrypts `plaintext` into a self-describing sealed blob
+    pub fn seal(&self, plaintext: &[u8]) -> Vec<u8> {
+        let sealed = encrypt(&self.plaintext, plaintext, SEALED_MAGIC);
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        encode_sealed(&self.wrapped, nonce, ciphertext)
+    }
+
+    /// Encrypts a JSON value into `{"$envelope": ...}`
+    pub fn seal_json(&self, value: &Value) -> Value {
+        let bytes = serde_json::to_vec(value).unwrap_or_default();
+        serde_json::json!({ JSON_ENVELOPE_FIELD: STANDARD.encode(self.seal(&bytes)) })
+    }
+}
+
+impl fmt::Debug for DataKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataKey")
+            .field("key_id", &self.wrapped.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// Envelope
+// ============================================================================
+
+/// Creates data keys and opens sealed blobs using a `KeyWrapper`
+#[derive(Clone)]
+pub struct Envelope {
+    wrapper: Arc<dyn KeyWrapper>,
+}
+
+impl Envelope {
+    /// Creates an envelope over a master key holder
+    pub fn new(wrapper: Arc<dyn KeyWrapper>) -> Self {
+        Self { wrapper }
+    }
+
+    /// Name of the master key provider
+    pub fn provider_name(&self) -> &'static str {
+        self.wrapper.name()
+    }
+
+    /// ID of the master key new data keys are wrapped with
+    pub fn active_key_id(&self) -> &str {
+        self.wrapper.active_key_id()
+    }
+
+    /// Generates a fresh data key for one capture
+    pub async fn generate_data_key(&self) -> Result<DataKey, EnvelopeError> {
+        let plaintext = Zeroizing::new(random_key());
+        let wrapped = self.wrapper.wrap(&plaintext).await?;
+        Ok(DataKey { plaintext, wrapped })
+    }
+
+    /// True if `bytes` is a sealed blob
+    pub fn is_sealed(bytes: &[u8]) -> bool {
+        bytes.starts_with(SEALED_MAGIC)
+    }
+
+    /// Decrypts a sealed blob; unsealed bytes are returned unchanged
+    pub async fn open(&self, bytes: Vec<u8>) -> Result<Vec<u8>, EnvelopeError> {
+        if !Self::is_sealed(&bytes) {
+            return Ok(bytes);
+        }
+        let sealed = decode_sealed(&bytes)?;
+        let data_key = self.wrapper.unwrap(&sealed.wrapped).await?;
+
+        let mut combined = Vec::with_capacity(NONCE_LEN + sealed.ciphertext.len());
+        combined.extend_from_slice(sealed.nonce);
+        combined.extend_from_slice(sealed.ciphertext);
+        decrypt(&data_key, &combined, SEALED_MAGIC)
+    }
+
+    /// Decrypts a sealed JSON value; other values are returned unchanged
+    pub async fn open_json(&self, value: Value) -> Result<Value, EnvelopeError> {
+        let Some(sealed) = sealed_json_blob(&value)? else {
+            return Ok(value);
+        };
+        let bytes = self.open(sealed).await?;
+        serde_json::from_slice(&bytes).map_err(|_| EnvelopeError::Malformed)
+    }
+
+    /// Re-wraps a sealed blob's data key under the active master key
+    ///
+    /// Returns None when `bytes` is not sealed or already uses the active
+    /// key. The payload ciphertext is reused as is.
+    pub async fn rewrap(&self, bytes: &[u8]) -> Result<Option<Vec<u8>>, EnvelopeError> {
+        if !Self::is_sealed(bytes) {
+            return Ok(None);
+        }
+        let sealed = decode_sealed(bytes)?;
+        if sealed.wrapped.key_id == self.active_key_id() {
+            return Ok(None);
+        }
+
+        let data_key = self.wrapper.unwrap(&sealed.wrapped).await?;
+        let wrapped = self.wrapper.wrap(&data_key).await?;
+        Ok(Some(encode_sealed(
+            &wrapped,
+            sealed.nonce,
+            sealed.ciphertext,
+        )))
+    }
+
+    /// `rewrap` for a sealed JSON value
+    pub async fn rewrap_json(&self, value: &Value) -> Result<Option<Value>, EnvelopeError> {
+        let Some(sealed) = sealed_json_blob(value)? else {
+            return Ok(None);
+        };
+        Ok(self
+            .rewrap(&sealed)
+            .await?
+            .map(|bytes| serde_json::json!({ JSON_ENVELOPE_FIELD: STANDARD.encode(bytes) })))
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parts of a sealed blob
+struct Sealed<'a> {
+    wrapped: WrappedKey,
+    nonce: &'a [u8],
+    ciphertext: &'a [u8],
+}
+
+fn encode_sealed(wrapped: &WrappedKey, nonce: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let key_id = wrapped.key_id.as_bytes();
+    let mut out = Vec::with_capacity(
+        SEALED_MAGIC.len()
+            + 3
+            + key_id.len()
+            + wrapped.ciphertext.len()
+            + nonce.len()
+            + ciphertext.len(),
+    );
+    out.extend_from_slice(SEALED_MAGIC);
+    out.push(key_id.len() as u8);
+    out.extend_from_slice(key_id);
+    out.extend_from_slice(&(wrapped.ciphertext.len() as u16).to_be_bytes());
+    out.extend_from_slice(&wrapped.ciphertext);
+    out.extend_from_slice(nonce);
+    out.extend_from_slice(ciphertext);
+    out
+}
+
+fn decode_sealed(bytes: &[u8]) -> Result<Sealed<'_>, EnvelopeError> {
+    let rest = bytes
+        .strip_prefix(SEALED_MAGIC.as_slice())
+        .ok_or(EnvelopeError::Malformed)?;
+
+    let (&key_id_len, rest) = rest.split_first().ok_or(EnvelopeError::Malformed)?;
+    let (key_id, rest) = split_checked(rest, key_id_len as usize)?;
+    let (wrapped_len, rest) = split_checked(rest, 2)?;
+    let wrapped_len = u16::from_be_bytes([wrapped_len[0], wrapped_len[1]]) as usize;
+    let (wrapped, rest) = split_checked(rest, wrapped_len)?;
+    let (nonce, ciphertext) = split_checked(rest, NONCE_LEN)?;
+
+    Ok(Sealed {
+        wrapped: WrappedKey {
+            key_id: String::from_utf8(key_id.to_vec()).map_err(|_| EnvelopeError::Malformed)?,
+            ciphertext: wrapped.to_vec(),
+        },
+        nonce,
+        ciphertext,
+    })
+}
+
+fn split_checked(bytes: &[u8], at: usize) -> Result<(&[u8], &[u8]), EnvelopeError> {
+    if bytes.len() < at {
+        return Err(EnvelopeError::Malformed);
+    }
+    Ok(bytes.split_at(at))
+}
+
+/// Extracts the sealed blob from `{"$envelope": ...}`, or None for plain JSON
+fn sealed_json_blob(value: &Value) -> Result<Option<Vec<u8>>, EnvelopeError> {
+    let Some(encoded) = value
+        .as_object()
+        .filter(|map| map.len() == 1)
+        .and_then(|map| map.get(JSON_ENVELOPE_FIELD))
+    else {
+        return Ok(None);
+    };
+    let encoded = encoded.as_str().ok_or(EnvelopeError::Malformed)?;
+    STANDARD
+        .decode(encoded)
+        .map(Some)
+        .map_err(|_| EnvelopeError::Malformed)
+}
+
+fn random_key() -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+    key
+}
+
+/// AES-256-GCM encrypt with a random nonce; returns nonce || ciphertext
+fn encrypt(key: &[u8; KEY_LEN], plaintext: &[u8], aad: &[u8]) -> Vec<u8> {
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let ciphertext = Aes256Gcm::new(key.into())
+        .encrypt(
+            &Nonce::from(nonce),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .expect("AES-GCM encryption of in-memory data cannot fail");
+
+    let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    out
+}
+
+/// Inverse of `encrypt`
+fn decrypt(key: &[u8; KEY_LEN], sealed: &[u8], aad: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+    let (nonce, ciphertext) = split_checked(sealed, NONCE_LEN)?;
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().map_err(|_| EnvelopeError::Malformed)?;
+    Aes256Gcm::new(key.into())
+        .decrypt(
+            &Nonce::from(nonce),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .map_err(|_| EnvelopeError::Decrypt)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn envelope(key_ids: &[&str]) -> Envelope {
+        Envelope::new(Arc::new(LocalKeyring::generate(key_ids)))
+    }
+
+    #[test]
+    fn test_keyring_from_tsv() {
+        let key = STANDARD.encode([7u8; KEY_LEN]);
+        let keyring = LocalKeyring::from_tsv(&format!(
+            "# master keys\n2025-01\t{key}\n\n2025-06\t{key}\n"
+        ))
+        .unwrap();
+        assert_eq!(keyring.key_count(), 2);
+        assert_eq!(keyring.active_key_id(), "2025-06");
+
+        assert!(matches!(
+            LocalKeyring::from_tsv("# nothing\n"),
+            Err(EnvelopeError::NoKeys)
+        ));
+        assert!(matches!(
+            LocalKeyring::from_tsv(&format!("k1\t{}\n", STANDARD.encode([1u8; 16]))),
+            Err(EnvelopeError::InvalidKeyFile(1))
+        ));
+        assert!(matches!(
+            LocalKeyring::from_tsv(&format!("k1\t{key}\nk1\t{key}\n")),
+            Err(EnvelopeError::DuplicateKey(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_seal_open_round_trip() {
+        let envelope = envelope(&["k1"]);
+        let data_key = envelope.generate_data_key().await.unwrap();
+        assert_eq!(data_key.key_id(), "k1");
+
+        let sealed = data_key.seal(b"photo bytes");
+        assert!(Envelope::is_sealed(&sealed));
+        assert!(!sealed.windows(11).any(|w| w == b"photo bytes"));
+        assert_eq!(envelope.open(sealed).await.unwrap(), b"photo bytes");
+
+        // Data written before encryption was enabled passes through
+        assert_eq!(envelope.open(b"legacy".to_vec()).await.unwrap(), b"legacy");
+    }
+
+    #[tokio::test]
+    async fn test_open_rejects_tampering_and_unknown_keys() {
+        let envelope = envelope(&["k1"]);
+        let data_key = envelope.generate_data_key().await.unwrap();
+
+        let mut sealed = data_key.seal(b"depth map");
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(matches!(
+            envelope.open(sealed).await,
+            Err(EnvelopeError::Decrypt)
+        ));
+
+        let other = self::envelope(&["k2"]);
+        assert!(matches!(
+            other.open(data_key.seal(b"depth map")).await,
+            Err(EnvelopeError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            envelope.open(SEALED_MAGIC.to_vec()).await,
+            Err(EnvelopeError::Malformed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_json_round_trip() {
+        let envelope = envelope(&["k1"]);
+        let data_key = envelope.generate_data_key().await.unwrap();
+        let location = json!({"latitude": 37.77, "longitude": -122.42, "accuracy": 5.0});
+
+        let sealed = data_key.seal_json(&location);
+        assert!(sealed.get("latitude").is_none());
+        assert_eq!(envelope.open_json(sealed).await.unwrap(), location);
+        assert_eq!(
+            envelope.open_json(location.clone()).await.unwrap(),
+            location
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rewrap_moves_to_active_key() {
+        let keyring = Arc::new(LocalKeyring::generate(&["old"]));
+        let old = Envelope::new(keyring.clone());
+        let data_key = old.generate_data_key().await.unwrap();
+        let sealed = data_key.seal(b"video");
+        assert_eq!(old.rewrap(&sealed).await.unwrap(), None);
+
+        // New keyring with the old key kept and a new active key
+        let rotated = Envelope::new(Arc::new(LocalKeyring {
+            keys: [
+                ("old".to_string(), keyring.keys["old"].clone()),
+                ("new".to_string(), Zeroizing::new(random_key())),
+            ]
+            .into_iter()
+            .collect(),
+            active: "new".to_string(),
+        }));
+        let rewrapped = rotated.rewrap(&sealed).await.unwrap().unwrap();
+        assert_eq!(decode_sealed(&rewrapped).unwrap().wrapped.key_id, "new");
+        assert_eq!(rotated.open(rewrapped.clone()).await.unwrap(), b"video");
+        assert_eq!(rotated.rewrap(&rewrapped).await.unwrap(), None);
+
+        let location = data_key.seal_json(&json!({"latitude": 1.0}));
+        let rewrapped = rotated.rewrap_json(&location).await.unwrap().unwrap();
+        assert_eq!(
+            rotated.open_json(rewrapped).await.unwrap(),
+            json!({"latitude": 1.0})
+        );
+    }
+}
@@ -0,0 +1,329 @@
+//! Offline Reverse Geocoder
+//!
+//! Turns coarse coordinates into human-readable place labels such as
+//! "San Francisco, CA, US" without any network calls.
+//!
+//! ## Data
+//! A compact GeoNames-style city list is bundled with the binary
+//! (`data/gazetteer.tsv`). Setting `GAZETTEER_PATH` loads a different file at
+//! startup instead; both the compact 6-column format and the full 19-column
+//! GeoNames dump format (`cities15000.txt` etc.) are accepted.
+//!
+//! ## Spatial Index
+//! Places are bucketed into a 1° x 1° grid. A lookup scans only the cells that
+//! can contain a place within `MAX_LABEL_DISTANCE_KM` of the query point.
+//!
+//! ## Privacy
+//! Callers must geocode the already-generalized (published) location, never
+//! the precise one, so the label reveals no more than `location_coarse`.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+/// Bundled compact gazetteer
+const BUNDLED_GAZETTEER: &str = include_str!("../../data/gazetteer.tsv");
+
+/// Maximum distance from the nearest place for a label to be assigned (km)
+pub const MAX_LABEL_DISTANCE_KM: f64 = 50.0;
+
+/// Mean Earth radius for haversine distances (km)
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Approximate kilometers per degree of latitude
+const KM_PER_DEGREE: f64 = 111.32;
+
+/// Column count of the compact bundled format
+const COMPACT_COLUMNS: usize = 6;
+
+/// Minimum column count of a GeoNames cities dump row
+const GEONAMES_COLUMNS: usize = 15;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors loading a gazetteer
+#[derive(Debug, Error)]
+pub enum GazetteerError {
+    #[error("Failed to read gazetteer file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Gazetteer contains no valid places")]
+    Empty,
+}
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// A populated place in the gazetteer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    /// Place name (e.g., "San Francisco")
+    pub name: String,
+    /// Latitude of the place center
+    pub latitude: f64,
+    /// Longitude of the place center
+    pub longitude: f64,
+    /// First-level administrative code (e.g., "CA"), if alphabetic
+    pub admin1: Option<String>,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+}
+
+impl Place {
+    /// Formats the place as "Name, ADMIN1, CC" (admin1 omitted when absent)
+    pub fn label(&self) -> String {
+        match &self.admin1 {
+            Some(admin1) => format!("{}, {admin1}, {}", self.name, self.country_code),
+            None => format!("{}, {}", self.name, self.country_code),
+        }
+    }
+}
+
+/// Offline reverse geocoder with a grid spatial index
+#[derive(Debug)]
+pub struct Gazetteer {
+    places: Vec<Place>,
+    grid: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl Gazetteer {
+    /// Loads the gazetteer from `path`, or the bundled data when `None`
+    pub fn load(path: Option<&str>) -> Result<Self, GazetteerError> {
+        match path {
+            Some(path) => Self::from_tsv(&std::fs::read_to_string(path)?),
+            None => Self::bundled(),
+        }
+    }
+
+    /// Loads the bundled gazetteer
+    pub fn bundled() -> Result<Self, GazetteerError> {
+        Self::from_tsv(BUNDLED_GAZETTEER)
+    }
+
+    /// Parses tab-separated gazetteer data
+    ///
+    /// Lines starting with `#`, blank lines and malformed rows are skipped.
+    pub fn from_tsv(data: &str) -> Result<Self, GazetteerError> {
+        let places: Vec<Place> = data
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .filter_map(parse_row)
+            .collect();
+
+        if places.is_empty() {
+            return Err(GazetteerError::Empty);
+        }
+
+        let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (index, place) in places.iter().enumerate() {
+            grid.entry(grid_cell(place.latitude, place.longitude))
+                .or_default()
+                .push(index);
+        }
+
+        Ok(Self { places, grid })
+    }
+
+    /// Number of places in the gazetteer
+    pub fn place_count(&self) -> usize {
+        self.places.len()
+    }
+
+    /// Returns the nearest place within `max_distance_km`
+    pub fn nearest(&self, latitude: f64, longitude: f64, max_distance_km: f64) -> Option<&Place> {
+        if !latitude.is_finite() || !longitude.is_finite() {
+            return None;
+        }
+
+        let (cell_lat, cell_lng) = grid_cell(latitude, longitude);
+        let lat_span = (max_distance_km / KM_PER_DEGREE).ceil() as i32;
+        // Longitude degrees shrink toward the poles; scan the full ring there
+        let cos_lat = latitude.to_radians().cos().abs();
+        let lng_span = if cos_lat < 0.01 {
+            180
+        } else {
+            ((max_distance_km / (KM_PER_DEGREE * cos_lat)).ceil() as i32).min(180)
+        };
+
+        let mut best: Option<(f64, &Place)> = None;
+        for dlat in -lat_span..=lat_span {
+            for dlng in -lng_span..=lng_span {
+                let lng_cell = (cell_lng + dlng + 180).rem_euclid(360) - 180;
+                let Some(indices) = self.grid.get(&(cell_lat + dlat, lng_cell)) else {
+                    continue;
+                };
+                for &index in indices {
+                    let place = &self.places[index];
+                    let distance =
+                        haversine_km(latitude, longitude, place.latitude, place.longitude);
+                    if distance <= max_distance_km
+                        && best.is_none_or(|(best_distance, _)| distance < best_distance)
+                    {
+                        best = Some((distance, place));
+                    }
+                }
+            }
+        }
+
+        best.map(|(_, place)| place)
+    }
+
+    /// Returns the label of the nearest place within `MAX_LABEL_DISTANCE_KM`
+    pub fn label(&self, latitude: f64, longitude: f64) -> Option<String> {
+        self.nearest(latitude, longitude, MAX_LABEL_DISTANCE_KM)
+            .map(Place::label)
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Parses a compact or GeoNames dump row
+fn parse_row(line: &str) -> Option<Place> {
+    let columns: Vec<&str> = line.split('\t').collect();
+
+    let (name, lat, lng, admin1, country) = if columns.len() >= GEONAMES_COLUMNS {
+        // geonameid, name, asciiname, alternatenames, latitude, longitude,
+        // feature class, feature code, country code, cc2, admin1 code, ...
+        (columns[1], columns[4], columns[5], columns[10], columns[8])
+    } else if columns.len() == COMPACT_COLUMNS {
+        // name, latitude, longitude, admin1, country code, population
+        (columns[0], columns[1], columns[2], columns[3], columns[4])
+    } else {
+        return None;
+    };
+
+    let latitude: f64 = lat.trim().parse().ok()?;
+    let longitude: f64 = lng.trim().parse().ok()?;
+    let name = name.trim();
+    let country_code = country.trim();
+    if name.is_empty()
+        || country_code.is_empty()
+        || !(-90.0..=90.0).contains(&latitude)
+        || !(-180.0..=180.0).contains(&longitude)
+    {
+        return None;
+    }
+
+    // GeoNames uses numeric admin1 codes outside a few countries; those are
+    // meaningless in a label
+    let admin1 = admin1.trim();
+    let admin1 = (!admin1.is_empty() && admin1.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| admin1.to_string());
+
+    Some(Place {
+        name: name.to_string(),
+        latitude,
+        longitude,
+        admin1,
+        country_code: country_code.to_string(),
+    })
+}
+
+/// 1° grid cell containing a coordinate
+fn grid_cell(latitude: f64, longitude: f64) -> (i32, i32) {
+    let lng_cell = (longitude.floor() as i32 + 180).rem_euclid(360) - 180;
+    (latitude.floor() as i32, lng_cell)
+}
+
+/// Great-circle distance in kilometers
+fn haversine_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let dlat = (lat2 - lat1).to_radians();
+    let dlng = (lng2 - lng1).to_radians();
+    let a = (dlat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (dlng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_gazetteer_loads() {
+        let gazetteer = Gazetteer::bundled().unwrap();
+        assert!(gazetteer.place_count() > 100);
+    }
+
+    #[test]
+    fn test_label_san_francisco_coarse() {
+        let gazetteer = Gazetteer::bundled().unwrap();
+        assert_eq!(
+            gazetteer.label(37.78, -122.42).as_deref(),
+            Some("San Francisco, CA, US")
+        );
+    }
+
+    #[test]
+    fn test_label_without_admin1() {
+        let gazetteer = Gazetteer::bundled().unwrap();
+        assert_eq!(gazetteer.label(51.51, -0.13).as_deref(), Some("London, GB"));
+        assert_eq!(
+            gazetteer.label(-33.87, 151.21).as_deref(),
+            Some("Sydney, NSW, AU")
+        );
+    }
+
+    #[test]
+    fn test_label_none_when_remote() {
+        let gazetteer = Gazetteer::bundled().unwrap();
+        // Middle of the Pacific
+        assert!(gazetteer.label(0.0, -150.0).is_none());
+        assert!(gazetteer.label(f64::NAN, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_nearest_across_grid_cells() {
+        let gazetteer = Gazetteer::from_tsv("Edge\t0.99\t0.99\t\tXX\t1\n").unwrap();
+        // Query point sits in a neighboring grid cell
+        let place = gazetteer.nearest(1.05, 1.05, 50.0).unwrap();
+        assert_eq!(place.name, "Edge");
+    }
+
+    #[test]
+    fn test_nearest_across_antimeridian() {
+        let gazetteer = Gazetteer::from_tsv("East\t-17.0\t179.9\t\tFJ\t1\n").unwrap();
+        let place = gazetteer.nearest(-17.0, -179.9, 50.0).unwrap();
+        assert_eq!(place.name, "East");
+    }
+
+    #[test]
+    fn test_parse_geonames_dump_row() {
+        let row = "5391959\tSan Francisco\tSan Francisco\tSF\t37.77493\t-122.41942\tP\tPPLA2\tUS\t\tCA\t075\t\t\t864816\t16\t28\tAmerica/Los_Angeles\t2022-01-01";
+        let gazetteer = Gazetteer::from_tsv(row).unwrap();
+        assert_eq!(
+            gazetteer.label(37.77, -122.42).as_deref(),
+            Some("San Francisco, CA, US")
+        );
+    }
+
+    #[test]
+    fn test_numeric_admin1_omitted() {
+        let row = "2988507\tParis\tParis\t\t48.85341\t2.3488\tP\tPPLC\tFR\t\t11\t75\t751\t75056\t2138551\t\t42\tEurope/Paris\t2022-01-01";
+        let gazetteer = Gazetteer::from_tsv(row).unwrap();
+        assert_eq!(gazetteer.label(48.85, 2.35).as_deref(), Some("Paris, FR"));
+    }
+
+    #[test]
+    fn test_empty_gazetteer_rejected() {
+        assert!(matches!(
+            Gazetteer::from_tsv("# only comments\n\nbad row\n"),
+            Err(GazetteerError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_haversine_known_distance() {
+        // San Francisco to Los Angeles ~559 km
+        let d = haversine_km(37.77493, -122.41942, 34.05223, -118.24368);
+        assert!((d - 559.0).abs() < 5.0);
+    }
+}
@@ -26,24 +26,41 @@
 //! - **Consistency:** Final hash matches last frame hash
 //! - **Attestation:** Attested hash matches submitted final hash
 //! - **Metadata:** Frame count and duration are consistent
+//! - **Derivation (v2 only):** Each `H_n = SHA256(H_{n-1} || d_n)` using the
+//!   client-submitted frame digests `d_n`, starting from a genesis value
+//!   bound to the device's registration challenge
 //!
 //! ## What We Cannot Verify
 //!
 //! - **Content:** Cannot recompute hashes from video frames (compression)
-//! - **Chain derivation:** Cannot verify each hash chains from previous
-//!   (would require raw frame data)
+//! - **Chain derivation (v1):** Legacy chains carry no frame digests, so
+//!   each hash cannot be checked against its predecessor
 //!
-//! The chain derivation is trusted through attestation - if Apple DCAppAttest
-//! signed this hash, it came from our iOS app processing real camera data.
+//! For v1 chains the derivation is trusted through attestation - if Apple
+//! DCAppAttest signed this hash, it came from our iOS app processing real
+//! camera data. For v2 chains a derivation break pinpoints `broken_at_frame`.
 
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use sha2::{Digest, Sha256};
 use tracing::{debug, info, instrument, warn};
 
 use crate::types::hash_chain_verification::{
-    HashChainData, HashChainVerification, HashChainVerificationError, HashChainVerifierConfig,
-    VideoAttestation,
+    HashChainData, HashChainSummary, HashChainVerification, HashChainVerificationError,
+    HashChainVerifierConfig, VideoAttestation, HASH_CHAIN_GENESIS_DOMAIN, HASH_CHAIN_VERSION_V1,
+    HASH_CHAIN_VERSION_V2,
 };
 
+/// Derive the v2 chain genesis `H_0` from a device registration challenge.
+///
+/// `H_0 = SHA256(HASH_CHAIN_GENESIS_DOMAIN || challenge)`. The iOS app computes
+/// the same value after registration so the first frame hash is bound to it.
+pub fn compute_genesis(challenge: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(HASH_CHAIN_GENESIS_DOMAIN);
+    hasher.update(challenge);
+    hasher.finalize().into()
+}
+
 // ============================================================================
 // Service
 // ============================================================================
@@ -94,6 +111,7 @@ impl HashChainVerifier {
     ///
     /// 1. Validate chain is not empty and within size limits
     /// 2. Validate all hashes are properly formatted (base64 SHA256)
+    ///    (v2 chains: recompute every link from the frame digests)
     /// 3. Verify checkpoints are at correct positions with matching hashes
     /// 4. Verify final hash matches last frame hash
     /// 5. Verify attestation hash matches submitted final hash
@@ -104,16 +122,32 @@ impl HashChainVerifier {
     /// On verification failure, returns a result with status=Fail and
     /// failure_reason set. Does not panic or return errors - always
     /// returns a usable HashChainVerification for the evidence package.
-    #[instrument(skip(self, chain_data, attestation), fields(frames = chain_data.frame_count()))]
     pub fn verify(
         &self,
         chain_data: &HashChainData,
         attestation: &VideoAttestation,
+    ) -> HashChainVerification {
+        self.verify_with_genesis(chain_data, attestation, None)
+    }
+
+    /// Verify hash chain structure, attestation and (for v2 chains) derivation.
+    ///
+    /// `genesis` is the device's registration-bound `H_0` (see [`compute_genesis`]).
+    /// Without it, a v2 chain is still checked link-by-link from frame 2 onward,
+    /// but the first frame cannot be tied to the device and `genesis_bound`
+    /// stays false.
+    #[instrument(skip(self, chain_data, attestation, genesis), fields(frames = chain_data.frame_count()))]
+    pub fn verify_with_genesis(
+        &self,
+        chain_data: &HashChainData,
+        attestation: &VideoAttestation,
+        genesis: Option<&[u8; 32]>,
     ) -> HashChainVerification {
         info!(
-            "Starting hash chain verification: {} frames, {} checkpoints",
+            "Starting hash chain verification: {} frames, {} checkpoints, version {}",
             chain_data.frame_count(),
-            chain_data.checkpoints.len()
+            chain_data.checkpoints.len(),
+            chain_data.version
         );
 
         // Step 1: Validate chain size
@@ -128,6 +162,20 @@ impl HashChainVerifier {
             return HashChainVerification::fail(e.to_string());
         }
 
+        // Step 2b: Recompute chain derivation (v2 only)
+        let genesis_bound = match self.verify_derivation(chain_data, genesis) {
+            Ok(bound) => bound,
+            Err(e) => {
+                warn!("Chain derivation verification failed: {}", e);
+                return match e {
+                    HashChainVerificationError::DerivationBroken { frame } => {
+                        HashChainVerification::broken(frame, e.to_string())
+                    }
+                    _ => HashChainVerification::fail(e.to_string()),
+                };
+            }
+        };
+
         // Step 3: Verify checkpoints
         if let Err(e) = self.verify_checkpoints(chain_data) {
             warn!("Checkpoint verification failed: {}", e);
@@ -169,15 +217,108 @@ impl HashChainVerifier {
                     "Hash chain verification PARTIAL: {} frames, checkpoint {}",
                     frame_count, checkpoint_idx
                 );
-                return HashChainVerification::partial(frame_count, duration_ms, checkpoint_idx);
+                let mut result =
+                    HashChainVerification::partial(frame_count, duration_ms, checkpoint_idx);
+                result.derivation_verified = chain_data.is_v2();
+                result.genesis_bound = genesis_bound;
+                return result;
             }
         }
 
         info!(
-            "Hash chain verification PASSED: {} frames, {}ms duration",
-            frame_count, duration_ms
+            "Hash chain verification PASSED: {} frames, {}ms duration, derivation_verified={}",
+            frame_count,
+            duration_ms,
+            chain_data.is_v2()
+        );
+        let mut result = HashChainVerification::success(frame_count, duration_ms);
+        result.derivation_verified = chain_data.is_v2();
+        result.genesis_bound = genesis_bound;
+        result
+    }
+
+    /// Verify a hash chain summary without media or per-frame hashes.
+    ///
+    /// Used for hash-only (privacy mode) videos. The final hash is bound by
+    /// the payload assertion; each checkpoint must sit at its expected frame
+    /// and carry a verified assertion of its own (see
+    /// `HashChainSummary::attested_checkpoints`).
+    ///
+    /// Without frames the chain itself cannot be walked, so
+    /// `chain_structure_valid` is only set when every checkpoint expected for
+    /// the claimed length was submitted and attested. `derivation_verified`
+    /// is always false in this mode.
+    #[instrument(skip(self, summary, attestation), fields(frames = summary.chain_length))]
+    pub fn verify_summary(
+        &self,
+        summary: &HashChainSummary,
+        attestation: &VideoAttestation,
+    ) -> HashChainVerification {
+        info!(
+            "Starting no-media hash chain verification: {} frames, {} checkpoints",
+            summary.chain_length,
+            summary.checkpoints.len()
+        );
+
+        // Step 1: Validate claimed chain length
+        if summary.chain_length == 0 {
+            warn!("Summary chain length is zero");
+            return HashChainVerification::fail(HashChainVerificationError::EmptyChain.to_string());
+        }
+        if summary.chain_length as usize > self.config.max_frames {
+            let e = HashChainVerificationError::ChainTooLong {
+                count: summary.chain_length as usize,
+                max: self.config.max_frames,
+            };
+            warn!("Summary chain size validation failed: {}", e);
+            return HashChainVerification::fail(e.to_string());
+        }
+
+        // Step 2: Validate hash formats
+        let formats =
+            self.validate_single_hash(&summary.final_hash, summary.chain_length as usize)
+                .and_then(|_| {
+                    summary.checkpoints.iter().try_for_each(|c| {
+                        self.validate_single_hash(&c.hash, c.frame_number as usize)
+                    })
+                });
+        if let Err(e) = formats {
+            warn!("Summary hash format validation failed: {}", e);
+            return HashChainVerification::fail(e.to_string());
+        }
+
+        // Step 3: Verify checkpoint positions and assertions
+        if let Err(e) = self.verify_summary_checkpoints(summary) {
+            warn!("Summary checkpoint verification failed: {}", e);
+            return HashChainVerification::fail(e.to_string());
+        }
+
+        // Step 4: Verify attestation matches the submitted final hash
+        if attestation.final_hash != summary.final_hash {
+            let e = HashChainVerificationError::AttestationHashMismatch;
+            warn!("Summary attestation verification failed: {}", e);
+            return HashChainVerification::fail(e.to_string());
+        }
+
+        // Step 5: Verify frame count consistency
+        if let Err(e) = self.check_frame_count(summary.chain_length, attestation) {
+            warn!("Summary frame count verification failed: {}", e);
+            return HashChainVerification::fail(e.to_string());
+        }
+
+        let frame_count = summary.chain_length;
+        let duration_ms = (frame_count as f64 / self.config.expected_fps as f64 * 1000.0) as u32;
+        let all_checkpoints_present = summary.checkpoints.len()
+            == summary.expected_checkpoint_count(self.config.checkpoint_interval);
+
+        info!(
+            "No-media hash chain verification PASSED: {} frames, checkpoints complete={}",
+            frame_count, all_checkpoints_present
         );
-        HashChainVerification::success(frame_count, duration_ms)
+        let mut result = HashChainVerification::success(frame_count, duration_ms);
+        result.chain_structure_valid = all_checkpoints_present;
+        result.checkpoints_valid = all_checkpoints_present;
+        result
     }
 
     /// Validate chain is not empty and within size limits.
@@ -226,12 +367,84 @@ impl HashChainVerifier {
         Ok(())
     }
 
+    /// Recompute `H_n = SHA256(H_{n-1} || d_n)` for every frame of a v2 chain.
+    ///
+    /// Returns whether the first link was anchored to the genesis value.
+    /// v1 chains are accepted unchanged (derivation is trusted via attestation)
+    /// only when the device has no genesis; a device with one must submit v2,
+    /// so it cannot downgrade to escape the binding.
+    fn verify_derivation(
+        &self,
+        chain_data: &HashChainData,
+        genesis: Option<&[u8; 32]>,
+    ) -> Result<bool, HashChainVerificationError> {
+        match chain_data.version {
+            HASH_CHAIN_VERSION_V1 if genesis.is_some() => {
+                return Err(HashChainVerificationError::UnboundChainVersion(
+                    chain_data.version,
+                ))
+            }
+            HASH_CHAIN_VERSION_V1 => return Ok(false),
+            HASH_CHAIN_VERSION_V2 => {}
+            other => return Err(HashChainVerificationError::UnsupportedVersion(other)),
+        }
+
+        if chain_data.frame_digests.len() != chain_data.frame_hashes.len() {
+            return Err(HashChainVerificationError::FrameDigestCountMismatch {
+                expected: chain_data.frame_hashes.len(),
+                actual: chain_data.frame_digests.len(),
+            });
+        }
+
+        let mut previous: Option<[u8; 32]> = genesis.copied();
+        for (i, (hash, digest)) in chain_data
+            .frame_hashes
+            .iter()
+            .zip(&chain_data.frame_digests)
+            .enumerate()
+        {
+            let current = self.decode_hash(hash, i)?;
+            let digest = self.decode_hash(digest, i)?;
+
+            if let Some(prev) = previous {
+                let mut hasher = Sha256::new();
+                hasher.update(prev);
+                hasher.update(digest);
+                let expected: [u8; 32] = hasher.finalize().into();
+
+                if expected != current {
+                    return Err(HashChainVerificationError::DerivationBroken {
+                        frame: i as u32 + 1,
+                    });
+                }
+            }
+
+            previous = Some(current);
+        }
+
+        debug!(
+            "Chain derivation verified for {} frames (genesis bound: {})",
+            chain_data.frame_hashes.len(),
+            genesis.is_some()
+        );
+        Ok(genesis.is_some())
+    }
+
     /// Validate a single hash is properly formatted.
     fn validate_single_hash(
         &self,
         hash: &str,
         index: usize,
     ) -> Result<(), HashChainVerificationError> {
+        self.decode_hash(hash, index).map(|_| ())
+    }
+
+    /// Decode a base64 SHA256 hash into its 32 raw bytes.
+    fn decode_hash(
+        &self,
+        hash: &str,
+        index: usize,
+    ) -> Result<[u8; 32], HashChainVerificationError> {
         // Decode from base64
         let decoded =
             BASE64
@@ -242,14 +455,13 @@ impl HashChainVerifier {
                 })?;
 
         // Verify length (SHA256 = 32 bytes)
-        if decoded.len() != 32 {
-            return Err(HashChainVerificationError::HashLengthMismatch {
+        decoded
+            .as_slice()
+            .try_into()
+            .map_err(|_| HashChainVerificationError::HashLengthMismatch {
                 index,
                 actual: decoded.len(),
-            });
-        }
-
-        Ok(())
+            })
     }
 
     /// Verify checkpoints are at correct positions with matching hashes.
@@ -353,14 +565,68 @@ impl HashChainVerifier {
         Ok(())
     }
 
+    /// Verify summary checkpoints are sequential, within the chain and attested.
+    ///
+    /// Fewer checkpoints than the chain length implies is tolerated (the
+    /// result reports `checkpoints_valid = false`); a gap in the sequence is not.
+    fn verify_summary_checkpoints(
+        &self,
+        summary: &HashChainSummary,
+    ) -> Result<(), HashChainVerificationError> {
+        for (position, checkpoint) in summary.checkpoints.iter().enumerate() {
+            if checkpoint.index != position as u32 {
+                return Err(HashChainVerificationError::MissingCheckpoint {
+                    index: position as u32,
+                });
+            }
+
+            let expected_frame = (checkpoint.index + 1) * self.config.checkpoint_interval;
+            if checkpoint.frame_number != expected_frame {
+                return Err(HashChainVerificationError::CheckpointWrongPosition {
+                    index: checkpoint.index,
+                    expected: expected_frame,
+                    actual: checkpoint.frame_number,
+                });
+            }
+
+            if checkpoint.frame_number > summary.chain_length {
+                return Err(HashChainVerificationError::CheckpointBeyondChain {
+                    index: checkpoint.index,
+                    frame: checkpoint.frame_number,
+                    chain_length: summary.chain_length,
+                });
+            }
+
+            if !summary.attested_checkpoints.contains(&checkpoint.index) {
+                return Err(HashChainVerificationError::CheckpointNotAttested {
+                    index: checkpoint.index,
+                });
+            }
+
+            debug!(
+                "Summary checkpoint {} at frame {} verified",
+                checkpoint.index, checkpoint.frame_number
+            );
+        }
+
+        Ok(())
+    }
+
     /// Verify frame count matches attestation claim.
     fn verify_frame_count(
         &self,
         chain_data: &HashChainData,
         attestation: &VideoAttestation,
     ) -> Result<(), HashChainVerificationError> {
-        let chain_count = chain_data.frame_hashes.len() as u32;
+        self.check_frame_count(chain_data.frame_hashes.len() as u32, attestation)
+    }
 
+    /// Compare a chain frame count with the attested frame count.
+    fn check_frame_count(
+        &self,
+        chain_count: u32,
+        attestation: &VideoAttestation,
+    ) -> Result<(), HashChainVerificationError> {
         // Allow some tolerance for frame count (dropped frames possible)
         let diff = (chain_count as i32 - attestation.frame_count as i32).unsigned_abs();
         let tolerance = 5; // Allow up to 5 frames difference
@@ -434,6 +700,34 @@ mod tests {
             frame_hashes,
             checkpoints: vec![],
             final_hash,
+            version: HASH_CHAIN_VERSION_V1,
+            frame_digests: vec![],
+        }
+    }
+
+    /// Create a v2 chain whose hashes derive from `genesis` and per-frame digests
+    fn make_v2_chain(frame_count: usize, genesis: [u8; 32]) -> HashChainData {
+        let mut previous = genesis;
+        let mut frame_hashes = Vec::with_capacity(frame_count);
+        let mut frame_digests = Vec::with_capacity(frame_count);
+
+        for i in 0..frame_count {
+            let digest: [u8; 32] = Sha256::digest(format!("frame-{i}").as_bytes()).into();
+            let mut hasher = Sha256::new();
+            hasher.update(previous);
+            hasher.update(digest);
+            previous = hasher.finalize().into();
+
+            frame_digests.push(BASE64.encode(digest));
+            frame_hashes.push(BASE64.encode(previous));
+        }
+
+        HashChainData {
+            final_hash: frame_hashes.last().cloned().unwrap_or_default(),
+            frame_hashes,
+            checkpoints: vec![],
+            version: HASH_CHAIN_VERSION_V2,
+            frame_digests,
         }
     }
 
@@ -470,6 +764,8 @@ mod tests {
             frame_hashes: vec![],
             checkpoints: vec![],
             final_hash: make_hash(0),
+            version: HASH_CHAIN_VERSION_V1,
+            frame_digests: vec![],
         };
         let attestation = make_attestation(&chain);
 
@@ -709,4 +1005,244 @@ mod tests {
         // Duration calculated at 60fps
         assert_eq!(result.duration_ms, 8333); // 500 frames / 60 fps * 1000
     }
+
+    #[test]
+    fn test_verify_v2_chain_with_genesis() {
+        let verifier = HashChainVerifier::new();
+        let genesis = compute_genesis(b"registration-challenge");
+        let chain = make_v2_chain(150, genesis);
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify_with_genesis(&chain, &attestation, Some(&genesis));
+        assert_eq!(result.status, VerificationStatus::Pass);
+        assert!(result.derivation_verified);
+        assert!(result.genesis_bound);
+        assert_eq!(result.broken_at_frame, None);
+    }
+
+    #[test]
+    fn test_verify_v1_chain_rejected_when_device_has_genesis() {
+        let verifier = HashChainVerifier::new();
+        let chain = make_chain(30);
+        let attestation = make_attestation(&chain);
+        let genesis = compute_genesis(b"registration-challenge");
+
+        let result = verifier.verify_with_genesis(&chain, &attestation, Some(&genesis));
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(!result.genesis_bound);
+        assert!(result.failure_reason.unwrap().contains("version 1"));
+
+        // Devices registered before genesis binding keep the v1 path
+        let result = verifier.verify_with_genesis(&chain, &attestation, None);
+        assert_eq!(result.status, VerificationStatus::Pass);
+    }
+
+    #[test]
+    fn test_verify_v2_chain_without_genesis() {
+        let verifier = HashChainVerifier::new();
+        let chain = make_v2_chain(30, compute_genesis(b"unknown"));
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify(&chain, &attestation);
+        assert_eq!(result.status, VerificationStatus::Pass);
+        assert!(result.derivation_verified);
+        assert!(!result.genesis_bound);
+    }
+
+    #[test]
+    fn test_verify_v2_chain_wrong_genesis() {
+        let verifier = HashChainVerifier::new();
+        let chain = make_v2_chain(30, compute_genesis(b"device-a"));
+        let attestation = make_attestation(&chain);
+        let other_genesis = compute_genesis(b"device-b");
+
+        let result = verifier.verify_with_genesis(&chain, &attestation, Some(&other_genesis));
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert_eq!(result.broken_at_frame, Some(1));
+    }
+
+    #[test]
+    fn test_verify_v2_chain_broken_link() {
+        let verifier = HashChainVerifier::new();
+        let genesis = compute_genesis(b"registration-challenge");
+        let mut chain = make_v2_chain(100, genesis);
+        // Splice in a foreign frame digest at frame 42 (index 41)
+        chain.frame_digests[41] = make_hash(7);
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify_with_genesis(&chain, &attestation, Some(&genesis));
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert_eq!(result.broken_at_frame, Some(42));
+        assert!(result.failure_reason.unwrap().contains("derivation"));
+    }
+
+    #[test]
+    fn test_verify_v2_chain_missing_digests() {
+        let verifier = HashChainVerifier::new();
+        let mut chain = make_v2_chain(10, compute_genesis(b"challenge"));
+        chain.frame_digests.truncate(5);
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify(&chain, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert_eq!(result.broken_at_frame, None);
+        assert!(result.failure_reason.unwrap().contains("digest count"));
+    }
+
+    #[test]
+    fn test_verify_unsupported_version() {
+        let verifier = HashChainVerifier::new();
+        let mut chain = make_chain(10);
+        chain.version = 9;
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify(&chain, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(result.failure_reason.unwrap().contains("Unsupported"));
+    }
+
+    #[test]
+    fn test_v1_chain_not_derivation_verified() {
+        let verifier = HashChainVerifier::new();
+        let chain = make_chain(10);
+        let attestation = make_attestation(&chain);
+
+        let result = verifier.verify(&chain, &attestation);
+        assert!(result.is_valid());
+        assert!(!result.derivation_verified);
+    }
+
+    /// Create a hash chain summary with all expected checkpoints attested
+    fn make_summary(chain_length: u32) -> HashChainSummary {
+        let checkpoints: Vec<HashCheckpoint> = (0..chain_length / 150)
+            .map(|i| HashCheckpoint {
+                index: i,
+                frame_number: (i + 1) * 150,
+                hash: make_hash(i as u8 + 1),
+                timestamp: (i + 1) as f64 * 5.0,
+            })
+            .collect();
+        HashChainSummary {
+            final_hash: make_hash(0xFF),
+            chain_length,
+            attested_checkpoints: checkpoints.iter().map(|c| c.index).collect(),
+            checkpoints,
+        }
+    }
+
+    fn make_summary_attestation(summary: &HashChainSummary) -> VideoAttestation {
+        VideoAttestation {
+            final_hash: summary.final_hash.clone(),
+            assertion: BASE64.encode(b"test_assertion"),
+            duration_ms: (summary.chain_length as u64 * 1000) / 30,
+            frame_count: summary.chain_length,
+            is_partial: false,
+            checkpoint_index: None,
+        }
+    }
+
+    #[test]
+    fn test_verify_summary_with_attested_checkpoints() {
+        let verifier = HashChainVerifier::new();
+        let summary = make_summary(450);
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Pass);
+        assert_eq!(result.frame_count, 450);
+        assert!(result.chain_structure_valid);
+        assert!(result.checkpoints_valid);
+        assert!(!result.derivation_verified);
+    }
+
+    #[test]
+    fn test_verify_summary_without_checkpoints() {
+        let verifier = HashChainVerifier::new();
+        let mut summary = make_summary(450);
+        summary.checkpoints.clear();
+        summary.attested_checkpoints.clear();
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Pass);
+        assert!(!result.chain_structure_valid);
+        assert!(!result.checkpoints_valid);
+    }
+
+    #[test]
+    fn test_verify_summary_short_chain_needs_no_checkpoints() {
+        let verifier = HashChainVerifier::new();
+        let summary = make_summary(90);
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert!(result.is_valid());
+        assert!(result.checkpoints_valid);
+    }
+
+    #[test]
+    fn test_verify_summary_unattested_checkpoint() {
+        let verifier = HashChainVerifier::new();
+        let mut summary = make_summary(450);
+        summary.attested_checkpoints.retain(|&i| i != 1);
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(result.failure_reason.unwrap().contains("Checkpoint 1"));
+    }
+
+    #[test]
+    fn test_verify_summary_checkpoint_gap() {
+        let verifier = HashChainVerifier::new();
+        let mut summary = make_summary(450);
+        summary.checkpoints.remove(1);
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(result.failure_reason.unwrap().contains("checkpoint 1"));
+    }
+
+    #[test]
+    fn test_verify_summary_checkpoint_beyond_chain() {
+        let verifier = HashChainVerifier::new();
+        let mut summary = make_summary(450);
+        summary.chain_length = 400;
+        let mut attestation = make_summary_attestation(&summary);
+        attestation.frame_count = 400;
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(result.failure_reason.unwrap().contains("beyond"));
+    }
+
+    #[test]
+    fn test_verify_summary_attestation_mismatch() {
+        let verifier = HashChainVerifier::new();
+        let summary = make_summary(300);
+        let mut attestation = make_summary_attestation(&summary);
+        attestation.final_hash = make_hash(0xAB);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(!result.attestation_valid);
+    }
+
+    #[test]
+    fn test_verify_summary_empty_chain() {
+        let verifier = HashChainVerifier::new();
+        let summary = make_summary(0);
+        let attestation = make_summary_attestation(&summary);
+
+        let result = verifier.verify_summary(&summary, &attestation);
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert!(result.failure_reason.unwrap().contains("Empty"));
+    }
+
+    #[test]
+    fn test_compute_genesis_is_deterministic() {
+        assert_eq!(compute_genesis(b"abc"), compute_genesis(b"abc"));
+        assert_ne!(compute_genesis(b"abc"), compute_genesis(b"abd"));
+    }
 }
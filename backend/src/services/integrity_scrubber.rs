@@ -0,0 +1,275 @@
+//! Stored Media Integrity Scrubber
+//!
+//! Periodically re-hashes stored capture media and compares it with the
+//! `target_media_hash` recorded at upload, so bit-rot or tampering in the
+//! bucket is detected instead of silently served.
+//!
+//! ## Scheduling
+//! Each run checks the `INTEGRITY_SCRUB_BATCH_SIZE` least recently checked
+//! captures with stored media (never-checked first), then waits
+//! `INTEGRITY_SCRUB_INTERVAL_SECS`. Results go to `captures.integrity_status`
+//! and are surfaced by `/verify/{id}`.
+//!
+//! ## Error Handling
+//! Encrypted objects that fail authentication are tampered and reported as
+//! `mismatch`. Transient storage errors leave the status unchanged but still
+//! stamp `integrity_checked_at`, so the capture is retried after the rest of
+//! the rotation instead of starving it.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::services::storage::sha256_digest;
+use crate::services::StorageService;
+
+// ============================================================================
+// Integrity Status
+// ============================================================================
+
+/// Result of the last integrity check of a capture's stored media
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityStatus {
+    /// Not yet checked, or no stored media to check (hash-only captures)
+    Unchecked,
+    /// Stored bytes hash to `target_media_hash`
+    Verified,
+    /// Stored bytes differ from `target_media_hash`
+    Mismatch,
+    /// Stored object no longer exists
+    Missing,
+}
+
+impl IntegrityStatus {
+    /// Value stored in `captures.integrity_status`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IntegrityStatus::Unchecked => "unchecked",
+            IntegrityStatus::Verified => "verified",
+            IntegrityStatus::Mismatch => "mismatch",
+            IntegrityStatus::Missing => "missing",
+        }
+    }
+
+    /// Compares stored bytes with the recorded media hash
+    pub fn of(stored: &[u8], expected_sha256: &[u8]) -> Self {
+        if sha256_digest(stored)[..] == *expected_sha256 {
+            IntegrityStatus::Verified
+        } else {
+            IntegrityStatus::Mismatch
+        }
+    }
+}
+
+impl fmt::Display for IntegrityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IntegrityStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unchecked" => Ok(IntegrityStatus::Unchecked),
+            "verified" => Ok(IntegrityStatus::Verified),
+            "mismatch" => Ok(IntegrityStatus::Mismatch),
+            "missing" => Ok(IntegrityStatus::Missing),
+            _ => Err(format!("Unknown integrity status: {s}")),
+        }
+    }
+}
+
+// ============================================================================
+// Scrubber
+// ============================================================================
+
+/// Counts from one scrubber run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScrubReport {
+    pub verified: usize,
+    pub mismatched: usize,
+    pub missing: usize,
+    pub errors: usize,
+}
+
+/// Capture selected for re-hashing
+#[derive(sqlx::FromRow)]
+struct ScrubCandidate {
+    id: Uuid,
+    target_media_hash: Vec<u8>,
+    media_key: String,
+}
+
+/// Re-hashes stored media against recorded hashes
+pub struct IntegrityScrubber {
+    db: PgPool,
+    storage: Arc<StorageService>,
+    batch_size: i64,
+}
+
+impl IntegrityScrubber {
+    /// Creates a scrubber checking `batch_size` captures per run
+    pub fn new(db: PgPool, storage: Arc<StorageService>, batch_size: i64) -> Self {
+        Self {
+            db,
+            storage,
+            batch_size,
+        }
+    }
+
+    /// Spawns the scrubber as a background task running every `interval`
+    pub fn spawn(self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self.run_once().await {
+                    Ok(report) => info!(?report, "[integrity] Scrub run complete"),
+                    Err(e) => warn!(error = %e, "[integrity] Scrub run failed"),
+                }
+            }
+        })
+    }
+
+    /// Checks one batch of the least recently checked captures
+    pub async fn run_once(&self) -> Result<ScrubReport, sqlx::Error> {
+        // Video captures are checked against the video; photos against the photo
+        let candidates = sqlx::query_as::<_, ScrubCandidate>(
+            r#"
+            SELECT id, target_media_hash,
+                   COALESCE(video_s3_key, photo_s3_key) AS media_key
+            FROM captures
+            WHERE media_stored
+              AND COALESCE(video_s3_key, photo_s3_key) IS NOT NULL
+            ORDER BY integrity_checked_at NULLS FIRST, uploaded_at
+            LIMIT $1
+            "#,
+        )
+        .bind(self.batch_size)
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut report = ScrubReport::default();
+        for candidate in candidates {
+            let status = match self
+                .storage
+                .download_authenticated(&candidate.media_key)
+                .await
+            {
+                Ok(Some(bytes)) => IntegrityStatus::of(&bytes, &candidate.target_media_hash),
+                Ok(None) => IntegrityStatus::Mismatch,
+                Err(ApiError::CaptureNotFound) => IntegrityStatus::Missing,
+                Err(e) => {
+                    warn!(
+                        capture_id = %candidate.id,
+                        key = %candidate.media_key,
+                        error = %e,
+                        "[integrity] Could not read stored media, will retry"
+                    );
+                    report.errors += 1;
+                    // Rotate to the back of the queue; the status is unchanged
+                    sqlx::query("UPDATE captures SET integrity_checked_at = NOW() WHERE id = $1")
+                        .bind(candidate.id)
+                        .execute(&self.db)
+                        .await?;
+                    continue;
+                }
+            };
+
+            match status {
+                IntegrityStatus::Verified => report.verified += 1,
+                IntegrityStatus::Mismatch => {
+                    error!(
+                        capture_id = %candidate.id,
+                        key = %candidate.media_key,
+                        "[integrity] Stored media does not match recorded hash"
+                    );
+                    report.mismatched += 1;
+                }
+                IntegrityStatus::Missing => {
+                    error!(
+                        capture_id = %candidate.id,
+                        key = %candidate.media_key,
+                        "[integrity] Stored media is missing"
+                    );
+                    report.missing += 1;
+                }
+                IntegrityStatus::Unchecked => {}
+            }
+
+            sqlx::query(
+                r#"
+                UPDATE captures
+                SET integrity_status = $2, integrity_checked_at = NOW()
+                WHERE id = $1
+                "#,
+            )
+            .bind(candidate.id)
+            .bind(status.as_str())
+            .execute(&self.db)
+            .await?;
+        }
+
+        Ok(report)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrity_status_of_matching_bytes() {
+        let bytes = b"stored photo";
+        let expected = sha256_digest(bytes);
+        assert_eq!(
+            IntegrityStatus::of(bytes, &expected),
+            IntegrityStatus::Verified
+        );
+    }
+
+    #[test]
+    fn test_integrity_status_of_altered_bytes() {
+        let expected = sha256_digest(b"stored photo");
+        assert_eq!(
+            IntegrityStatus::of(b"stored phot0", &expected),
+            IntegrityStatus::Mismatch
+        );
+        // A malformed recorded hash never verifies
+        assert_eq!(
+            IntegrityStatus::of(b"stored photo", &expected[..16]),
+            IntegrityStatus::Mismatch
+        );
+    }
+
+    #[test]
+    fn test_integrity_status_round_trips() {
+        for status in [
+            IntegrityStatus::Unchecked,
+            IntegrityStatus::Verified,
+            IntegrityStatus::Mismatch,
+            IntegrityStatus::Missing,
+        ] {
+            assert_eq!(status.to_string().parse::<IntegrityStatus>(), Ok(status));
+            assert_eq!(
+                serde_json::to_value(status).unwrap(),
+                serde_json::json!(status.as_str())
+            );
+        }
+        assert!("corrupt".parse::<IntegrityStatus>().is_err());
+    }
+}
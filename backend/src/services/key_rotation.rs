@@ -0,0 +1,272 @@
+//! Master Key Rotation
+//!
+//! Re-wraps every capture's data key under the active master key, in the
+//! database (`location_precise`) and in stored media and derivatives. Payloads
+//! are not re-encrypted; only the wrapped data key in each sealed blob changes.
+//!
+//! Each re-wrapped object is written under the content-addressed key of its
+//! new ciphertext (derivatives move off `captures/{id}/...` too). The capture row is switched to the new
+//! keys in one transaction, and only then are the old objects deleted.
+//!
+//! ## Usage
+//! 1. Append a new key to `MASTER_KEY_FILE` (the last key is active)
+//! 2. Restart the API so new captures use it
+//! 3. Run `realitycam-api rotate-keys`
+//! 4. Once it reports no failures, the old key can be removed from the file
+//!
+//! Captures are selected by `captures.data_key_id`, so an interrupted run can
+//! simply be started again.
+
+use std::sync::Arc;
+
+use sqlx::PgPool;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::services::envelope::Envelope;
+use crate::services::StorageService;
+
+// ============================================================================
+// Report
+// ============================================================================
+
+/// Counts from one rotation run
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeyRotationReport {
+    /// Captures now wrapped under the active key
+    pub captures: usize,
+    /// Stored objects rewritten with a re-wrapped data key
+    pub objects: usize,
+    /// Captures left on an old key because a step failed
+    pub failed: usize,
+}
+
+// ============================================================================
+// Rotator
+// ============================================================================
+
+/// Capture still wrapped under an old master key
+#[derive(sqlx::FromRow)]
+struct RotationCandidate {
+    id: Uuid,
+    location_precise: Option<serde_json::Value>,
+    photo_s3_key: Option<String>,
+    depth_map_s3_key: Option<String>,
+    video_s3_key: Option<String>,
+    hash_chain_s3_key: Option<String>,
+    thumbnail_s3_key: Option<String>,
+    thumbnail_webp_s3_key: Option<String>,
+    poster_s3_key: Option<String>,
+    depth_heatmap_s3_key: Option<String>,
+}
+
+/// Re-wraps capture data keys under the active master key
+pub struct KeyRotator {
+    db: PgPool,
+    storage: Arc<StorageService>,
+    envelope: Arc<Envelope>,
+    batch_size: i64,
+}
+
+impl KeyRotator {
+    /// Creates a rotator processing `batch_size` captures per query
+    pub fn new(
+        db: PgPool,
+        storage: Arc<StorageService>,
+        envelope: Arc<Envelope>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            db,
+            storage,
+            envelope,
+            batch_size,
+        }
+    }
+
+    /// Rotates all captures not yet on the active key
+    pub async fn run(&self) -> Result<KeyRotationReport, sqlx::Error> {
+        let active_key_id = self.envelope.active_key_id().to_string();
+        let mut report = KeyRotationReport::default();
+        let mut failed: Vec<Uuid> = Vec::new();
+
+        info!(active_key_id = %active_key_id, "[key_rotation] Starting rotation");
+
+        loop {
+            // Failed captures keep their old key ID; skip them for this run
+            let candidates = sqlx::query_as::<_, RotationCandidate>(
+                r#"
+                SELECT id, location_precise, photo_s3_key, depth_map_s3_key,
+                       video_s3_key, hash_chain_s3_key, thumbnail_s3_key,
+                       thumbnail_webp_s3_key, poster_s3_key, depth_heatmap_s3_key
+                FROM captures
+                WHERE data_key_id IS NOT NULL AND data_key_id <> $1
+                  AND NOT (id = ANY($3))
+                ORDER BY uploaded_at
+                LIMIT $2
+                "#,
+            )
+            .bind(&active_key_id)
+            .bind(self.batch_size)
+            .bind(&failed)
+            .fetch_all(&self.db)
+            .await?;
+
+            if candidates.is_empty() {
+                break;
+            }
+
+            for candidate in candidates {
+                match self.rotate_capture(&candidate, &active_key_id).await {
+                    Ok(objects) => {
+                        report.captures += 1;
+                        report.objects += objects;
+                    }
+                    Err(e) => {
+                        warn!(
+                            capture_id = %candidate.id,
+                            error = %e,
+                            "[key_rotation] Failed to rotate capture, leaving it on its old key"
+                        );
+                        report.failed += 1;
+                        failed.push(candidate.id);
+                    }
+                }
+            }
+        }
+
+        info!(?report, "[key_rotation] Rotation complete");
+        Ok(report)
+    }
+
+    /// Re-wraps one capture's objects and location, then records the new key
+    async fn rotate_capture(
+        &self,
+        candidate: &RotationCandidate,
+        active_key_id: &str,
+    ) -> Result<usize, ApiError> {
+        // (column, old key, new key) for every object rewritten
+        let mut rewritten: Vec<(&str, &str, String)> = Vec::new();
+        for (column, key) in [
+            ("photo_s3_key", &candidate.photo_s3_key),
+            ("depth_map_s3_key", &candidate.depth_map_s3_key),
+            ("video_s3_key", &candidate.video_s3_key),
+            ("hash_chain_s3_key", &candidate.hash_chain_s3_key),
+            ("thumbnail_s3_key", &candidate.thumbnail_s3_key),
+            ("thumbnail_webp_s3_key", &candidate.thumbnail_webp_s3_key),
+            ("poster_s3_key", &candidate.poster_s3_key),
+            ("depth_heatmap_s3_key", &candidate.depth_heatmap_s3_key),
+        ] {
+            let Some(key) = key else {
+                continue;
+            };
+            // Objects deleted by retention have nothing left to re-wrap
+            match self.storage.rewrap(key).await {
+                Ok(Some(new_key)) => rewritten.push((column, key, new_key)),
+                Ok(None) | Err(ApiError::CaptureNotFound) => {}
+                Err(e) => {
+                    self.discard(rewritten.iter().map(|(_, _, new)| new.as_str()))
+                        .await;
+                    return Err(e);
+                }
+            }
+        }
+
+        let location = match &candidate.location_precise {
+            Some(location) => match self.envelope.rewrap_json(location).await {
+                Ok(location) => location,
+                Err(e) => {
+                    self.discard(rewritten.iter().map(|(_, _, new)| new.as_str()))
+                        .await;
+                    return Err(ApiError::Internal(e.into()));
+                }
+            },
+            None => None,
+        };
+
+        let adopted = match self
+            .record_rotation(candidate.id, &rewritten, location, active_key_id)
+            .await
+        {
+            Ok(adopted) => adopted,
+            Err(e) => {
+                self.discard(rewritten.iter().map(|(_, _, new)| new.as_str()))
+                    .await;
+                return Err(e.into());
+            }
+        };
+
+        // The row now references the new objects; drop whichever side lost
+        for ((_, old_key, new_key), adopted) in rewritten.iter().zip(&adopted) {
+            let unreferenced = if *adopted { *old_key } else { new_key.as_str() };
+            self.discard([unreferenced]).await;
+        }
+
+        Ok(adopted.iter().filter(|adopted| **adopted).count())
+    }
+
+    /// Switches the capture to the re-wrapped objects and location in one
+    /// transaction
+    ///
+    /// A key is only replaced if the row still holds the old one, so keys
+    /// cleared by retention meanwhile stay cleared. Returns, per rewritten
+    /// object, whether the row adopted the new key.
+    async fn record_rotation(
+        &self,
+        capture_id: Uuid,
+        rewritten: &[(&str, &str, String)],
+        location: Option<serde_json::Value>,
+        active_key_id: &str,
+    ) -> Result<Vec<bool>, sqlx::Error> {
+        let mut tx = self.db.begin().await?;
+
+        let mut adopted = Vec::with_capacity(rewritten.len());
+        for (column, old_key, new_key) in rewritten {
+            // NOTE: Using runtime query to avoid SQLX_OFFLINE cache issues
+            let result = sqlx::query(&format!(
+                "UPDATE captures SET {column} = $3 WHERE id = $1 AND {column} = $2"
+            ))
+            .bind(capture_id)
+            .bind(old_key)
+            .bind(new_key)
+            .execute(&mut *tx)
+            .await?;
+            adopted.push(result.rows_affected() > 0);
+        }
+
+        // A location cleared by retention meanwhile must stay cleared
+        sqlx::query(
+            r#"
+            UPDATE captures
+            SET location_precise = CASE
+                    WHEN location_precise IS NULL THEN NULL
+                    ELSE COALESCE($2, location_precise)
+                END,
+                data_key_id = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(capture_id)
+        .bind(location)
+        .bind(active_key_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(adopted)
+    }
+
+    /// Deletes objects no capture references; failures only leave orphans
+    async fn discard<'k>(&self, keys: impl IntoIterator<Item = &'k str>) {
+        for key in keys {
+            if let Err(e) = self.storage.delete(key).await {
+                warn!(
+                    key = %key,
+                    error = %e,
+                    "[key_rotation] Failed to delete unreferenced object"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,526 @@
+//! Location Plausibility Service
+//!
+//! `validate_location` only checks coordinate bounds. This service compares a
+//! capture's precise location with the same device's neighboring captures and
+//! inspects the fix itself for signs of a spoofed or hand-entered location.
+//!
+//! ## Checks
+//! 1. **Travel speed:** distance to the nearest earlier and later capture
+//!    fixes, less both fixes' reported accuracy, divided by the time between
+//!    them must not exceed `MAX_PLAUSIBLE_SPEED_MPS` (faster than an airliner)
+//! 2. **Accuracy:** a reported accuracy of 0 m (or less) is not achievable by
+//!    a GNSS receiver and is typical of mock location providers
+//! 3. **Precision:** coordinates rounded to a few decimal places contradict a
+//!    fine reported accuracy. Too many decimal places are flagged only when
+//!    the submitted literal carries more significant digits than an f64 holds:
+//!    platform location APIs produce doubles, and their unrounded values
+//!    routinely print with 13-16 decimals, so a parsed f64 alone cannot show
+//!    excess precision.
+//!
+//! All checks are NON-BLOCKING; results are recorded in
+//! `MetadataEvidence.location_plausible` with a reason. The reason is shown on
+//! the public verify page, so it never names neighboring captures; their
+//! details are logged server-side only.
+//!
+//! Neighboring locations may be sealed at rest (see `envelope`); they are
+//! decrypted here rather than read with JSONB operators in SQL.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::services::envelope::Envelope;
+use crate::types::capture::CaptureLocation;
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+/// Fastest plausible travel between captures (m/s, ~1080 km/h)
+const MAX_PLAUSIBLE_SPEED_MPS: f64 = 300.0;
+
+/// Accuracy assumed for fixes that do not report one (meters)
+const DEFAULT_ACCURACY_M: f64 = 65.0;
+
+/// Upper bound on the accuracy credited to a single fix (meters), so a
+/// vague fix cannot excuse arbitrary jumps
+const MAX_CREDITED_ACCURACY_M: f64 = 5_000.0;
+
+/// Minimum time between fixes used for speed (seconds)
+const MIN_INTERVAL_SECONDS: f64 = 1.0;
+
+/// Coordinates with at most this many decimal places are "round"
+const ROUND_COORDINATE_DECIMALS: usize = 3;
+
+/// Reported accuracy below which round coordinates are contradictory (meters)
+const ROUND_COORDINATE_ACCURACY_M: f64 = 100.0;
+
+/// Most significant digits a shortest round-trip f64 literal needs
+const F64_MAX_SIGNIFICANT_DIGITS: usize = 17;
+
+/// Mean Earth radius (meters)
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// A located capture from the device's history
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationFix {
+    /// When the capture was taken
+    pub captured_at: DateTime<Utc>,
+    /// Latitude in degrees
+    pub latitude: f64,
+    /// Longitude in degrees
+    pub longitude: f64,
+    /// Reported accuracy in meters
+    pub accuracy: Option<f64>,
+}
+
+/// Result of location plausibility checks
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationPlausibility {
+    /// Whether all checks passed
+    pub plausible: bool,
+    /// Why the location is implausible (findings joined with "; ")
+    pub reason: Option<String>,
+}
+
+// ============================================================================
+// History Loading
+// ============================================================================
+
+/// Loads the device's nearest located captures before and after `captured_at`
+///
+/// Uploads may arrive out of order, so both neighbors are returned. Sealed
+/// locations are opened with `envelope`; without it they are skipped.
+pub async fn load_neighbor_fixes(
+    pool: &PgPool,
+    envelope: Option<&Envelope>,
+    device_id: Uuid,
+    captured_at: DateTime<Utc>,
+) -> Result<Vec<LocationFix>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, (DateTime<Utc>, serde_json::Value)>(
+        r#"
+        (
+            SELECT captured_at, location_precise
+            FROM captures
+            WHERE device_id = $1 AND location_precise IS NOT NULL AND captured_at <= $2
+            ORDER BY captured_at DESC
+            LIMIT 1
+        )
+        UNION ALL
+        (
+            SELECT captured_at, location_precise
+            FROM captures
+            WHERE device_id = $1 AND location_precise IS NOT NULL AND captured_at > $2
+            ORDER BY captured_at ASC
+            LIMIT 1
+        )
+        "#,
+    )
+    .bind(device_id)
+    .bind(captured_at)
+    .fetch_all(pool)
+    .await?;
+
+    let mut fixes = Vec::with_capacity(rows.len());
+    for (captured_at, location) in rows {
+        let location = match envelope {
+            Some(envelope) => match envelope.open_json(location).await {
+                Ok(location) => location,
+                Err(e) => {
+                    warn!(error = %e, "[location_plausibility] Could not decrypt neighbor location");
+                    continue;
+                }
+            },
+            None => location,
+        };
+        fixes.extend(fix_from_json(captured_at, &location));
+    }
+    Ok(fixes)
+}
+
+/// Reads a fix from a stored `location_precise` value
+///
+/// Returns None for sealed values and values without coordinates.
+fn fix_from_json(captured_at: DateTime<Utc>, location: &serde_json::Value) -> Option<LocationFix> {
+    Some(LocationFix {
+        captured_at,
+        latitude: location.get("latitude")?.as_f64()?,
+        longitude: location.get("longitude")?.as_f64()?,
+        accuracy: location.get("accuracy").and_then(|a| a.as_f64()),
+    })
+}
+
+// ============================================================================
+// Plausibility Checks
+// ============================================================================
+
+/// Checks a capture location against its neighbors and for perfect values
+///
+/// # Arguments
+/// * `location` - Precise capture location from metadata
+/// * `captured_at` - Claimed capture time
+/// * `neighbors` - Nearest located captures of the same device
+/// * `literal_digits` - Most significant digits in the submitted coordinate
+///   literals (see `coordinate_literal_digits`), if the raw JSON was available
+///
+/// # Returns
+/// LocationPlausibility with the joined findings, if any
+pub fn check_location_plausibility(
+    location: &CaptureLocation,
+    captured_at: DateTime<Utc>,
+    neighbors: &[LocationFix],
+    literal_digits: Option<usize>,
+) -> LocationPlausibility {
+    let mut reasons = Vec::new();
+
+    if let Some(accuracy) = location.accuracy {
+        if accuracy <= 0.0 {
+            reasons.push(format!(
+                "reported accuracy of {accuracy} m is not achievable by GPS"
+            ));
+        }
+    }
+
+    let decimals = decimal_places(location.latitude).max(decimal_places(location.longitude));
+    let accuracy = location.accuracy.unwrap_or(DEFAULT_ACCURACY_M);
+    if decimals <= ROUND_COORDINATE_DECIMALS && accuracy < ROUND_COORDINATE_ACCURACY_M {
+        reasons.push(format!(
+            "coordinates rounded to {decimals} decimal places contradict reported accuracy of {accuracy} m"
+        ));
+    }
+
+    if let Some(digits) = literal_digits.filter(|d| *d > F64_MAX_SIGNIFICANT_DIGITS) {
+        reasons.push(format!(
+            "coordinates submitted with {digits} significant digits exceed what a device location API can produce"
+        ));
+    }
+
+    let current = LocationFix {
+        captured_at,
+        latitude: location.latitude,
+        longitude: location.longitude,
+        accuracy: location.accuracy,
+    };
+    let mut impossible_travel = false;
+    for neighbor in neighbors {
+        let speed = travel_speed_mps(&current, neighbor);
+        if speed > MAX_PLAUSIBLE_SPEED_MPS {
+            warn!(
+                neighbor_captured_at = %neighbor.captured_at.to_rfc3339(),
+                speed_kmh = speed * 3.6,
+                "[location_plausibility] Impossible travel from neighboring capture"
+            );
+            impossible_travel = true;
+        }
+    }
+    if impossible_travel {
+        reasons
+            .push("travel from the device's other captures is not physically possible".to_string());
+    }
+
+    debug!(
+        accuracy = ?location.accuracy,
+        neighbors = neighbors.len(),
+        findings = reasons.len(),
+        "[location_plausibility] Location checked"
+    );
+
+    LocationPlausibility {
+        plausible: reasons.is_empty(),
+        reason: (!reasons.is_empty()).then(|| reasons.join("; ")),
+    }
+}
+
+/// Loads neighboring fixes and checks a capture location against them
+pub async fn check_device_location(
+    pool: &PgPool,
+    envelope: Option<&Envelope>,
+    device_id: Uuid,
+    location: &CaptureLocation,
+    captured_at: DateTime<Utc>,
+    literal_digits: Option<usize>,
+) -> Result<LocationPlausibility, sqlx::Error> {
+    let neighbors = load_neighbor_fixes(pool, envelope, device_id, captured_at).await?;
+    Ok(check_location_plausibility(
+        location,
+        captured_at,
+        &neighbors,
+        literal_digits,
+    ))
+}
+
+/// Most significant digits among the `latitude` and `longitude` number
+/// literals in raw metadata JSON
+///
+/// serde_json rounds literals to the nearest f64, so excess precision is only
+/// visible in the submitted text. Returns None if no coordinate is found.
+pub fn coordinate_literal_digits(raw_json: &str) -> Option<usize> {
+    ["\"latitude\"", "\"longitude\""]
+        .iter()
+        .flat_map(|key| raw_json.match_indices(key))
+        .filter_map(|(start, key)| {
+            let rest = raw_json[start + key.len()..].trim_start();
+            let rest = rest.strip_prefix(':')?.trim_start();
+            let end = rest
+                .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+                .unwrap_or(rest.len());
+            let literal = &rest[..end];
+            (!literal.is_empty()).then(|| significant_digits(literal))
+        })
+        .max()
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Accuracy credited to a fix (reported or default, clamped)
+fn credited_accuracy(fix: &LocationFix) -> f64 {
+    fix.accuracy
+        .filter(|a| a.is_finite() && *a > 0.0)
+        .unwrap_or(DEFAULT_ACCURACY_M)
+        .min(MAX_CREDITED_ACCURACY_M)
+}
+
+/// Minimum speed needed to travel between two fixes (m/s)
+///
+/// Both fixes' accuracy radii are subtracted from the distance, so poor
+/// accuracy makes the check more lenient.
+fn travel_speed_mps(a: &LocationFix, b: &LocationFix) -> f64 {
+    let distance = haversine_m(a.latitude, a.longitude, b.latitude, b.longitude);
+    let effective = (distance - credited_accuracy(a) - credited_accuracy(b)).max(0.0);
+    let seconds = (a.captured_at - b.captured_at).num_milliseconds().abs() as f64 / 1000.0;
+    effective / seconds.max(MIN_INTERVAL_SECONDS)
+}
+
+/// Decimal places in the shortest round-trip representation of a value
+fn decimal_places(value: f64) -> usize {
+    let repr = value.abs().to_string();
+    repr.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Significant digits in the mantissa of a JSON number literal
+///
+/// Leading zeros are not significant; trailing zeros after the decimal point
+/// are, since they claim precision.
+fn significant_digits(literal: &str) -> usize {
+    let mantissa = literal.split(['e', 'E']).next().unwrap_or_default();
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let digits = digits.trim_start_matches('0');
+    if mantissa.contains('.') {
+        digits.len()
+    } else {
+        digits.trim_end_matches('0').len()
+    }
+}
+
+/// Great-circle distance in meters
+fn haversine_m(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let dlat = (lat2 - lat1).to_radians();
+    let dlng = (lng2 - lng1).to_radians();
+    let a = (dlat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (dlng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_M * a.sqrt().min(1.0).asin()
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn now() -> DateTime<Utc> {
+        "2025-12-10T12:00:00Z".parse().unwrap()
+    }
+
+    fn location(latitude: f64, longitude: f64, accuracy: Option<f64>) -> CaptureLocation {
+        CaptureLocation {
+            latitude,
+            longitude,
+            altitude: None,
+            accuracy,
+        }
+    }
+
+    fn fix(minutes_ago: i64, latitude: f64, longitude: f64) -> LocationFix {
+        LocationFix {
+            captured_at: now() - Duration::minutes(minutes_ago),
+            latitude,
+            longitude,
+            accuracy: Some(10.0),
+        }
+    }
+
+    #[test]
+    fn test_plausible_without_history() {
+        let result = check_location_plausibility(
+            &location(37.77493012, -122.41941551, Some(8.0)),
+            now(),
+            &[],
+            None,
+        );
+        assert!(result.plausible);
+        assert!(result.reason.is_none());
+    }
+
+    #[test]
+    fn test_plausible_walking_distance() {
+        // ~500 m in 10 minutes
+        let result = check_location_plausibility(
+            &location(37.77943012, -122.41941551, Some(8.0)),
+            now(),
+            &[fix(10, 37.77493012, -122.41941551)],
+            None,
+        );
+        assert!(result.plausible);
+    }
+
+    #[test]
+    fn test_impossible_travel_speed() {
+        // San Francisco to New York in 10 minutes
+        let result = check_location_plausibility(
+            &location(40.71427012, -74.00597113, Some(8.0)),
+            now(),
+            &[fix(10, 37.77493012, -122.41941551)],
+            None,
+        );
+        assert!(!result.plausible);
+        let reason = result.reason.unwrap();
+        assert!(reason.contains("not physically possible"));
+        // The public reason must not reveal when the other capture was taken
+        assert!(!reason.contains("2025-12-10"));
+    }
+
+    #[test]
+    fn test_accuracy_weights_travel_check() {
+        // ~2 km jump in 1 second is fine when both fixes are this vague
+        let vague = LocationFix {
+            accuracy: Some(1_500.0),
+            ..fix(0, 37.77493012, -122.41941551)
+        };
+        let result = check_location_plausibility(
+            &location(37.79293012, -122.41941551, Some(1_500.0)),
+            now() + Duration::seconds(1),
+            &[vague],
+            None,
+        );
+        assert!(result.plausible);
+
+        let precise = fix(0, 37.77493012, -122.41941551);
+        let result = check_location_plausibility(
+            &location(37.79293012, -122.41941551, Some(5.0)),
+            now() + Duration::seconds(1),
+            &[precise],
+            None,
+        );
+        assert!(!result.plausible);
+    }
+
+    #[test]
+    fn test_zero_accuracy_flagged() {
+        let result = check_location_plausibility(
+            &location(37.77493012, -122.41941551, Some(0.0)),
+            now(),
+            &[],
+            None,
+        );
+        assert!(!result.plausible);
+        assert!(result.reason.unwrap().contains("accuracy"));
+    }
+
+    /// Checks a location as submitted in raw metadata JSON
+    fn check_raw(raw_json: &str) -> LocationPlausibility {
+        let metadata: serde_json::Value = serde_json::from_str(raw_json).unwrap();
+        let location: CaptureLocation =
+            serde_json::from_value(metadata["location"].clone()).unwrap();
+        check_location_plausibility(&location, now(), &[], coordinate_literal_digits(raw_json))
+    }
+
+    #[test]
+    fn test_unrounded_client_coordinates_plausible() {
+        // CLLocationCoordinate2D values as JSONEncoder writes them: the
+        // simulator's Apple Park fix and an unrounded on-device fix
+        let result = check_raw(
+            r#"{"location":{"latitude":37.33233141,"longitude":-122.0312186,"accuracy":5.0}}"#,
+        );
+        assert!(result.plausible);
+
+        let result = check_raw(
+            r#"{"location":{"latitude":51.50181884195567,"longitude":-0.1418847357055851,"altitude":24.173513412475586,"accuracy":4.7453932762146}}"#,
+        );
+        assert!(result.plausible);
+    }
+
+    #[test]
+    fn test_excess_coordinate_precision_flagged() {
+        let result = check_raw(
+            r#"{"location":{"latitude":37.774929512345678901234,"longitude":-122.4194155,"accuracy":5.0}}"#,
+        );
+        assert!(!result.plausible);
+        assert!(result.reason.unwrap().contains("significant digits"));
+
+        // Padding with zeros also claims precision no double carries
+        let result = check_raw(
+            r#"{"location":{"latitude":37.77490000000000000000,"longitude":-122.4194155,"accuracy":5.0}}"#,
+        );
+        assert!(!result.plausible);
+    }
+
+    #[test]
+    fn test_coordinate_literal_digits() {
+        assert_eq!(
+            coordinate_literal_digits(r#"{"latitude": -0.1418847357055851, "longitude" :37}"#),
+            Some(16)
+        );
+        assert_eq!(
+            coordinate_literal_digits(r#"{"latitude":3.7e1,"longitude":1220}"#),
+            Some(3)
+        );
+        assert_eq!(coordinate_literal_digits(r#"{"location":null}"#), None);
+    }
+
+    #[test]
+    fn test_round_coordinates_with_fine_accuracy_flagged() {
+        let result =
+            check_location_plausibility(&location(37.0, -122.5, Some(5.0)), now(), &[], None);
+        assert!(!result.plausible);
+        assert!(result.reason.unwrap().contains("rounded"));
+
+        // Round coordinates are fine when the fix itself is vague
+        let result =
+            check_location_plausibility(&location(37.0, -122.5, Some(1_000.0)), now(), &[], None);
+        assert!(result.plausible);
+    }
+
+    #[test]
+    fn test_decimal_places() {
+        assert_eq!(decimal_places(37.0), 0);
+        assert_eq!(decimal_places(-122.419), 3);
+        assert_eq!(decimal_places(37.77493012), 8);
+    }
+
+    #[test]
+    fn test_fix_from_stored_json() {
+        let fix = fix_from_json(
+            now(),
+            &serde_json::json!({"latitude": 37.7, "longitude": -122.4, "altitude": null, "accuracy": 8.0}),
+        )
+        .unwrap();
+        assert_eq!(
+            (fix.latitude, fix.longitude, fix.accuracy),
+            (37.7, -122.4, Some(8.0))
+        );
+
+        // Sealed locations carry no readable coordinates
+        assert!(fix_from_json(now(), &serde_json::json!({"$envelope": "UkNFTkMx"})).is_none());
+    }
+}
@@ -0,0 +1,661 @@
+//! Media Derivatives Service
+//!
+//! Generates the web-facing renderings of a capture after upload:
+//! - JPEG and WebP thumbnails of photos (and of video poster frames)
+//! - A full-size JPEG poster frame for videos (via ffmpeg)
+//! - A false-colour PNG heatmap of the photo depth map
+//!
+//! ## Execution Model
+//! Derivatives are produced off the request path: the upload handler spawns a
+//! background task once the capture row exists. Image work runs on the blocking
+//! pool; resulting keys are written to the `captures` derivative columns.
+//!
+//! Derivatives are as revealing as the original (the poster is a full-size
+//! frame), so with encryption at rest they are sealed with the capture's data
+//! key and served through `/verify/{id}/media/{file}`.
+//!
+//! ## Error Handling
+//! All failures are non-fatal. A missing derivative only means `/verify/{id}`
+//! omits the corresponding URL.
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::{DynamicImage, ExtendedColorType, ImageEncoder};
+use sqlx::PgPool;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::process::Command;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use crate::services::depth_analysis::{
+    decompress_depth_map, parse_float32_array, MAX_VALID_DEPTH, MIN_VALID_DEPTH,
+};
+use crate::services::envelope::DataKey;
+use crate::services::storage::DerivativeKind;
+use crate::services::StorageService;
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+/// Longest edge of generated thumbnails (pixels)
+pub const THUMBNAIL_MAX_EDGE: u32 = 480;
+
+/// Colormap position of the farthest depth; skips Turbo's near-black low end
+/// so distant surfaces stay visible
+const HEATMAP_FAR_COLOR: f32 = 0.1;
+
+/// JPEG quality for thumbnails and poster frames (0-100)
+const JPEG_QUALITY: u8 = 80;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Errors that can occur while rendering derivatives
+#[derive(Debug, Error)]
+pub enum MediaDerivativeError {
+    #[error("Failed to decode image: {0}")]
+    Decode(String),
+
+    #[error("Failed to encode image: {0}")]
+    Encode(String),
+
+    #[error("Invalid depth map: {0}")]
+    DepthMap(String),
+
+    #[error("Poster extraction failed: {0}")]
+    Poster(String),
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Encoded thumbnail pair
+#[derive(Debug, Clone)]
+pub struct Thumbnails {
+    pub jpeg: Vec<u8>,
+    pub webp: Vec<u8>,
+}
+
+/// Renders JPEG and WebP thumbnails, preserving aspect ratio
+///
+/// # Arguments
+/// * `image_bytes` - Encoded source image (JPEG, PNG or WebP)
+pub fn render_thumbnails(image_bytes: &[u8]) -> Result<Thumbnails, MediaDerivativeError> {
+    let source = image::load_from_memory(image_bytes)
+        .map_err(|e| MediaDerivativeError::Decode(e.to_string()))?;
+    // `thumbnail` scales up as well as down; small images are kept as-is
+    let resized = if source.width().max(source.height()) > THUMBNAIL_MAX_EDGE {
+        source.thumbnail(THUMBNAIL_MAX_EDGE, THUMBNAIL_MAX_EDGE)
+    } else {
+        source
+    };
+    let thumbnail = DynamicImage::ImageRgb8(resized.to_rgb8());
+
+    let mut jpeg = Vec::new();
+    thumbnail
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY))
+        .map_err(|e| MediaDerivativeError::Encode(e.to_string()))?;
+
+    let mut webp = Vec::new();
+    thumbnail
+        .write_with_encoder(WebPEncoder::new_lossless(&mut webp))
+        .map_err(|e| MediaDerivativeError::Encode(e.to_string()))?;
+
+    Ok(Thumbnails { jpeg, webp })
+}
+
+/// Renders a gzipped Float32 depth map as a false-colour PNG
+///
+/// Depth is normalized over the valid range present in the map; near surfaces
+/// are warm and far surfaces cool (Turbo colormap). Pixels without a valid
+/// depth reading are transparent.
+///
+/// # Arguments
+/// * `compressed` - Gzip-compressed little-endian Float32 depth map
+/// * `width`, `height` - Depth map dimensions from capture metadata
+pub fn render_depth_heatmap(
+    compressed: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, MediaDerivativeError> {
+    let raw = decompress_depth_map(compressed)
+        .map_err(|e| MediaDerivativeError::DepthMap(e.to_string()))?;
+    let depths =
+        parse_float32_array(&raw).map_err(|e| MediaDerivativeError::DepthMap(e.to_string()))?;
+
+    let expected = width as usize * height as usize;
+    if expected == 0 || depths.len() != expected {
+        return Err(MediaDerivativeError::DepthMap(format!(
+            "expected {width}x{height} = {expected} values, got {}",
+            depths.len()
+        )));
+    }
+
+    let is_valid = |d: f32| d.is_finite() && (MIN_VALID_DEPTH..=MAX_VALID_DEPTH).contains(&d);
+    let (min, max) = depths
+        .iter()
+        .copied()
+        .filter(|d| is_valid(*d))
+        .fold((f32::MAX, f32::MIN), |(lo, hi), d| (lo.min(d), hi.max(d)));
+    if min > max {
+        return Err(MediaDerivativeError::DepthMap(
+            "no valid depth values".to_string(),
+        ));
+    }
+    let range = (max - min).max(f32::EPSILON);
+
+    let mut rgba = Vec::with_capacity(expected * 4);
+    for depth in depths {
+        if is_valid(depth) {
+            let nearness = 1.0 - (depth - min) / range;
+            let [r, g, b] = turbo(HEATMAP_FAR_COLOR + (1.0 - HEATMAP_FAR_COLOR) * nearness);
+            rgba.extend_from_slice(&[r, g, b, 255]);
+        } else {
+            rgba.extend_from_slice(&[0, 0, 0, 0]);
+        }
+    }
+
+    let mut png = Vec::new();
+    PngEncoder::new(&mut png)
+        .write_image(&rgba, width, height, ExtendedColorType::Rgba8)
+        .map_err(|e| MediaDerivativeError::Encode(e.to_string()))?;
+
+    Ok(png)
+}
+
+/// Polynomial approximation of the Turbo colormap for `x` in 0.0..=1.0
+fn turbo(x: f32) -> [u8; 3] {
+    let x = x.clamp(0.0, 1.0);
+    let r = 0.135_721_38
+        + x * (4.615_392_6
+            + x * (-42.660_324 + x * (132.131_08 + x * (-152.942_4 + x * 59.286_38))));
+    let g = 0.091_402_61
+        + x * (2.194_188_4
+            + x * (4.842_966_6 + x * (-14.185_033 + x * (4.277_298_6 + x * 2.829_566))));
+    let b = 0.106_673_3
+        + x * (12.641_946
+            + x * (-60.582_047 + x * (110.362_77 + x * (-89.903_11 + x * 27.348_25))));
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+/// Extracts the first video frame as JPEG using ffmpeg
+///
+/// ffmpeg needs a seekable input for MP4/MOV, so the video is written to a
+/// temporary file which is removed afterwards.
+pub async fn extract_poster_frame(
+    video_bytes: &[u8],
+    ffmpeg_path: &str,
+) -> Result<Vec<u8>, MediaDerivativeError> {
+    let input: PathBuf = std::env::temp_dir().join(format!("realitycam-poster-{}", Uuid::new_v4()));
+    tokio::fs::write(&input, video_bytes)
+        .await
+        .map_err(|e| MediaDerivativeError::Poster(format!("temp file: {e}")))?;
+
+    let output = Command::new(ffmpeg_path)
+        .args(["-v", "error", "-i"])
+        .arg(&input)
+        .args(["-frames:v", "1", "-f", "image2", "-c:v", "mjpeg", "pipe:1"])
+        .output()
+        .await;
+
+    if let Err(e) = tokio::fs::remove_file(&input).await {
+        warn!(path = %input.display(), error = %e, "Failed to remove poster temp file");
+    }
+
+    let output = output.map_err(|e| MediaDerivativeError::Poster(format!("{ffmpeg_path}: {e}")))?;
+    if !output.status.success() || output.stdout.is_empty() {
+        return Err(MediaDerivativeError::Poster(format!(
+            "ffmpeg exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+// ============================================================================
+// Background Job
+// ============================================================================
+
+/// Source media for a derivative job
+///
+/// `data_key` is the capture's data key when encrypting at rest; derivatives
+/// are sealed with it.
+#[derive(Debug)]
+pub enum DerivativeJob {
+    /// Photo capture: thumbnails plus a depth heatmap
+    Photo {
+        photo_key: String,
+        depth_key: String,
+        depth_dimensions: (u32, u32),
+        data_key: Option<DataKey>,
+    },
+    /// Video capture: poster frame plus thumbnails of the poster
+    Video {
+        video_key: String,
+        data_key: Option<DataKey>,
+    },
+}
+
+/// Keys written by a derivative job
+#[derive(Debug, Default)]
+struct DerivativeKeys {
+    thumbnail: Option<String>,
+    thumbnail_webp: Option<String>,
+    poster: Option<String>,
+    depth_heatmap: Option<String>,
+}
+
+/// Spawns derivative generation for a capture in the background
+///
+/// The caller is not blocked; all outcomes are logged.
+pub fn spawn_derivatives(
+    db: PgPool,
+    storage: Arc<StorageService>,
+    ffmpeg_path: String,
+    capture_id: Uuid,
+    job: DerivativeJob,
+) {
+    tokio::spawn(async move {
+        let keys = generate(&storage, &ffmpeg_path, capture_id, job).await;
+
+        match store_keys(&db, capture_id, &keys).await {
+            Ok(true) => {}
+            Ok(false) => {
+                // The capture was deleted while rendering; nothing else will
+                // remove these objects
+                info!(capture_id = %capture_id, "Capture deleted, discarding its derivatives");
+                discard(&storage, capture_id, &keys).await;
+                return;
+            }
+            Err(e) => {
+                warn!(capture_id = %capture_id, error = %e, "Failed to record derivative keys");
+                return;
+            }
+        }
+
+        info!(
+            capture_id = %capture_id,
+            thumbnail = keys.thumbnail.is_some(),
+            poster = keys.poster.is_some(),
+            depth_heatmap = keys.depth_heatmap.is_some(),
+            "Media derivatives generated"
+        );
+    });
+}
+
+/// Renders and uploads every derivative the job can produce
+async fn generate(
+    storage: &StorageService,
+    ffmpeg_path: &str,
+    capture_id: Uuid,
+    job: DerivativeJob,
+) -> DerivativeKeys {
+    let mut keys = DerivativeKeys::default();
+
+    let (thumbnail_source, data_key) = match job {
+        DerivativeJob::Photo {
+            photo_key,
+            depth_key,
+            depth_dimensions: (width, height),
+            data_key,
+        } => {
+            keys.depth_heatmap = render_and_upload(
+                storage,
+                capture_id,
+                DerivativeKind::DepthHeatmap,
+                &depth_key,
+                data_key.as_ref(),
+                move |bytes| render_depth_heatmap(&bytes, width, height),
+            )
+            .await;
+            (download(storage, capture_id, &photo_key).await, data_key)
+        }
+        DerivativeJob::Video {
+            video_key,
+            data_key,
+        } => {
+            let Some(video) = download(storage, capture_id, &video_key).await else {
+                return keys;
+            };
+            let poster = match extract_poster_frame(&video, ffmpeg_path).await {
+                Ok(poster) => {
+                    keys.poster = upload(
+                        storage,
+                        capture_id,
+                        DerivativeKind::Poster,
+                        poster.clone(),
+                        data_key.as_ref(),
+                    )
+                    .await;
+                    Some(poster)
+                }
+                Err(e) => {
+                    warn!(capture_id = %capture_id, error = %e, "Skipping video poster frame");
+                    None
+                }
+            };
+            (poster, data_key)
+        }
+    };
+
+    let Some(source) = thumbnail_source else {
+        return keys;
+    };
+
+    match tokio::task::spawn_blocking(move || render_thumbnails(&source)).await {
+        Ok(Ok(thumbnails)) => {
+            keys.thumbnail = upload(
+                storage,
+                capture_id,
+                DerivativeKind::ThumbnailJpeg,
+                thumbnails.jpeg,
+                data_key.as_ref(),
+            )
+            .await;
+            keys.thumbnail_webp = upload(
+                storage,
+                capture_id,
+                DerivativeKind::ThumbnailWebp,
+                thumbnails.webp,
+                data_key.as_ref(),
+            )
+            .await;
+        }
+        Ok(Err(e)) => warn!(capture_id = %capture_id, error = %e, "Thumbnail rendering failed"),
+        Err(e) => warn!(capture_id = %capture_id, error = %e, "Thumbnail task panicked"),
+    }
+
+    keys
+}
+
+/// Downloads a source file, logging failures
+async fn download(storage: &StorageService, capture_id: Uuid, key: &str) -> Option<Vec<u8>> {
+    match storage.download(key).await {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            warn!(capture_id = %capture_id, key = %key, error = %e, "Derivative source download failed");
+            None
+        }
+    }
+}
+
+/// Uploads an encoded derivative, logging failures
+async fn upload(
+    storage: &StorageService,
+    capture_id: Uuid,
+    kind: DerivativeKind,
+    bytes: Vec<u8>,
+    data_key: Option<&DataKey>,
+) -> Option<String> {
+    match storage
+        .upload_derivative(capture_id, kind, bytes, data_key)
+        .await
+    {
+        Ok(key) => Some(key),
+        Err(e) => {
+            warn!(capture_id = %capture_id, kind = ?kind, error = %e, "Derivative upload failed");
+            None
+        }
+    }
+}
+
+/// Downloads `source_key`, renders it on the blocking pool and uploads the result
+async fn render_and_upload<F>(
+    storage: &StorageService,
+    capture_id: Uuid,
+    kind: DerivativeKind,
+    source_key: &str,
+    data_key: Option<&DataKey>,
+    render: F,
+) -> Option<String>
+where
+    F: FnOnce(Vec<u8>) -> Result<Vec<u8>, MediaDerivativeError> + Send + 'static,
+{
+    let source = download(storage, capture_id, source_key).await?;
+    match tokio::task::spawn_blocking(move || render(source)).await {
+        Ok(Ok(bytes)) => upload(storage, capture_id, kind, bytes, data_key).await,
+        Ok(Err(e)) => {
+            warn!(capture_id = %capture_id, kind = ?kind, error = %e, "Derivative rendering failed");
+            None
+        }
+        Err(e) => {
+            warn!(capture_id = %capture_id, kind = ?kind, error = %e, "Derivative task panicked");
+            None
+        }
+    }
+}
+
+/// Records generated keys, keeping any previously stored value for gaps
+///
+/// Returns false if the capture no longer exists.
+async fn store_keys(
+    db: &PgPool,
+    capture_id: Uuid,
+    keys: &DerivativeKeys,
+) -> Result<bool, sqlx::Error> {
+    // NOTE: Using runtime query to avoid SQLX_OFFLINE cache issues when schema changes
+    let result = sqlx::query(
+        r#"
+        UPDATE captures
+        SET thumbnail_s3_key = COALESCE($2, thumbnail_s3_key),
+            thumbnail_webp_s3_key = COALESCE($3, thumbnail_webp_s3_key),
+            poster_s3_key = COALESCE($4, poster_s3_key),
+            depth_heatmap_s3_key = COALESCE($5, depth_heatmap_s3_key)
+        WHERE id = $1
+        "#,
+    )
+    .bind(capture_id)
+    .bind(&keys.thumbnail)
+    .bind(&keys.thumbnail_webp)
+    .bind(&keys.poster)
+    .bind(&keys.depth_heatmap)
+    .execute(db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+    debug!(capture_id = %capture_id, "Derivative keys stored");
+    Ok(true)
+}
+
+/// Deletes uploaded derivatives of a capture that no longer exists
+async fn discard(storage: &StorageService, capture_id: Uuid, keys: &DerivativeKeys) {
+    for key in [
+        &keys.thumbnail,
+        &keys.thumbnail_webp,
+        &keys.poster,
+        &keys.depth_heatmap,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if let Err(e) = storage.delete(key).await {
+            warn!(capture_id = %capture_id, key = %key, error = %e, "Failed to delete orphaned derivative");
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApiError;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use image::{ImageFormat, RgbImage};
+    use std::io::{Cursor, Write};
+
+    fn encoded_photo(width: u32, height: u32) -> Vec<u8> {
+        let img = RgbImage::from_fn(width, height, |x, y| image::Rgb([x as u8, y as u8, 128]));
+        let mut bytes = Vec::new();
+        DynamicImage::ImageRgb8(img)
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Jpeg)
+            .unwrap();
+        bytes
+    }
+
+    fn gzipped_depths(depths: &[f32]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        for d in depths {
+            encoder.write_all(&d.to_le_bytes()).unwrap();
+        }
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_thumbnails_fit_max_edge_and_keep_aspect() {
+        let thumbs = render_thumbnails(&encoded_photo(1920, 1080)).unwrap();
+
+        assert_eq!(&thumbs.jpeg[..3], &[0xFF, 0xD8, 0xFF]);
+        assert_eq!(&thumbs.webp[..4], b"RIFF");
+        assert_eq!(&thumbs.webp[8..12], b"WEBP");
+
+        let jpeg = image::load_from_memory(&thumbs.jpeg).unwrap();
+        assert_eq!(jpeg.width(), THUMBNAIL_MAX_EDGE);
+        assert_eq!(jpeg.height(), 270);
+
+        let webp = image::load_from_memory(&thumbs.webp).unwrap();
+        assert_eq!((webp.width(), webp.height()), (jpeg.width(), jpeg.height()));
+    }
+
+    #[test]
+    fn test_thumbnails_do_not_upscale_small_images() {
+        let thumbs = render_thumbnails(&encoded_photo(200, 100)).unwrap();
+        let jpeg = image::load_from_memory(&thumbs.jpeg).unwrap();
+        assert_eq!((jpeg.width(), jpeg.height()), (200, 100));
+    }
+
+    #[test]
+    fn test_thumbnails_reject_garbage() {
+        assert!(matches!(
+            render_thumbnails(b"not an image"),
+            Err(MediaDerivativeError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_depth_heatmap_colours_and_transparency() {
+        // near, far, invalid (too close), NaN
+        let png = render_depth_heatmap(&gzipped_depths(&[0.5, 5.0, 0.01, f32::NAN]), 2, 2).unwrap();
+
+        let img = image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(img.dimensions(), (2, 2));
+
+        let near = img.get_pixel(0, 0);
+        let far = img.get_pixel(1, 0);
+        assert_eq!(near[3], 255);
+        assert_eq!(far[3], 255);
+        assert!(near[0] > near[2], "near should be warm: {near:?}");
+        assert!(far[2] > far[0], "far should be cool: {far:?}");
+        assert_eq!(img.get_pixel(0, 1)[3], 0);
+        assert_eq!(img.get_pixel(1, 1)[3], 0);
+    }
+
+    #[test]
+    fn test_depth_heatmap_rejects_dimension_mismatch() {
+        let result = render_depth_heatmap(&gzipped_depths(&[1.0, 2.0, 3.0]), 2, 2);
+        assert!(matches!(result, Err(MediaDerivativeError::DepthMap(_))));
+    }
+
+    #[test]
+    fn test_depth_heatmap_rejects_all_invalid() {
+        let result = render_depth_heatmap(&gzipped_depths(&[0.0, f32::NAN]), 2, 1);
+        assert!(matches!(result, Err(MediaDerivativeError::DepthMap(_))));
+    }
+
+    #[tokio::test]
+    async fn test_poster_frame_missing_ffmpeg_is_an_error() {
+        let result = extract_poster_frame(b"video", "/nonexistent/ffmpeg").await;
+        assert!(matches!(result, Err(MediaDerivativeError::Poster(_))));
+    }
+
+    #[tokio::test]
+    async fn test_generate_photo_derivatives_in_memory() {
+        let storage = StorageService::in_memory();
+        let capture_id = Uuid::new_v4();
+        let (photo_key, depth_key) = storage
+            .upload_capture_files(
+                capture_id,
+                encoded_photo(640, 480),
+                gzipped_depths(&[0.5, 1.0, 2.0, 4.0]),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let keys = generate(
+            &storage,
+            "ffmpeg",
+            capture_id,
+            DerivativeJob::Photo {
+                photo_key,
+                depth_key,
+                depth_dimensions: (2, 2),
+                data_key: None,
+            },
+        )
+        .await;
+
+        assert_eq!(
+            keys.thumbnail.as_deref(),
+            Some(DerivativeKind::ThumbnailJpeg.s3_key(capture_id).as_str())
+        );
+        assert!(keys.thumbnail_webp.is_some());
+        assert_eq!(
+            keys.depth_heatmap,
+            Some(DerivativeKind::DepthHeatmap.s3_key(capture_id))
+        );
+        assert!(storage
+            .download(&DerivativeKind::ThumbnailWebp.s3_key(capture_id))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_discard_removes_uploaded_derivatives() {
+        let storage = StorageService::in_memory();
+        let capture_id = Uuid::new_v4();
+        let thumbnails = render_thumbnails(&encoded_photo(64, 48)).unwrap();
+        let keys = DerivativeKeys {
+            thumbnail: upload(
+                &storage,
+                capture_id,
+                DerivativeKind::ThumbnailJpeg,
+                thumbnails.jpeg,
+                None,
+            )
+            .await,
+            thumbnail_webp: upload(
+                &storage,
+                capture_id,
+                DerivativeKind::ThumbnailWebp,
+                thumbnails.webp,
+                None,
+            )
+            .await,
+            ..DerivativeKeys::default()
+        };
+
+        discard(&storage, capture_id, &keys).await;
+
+        for kind in [DerivativeKind::ThumbnailJpeg, DerivativeKind::ThumbnailWebp] {
+            assert!(matches!(
+                storage.download(&kind.s3_key(capture_id)).await,
+                Err(ApiError::CaptureNotFound)
+            ));
+        }
+    }
+}
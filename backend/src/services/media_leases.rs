@@ -0,0 +1,70 @@
+//! Media Key Leases
+//!
+//! Content-addressed media may be shared by several captures, so deleting a
+//! capture only removes an object nothing else references (see `retention`).
+//! An upload leases each key before it checks for or writes the object, so a
+//! concurrent deletion keeps the object until the upload's capture row
+//! exists. Both sides take a transaction-scoped advisory lock on the key.
+//!
+//! ## Lifetime
+//! Leases are held per upload (`capture_id`) and released once the capture
+//! row commits. Uploads that fail before then leave their leases to expire
+//! after `MEDIA_KEY_LEASE_SECS`; the retention sweeper purges expired rows.
+
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// How long a media key lease outlives the upload that took it (1 hour)
+const MEDIA_KEY_LEASE_SECS: i32 = 3600;
+
+/// Leases a content-addressed media key for an upload in progress
+///
+/// Uploads call this before checking for or writing the object. The lease
+/// keeps deletion from removing the object until `release_media_keys` is
+/// called for `capture_id`, or `MEDIA_KEY_LEASE_SECS` have passed.
+pub async fn lease_media_key(db: &PgPool, key: &str, capture_id: Uuid) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+    lock_media_key(&mut tx, key).await?;
+    sqlx::query(
+        r#"
+        INSERT INTO media_key_leases (key, capture_id, expires_at)
+        VALUES ($1, $2, NOW() + make_interval(secs => $3))
+        ON CONFLICT (key, capture_id) DO UPDATE SET expires_at = EXCLUDED.expires_at
+        "#,
+    )
+    .bind(key)
+    .bind(capture_id)
+    .bind(MEDIA_KEY_LEASE_SECS)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await
+}
+
+/// Releases every lease held by an upload
+///
+/// Call once the capture row has committed; from then on the row itself
+/// keeps the objects from being deleted.
+pub async fn release_media_keys(db: &PgPool, capture_id: Uuid) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM media_key_leases WHERE capture_id = $1")
+        .bind(capture_id)
+        .execute(db)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Deletes leases left behind by uploads that never released them
+pub async fn purge_expired_leases(db: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM media_key_leases WHERE expires_at < NOW()")
+        .execute(db)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Takes the transaction-scoped advisory lock for a media key
+pub(crate) async fn lock_media_key(conn: &mut PgConnection, key: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+        .bind(key)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
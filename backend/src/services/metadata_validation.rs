@@ -5,20 +5,25 @@
 //!
 //! ## Validation Checks
 //! 1. Timestamp: Within 15 minutes of server time
-//! 2. Device Model: iPhone Pro whitelist (LiDAR-capable)
+//! 2. Device Model: Known in the device capability registry with an active
+//!    depth sensor (LiDAR or ToF)
 //! 3. Location: Valid GPS coordinate bounds
-//! 4. Resolution: Known LiDAR depth map dimensions
+//! 4. Resolution: Expected depth map dimensions for the device model, or known
+//!    LiDAR formats when the model is not in the registry
+//! 5. Model identity: Claimed model against the registered device and any
+//!    attestation-derived model (`check_model_identity`)
 //!
 //! ## Thresholds (from Epic 4 Tech Spec AC-4.6)
 //! - Timestamp window: 15 minutes (900 seconds)
 //! - Valid latitude: -90 to 90
 //! - Valid longitude: -180 to 180
-//! - Valid resolutions: 256x192, 320x240, 640x480 (+/- 10px tolerance)
+//! - Fallback resolutions: 256x192, 320x240, 640x480 (+/- 10px tolerance)
 
 use chrono::{DateTime, Utc};
 use tracing::{debug, info};
 
 use crate::models::MetadataEvidence;
+use crate::services::device_registry::{DeviceCapability, DeviceRegistry};
 use crate::types::capture::{CaptureLocation, CaptureMetadataPayload};
 
 // ============================================================================
@@ -31,7 +36,8 @@ const TIMESTAMP_WINDOW_SECONDS: i64 = 900;
 /// Resolution tolerance in pixels (allow +/- 10 pixels per dimension)
 const RESOLUTION_TOLERANCE: u32 = 10;
 
-/// Known LiDAR depth map resolutions (width, height)
+/// Known LiDAR depth map resolutions (width, height), used for models not in
+/// the device capability registry
 const VALID_RESOLUTIONS: &[(u32, u32)] = &[
     (256, 192), // iPhone Pro LiDAR standard
     (320, 240), // QVGA
@@ -39,23 +45,6 @@ const VALID_RESOLUTIONS: &[(u32, u32)] = &[
     (384, 288), // Alternative resolution
 ];
 
-/// iPhone Pro models with LiDAR capability
-/// Using lowercase for case-insensitive matching
-const IPHONE_PRO_WHITELIST: &[&str] = &[
-    "iphone 12 pro",
-    "iphone 12 pro max",
-    "iphone 13 pro",
-    "iphone 13 pro max",
-    "iphone 14 pro",
-    "iphone 14 pro max",
-    "iphone 15 pro",
-    "iphone 15 pro max",
-    "iphone 16 pro",
-    "iphone 16 pro max",
-    "iphone 17 pro",     // Future-proofing
-    "iphone 17 pro max", // Future-proofing
-];
-
 // ============================================================================
 // Validation Result Types
 // ============================================================================
@@ -72,12 +61,32 @@ pub struct TimestampValidation {
 /// Result of device model verification
 #[derive(Debug, Clone)]
 pub struct ModelVerification {
-    /// Whether the model is in the Pro whitelist
+    /// Whether the model is registered with an active depth sensor
     pub is_verified: bool,
     /// The model name (cleaned up)
     pub model_name: String,
 }
 
+/// Registered identity of the uploading device
+#[derive(Debug, Clone, Copy)]
+pub struct RegisteredIdentity<'a> {
+    /// Device platform ("ios" or "android")
+    pub platform: &'a str,
+    /// Model claimed at registration
+    pub model: &'a str,
+    /// Model from hardware-enforced ID attestation, if any
+    pub attested_model: Option<&'a str>,
+}
+
+/// Result of cross-checking the capture model against the registered device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelIdentityCheck {
+    /// Whether the claimed model matches the attested model
+    pub attested: bool,
+    /// Whether the claimed model contradicts the registered or attested model
+    pub mismatch: bool,
+}
+
 /// Result of location validation
 #[derive(Debug, Clone)]
 pub struct LocationValidation {
@@ -123,32 +132,28 @@ pub fn validate_timestamp(
     }
 }
 
-/// Verifies the device model against iPhone Pro whitelist
+/// Verifies the device model against the device capability registry
 ///
-/// Uses case-insensitive partial matching to handle various formats:
+/// A model is verified when the registry knows it and its depth data comes
+/// from an active sensor (LiDAR or ToF). Hardware identifiers ("iPhone16,1")
+/// and marketing names in various formats are accepted:
 /// - "iPhone 15 Pro"
 /// - "iPhone15Pro"
 /// - "Apple iPhone 15 Pro Max"
 ///
 /// # Arguments
 /// * `model` - Device model string from metadata
+/// * `registry` - Device capability registry
 ///
 /// # Returns
 /// ModelVerification with verification result and cleaned model name
-pub fn verify_device_model(model: &str) -> ModelVerification {
-    let model_lower = model.to_lowercase();
-
-    // Check if any whitelist entry is contained in the model string
-    let is_verified = IPHONE_PRO_WHITELIST.iter().any(|&whitelist_model| {
-        // Remove spaces for flexible matching
-        let model_normalized = model_lower.replace(' ', "");
-        let whitelist_normalized = whitelist_model.replace(' ', "");
-
-        model_normalized.contains(&whitelist_normalized)
-    });
+pub fn verify_device_model(model: &str, registry: &DeviceRegistry) -> ModelVerification {
+    let capability = registry.lookup(model);
+    let is_verified = capability.is_some_and(|device| device.depth_sensor.is_active_sensor());
 
     debug!(
         model = %model,
+        registered_as = capability.map(|device| device.marketing_name.as_str()),
         is_verified = is_verified,
         "[metadata_validation] Device model verified"
     );
@@ -198,19 +203,25 @@ pub fn validate_location(location: Option<&CaptureLocation>) -> LocationValidati
     }
 }
 
-/// Validates depth map resolution against known LiDAR formats
+/// Validates depth map resolution against the device's expected formats
 ///
-/// Allows tolerance of +/- 10 pixels per dimension to handle
-/// device-specific variations.
+/// Registered devices are checked against their own depth resolutions, so a
+/// device without a depth sensor can never report a valid depth map. Models
+/// not in the registry fall back to the known LiDAR formats. Allows tolerance
+/// of +/- 10 pixels per dimension to handle device-specific variations.
 ///
 /// # Arguments
 /// * `width` - Depth map width in pixels
 /// * `height` - Depth map height in pixels
+/// * `capability` - Registry entry for the device model, if known
 ///
 /// # Returns
-/// true if resolution matches a known format (within tolerance)
-pub fn validate_resolution(width: u32, height: u32) -> bool {
-    let is_valid = VALID_RESOLUTIONS.iter().any(|&(valid_w, valid_h)| {
+/// true if resolution matches an expected format (within tolerance)
+pub fn validate_resolution(width: u32, height: u32, capability: Option<&DeviceCapability>) -> bool {
+    let expected = capability.map_or(VALID_RESOLUTIONS, |device| {
+        device.depth_resolutions.as_slice()
+    });
+    let is_valid = expected.iter().any(|&(valid_w, valid_h)| {
         let width_match = width.abs_diff(valid_w) <= RESOLUTION_TOLERANCE;
         let height_match = height.abs_diff(valid_h) <= RESOLUTION_TOLERANCE;
         width_match && height_match
@@ -226,6 +237,50 @@ pub fn validate_resolution(width: u32, height: u32) -> bool {
     is_valid
 }
 
+/// Cross-checks the capture's claimed model against the registered device
+///
+/// Models are compared through the device capability registry, so a
+/// marketing name and its hardware identifier are the same model.
+///
+/// # Arguments
+/// * `claimed` - Device model string from capture metadata
+/// * `registered` - Registered identity of the uploading device
+/// * `registry` - Device capability registry
+///
+/// # Returns
+/// ModelIdentityCheck with attestation match and mismatch flags
+pub fn check_model_identity(
+    claimed: &str,
+    registered: &RegisteredIdentity<'_>,
+    registry: &DeviceRegistry,
+) -> ModelIdentityCheck {
+    let same = |other: &str| registry.same_model(registered.platform, claimed, other);
+    let attested = registered.attested_model.is_some_and(same);
+    let mismatch = !same(registered.model) || (registered.attested_model.is_some() && !attested);
+
+    debug!(
+        claimed = %claimed,
+        registered_model = %registered.model,
+        attested_model = ?registered.attested_model,
+        attested = attested,
+        mismatch = mismatch,
+        "[metadata_validation] Model identity cross-checked"
+    );
+
+    ModelIdentityCheck { attested, mismatch }
+}
+
+/// Records a model identity check in metadata evidence
+///
+/// A mismatching model is never reported as verified.
+pub fn apply_model_identity(evidence: &mut MetadataEvidence, check: ModelIdentityCheck) {
+    evidence.model_attested = check.attested;
+    evidence.model_mismatch = check.mismatch;
+    if check.mismatch {
+        evidence.model_verified = false;
+    }
+}
+
 // ============================================================================
 // Main Validation Orchestrator
 // ============================================================================
@@ -234,16 +289,20 @@ pub fn validate_resolution(width: u32, height: u32) -> bool {
 ///
 /// This is the main entry point for metadata validation. It:
 /// 1. Validates timestamp against server time
-/// 2. Verifies device model against whitelist
+/// 2. Verifies device model against the device capability registry
 /// 3. Validates location coordinates
 /// 4. Validates depth map resolution
 ///
 /// # Arguments
 /// * `metadata` - Capture metadata payload from upload
+/// * `registry` - Device capability registry
 ///
 /// # Returns
 /// MetadataEvidence struct with all validation results
-pub fn validate_metadata(metadata: &CaptureMetadataPayload) -> MetadataEvidence {
+pub fn validate_metadata(
+    metadata: &CaptureMetadataPayload,
+    registry: &DeviceRegistry,
+) -> MetadataEvidence {
     let server_time = Utc::now();
 
     info!(
@@ -267,7 +326,7 @@ pub fn validate_metadata(metadata: &CaptureMetadataPayload) -> MetadataEvidence
     };
 
     // Verify device model
-    let model_result = verify_device_model(&metadata.device_model);
+    let model_result = verify_device_model(&metadata.device_model, registry);
 
     // Validate location
     let location_result = validate_location(metadata.location.as_ref());
@@ -276,6 +335,7 @@ pub fn validate_metadata(metadata: &CaptureMetadataPayload) -> MetadataEvidence
     let resolution_valid = validate_resolution(
         metadata.depth_map_dimensions.width,
         metadata.depth_map_dimensions.height,
+        registry.lookup(&metadata.device_model),
     );
 
     info!(
@@ -291,13 +351,20 @@ pub fn validate_metadata(metadata: &CaptureMetadataPayload) -> MetadataEvidence
 
     MetadataEvidence {
         timestamp_valid: timestamp_result.is_valid,
+        timestamp_consistency: None, // Set by check_timestamp_consistency
         timestamp_delta_seconds: timestamp_result.delta_seconds,
         model_verified: model_result.is_verified,
         model_name: model_result.model_name,
+        model_attested: false, // Set by check_model_identity
+        model_mismatch: false,
         resolution_valid,
         location_available: location_result.is_available,
         location_opted_out: location_result.opted_out,
+        location_plausible: None,
+        location_plausibility_reason: None,
         location_coarse: None, // Set by privacy controls (Story 4-8)
+        location_privacy: None,
+        location_label: None,
     }
 }
 
@@ -311,6 +378,10 @@ mod tests {
     use crate::types::capture::DepthMapDimensions;
     use chrono::Duration;
 
+    fn registry() -> DeviceRegistry {
+        DeviceRegistry::bundled().unwrap()
+    }
+
     // ========================================================================
     // Timestamp Validation Tests
     // ========================================================================
@@ -371,38 +442,38 @@ mod tests {
 
     #[test]
     fn test_model_verified_exact_match() {
-        let result = verify_device_model("iPhone 15 Pro");
+        let result = verify_device_model("iPhone 15 Pro", &registry());
         assert!(result.is_verified);
         assert_eq!(result.model_name, "iPhone 15 Pro");
     }
 
     #[test]
     fn test_model_verified_case_insensitive() {
-        let result = verify_device_model("IPHONE 15 PRO MAX");
+        let result = verify_device_model("IPHONE 15 PRO MAX", &registry());
         assert!(result.is_verified);
     }
 
     #[test]
     fn test_model_verified_with_prefix() {
-        let result = verify_device_model("Apple iPhone 15 Pro");
+        let result = verify_device_model("Apple iPhone 15 Pro", &registry());
         assert!(result.is_verified);
     }
 
     #[test]
     fn test_model_verified_no_spaces() {
-        let result = verify_device_model("iPhone15Pro");
+        let result = verify_device_model("iPhone15Pro", &registry());
         assert!(result.is_verified);
     }
 
     #[test]
     fn test_model_not_verified_regular_iphone() {
-        let result = verify_device_model("iPhone 15");
+        let result = verify_device_model("iPhone 15", &registry());
         assert!(!result.is_verified);
     }
 
     #[test]
     fn test_model_not_verified_ipad() {
-        let result = verify_device_model("iPad Pro");
+        let result = verify_device_model("iPad Pro", &registry());
         assert!(!result.is_verified);
     }
 
@@ -422,11 +493,25 @@ mod tests {
         ];
 
         for model in models {
-            let result = verify_device_model(model);
+            let result = verify_device_model(model, &registry());
             assert!(result.is_verified, "Model {model} should be verified");
         }
     }
 
+    #[test]
+    fn test_model_verified_hardware_identifier() {
+        let result = verify_device_model("iPhone16,1", &registry());
+        assert!(result.is_verified);
+        assert_eq!(result.model_name, "iPhone16,1");
+    }
+
+    #[test]
+    fn test_model_verified_android_tof() {
+        assert!(verify_device_model("SM-G988B", &registry()).is_verified);
+        // ARCore software depth is not an active sensor
+        assert!(!verify_device_model("Pixel 8 Pro", &registry()).is_verified);
+    }
+
     // ========================================================================
     // Location Validation Tests
     // ========================================================================
@@ -525,37 +610,103 @@ mod tests {
 
     #[test]
     fn test_resolution_valid_iphone_standard() {
-        assert!(validate_resolution(256, 192));
+        assert!(validate_resolution(256, 192, None));
     }
 
     #[test]
     fn test_resolution_valid_qvga() {
-        assert!(validate_resolution(320, 240));
+        assert!(validate_resolution(320, 240, None));
     }
 
     #[test]
     fn test_resolution_valid_vga() {
-        assert!(validate_resolution(640, 480));
+        assert!(validate_resolution(640, 480, None));
     }
 
     #[test]
     fn test_resolution_valid_with_tolerance() {
         // Within 10 pixel tolerance
-        assert!(validate_resolution(260, 196)); // 256+4, 192+4
-        assert!(validate_resolution(250, 186)); // 256-6, 192-6
+        assert!(validate_resolution(260, 196, None)); // 256+4, 192+4
+        assert!(validate_resolution(250, 186, None)); // 256-6, 192-6
     }
 
     #[test]
     fn test_resolution_invalid_outside_tolerance() {
         // Outside 10 pixel tolerance
-        assert!(!validate_resolution(256 + 11, 192));
-        assert!(!validate_resolution(256, 192 + 11));
+        assert!(!validate_resolution(256 + 11, 192, None));
+        assert!(!validate_resolution(256, 192 + 11, None));
     }
 
     #[test]
     fn test_resolution_invalid_unknown() {
-        assert!(!validate_resolution(100, 100));
-        assert!(!validate_resolution(1920, 1080));
+        assert!(!validate_resolution(100, 100, None));
+        assert!(!validate_resolution(1920, 1080, None));
+    }
+
+    #[test]
+    fn test_resolution_uses_registered_formats() {
+        let registry = registry();
+        let pro = registry.lookup("iPhone 15 Pro");
+        assert!(validate_resolution(256, 192, pro));
+
+        // Non-LiDAR iPhone cannot produce a LiDAR depth map
+        let base = registry.lookup("iPhone 15");
+        assert!(!validate_resolution(256, 192, base));
+        assert!(validate_resolution(640, 480, base));
+
+        // No depth source at all
+        let se = registry.lookup("iPhone14,6");
+        assert!(!validate_resolution(640, 480, se));
+    }
+
+    // ========================================================================
+    // Model Identity Tests
+    // ========================================================================
+
+    #[test]
+    fn test_model_identity_matches_registered() {
+        let registered = RegisteredIdentity {
+            platform: "ios",
+            model: "iPhone 15 Pro",
+            attested_model: None,
+        };
+        let check = check_model_identity("iPhone16,1", &registered, &registry());
+        assert_eq!(
+            check,
+            ModelIdentityCheck {
+                attested: false,
+                mismatch: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_model_identity_attested_android() {
+        let registered = RegisteredIdentity {
+            platform: "android",
+            model: "Galaxy S24 Ultra",
+            attested_model: Some("SM-S928B"),
+        };
+        let check = check_model_identity("Galaxy S24 Ultra", &registered, &registry());
+        assert!(check.attested);
+        assert!(!check.mismatch);
+    }
+
+    #[test]
+    fn test_model_identity_mismatch_clears_verified() {
+        let registered = RegisteredIdentity {
+            platform: "ios",
+            model: "iPhone 15",
+            attested_model: None,
+        };
+        let check = check_model_identity("iPhone 15 Pro", &registered, &registry());
+        assert!(check.mismatch);
+
+        let mut evidence = validate_metadata(&create_valid_metadata(), &registry());
+        assert!(evidence.model_verified);
+        apply_model_identity(&mut evidence, check);
+        assert!(evidence.model_mismatch);
+        assert!(!evidence.model_verified);
     }
 
     // ========================================================================
@@ -580,13 +731,14 @@ mod tests {
                 altitude: None,
                 accuracy: None,
             }),
+            location_privacy: None,
         }
     }
 
     #[test]
     fn test_full_validation_all_valid() {
         let metadata = create_valid_metadata();
-        let result = validate_metadata(&metadata);
+        let result = validate_metadata(&metadata, &registry());
 
         assert!(result.timestamp_valid);
         assert!(result.model_verified);
@@ -600,7 +752,7 @@ mod tests {
     fn test_full_validation_no_location() {
         let mut metadata = create_valid_metadata();
         metadata.location = None;
-        let result = validate_metadata(&metadata);
+        let result = validate_metadata(&metadata, &registry());
 
         assert!(result.timestamp_valid);
         assert!(result.model_verified);
@@ -613,7 +765,7 @@ mod tests {
     fn test_full_validation_unverified_model() {
         let mut metadata = create_valid_metadata();
         metadata.device_model = "Samsung Galaxy".to_string();
-        let result = validate_metadata(&metadata);
+        let result = validate_metadata(&metadata, &registry());
 
         assert!(result.timestamp_valid);
         assert!(!result.model_verified);
@@ -628,7 +780,7 @@ mod tests {
             width: 100,
             height: 100,
         };
-        let result = validate_metadata(&metadata);
+        let result = validate_metadata(&metadata, &registry());
 
         assert!(result.timestamp_valid);
         assert!(result.model_verified);
@@ -640,7 +792,7 @@ mod tests {
         let mut metadata = create_valid_metadata();
         // Set timestamp to 1 hour ago (outside 15 min window)
         metadata.captured_at = (Utc::now() - Duration::hours(1)).to_rfc3339();
-        let result = validate_metadata(&metadata);
+        let result = validate_metadata(&metadata, &registry());
 
         assert!(!result.timestamp_valid);
         assert!(result.timestamp_delta_seconds > 3500); // ~1 hour in seconds
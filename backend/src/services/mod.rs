@@ -2,20 +2,40 @@
 //!
 //! This module contains business logic services that are used by route handlers.
 
+pub mod analysis_plausibility;
 pub mod android_attestation;
 pub mod attestation;
+pub mod blob_store;
 pub mod c2pa;
 pub mod capture_attestation;
 pub mod challenge_store;
+pub mod confidence_policy;
+pub mod debug_log_layer;
 pub mod debug_logs;
 pub mod depth_analysis;
+pub mod detection_scoring;
+pub mod device_registry;
+pub mod envelope;
+pub mod gazetteer;
 pub mod hash_chain_verifier;
+pub mod integrity_scrubber;
+pub mod key_rotation;
+pub mod location_plausibility;
+pub mod media_derivatives;
+pub mod media_leases;
 pub mod metadata_validation;
+pub mod moire_detection;
 pub mod privacy;
+pub mod retention;
 pub mod storage;
+pub mod timestamp_consistency;
+pub mod verification_log;
 pub mod video_depth_analysis;
 pub mod video_evidence;
 
+pub use analysis_plausibility::{
+    check_depth_plausibility, load_model_depth_history, ModelDepthHistory, PlausibilityReport,
+};
 pub use android_attestation::{
     parse_certificate_chain as parse_android_certificate_chain, parse_key_attestation_extension,
     validate_challenge as validate_android_challenge, validate_security_level,
@@ -35,13 +55,41 @@ pub use c2pa::{
 };
 pub use capture_attestation::{
     compute_hash_only_client_data_hash, verify_capture_assertion, verify_hash_only_assertion,
-    CaptureAssertionError, CaptureAssertionResult,
+    verify_hash_only_checkpoint_assertions, verify_hash_only_final_hash_binding,
+    verify_video_assertion, CaptureAssertionError, CaptureAssertionResult,
 };
 pub use challenge_store::{ChallengeEntry, ChallengeError, ChallengeStore};
+pub use confidence_policy::{ConfidencePolicies, ConfidencePolicyError, PolicyEvaluation};
+pub use debug_log_layer::{DebugLogLayer, DebugLogWriter};
 pub use depth_analysis::{analyze_depth_map, analyze_depth_map_from_bytes};
-pub use hash_chain_verifier::HashChainVerifier;
-pub use metadata_validation::validate_metadata;
-pub use privacy::process_location_for_evidence;
-pub use storage::{depth_map_s3_key, photo_s3_key, StorageService};
+pub use detection_scoring::{DetectionPolicy, DetectionPolicyError};
+pub use device_registry::{DeviceRegistry, DeviceRegistryError};
+pub use envelope::{DataKey, Envelope, EnvelopeError, KeyWrapper, LocalKeyring, WrappedKey};
+pub use gazetteer::{Gazetteer, GazetteerError};
+pub use hash_chain_verifier::{compute_genesis as compute_hash_chain_genesis, HashChainVerifier};
+pub use integrity_scrubber::{IntegrityScrubber, IntegrityStatus};
+pub use key_rotation::{KeyRotationReport, KeyRotator};
+pub use location_plausibility::{check_device_location, coordinate_literal_digits};
+pub use media_derivatives::{spawn_derivatives, DerivativeJob};
+pub use metadata_validation::{
+    apply_model_identity, check_model_identity, validate_metadata, RegisteredIdentity,
+};
+pub use moire_detection::{analyze_photo_moire, compare_client_moire, server_detection_flags};
+pub use privacy::{
+    process_location_for_evidence, protect_location, verify_metadata_disclosure,
+    LocationPrivacyBounds,
+};
+pub use retention::{
+    delete_capture, find_tombstone_by_hash, DeletedBy, RetentionPolicies, RetentionPolicyError,
+    RetentionSweeper, Tombstone,
+};
+pub use storage::{
+    content_addressed_key, sha256_digest, DerivativeKind, MediaAsset, MediaUrl, StorageService,
+};
+pub use timestamp_consistency::check_device_timestamp;
+pub use verification_log::{
+    capture_verification_summary, verification_stats, CaptureVerificationSummary, IpLogMode,
+    NewVerificationLog, VerificationAction, VerificationLogger, VerificationStats,
+};
 pub use video_depth_analysis::VideoDepthAnalysisService;
 pub use video_evidence::{VideoEvidenceConfig, VideoEvidenceService};
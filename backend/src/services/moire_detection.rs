@@ -0,0 +1,601 @@
+//! Server-side Moire Detection Service
+//!
+//! The iOS client runs its own moire/halftone analysis, but its result is
+//! self-reported and cannot be trusted on its own. For full-mode captures the
+//! JPEG is uploaded, so the backend repeats the analysis independently and
+//! compares the two.
+//!
+//! ## Algorithm
+//! 1. Decode the JPEG to luma (IDCT-scaled towards `DECODE_TARGET_SIZE`)
+//! 2. Take a centered power-of-two square crop (at most `ANALYSIS_SIZE`)
+//! 3. Remove the mean, apply a 2D Hann window and run a 2D FFT
+//! 4. Search the mid/high frequency band for isolated spectral peaks.
+//!    Prominence is measured against the spectrum radially and tangentially
+//!    around the peak, so edges (radial streaks) and ordinary 1/f falloff do
+//!    not register. JPEG block harmonics are excluded.
+//! 5. Peaks at or above `PEAK_PROMINENCE_THRESHOLD` indicate a periodic
+//!    pattern (screen pixel grid or print halftone)
+//!
+//! Analysis is NON-BLOCKING: undecodable photos produce a `Failed` result.
+
+use std::f32::consts::PI;
+use std::time::Instant;
+
+use chrono::Utc;
+use jpeg_decoder::{Decoder, PixelFormat};
+use rustfft::{num_complex::Complex, FftPlanner};
+use tracing::debug;
+
+use crate::types::detection::{
+    ConfidenceFlag, DetectionResults, FrequencyPeak, MoireAnalysisResult, MoireAnalysisStatus,
+    ScreenType, ServerDetectionResults,
+};
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+/// Algorithm version recorded in server results (distinct from iOS versions)
+pub const SERVER_MOIRE_ALGORITHM_VERSION: &str = "server-fft-1.0";
+
+/// Side of the square region analyzed (power of two)
+const ANALYSIS_SIZE: usize = 512;
+
+/// Smallest crop worth analyzing
+const MIN_ANALYSIS_SIZE: usize = 64;
+
+/// Requested decode size; the decoder picks the smallest IDCT scale above it
+const DECODE_TARGET_SIZE: u16 = (ANALYSIS_SIZE * 2) as u16;
+
+/// Frequencies below this fraction of the crop size are scene content
+const MIN_RADIUS_FRACTION: f32 = 0.04;
+
+/// Frequencies above this fraction of Nyquist are too close to the edge
+const MAX_RADIUS_FRACTION: f32 = 0.95;
+
+/// Inner and outer distance (bins) of the background samples around a peak
+const BACKGROUND_INNER: i32 = 3;
+const BACKGROUND_OUTER: i32 = 6;
+
+/// Bins around a JPEG block harmonic that are ignored
+const BLOCK_HARMONIC_TOLERANCE: i32 = 2;
+
+/// Peak prominence above which a periodic pattern is reported
+const PEAK_PROMINENCE_THRESHOLD: f32 = 10.0;
+
+/// Peak prominence that maps to full confidence
+const STRONG_PROMINENCE: f32 = 40.0;
+
+/// Maximum peaks reported in the result
+const MAX_REPORTED_PEAKS: usize = 8;
+
+/// Angular tolerance for axis-aligned (pixel grid) peaks
+const AXIS_TOLERANCE_RAD: f32 = 5.0 * PI / 180.0;
+
+/// Relative frequency tolerance when pairing orthogonal halftone peaks
+const HALFTONE_FREQUENCY_TOLERANCE: f32 = 0.1;
+
+// ============================================================================
+// Photo Analysis
+// ============================================================================
+
+/// Luma plane of a decoded photo
+struct LumaImage {
+    width: usize,
+    height: usize,
+    pixels: Vec<f32>,
+    /// JPEG block period in decoded pixels (8 at full scale)
+    block_period: f32,
+}
+
+/// Analyzes an uploaded JPEG for moire and halftone patterns
+///
+/// # Arguments
+/// * `jpeg_bytes` - Raw photo bytes as uploaded
+///
+/// # Returns
+/// ServerDetectionResults with no client comparison yet
+pub fn analyze_photo_moire(jpeg_bytes: &[u8]) -> ServerDetectionResults {
+    let start = Instant::now();
+
+    let (moire, halftone_detected) = match decode_luma(jpeg_bytes) {
+        Ok(image) => analyze_luma(&image),
+        Err(status) => (empty_result(status), false),
+    };
+
+    ServerDetectionResults {
+        moire: MoireAnalysisResult {
+            analysis_time_ms: start.elapsed().as_millis() as i32,
+            ..moire
+        },
+        halftone_detected,
+        client_agreement: None,
+    }
+}
+
+/// Decodes a JPEG into a luma plane
+fn decode_luma(jpeg_bytes: &[u8]) -> Result<LumaImage, MoireAnalysisStatus> {
+    let mut decoder = Decoder::new(jpeg_bytes);
+    decoder.read_info().map_err(|e| {
+        debug!(error = %e, "[moire_detection] Failed to read JPEG header");
+        MoireAnalysisStatus::Failed
+    })?;
+    let original_width = decoder
+        .info()
+        .map(|info| info.width)
+        .ok_or(MoireAnalysisStatus::Failed)?;
+
+    let (width, height) = decoder
+        .scale(DECODE_TARGET_SIZE, DECODE_TARGET_SIZE)
+        .map_err(|_| MoireAnalysisStatus::Failed)?;
+    let data = decoder.decode().map_err(|e| {
+        debug!(error = %e, "[moire_detection] Failed to decode JPEG");
+        MoireAnalysisStatus::Failed
+    })?;
+    let format = decoder
+        .info()
+        .map(|info| info.pixel_format)
+        .ok_or(MoireAnalysisStatus::Failed)?;
+
+    let pixels: Vec<f32> = match format {
+        PixelFormat::L8 => data.iter().map(|&v| v as f32).collect(),
+        PixelFormat::L16 => data
+            .chunks_exact(2)
+            .map(|v| u16::from_be_bytes([v[0], v[1]]) as f32 / 257.0)
+            .collect(),
+        PixelFormat::RGB24 => data
+            .chunks_exact(3)
+            .map(|v| 0.299 * v[0] as f32 + 0.587 * v[1] as f32 + 0.114 * v[2] as f32)
+            .collect(),
+        PixelFormat::CMYK32 => return Err(MoireAnalysisStatus::Unavailable),
+    };
+
+    Ok(LumaImage {
+        width: width as usize,
+        height: height as usize,
+        pixels,
+        block_period: 8.0 * width as f32 / original_width.max(1) as f32,
+    })
+}
+
+/// Runs the spectral peak search on a luma plane
+///
+/// # Returns
+/// (moire result, halftone detected)
+fn analyze_luma(image: &LumaImage) -> (MoireAnalysisResult, bool) {
+    let side = image.width.min(image.height).min(ANALYSIS_SIZE);
+    if side < MIN_ANALYSIS_SIZE {
+        return (empty_result(MoireAnalysisStatus::Unavailable), false);
+    }
+    // Largest power of two that fits
+    let n = 1usize << (usize::BITS - 1 - side.leading_zeros());
+
+    let spectrum = magnitude_spectrum(image, n);
+    let peaks = find_peaks(&spectrum, n, image.block_period);
+
+    let max_prominence = peaks.first().map(|p| p.prominence).unwrap_or(0.0);
+    let detected = max_prominence >= PEAK_PROMINENCE_THRESHOLD;
+    let confidence = if detected {
+        0.5 + 0.5
+            * ((max_prominence - PEAK_PROMINENCE_THRESHOLD)
+                / (STRONG_PROMINENCE - PEAK_PROMINENCE_THRESHOLD))
+                .min(1.0)
+    } else {
+        0.5 * max_prominence / PEAK_PROMINENCE_THRESHOLD
+    };
+
+    let halftone_detected = detected && is_halftone(&peaks);
+    let screen_type = match peaks.first() {
+        Some(_) if !detected || halftone_detected => None,
+        Some(peak) if is_axis_aligned(peak.angle) => Some(ScreenType::Lcd),
+        Some(_) => Some(ScreenType::Unknown),
+        None => None,
+    };
+
+    debug!(
+        crop_size = n,
+        peak_count = peaks.len(),
+        max_prominence = max_prominence,
+        detected = detected,
+        halftone_detected = halftone_detected,
+        "[moire_detection] Spectral analysis completed"
+    );
+
+    (
+        MoireAnalysisResult {
+            detected,
+            confidence,
+            peaks,
+            screen_type,
+            analysis_time_ms: 0,
+            algorithm_version: SERVER_MOIRE_ALGORITHM_VERSION.to_string(),
+            computed_at: Utc::now(),
+            status: MoireAnalysisStatus::Completed,
+        },
+        halftone_detected,
+    )
+}
+
+/// Builds a result without analysis (failed or unavailable)
+fn empty_result(status: MoireAnalysisStatus) -> MoireAnalysisResult {
+    MoireAnalysisResult {
+        detected: false,
+        confidence: 0.0,
+        peaks: Vec::new(),
+        screen_type: None,
+        analysis_time_ms: 0,
+        algorithm_version: SERVER_MOIRE_ALGORITHM_VERSION.to_string(),
+        computed_at: Utc::now(),
+        status,
+    }
+}
+
+// ============================================================================
+// Spectrum
+// ============================================================================
+
+/// Computes the windowed 2D FFT magnitude of the centered `n` x `n` crop
+fn magnitude_spectrum(image: &LumaImage, n: usize) -> Vec<f32> {
+    let x0 = (image.width - n) / 2;
+    let y0 = (image.height - n) / 2;
+
+    let mut crop: Vec<f32> = (0..n)
+        .flat_map(|y| {
+            let row = (y0 + y) * image.width + x0;
+            image.pixels[row..row + n].iter().copied()
+        })
+        .collect();
+    let mean = crop.iter().sum::<f32>() / crop.len() as f32;
+
+    let window: Vec<f32> = (0..n)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / (n - 1) as f32).cos())
+        .collect();
+    for (i, value) in crop.iter_mut().enumerate() {
+        *value = (*value - mean) * window[i / n] * window[i % n];
+    }
+
+    let mut data: Vec<Complex<f32>> = crop.into_iter().map(|v| Complex::new(v, 0.0)).collect();
+    let fft = FftPlanner::new().plan_fft_forward(n);
+    for row in data.chunks_exact_mut(n) {
+        fft.process(row);
+    }
+    transpose(&mut data, n);
+    for column in data.chunks_exact_mut(n) {
+        fft.process(column);
+    }
+    transpose(&mut data, n);
+
+    data.iter().map(|c| c.norm()).collect()
+}
+
+/// Transposes a square matrix in place
+fn transpose(data: &mut [Complex<f32>], n: usize) {
+    for y in 0..n {
+        for x in (y + 1)..n {
+            data.swap(y * n + x, x * n + y);
+        }
+    }
+}
+
+/// Magnitude at signed frequency (kx, ky)
+fn magnitude_at(spectrum: &[f32], n: usize, kx: i32, ky: i32) -> f32 {
+    let wrap = |k: i32| k.rem_euclid(n as i32) as usize;
+    spectrum[wrap(ky) * n + wrap(kx)]
+}
+
+/// Whether (kx, ky) sits on a JPEG block harmonic
+fn is_block_harmonic(kx: i32, ky: i32, n: usize, block_period: f32) -> bool {
+    if block_period < 2.0 {
+        return false;
+    }
+    let spacing = n as f32 / block_period;
+    let near = |k: i32| {
+        let nearest = (k as f32 / spacing).round() * spacing;
+        (k as f32 - nearest).abs() <= BLOCK_HARMONIC_TOLERANCE as f32
+    };
+    near(kx) && near(ky)
+}
+
+/// Mean magnitude along one arm from (kx, ky) in direction (dx, dy)
+fn arm_mean(spectrum: &[f32], n: usize, kx: i32, ky: i32, dx: f32, dy: f32) -> f32 {
+    let samples: Vec<f32> = (BACKGROUND_INNER..=BACKGROUND_OUTER)
+        .map(|d| {
+            let sx = kx + (dx * d as f32).round() as i32;
+            let sy = ky + (dy * d as f32).round() as i32;
+            magnitude_at(spectrum, n, sx, sy)
+        })
+        .collect();
+    samples.iter().sum::<f32>() / samples.len() as f32
+}
+
+/// Finds isolated spectral peaks, strongest first
+///
+/// Only the upper half-plane is searched since the spectrum of a real image
+/// is symmetric.
+fn find_peaks(spectrum: &[f32], n: usize, block_period: f32) -> Vec<FrequencyPeak> {
+    let half = (n / 2) as i32;
+    let min_radius = n as f32 * MIN_RADIUS_FRACTION;
+    let max_radius = half as f32 * MAX_RADIUS_FRACTION;
+
+    let mut peaks = Vec::new();
+    for ky in 0..half {
+        for kx in -half + 1..half {
+            if ky == 0 && kx <= 0 {
+                continue;
+            }
+            let radius = ((kx * kx + ky * ky) as f32).sqrt();
+            if radius < min_radius || radius > max_radius {
+                continue;
+            }
+            if is_block_harmonic(kx, ky, n, block_period) {
+                continue;
+            }
+
+            let magnitude = magnitude_at(spectrum, n, kx, ky);
+            let is_local_max = (-1..=1).all(|dy| {
+                (-1..=1).all(|dx| {
+                    (dx == 0 && dy == 0) || magnitude_at(spectrum, n, kx + dx, ky + dy) < magnitude
+                })
+            });
+            if !is_local_max {
+                continue;
+            }
+
+            // Background is the strongest of the four arms, so a peak must
+            // stand out radially (not an edge streak) and tangentially
+            let (ux, uy) = (kx as f32 / radius, ky as f32 / radius);
+            let background = [(ux, uy), (-ux, -uy), (-uy, ux), (uy, -ux)]
+                .iter()
+                .map(|&(dx, dy)| arm_mean(spectrum, n, kx, ky, dx, dy))
+                .fold(0.0f32, f32::max);
+            if background <= f32::EPSILON {
+                continue;
+            }
+
+            peaks.push(FrequencyPeak {
+                frequency: radius / n as f32,
+                magnitude,
+                angle: (ky as f32).atan2(kx as f32),
+                prominence: magnitude / background,
+            });
+        }
+    }
+
+    peaks.sort_by(|a, b| b.prominence.total_cmp(&a.prominence));
+    peaks.truncate(MAX_REPORTED_PEAKS);
+    peaks
+}
+
+/// Whether a peak angle lies on the horizontal or vertical axis
+fn is_axis_aligned(angle: f32) -> bool {
+    let folded = angle.rem_euclid(PI / 2.0);
+    folded < AXIS_TOLERANCE_RAD || PI / 2.0 - folded < AXIS_TOLERANCE_RAD
+}
+
+/// Whether significant peaks form a rotated orthogonal pair of similar
+/// frequency (a halftone dot screen; pixel grids are axis-aligned)
+fn is_halftone(peaks: &[FrequencyPeak]) -> bool {
+    let significant: Vec<&FrequencyPeak> = peaks
+        .iter()
+        .filter(|p| p.prominence >= PEAK_PROMINENCE_THRESHOLD && !is_axis_aligned(p.angle))
+        .collect();
+
+    significant.iter().enumerate().any(|(i, a)| {
+        significant[i + 1..].iter().any(|b| {
+            let separation = (a.angle - b.angle).abs().rem_euclid(PI);
+            let orthogonal = (separation - PI / 2.0).abs() < AXIS_TOLERANCE_RAD * 2.0;
+            let similar = (a.frequency - b.frequency).abs()
+                <= HALFTONE_FREQUENCY_TOLERANCE * a.frequency.max(b.frequency);
+            orthogonal && similar
+        })
+    })
+}
+
+// ============================================================================
+// Client Comparison
+// ============================================================================
+
+/// Compares the server result with the client's moire result
+///
+/// # Returns
+/// Some(agree) when both analyses completed, None otherwise
+pub fn compare_client_moire(
+    server: &MoireAnalysisResult,
+    client: Option<&DetectionResults>,
+) -> Option<bool> {
+    let client = client?.moire.as_ref()?;
+    if server.status != MoireAnalysisStatus::Completed
+        || client.status != MoireAnalysisStatus::Completed
+    {
+        return None;
+    }
+    Some(server.detected == client.detected)
+}
+
+/// Confidence flags raised by server-side detection
+pub fn server_detection_flags(results: &ServerDetectionResults) -> Vec<ConfidenceFlag> {
+    let mut flags = Vec::new();
+    if results.moire.detected {
+        flags.push(if results.halftone_detected {
+            ConfidenceFlag::PrintDetected
+        } else {
+            ConfidenceFlag::ScreenDetected
+        });
+    }
+    if results.client_agreement == Some(false) {
+        flags.push(ConfidenceFlag::ServerClientDisagree);
+    }
+    flags
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: usize = 512;
+
+    /// Deterministic pseudo-random texture in [0, 1)
+    fn noise(seed: u64) -> impl FnMut() -> f32 {
+        let mut state = seed;
+        move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 40) as f32 / (1u64 << 24) as f32
+        }
+    }
+
+    /// Natural-looking scene: smooth gradient, a lens-blurred edge and sensor noise
+    fn scene(width: usize, height: usize) -> Vec<f32> {
+        let mut rand = noise(7);
+        (0..width * height)
+            .map(|i| {
+                let (x, y) = ((i % width) as f32, (i / width) as f32);
+                let edge = 60.0 / (1.0 + (-(x + 0.3 * y - width as f32 * 0.6)).exp());
+                80.0 + 0.1 * x + 0.05 * y + edge + 8.0 * rand()
+            })
+            .collect()
+    }
+
+    fn image(pixels: Vec<f32>, size: usize) -> LumaImage {
+        LumaImage {
+            width: size,
+            height: size,
+            pixels,
+            block_period: 8.0,
+        }
+    }
+
+    /// Adds a sinusoidal grating with `period` pixels at `angle` degrees
+    fn add_grating(pixels: &mut [f32], width: usize, period: f32, angle: f32, amplitude: f32) {
+        let (s, c) = angle.to_radians().sin_cos();
+        for (i, value) in pixels.iter_mut().enumerate() {
+            let (x, y) = ((i % width) as f32, (i / width) as f32);
+            *value += amplitude * (2.0 * PI * (x * c + y * s) / period).sin();
+        }
+    }
+
+    #[test]
+    fn test_natural_scene_not_detected() {
+        let (result, halftone) = analyze_luma(&image(scene(SIZE, SIZE), SIZE));
+        assert_eq!(result.status, MoireAnalysisStatus::Completed);
+        assert!(!result.detected, "peaks: {:?}", result.peaks.first());
+        assert!(!halftone);
+        assert!(result.confidence < 0.5);
+        assert_eq!(result.algorithm_version, SERVER_MOIRE_ALGORITHM_VERSION);
+    }
+
+    #[test]
+    fn test_axis_aligned_grid_detected_as_screen() {
+        let mut pixels = scene(SIZE, SIZE);
+        add_grating(&mut pixels, SIZE, 6.7, 0.0, 6.0);
+        add_grating(&mut pixels, SIZE, 6.7, 90.0, 6.0);
+
+        let (result, halftone) = analyze_luma(&image(pixels, SIZE));
+        assert!(result.detected);
+        assert!(!halftone);
+        assert_eq!(result.screen_type, Some(ScreenType::Lcd));
+        assert!(result.confidence >= 0.5);
+        let peak = &result.peaks[0];
+        assert!((peak.frequency - 1.0 / 6.7).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_rotated_dot_screen_detected_as_halftone() {
+        let mut pixels = scene(SIZE, SIZE);
+        add_grating(&mut pixels, SIZE, 5.3, 45.0, 6.0);
+        add_grating(&mut pixels, SIZE, 5.3, 135.0, 6.0);
+
+        let (result, halftone) = analyze_luma(&image(pixels, SIZE));
+        assert!(result.detected);
+        assert!(halftone);
+        assert_eq!(result.screen_type, None);
+    }
+
+    #[test]
+    fn test_block_harmonics_ignored() {
+        // A pattern with the JPEG block period is a compression artifact
+        let mut pixels = scene(SIZE, SIZE);
+        add_grating(&mut pixels, SIZE, 8.0, 0.0, 6.0);
+
+        let (result, _) = analyze_luma(&image(pixels, SIZE));
+        assert!(!result.detected);
+    }
+
+    #[test]
+    fn test_small_image_unavailable() {
+        let (result, _) = analyze_luma(&image(scene(32, 32), 32));
+        assert_eq!(result.status, MoireAnalysisStatus::Unavailable);
+        assert!(!result.detected);
+    }
+
+    #[test]
+    fn test_invalid_jpeg_fails() {
+        let results = analyze_photo_moire(b"not a jpeg");
+        assert_eq!(results.moire.status, MoireAnalysisStatus::Failed);
+        assert!(!results.moire.detected);
+        assert!(results.client_agreement.is_none());
+    }
+
+    fn client_moire(detected: bool, status: MoireAnalysisStatus) -> DetectionResults {
+        DetectionResults {
+            moire: Some(MoireAnalysisResult {
+                detected,
+                status,
+                ..empty_result(status)
+            }),
+            texture: None,
+            artifacts: None,
+            aggregated_confidence: None,
+            cross_validation: None,
+            computed_at: Utc::now(),
+            total_processing_time_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_compare_client_moire() {
+        let server = MoireAnalysisResult {
+            detected: true,
+            ..empty_result(MoireAnalysisStatus::Completed)
+        };
+        let agree = client_moire(true, MoireAnalysisStatus::Completed);
+        let disagree = client_moire(false, MoireAnalysisStatus::Completed);
+        let unavailable = client_moire(false, MoireAnalysisStatus::Unavailable);
+
+        assert_eq!(compare_client_moire(&server, Some(&agree)), Some(true));
+        assert_eq!(compare_client_moire(&server, Some(&disagree)), Some(false));
+        assert_eq!(compare_client_moire(&server, Some(&unavailable)), None);
+        assert_eq!(compare_client_moire(&server, None), None);
+    }
+
+    #[test]
+    fn test_server_detection_flags() {
+        let results = ServerDetectionResults {
+            moire: MoireAnalysisResult {
+                detected: true,
+                ..empty_result(MoireAnalysisStatus::Completed)
+            },
+            halftone_detected: true,
+            client_agreement: Some(false),
+        };
+        assert_eq!(
+            server_detection_flags(&results),
+            vec![
+                ConfidenceFlag::PrintDetected,
+                ConfidenceFlag::ServerClientDisagree
+            ]
+        );
+
+        let clean = ServerDetectionResults {
+            moire: empty_result(MoireAnalysisStatus::Completed),
+            halftone_detected: false,
+            client_agreement: Some(true),
+        };
+        assert!(server_detection_flags(&clean).is_empty());
+    }
+}
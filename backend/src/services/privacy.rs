@@ -2,28 +2,60 @@
 //!
 //! Implements privacy controls for capture metadata, including:
 //! - Location coarsening (GPS to ~1km precision)
+//! - Selectable location privacy policies (rounding, geohash, hex cells,
+//!   planar Laplace jitter) within server-configured bounds
 //! - Location display formatting
 //! - Privacy-aware evidence generation
+//! - Salted metadata commitments with selective disclosure
 //!
 //! ## Privacy Levels
 //! - Precise: 6 decimal places (~0.1m) - internal storage only
-//! - Coarse: 2 decimal places (~1.1km) - public display
+//! - Coarse: 2 decimal places (~1.1km) - public display (default policy)
+//! - Policy: device-selected level, clamped to the configured radius bounds
 //!
 //! ## Principles
 //! - Never expose precise location via public API
 //! - Treat missing location as user choice (opted-out), not failure
 //! - Raw depth maps are never publicly accessible
+//! - Committed metadata is only revealed by the owning device
 
+use rand::rngs::{OsRng, StdRng};
+use rand::{Rng, RngCore, SeedableRng};
+use sha2::{Digest, Sha256};
 use tracing::debug;
 
+use crate::config::Config;
+use crate::error::ApiError;
 use crate::types::capture::CaptureLocation;
+use crate::types::location_privacy::{
+    MAX_GEOHASH_PRECISION, MAX_HEX_RESOLUTION, MAX_ROUNDED_DECIMAL_PLACES,
+};
+use crate::types::{CommitmentField, LocationPrivacyEvidence, LocationPrivacyPolicy};
 
 // ============================================================================
 // Configuration Constants
 // ============================================================================
 
-/// Decimal places for coarse location (2 = ~1.1km precision)
-const COARSE_DECIMAL_PLACES: i32 = 2;
+/// Meters per degree of latitude (and of longitude at the equator)
+const METERS_PER_DEGREE_LAT: f64 = 111_320.0;
+
+/// Authalic Earth radius used for the equal-area hex projection (meters)
+const EARTH_RADIUS_M: f64 = 6_371_007.180_918_475;
+
+/// Average H3 resolution-0 cell area in square meters
+const HEX_RES0_AREA_M2: f64 = 4_357_449_416_078.381;
+
+/// Geohash base32 alphabet
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Domain separator for metadata commitments
+const COMMITMENT_DOMAIN: &[u8] = b"realitycam.commitment.v1";
+
+/// Minimum commitment salt length in bytes (128 bits)
+pub const MIN_COMMITMENT_SALT_BYTES: usize = 16;
+
+/// Maximum length of a disclosed device model name
+const MAX_DEVICE_MODEL_LEN: usize = 128;
 
 // ============================================================================
 // Location Coarsening Functions
@@ -31,17 +63,19 @@ const COARSE_DECIMAL_PLACES: i32 = 2;
 
 /// Coarsens GPS coordinates for privacy
 ///
-/// Rounds coordinates to 2 decimal places, providing approximately
-/// 1.1km precision (at equator, less at higher latitudes).
+/// Rounds coordinates to the given number of decimal places; 2 places give
+/// approximately 1.1km precision (at equator, less east-west at higher
+/// latitudes).
 ///
 /// # Arguments
 /// * `latitude` - Precise latitude (-90 to 90)
 /// * `longitude` - Precise longitude (-180 to 180)
+/// * `decimal_places` - Decimal places to keep
 ///
 /// # Returns
 /// Tuple of (coarse_lat, coarse_lng)
-pub fn coarsen_coordinates(latitude: f64, longitude: f64) -> (f64, f64) {
-    let factor = 10f64.powi(COARSE_DECIMAL_PLACES);
+pub fn coarsen_coordinates(latitude: f64, longitude: f64, decimal_places: u8) -> (f64, f64) {
+    let factor = 10f64.powi(decimal_places as i32);
     let coarse_lat = (latitude * factor).round() / factor;
     let coarse_lng = (longitude * factor).round() / factor;
 
@@ -61,28 +95,483 @@ pub fn coarsen_coordinates(latitude: f64, longitude: f64) -> (f64, f64) {
 /// Returns a human-readable string like "37.77, -122.42"
 ///
 /// # Arguments
-/// * `latitude` - Coarse latitude
-/// * `longitude` - Coarse longitude
+/// * `latitude` - Precise latitude
+/// * `longitude` - Precise longitude
+/// * `decimal_places` - Decimal places to keep
 ///
 /// # Returns
 /// Formatted coordinate string
-pub fn format_location_coarse(latitude: f64, longitude: f64) -> String {
-    let (coarse_lat, coarse_lng) = coarsen_coordinates(latitude, longitude);
-    format!("{coarse_lat:.2}, {coarse_lng:.2}")
+pub fn format_location_coarse(latitude: f64, longitude: f64, decimal_places: u8) -> String {
+    let (coarse_lat, coarse_lng) = coarsen_coordinates(latitude, longitude, decimal_places);
+    let places = decimal_places as usize;
+    format!("{coarse_lat:.places$}, {coarse_lng:.places$}")
 }
 
 /// Processes location data for privacy-aware storage
 ///
-/// Takes precise location and returns coarse string for public display.
+/// Applies the requested policy (or the server default) within the server
+/// bounds and returns the display string with the policy record for evidence.
 /// Returns None if location is not available.
 ///
 /// # Arguments
 /// * `location` - Optional capture location from metadata
+/// * `requested` - Policy chosen by the capturing device, if any
+/// * `bounds` - Server-configured default and radius bounds
 ///
 /// # Returns
-/// Optional coarse location string for display
-pub fn process_location_for_evidence(location: Option<&CaptureLocation>) -> Option<String> {
-    location.map(|loc| format_location_coarse(loc.latitude, loc.longitude))
+/// Optional generalized location for display and evidence
+pub fn process_location_for_evidence(
+    location: Option<&CaptureLocation>,
+    requested: Option<LocationPrivacyPolicy>,
+    bounds: &LocationPrivacyBounds,
+) -> Option<ProtectedLocation> {
+    location.map(|loc| {
+        let mut seed = [0u8; 32];
+        OsRng.fill_bytes(&mut seed);
+        protect_location(loc.latitude, loc.longitude, requested, bounds, seed)
+    })
+}
+
+// ============================================================================
+// Location Privacy Policies
+// ============================================================================
+
+/// Server-configured location privacy default and bounds
+#[derive(Debug, Clone, Copy)]
+pub struct LocationPrivacyBounds {
+    /// Policy used when the device does not request one
+    pub default_policy: LocationPrivacyPolicy,
+    /// Smallest uncertainty radius a device may choose (meters)
+    pub min_radius_m: f64,
+    /// Largest uncertainty radius a device may choose (meters)
+    pub max_radius_m: f64,
+}
+
+impl LocationPrivacyBounds {
+    /// Reads the location privacy settings from the application config
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            default_policy: config.location_privacy_default,
+            min_radius_m: config.location_privacy_min_radius_m,
+            max_radius_m: config.location_privacy_max_radius_m,
+        }
+    }
+}
+
+/// Location generalized by a privacy policy
+#[derive(Debug, Clone)]
+pub struct ProtectedLocation {
+    /// Published latitude
+    pub latitude: f64,
+    /// Published longitude
+    pub longitude: f64,
+    /// Display string ("lat, lng")
+    pub display: String,
+    /// Policy record for the evidence package
+    pub evidence: LocationPrivacyEvidence,
+}
+
+/// Applies a location privacy policy within server bounds
+///
+/// The requested policy is adjusted to the nearest level of the same method
+/// whose uncertainty radius lies within `[min_radius_m, max_radius_m]`. When
+/// both bounds cannot be met, the minimum (privacy) bound wins.
+///
+/// # Arguments
+/// * `latitude` - Precise latitude
+/// * `longitude` - Precise longitude
+/// * `requested` - Policy chosen by the device, or None for the server default
+/// * `bounds` - Server-configured default and radius bounds
+/// * `seed` - Per-capture random seed (used by planar Laplace jitter)
+pub fn protect_location(
+    latitude: f64,
+    longitude: f64,
+    requested: Option<LocationPrivacyPolicy>,
+    bounds: &LocationPrivacyBounds,
+    seed: [u8; 32],
+) -> ProtectedLocation {
+    let requested = requested.unwrap_or(bounds.default_policy);
+    let policy = clamp_policy(requested, latitude, bounds);
+    let radius_m = policy_radius_m(&policy, latitude);
+
+    let (published_lat, published_lng, cell) = match policy {
+        LocationPrivacyPolicy::Rounded { decimal_places } => {
+            let (lat, lng) = coarsen_coordinates(latitude, longitude, decimal_places);
+            (lat, lng, None)
+        }
+        LocationPrivacyPolicy::Geohash { precision } => {
+            let (hash, lat, lng) = geohash_cell(latitude, longitude, precision);
+            (lat, lng, Some(hash))
+        }
+        LocationPrivacyPolicy::HexCell { resolution } => {
+            let (cell_id, lat, lng) = hex_cell(latitude, longitude, resolution);
+            (lat, lng, Some(cell_id))
+        }
+        LocationPrivacyPolicy::PlanarLaplace { radius_m } => {
+            let (lat, lng) = planar_laplace_jitter(latitude, longitude, radius_m, seed);
+            (lat, lng, None)
+        }
+    };
+
+    let display_places = match policy {
+        LocationPrivacyPolicy::Rounded { decimal_places } => decimal_places,
+        _ => display_decimal_places(radius_m),
+    };
+    let display = format_location_coarse(published_lat, published_lng, display_places);
+
+    debug!(
+        requested = %requested,
+        applied = %policy,
+        radius_m = radius_m,
+        "[privacy] Location privacy policy applied"
+    );
+
+    ProtectedLocation {
+        latitude: published_lat,
+        longitude: published_lng,
+        display,
+        evidence: LocationPrivacyEvidence {
+            policy,
+            radius_m,
+            cell,
+            adjusted: policy != requested,
+        },
+    }
+}
+
+/// Approximate uncertainty radius of a policy at the given latitude (meters)
+///
+/// Cell-based policies use the half-diagonal (or hexagon circumradius);
+/// planar Laplace uses its expected displacement.
+pub fn policy_radius_m(policy: &LocationPrivacyPolicy, latitude: f64) -> f64 {
+    match *policy {
+        LocationPrivacyPolicy::Rounded { decimal_places } => {
+            let cell_deg = 10f64.powi(-(decimal_places as i32));
+            cell_half_diagonal_m(cell_deg, cell_deg, latitude)
+        }
+        LocationPrivacyPolicy::Geohash { precision } => {
+            let (lat_deg, lng_deg) = geohash_cell_size_deg(precision);
+            cell_half_diagonal_m(lat_deg, lng_deg, latitude)
+        }
+        LocationPrivacyPolicy::HexCell { resolution } => hex_circumradius_m(resolution),
+        LocationPrivacyPolicy::PlanarLaplace { radius_m } => radius_m,
+    }
+}
+
+/// Adjusts a policy to the nearest level within the server bounds
+fn clamp_policy(
+    requested: LocationPrivacyPolicy,
+    latitude: f64,
+    bounds: &LocationPrivacyBounds,
+) -> LocationPrivacyPolicy {
+    if requested.validate().is_err() {
+        return bounds.default_policy;
+    }
+
+    if let LocationPrivacyPolicy::PlanarLaplace { radius_m } = requested {
+        return LocationPrivacyPolicy::PlanarLaplace {
+            radius_m: radius_m.min(bounds.max_radius_m).max(bounds.min_radius_m),
+        };
+    }
+
+    let mut policy = requested;
+    while policy_radius_m(&policy, latitude) > bounds.max_radius_m {
+        match finer_level(&policy) {
+            Some(next) if policy_radius_m(&next, latitude) >= bounds.min_radius_m => policy = next,
+            _ => break,
+        }
+    }
+    while policy_radius_m(&policy, latitude) < bounds.min_radius_m {
+        match coarser_level(&policy) {
+            Some(next) => policy = next,
+            None => break,
+        }
+    }
+    policy
+}
+
+/// Next more precise level of the same method
+fn finer_level(policy: &LocationPrivacyPolicy) -> Option<LocationPrivacyPolicy> {
+    match *policy {
+        LocationPrivacyPolicy::Rounded { decimal_places } => {
+            (decimal_places < MAX_ROUNDED_DECIMAL_PLACES).then(|| LocationPrivacyPolicy::Rounded {
+                decimal_places: decimal_places + 1,
+            })
+        }
+        LocationPrivacyPolicy::Geohash { precision } => {
+            (precision < MAX_GEOHASH_PRECISION).then(|| LocationPrivacyPolicy::Geohash {
+                precision: precision + 1,
+            })
+        }
+        LocationPrivacyPolicy::HexCell { resolution } => {
+            (resolution < MAX_HEX_RESOLUTION).then(|| LocationPrivacyPolicy::HexCell {
+                resolution: resolution + 1,
+            })
+        }
+        LocationPrivacyPolicy::PlanarLaplace { .. } => None,
+    }
+}
+
+/// Next less precise level of the same method
+fn coarser_level(policy: &LocationPrivacyPolicy) -> Option<LocationPrivacyPolicy> {
+    match *policy {
+        LocationPrivacyPolicy::Rounded { decimal_places } => decimal_places
+            .checked_sub(1)
+            .map(|decimal_places| LocationPrivacyPolicy::Rounded { decimal_places }),
+        LocationPrivacyPolicy::Geohash { precision } => {
+            (precision > 1).then(|| LocationPrivacyPolicy::Geohash {
+                precision: precision - 1,
+            })
+        }
+        LocationPrivacyPolicy::HexCell { resolution } => resolution
+            .checked_sub(1)
+            .map(|resolution| LocationPrivacyPolicy::HexCell { resolution }),
+        LocationPrivacyPolicy::PlanarLaplace { .. } => None,
+    }
+}
+
+/// Half-diagonal of a lat/lng cell in meters at the given latitude
+fn cell_half_diagonal_m(lat_deg: f64, lng_deg: f64, latitude: f64) -> f64 {
+    let height_m = lat_deg * METERS_PER_DEGREE_LAT;
+    let width_m = lng_deg * METERS_PER_DEGREE_LAT * latitude.to_radians().cos().abs();
+    0.5 * height_m.hypot(width_m)
+}
+
+/// Decimal places that resolve a published location without overstating it
+fn display_decimal_places(radius_m: f64) -> u8 {
+    (0..MAX_ROUNDED_DECIMAL_PLACES)
+        .find(|&places| METERS_PER_DEGREE_LAT * 10f64.powi(-(places as i32)) <= radius_m)
+        .unwrap_or(MAX_ROUNDED_DECIMAL_PLACES)
+}
+
+// ----------------------------------------------------------------------------
+// Geohash
+// ----------------------------------------------------------------------------
+
+/// Geohash cell size in degrees (lat, lng) for a precision
+fn geohash_cell_size_deg(precision: u8) -> (f64, f64) {
+    let bits = 5 * precision as i32;
+    let lng_bits = (bits + 1) / 2;
+    let lat_bits = bits / 2;
+    (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(lng_bits))
+}
+
+/// Encodes a location as a geohash and returns the cell center
+///
+/// # Returns
+/// Tuple of (geohash, center_lat, center_lng)
+pub fn geohash_cell(latitude: f64, longitude: f64, precision: u8) -> (String, f64, f64) {
+    let mut lat_range = (-90.0_f64, 90.0_f64);
+    let mut lng_range = (-180.0_f64, 180.0_f64);
+    let mut hash = String::with_capacity(precision as usize);
+    let mut even_bit = true;
+
+    for _ in 0..precision {
+        let mut index = 0usize;
+        for _ in 0..5 {
+            let (range, value) = if even_bit {
+                (&mut lng_range, longitude)
+            } else {
+                (&mut lat_range, latitude)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even_bit = !even_bit;
+        }
+        hash.push(GEOHASH_ALPHABET[index] as char);
+    }
+
+    (
+        hash,
+        (lat_range.0 + lat_range.1) / 2.0,
+        (lng_range.0 + lng_range.1) / 2.0,
+    )
+}
+
+// ----------------------------------------------------------------------------
+// H3-style hexagonal cells
+// ----------------------------------------------------------------------------
+
+/// Circumradius (edge length) of a hexagon at the given resolution in meters
+///
+/// Matches the H3 average cell area: each resolution has 1/7 the area of the
+/// previous one.
+fn hex_circumradius_m(resolution: u8) -> f64 {
+    let area_m2 = HEX_RES0_AREA_M2 / 7f64.powi(resolution as i32);
+    (2.0 * area_m2 / (3.0 * 3f64.sqrt())).sqrt()
+}
+
+/// Snaps a location to the center of its hexagonal cell
+///
+/// Hexagons are laid out on a Lambert cylindrical equal-area projection so
+/// every cell covers the same ground area at any latitude. This is an H3-style
+/// grid (same per-resolution areas), not the H3 icosahedral index.
+///
+/// # Returns
+/// Tuple of (cell_id, center_lat, center_lng)
+pub fn hex_cell(latitude: f64, longitude: f64, resolution: u8) -> (String, f64, f64) {
+    let size = hex_circumradius_m(resolution);
+    let x = EARTH_RADIUS_M * longitude.to_radians();
+    let y = EARTH_RADIUS_M * latitude.to_radians().sin();
+
+    // Pointy-top axial coordinates, then cube rounding to the nearest hexagon
+    let q = (3f64.sqrt() / 3.0 * x - y / 3.0) / size;
+    let r = (2.0 / 3.0 * y) / size;
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+
+    let center_x = size * 3f64.sqrt() * (rq + rr / 2.0);
+    let center_y = size * 1.5 * rr;
+    let center_lat = (center_y / EARTH_RADIUS_M)
+        .clamp(-1.0, 1.0)
+        .asin()
+        .to_degrees();
+    let center_lng = normalize_longitude((center_x / EARTH_RADIUS_M).to_degrees());
+
+    (
+        format!("h{resolution}:{}:{}", rq as i64, rr as i64),
+        center_lat,
+        center_lng,
+    )
+}
+
+// ----------------------------------------------------------------------------
+// Planar Laplace jitter
+// ----------------------------------------------------------------------------
+
+/// Perturbs a location with planar Laplace noise (geo-indistinguishability)
+///
+/// With privacy parameter epsilon = 2 / radius_m the displacement distance
+/// follows Gamma(2, 1/epsilon), sampled as the sum of two exponentials, so the
+/// expected displacement equals `radius_m`. The seed must be fresh per capture:
+/// reusing it would let repeated captures cancel out the noise.
+pub fn planar_laplace_jitter(
+    latitude: f64,
+    longitude: f64,
+    radius_m: f64,
+    seed: [u8; 32],
+) -> (f64, f64) {
+    let mut rng = StdRng::from_seed(seed);
+    let theta = rng.gen::<f64>() * std::f64::consts::TAU;
+    // 1 - gen() lies in (0, 1], keeping ln() finite
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = 1.0 - rng.gen::<f64>();
+    let distance_m = -(u1.ln() + u2.ln()) * radius_m / 2.0;
+
+    let dlat = distance_m * theta.sin() / METERS_PER_DEGREE_LAT;
+    let cos_lat = latitude.to_radians().cos().abs().max(1e-6);
+    let dlng = distance_m * theta.cos() / (METERS_PER_DEGREE_LAT * cos_lat);
+
+    (
+        (latitude + dlat).clamp(-90.0, 90.0),
+        normalize_longitude(longitude + dlng),
+    )
+}
+
+/// Wraps a longitude into [-180, 180]
+fn normalize_longitude(longitude: f64) -> f64 {
+    if (-180.0..=180.0).contains(&longitude) {
+        longitude
+    } else {
+        (longitude + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+// ============================================================================
+// Metadata Commitments
+// ============================================================================
+
+/// Computes the salted commitment to a metadata field value
+///
+/// `SHA256(domain || 0x00 || field || 0x00 || len(salt) as u32 BE || salt || value)`.
+/// The field name is bound so a commitment for one field cannot be opened as
+/// another, and the salt length is bound so bytes cannot be shifted between
+/// salt and value to open the commitment as a different disclosure.
+///
+/// # Arguments
+/// * `field` - Committed metadata field
+/// * `salt` - Random salt chosen by the client
+/// * `value` - Canonical field value (UTF-8)
+pub fn compute_metadata_commitment(field: CommitmentField, salt: &[u8], value: &str) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(COMMITMENT_DOMAIN);
+    hasher.update([0u8]);
+    hasher.update(field.to_string().as_bytes());
+    hasher.update([0u8]);
+    hasher.update((salt.len() as u32).to_be_bytes());
+    hasher.update(salt);
+    hasher.update(value.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Checks that a disclosed value uses the canonical format for its field
+///
+/// - location: "latitude,longitude" with both in range
+/// - timestamp: RFC3339
+/// - device_model: non-empty, at most 128 characters
+pub fn validate_disclosed_value(field: CommitmentField, value: &str) -> Result<(), ApiError> {
+    let valid = match field {
+        CommitmentField::Location => value
+            .split_once(',')
+            .and_then(|(lat, lng)| Some((lat.parse::<f64>().ok()?, lng.parse::<f64>().ok()?)))
+            .is_some_and(|(lat, lng)| {
+                (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lng)
+            }),
+        CommitmentField::Timestamp => chrono::DateTime::parse_from_rfc3339(value).is_ok(),
+        CommitmentField::DeviceModel => {
+            !value.trim().is_empty() && value.chars().count() <= MAX_DEVICE_MODEL_LEN
+        }
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::Validation(format!(
+            "Disclosed {field} value is not in canonical format"
+        )))
+    }
+}
+
+/// Verifies a disclosed value and salt against the stored commitment
+///
+/// # Returns
+/// `Ok(())` if the value is well-formed, the salt is long enough, and the
+/// recomputed commitment matches; otherwise `ApiError::Validation`.
+pub fn verify_metadata_disclosure(
+    field: CommitmentField,
+    commitment: &[u8],
+    value: &str,
+    salt: &[u8],
+) -> Result<(), ApiError> {
+    validate_disclosed_value(field, value)?;
+
+    if salt.len() < MIN_COMMITMENT_SALT_BYTES {
+        return Err(ApiError::Validation(format!(
+            "Commitment salt must be at least {MIN_COMMITMENT_SALT_BYTES} bytes"
+        )));
+    }
+
+    let recomputed = compute_metadata_commitment(field, salt, value);
+    if recomputed.as_slice() != commitment {
+        debug!(field = %field, "[privacy] Disclosure does not match commitment");
+        return Err(ApiError::Validation(format!(
+            "Disclosed {field} does not match commitment"
+        )));
+    }
+
+    Ok(())
 }
 
 // ============================================================================
@@ -93,31 +582,35 @@ pub fn process_location_for_evidence(location: Option<&CaptureLocation>) -> Opti
 mod tests {
     use super::*;
 
+    fn test_bounds() -> LocationPrivacyBounds {
+        LocationPrivacyBounds::from_config(&Config::default_for_test())
+    }
+
     #[test]
     fn test_coarsen_coordinates_basic() {
         // San Francisco City Hall
-        let (lat, lng) = coarsen_coordinates(37.779260, -122.419336);
+        let (lat, lng) = coarsen_coordinates(37.779260, -122.419336, 2);
         assert!((lat - 37.78).abs() < 0.001);
         assert!((lng - (-122.42)).abs() < 0.001);
     }
 
     #[test]
     fn test_coarsen_coordinates_rounding_down() {
-        let (lat, lng) = coarsen_coordinates(37.774, -122.414);
+        let (lat, lng) = coarsen_coordinates(37.774, -122.414, 2);
         assert!((lat - 37.77).abs() < 0.001);
         assert!((lng - (-122.41)).abs() < 0.001);
     }
 
     #[test]
     fn test_coarsen_coordinates_rounding_up() {
-        let (lat, lng) = coarsen_coordinates(37.776, -122.416);
+        let (lat, lng) = coarsen_coordinates(37.776, -122.416, 2);
         assert!((lat - 37.78).abs() < 0.001);
         assert!((lng - (-122.42)).abs() < 0.001);
     }
 
     #[test]
     fn test_coarsen_coordinates_equator() {
-        let (lat, lng) = coarsen_coordinates(0.0, 0.0);
+        let (lat, lng) = coarsen_coordinates(0.0, 0.0, 2);
         assert!((lat - 0.0).abs() < 0.001);
         assert!((lng - 0.0).abs() < 0.001);
     }
@@ -125,33 +618,33 @@ mod tests {
     #[test]
     fn test_coarsen_coordinates_poles() {
         // North pole
-        let (lat, _) = coarsen_coordinates(90.0, 0.0);
+        let (lat, _) = coarsen_coordinates(90.0, 0.0, 2);
         assert!((lat - 90.0).abs() < 0.001);
 
         // South pole
-        let (lat, _) = coarsen_coordinates(-90.0, 0.0);
+        let (lat, _) = coarsen_coordinates(-90.0, 0.0, 2);
         assert!((lat - (-90.0)).abs() < 0.001);
     }
 
     #[test]
     fn test_coarsen_coordinates_dateline() {
         // International date line
-        let (_, lng) = coarsen_coordinates(0.0, 180.0);
+        let (_, lng) = coarsen_coordinates(0.0, 180.0, 2);
         assert!((lng - 180.0).abs() < 0.001);
 
-        let (_, lng) = coarsen_coordinates(0.0, -180.0);
+        let (_, lng) = coarsen_coordinates(0.0, -180.0, 2);
         assert!((lng - (-180.0)).abs() < 0.001);
     }
 
     #[test]
     fn test_format_location_coarse() {
-        let result = format_location_coarse(37.779260, -122.419336);
+        let result = format_location_coarse(37.779260, -122.419336, 2);
         assert_eq!(result, "37.78, -122.42");
     }
 
     #[test]
     fn test_format_location_coarse_negative() {
-        let result = format_location_coarse(-33.8688, 151.2093);
+        let result = format_location_coarse(-33.8688, 151.2093, 2);
         assert_eq!(result, "-33.87, 151.21");
     }
 
@@ -163,14 +656,16 @@ mod tests {
             altitude: Some(10.0),
             accuracy: Some(5.0),
         };
-        let result = process_location_for_evidence(Some(&loc));
-        assert_eq!(result, Some("37.78, -122.42".to_string()));
+        let result = process_location_for_evidence(Some(&loc), None, &test_bounds()).unwrap();
+        assert_eq!(result.display, "37.78, -122.42");
+        assert_eq!(result.evidence.policy, LocationPrivacyPolicy::default());
+        assert!(!result.evidence.adjusted);
     }
 
     #[test]
     fn test_process_location_for_evidence_none() {
-        let result = process_location_for_evidence(None);
-        assert_eq!(result, None);
+        let result = process_location_for_evidence(None, None, &test_bounds());
+        assert!(result.is_none());
     }
 
     #[test]
@@ -179,7 +674,7 @@ mod tests {
         // At equator, 0.01 degrees longitude = ~1.11 km
         // 0.01 degrees latitude = ~1.11 km everywhere
         let precise = (37.7749295, -122.4194155);
-        let (coarse_lat, coarse_lng) = coarsen_coordinates(precise.0, precise.1);
+        let (coarse_lat, coarse_lng) = coarsen_coordinates(precise.0, precise.1, 2);
 
         // Should lose the fine precision
         assert!((coarse_lat - 37.77).abs() < 0.001);
@@ -192,4 +687,249 @@ mod tests {
         assert!(lat_diff < 0.01);
         assert!(lng_diff < 0.01);
     }
+
+    // ========================================================================
+    // Location Privacy Policy Tests
+    // ========================================================================
+
+    const SEED: [u8; 32] = [0x42; 32];
+
+    #[test]
+    fn test_format_location_coarse_other_precision() {
+        assert_eq!(
+            format_location_coarse(37.779260, -122.419336, 1),
+            "37.8, -122.4"
+        );
+    }
+
+    #[test]
+    fn test_geohash_known_value() {
+        // Reference: 57.64911,10.40744 -> u4pruydqqvj
+        let (hash, lat, lng) = geohash_cell(57.64911, 10.40744, 11);
+        assert_eq!(hash, "u4pruydqqvj");
+        assert!((lat - 57.64911).abs() < 0.0001);
+        assert!((lng - 10.40744).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_geohash_policy_publishes_cell_center() {
+        let policy = LocationPrivacyPolicy::Geohash { precision: 5 };
+        let result = protect_location(37.779260, -122.419336, Some(policy), &test_bounds(), SEED);
+        assert_eq!(result.evidence.cell.as_deref(), Some("9q8yy"));
+        assert_eq!(result.evidence.policy, policy);
+
+        // Nearby points in the same cell publish the same location
+        let nearby = protect_location(37.7795, -122.4190, Some(policy), &test_bounds(), SEED);
+        assert_eq!(nearby.display, result.display);
+    }
+
+    #[test]
+    fn test_hex_cell_radius_matches_h3_scale() {
+        // H3 res 7 average edge is ~1.4km, res 9 ~0.2km
+        assert!((hex_circumradius_m(7) - 1406.0).abs() < 30.0);
+        assert!((hex_circumradius_m(9) - 201.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_hex_cell_snaps_within_radius() {
+        for (lat, lng) in [
+            (37.779260, -122.419336),
+            (69.6492, 18.9553),
+            (-33.8688, 151.2093),
+        ] {
+            let (_, center_lat, center_lng) = hex_cell(lat, lng, 7);
+            let dlat_m = (center_lat - lat) * METERS_PER_DEGREE_LAT;
+            let dlng_m = (center_lng - lng) * METERS_PER_DEGREE_LAT * lat.to_radians().cos();
+            // Equal-area cells stretch north-south at high latitude; allow slack
+            assert!(dlat_m.hypot(dlng_m) < hex_circumradius_m(7) * 2.5);
+        }
+    }
+
+    #[test]
+    fn test_hex_cell_same_cell_for_nearby_points() {
+        let (a, _, _) = hex_cell(37.779260, -122.419336, 5);
+        let (b, _, _) = hex_cell(37.779300, -122.419300, 5);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_planar_laplace_varies_with_seed() {
+        let a = planar_laplace_jitter(37.779260, -122.419336, 1000.0, [1u8; 32]);
+        let b = planar_laplace_jitter(37.779260, -122.419336, 1000.0, [2u8; 32]);
+        let again = planar_laplace_jitter(37.779260, -122.419336, 1000.0, [1u8; 32]);
+        assert_ne!(a, b);
+        assert_eq!(a, again);
+    }
+
+    #[test]
+    fn test_planar_laplace_mean_displacement() {
+        let (lat, lng) = (37.779260, -122.419336);
+        let samples = 2000;
+        let total: f64 = (0..samples)
+            .map(|i| {
+                let mut seed = [0u8; 32];
+                seed[..4].copy_from_slice(&(i as u32).to_le_bytes());
+                let (jlat, jlng) = planar_laplace_jitter(lat, lng, 1000.0, seed);
+                let dlat_m = (jlat - lat) * METERS_PER_DEGREE_LAT;
+                let dlng_m = (jlng - lng) * METERS_PER_DEGREE_LAT * lat.to_radians().cos();
+                dlat_m.hypot(dlng_m)
+            })
+            .sum();
+        let mean = total / samples as f64;
+        assert!((mean - 1000.0).abs() < 100.0, "mean displacement {mean}");
+    }
+
+    #[test]
+    fn test_rounding_radius_shrinks_at_high_latitude() {
+        let policy = LocationPrivacyPolicy::default();
+        assert!(policy_radius_m(&policy, 70.0) < policy_radius_m(&policy, 0.0));
+    }
+
+    #[test]
+    fn test_policy_below_min_radius_is_coarsened() {
+        let requested = LocationPrivacyPolicy::Geohash { precision: 9 };
+        let result = protect_location(
+            37.779260,
+            -122.419336,
+            Some(requested),
+            &test_bounds(),
+            SEED,
+        );
+        assert!(result.evidence.adjusted);
+        assert!(result.evidence.radius_m >= 500.0);
+        assert_eq!(
+            result.evidence.policy,
+            LocationPrivacyPolicy::Geohash { precision: 6 }
+        );
+    }
+
+    #[test]
+    fn test_policy_above_max_radius_is_refined() {
+        let requested = LocationPrivacyPolicy::HexCell { resolution: 0 };
+        let result = protect_location(
+            37.779260,
+            -122.419336,
+            Some(requested),
+            &test_bounds(),
+            SEED,
+        );
+        assert!(result.evidence.adjusted);
+        assert!(result.evidence.radius_m <= 50_000.0);
+        assert!(result.evidence.radius_m >= 500.0);
+    }
+
+    #[test]
+    fn test_planar_laplace_radius_clamped() {
+        let requested = LocationPrivacyPolicy::PlanarLaplace { radius_m: 10.0 };
+        let result = protect_location(
+            37.779260,
+            -122.419336,
+            Some(requested),
+            &test_bounds(),
+            SEED,
+        );
+        assert_eq!(
+            result.evidence.policy,
+            LocationPrivacyPolicy::PlanarLaplace { radius_m: 500.0 }
+        );
+        assert!(result.evidence.cell.is_none());
+    }
+
+    #[test]
+    fn test_normalize_longitude_wraps() {
+        assert!((normalize_longitude(190.0) - (-170.0)).abs() < 1e-9);
+        assert!((normalize_longitude(-190.0) - 170.0).abs() < 1e-9);
+        assert_eq!(normalize_longitude(180.0), 180.0);
+    }
+
+    // ========================================================================
+    // Metadata Commitment Tests
+    // ========================================================================
+
+    const SALT: [u8; 16] = [0x5A; 16];
+
+    #[test]
+    fn test_metadata_commitment_deterministic() {
+        let a = compute_metadata_commitment(CommitmentField::Location, &SALT, "37.7793,-122.4193");
+        let b = compute_metadata_commitment(CommitmentField::Location, &SALT, "37.7793,-122.4193");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_metadata_commitment_binds_field_and_salt() {
+        let value = "2025-12-01T10:00:00Z";
+        let base = compute_metadata_commitment(CommitmentField::Timestamp, &SALT, value);
+        let other_field = compute_metadata_commitment(CommitmentField::DeviceModel, &SALT, value);
+        let other_salt =
+            compute_metadata_commitment(CommitmentField::Timestamp, &[0x5B; 16], value);
+        assert_ne!(base, other_field);
+        assert_ne!(base, other_salt);
+    }
+
+    #[test]
+    fn test_verify_metadata_disclosure_matches() {
+        let value = "37.7793,-122.4193";
+        let commitment = compute_metadata_commitment(CommitmentField::Location, &SALT, value);
+        assert!(
+            verify_metadata_disclosure(CommitmentField::Location, &commitment, value, &SALT)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_verify_metadata_disclosure_wrong_value() {
+        let commitment =
+            compute_metadata_commitment(CommitmentField::Location, &SALT, "37.7793,-122.4193");
+        let result = verify_metadata_disclosure(
+            CommitmentField::Location,
+            &commitment,
+            "40.7128,-74.0060",
+            &SALT,
+        );
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[test]
+    fn test_verify_metadata_disclosure_rejects_resplit_salt() {
+        let value = "37.7793,-122.4193";
+        let commitment = compute_metadata_commitment(CommitmentField::Location, &SALT, value);
+
+        // Move the first value byte into the salt: same concatenated bytes
+        let mut shifted_salt = SALT.to_vec();
+        shifted_salt.push(b'3');
+        let result = verify_metadata_disclosure(
+            CommitmentField::Location,
+            &commitment,
+            "7.7793,-122.4193",
+            &shifted_salt,
+        );
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[test]
+    fn test_verify_metadata_disclosure_short_salt() {
+        let salt = [0x5A; 8];
+        let commitment =
+            compute_metadata_commitment(CommitmentField::DeviceModel, &salt, "iPhone 15 Pro");
+        let result = verify_metadata_disclosure(
+            CommitmentField::DeviceModel,
+            &commitment,
+            "iPhone 15 Pro",
+            &salt,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_disclosed_value_formats() {
+        assert!(validate_disclosed_value(CommitmentField::Location, "37.7793,-122.4193").is_ok());
+        assert!(validate_disclosed_value(CommitmentField::Location, "91.0,0.0").is_err());
+        assert!(validate_disclosed_value(CommitmentField::Location, "somewhere").is_err());
+        assert!(
+            validate_disclosed_value(CommitmentField::Timestamp, "2025-12-01T10:00:00Z").is_ok()
+        );
+        assert!(validate_disclosed_value(CommitmentField::Timestamp, "yesterday").is_err());
+        assert!(validate_disclosed_value(CommitmentField::DeviceModel, "iPhone 15 Pro").is_ok());
+        assert!(validate_disclosed_value(CommitmentField::DeviceModel, "  ").is_err());
+    }
 }
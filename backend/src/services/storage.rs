@@ -1,203 +1,411 @@
-//! S3 Storage Service (Story 4.1)
+//! Capture Storage Service (Story 4.1)
 //!
-//! Provides functions for uploading capture files to S3-compatible storage.
-//! Supports both AWS S3 and LocalStack for development/testing.
+//! Provides functions for uploading capture files to blob storage.
+//! The backend (S3/LocalStack, local filesystem or in-memory) is chosen by
+//! `STORAGE_BACKEND`; see `blob_store`.
+//!
+//! When a master key is configured, capture media and its derivatives are
+//! sealed with the capture's data key before upload and opened again on
+//! download (see `envelope`); callers always see plaintext.
+
+use std::sync::Arc;
+use std::time::Duration;
 
-use aws_config::BehaviorVersion;
-use aws_sdk_s3::config::Credentials;
-use aws_sdk_s3::primitives::ByteStream;
-use aws_sdk_s3::Client as S3Client;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
 use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::config::Config;
 use crate::error::ApiError;
+use crate::services::blob_store::{
+    BlobStore, BlobStoreError, FsBlobStore, MemoryBlobStore, S3BlobStore, StorageBackend,
+};
+use crate::services::envelope::{DataKey, Envelope, EnvelopeError};
+use crate::services::media_leases::{lease_media_key, release_media_keys};
 
 // ============================================================================
 // S3 Key Patterns
 // ============================================================================
 
-/// Generates the S3 key for a capture's photo
-/// Pattern: captures/{capture_id}/photo.jpg
-pub fn photo_s3_key(capture_id: Uuid) -> String {
-    format!("captures/{capture_id}/photo.jpg")
+/// SHA-256 digest of `bytes`
+pub fn sha256_digest(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
 }
 
-/// Generates the S3 key for a capture's depth map
-/// Pattern: captures/{capture_id}/depth.gz
-pub fn depth_map_s3_key(capture_id: Uuid) -> String {
-    format!("captures/{capture_id}/depth.gz")
+/// Generates the content-addressed key for uploaded media
+/// Pattern: media/sha256/{first 2 hex chars}/{hex digest}.{extension}
+///
+/// Identical bytes map to the same object, so re-uploads are deduplicated and
+/// a stored object can be checked against the digest in its key.
+pub fn content_addressed_key(sha256: &[u8; 32], extension: &str) -> String {
+    let hex = hex::encode(sha256);
+    format!("media/sha256/{}/{hex}.{extension}", &hex[..2])
 }
 
-/// Generates the S3 key for a capture's video file
-/// Pattern: captures/{capture_id}/video.mp4
-pub fn video_s3_key(capture_id: Uuid) -> String {
-    format!("captures/{capture_id}/video.mp4")
+/// Server-generated renderings stored next to a capture's media
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DerivativeKind {
+    /// Web-sized JPEG thumbnail
+    ThumbnailJpeg,
+    /// Web-sized WebP thumbnail
+    ThumbnailWebp,
+    /// Full-size JPEG poster frame of a video
+    Poster,
+    /// False-colour PNG rendering of the depth map
+    DepthHeatmap,
 }
 
-/// Generates the S3 key for a capture's hash chain
-/// Pattern: captures/{capture_id}/hash_chain.json
-pub fn hash_chain_s3_key(capture_id: Uuid) -> String {
-    format!("captures/{capture_id}/hash_chain.json")
+impl DerivativeKind {
+    /// Every derivative a capture can have
+    pub const ALL: [DerivativeKind; 4] = [
+        DerivativeKind::ThumbnailJpeg,
+        DerivativeKind::ThumbnailWebp,
+        DerivativeKind::Poster,
+        DerivativeKind::DepthHeatmap,
+    ];
+
+    /// Generates the storage key for the derivative
+    /// Pattern: captures/{capture_id}/{thumbnail.jpg|thumbnail.webp|poster.jpg|depth_heatmap.png}
+    pub fn s3_key(self, capture_id: Uuid) -> String {
+        format!("captures/{capture_id}/{}", self.file_name())
+    }
+
+    /// File name of the derivative, also used in its API path
+    pub fn file_name(self) -> &'static str {
+        match self {
+            DerivativeKind::ThumbnailJpeg => "thumbnail.jpg",
+            DerivativeKind::ThumbnailWebp => "thumbnail.webp",
+            DerivativeKind::Poster => "poster.jpg",
+            DerivativeKind::DepthHeatmap => "depth_heatmap.png",
+        }
+    }
+
+    /// Looks up a derivative by its file name
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.file_name() == file_name)
+    }
+
+    /// MIME type of the encoded derivative
+    pub fn content_type(self) -> &'static str {
+        match self {
+            DerivativeKind::ThumbnailJpeg | DerivativeKind::Poster => "image/jpeg",
+            DerivativeKind::ThumbnailWebp => "image/webp",
+            DerivativeKind::DepthHeatmap => "image/png",
+        }
+    }
+
+    /// Name used in logs
+    fn label(self) -> &'static str {
+        match self {
+            DerivativeKind::ThumbnailJpeg => "JPEG thumbnail",
+            DerivativeKind::ThumbnailWebp => "WebP thumbnail",
+            DerivativeKind::Poster => "poster frame",
+            DerivativeKind::DepthHeatmap => "depth heatmap",
+        }
+    }
+}
+
+// ============================================================================
+// Media Access Policy
+// ============================================================================
+
+/// Default lifetime of presigned media URLs (15 minutes)
+pub const DEFAULT_MEDIA_URL_TTL_SECS: u64 = 900;
+
+/// Longest lifetime S3 accepts for presigned URLs (7 days)
+pub const MAX_MEDIA_URL_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Stored capture assets that may be handed out by URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaAsset {
+    /// Captured photo (or C2PA-signed photo)
+    Photo,
+    /// Captured video
+    Video,
+    /// Raw depth map or video depth keyframes
+    DepthMap,
+    /// Generated thumbnail or video poster frame
+    Thumbnail,
+    /// Generated false-colour depth rendering (no raw depth values)
+    DepthHeatmap,
 }
 
-/// Generates the S3 key for a video capture's depth data
-/// Pattern: captures/{capture_id}/video_depth.gz
-/// Note: Uses different key from photo depth to avoid collision
-pub fn video_depth_s3_key(capture_id: Uuid) -> String {
-    format!("captures/{capture_id}/video_depth.gz")
+/// How a stored asset may be shared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaAccess {
+    /// Anyone with the capture ID gets a short-lived presigned URL
+    Presigned,
+    /// Only the owning device, through an authenticated endpoint
+    OwnerOnly,
+}
+
+impl MediaAsset {
+    /// Access policy for the asset
+    ///
+    /// Raw depth maps are never publicly accessible (see `services::privacy`).
+    pub fn access(self) -> MediaAccess {
+        match self {
+            MediaAsset::Photo
+            | MediaAsset::Video
+            | MediaAsset::Thumbnail
+            | MediaAsset::DepthHeatmap => MediaAccess::Presigned,
+            MediaAsset::DepthMap => MediaAccess::OwnerOnly,
+        }
+    }
+}
+
+/// Short-lived URL for a stored asset
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaUrl {
+    /// Presigned GET URL
+    pub url: String,
+    /// When the URL stops working
+    pub expires_at: DateTime<Utc>,
 }
 
 // ============================================================================
 // Storage Service
 // ============================================================================
 
-/// S3 storage service for capture file uploads
+/// Storage service for capture file uploads
 #[derive(Clone)]
 pub struct StorageService {
-    pub(crate) client: S3Client,
-    pub(crate) bucket: String,
+    store: Arc<dyn BlobStore>,
+    media_url_ttl: Duration,
+    /// Opens sealed objects on download; None when encryption is disabled
+    envelope: Option<Arc<Envelope>>,
+    /// Database for media key leases (see `media_leases`)
+    key_leases: Option<PgPool>,
 }
 
 impl StorageService {
-    /// Creates a new StorageService from application config
+    /// Creates a new StorageService for the backend selected in config
     pub async fn new(config: &Config) -> Self {
-        // Load AWS config with custom endpoint for LocalStack
-        let client = if config.s3_endpoint.contains("localhost")
-            || config.s3_endpoint.contains("127.0.0.1")
-            || config.s3_endpoint.contains("localstack")
-        {
-            // LocalStack configuration - use explicit credentials and path-style
-            info!(
-                endpoint = %config.s3_endpoint,
-                bucket = %config.s3_bucket,
-                "Configuring S3 client for LocalStack"
-            );
-
-            // LocalStack accepts any credentials
-            let creds = Credentials::new("test", "test", None, None, "localstack");
-
-            let s3_config = aws_sdk_s3::Config::builder()
-                .behavior_version(BehaviorVersion::latest())
-                .endpoint_url(&config.s3_endpoint)
-                .credentials_provider(creds)
-                .region(aws_sdk_s3::config::Region::new("us-east-1"))
-                .force_path_style(true)
-                .build();
-
-            S3Client::from_conf(s3_config)
-        } else {
-            // Production AWS configuration
-            info!(
-                bucket = %config.s3_bucket,
-                "Configuring S3 client for AWS"
-            );
-            let aws_config = aws_config::load_defaults(BehaviorVersion::latest()).await;
-            S3Client::new(&aws_config)
+        let store: Arc<dyn BlobStore> = match config.storage_backend {
+            StorageBackend::S3 => Arc::new(S3BlobStore::new(config).await),
+            StorageBackend::Filesystem => {
+                info!(path = %config.storage_path, "Configuring filesystem blob storage");
+                Arc::new(FsBlobStore::new(&config.storage_path))
+            }
+            StorageBackend::Memory => {
+                warn!("Configuring in-memory blob storage; media is lost on restart");
+                Arc::new(MemoryBlobStore::new())
+            }
         };
+        Self::with_store(store).with_media_url_ttl(Duration::from_secs(config.media_url_ttl_secs))
+    }
 
+    /// Creates a StorageService over an existing blob store
+    pub fn with_store(store: Arc<dyn BlobStore>) -> Self {
         Self {
-            client,
-            bucket: config.s3_bucket.clone(),
+            store,
+            media_url_ttl: Duration::from_secs(DEFAULT_MEDIA_URL_TTL_SECS),
+            envelope: None,
+            key_leases: None,
         }
     }
 
-    /// Uploads a photo to S3
+    /// Sets the lifetime of presigned media URLs
+    pub fn with_media_url_ttl(mut self, ttl: Duration) -> Self {
+        self.media_url_ttl = ttl;
+        self
+    }
+
+    /// Enables transparent decryption of sealed objects
+    pub fn with_envelope(mut self, envelope: Arc<Envelope>) -> Self {
+        self.envelope = Some(envelope);
+        self
+    }
+
+    /// Leases content-addressed keys before uploading, so concurrent capture
+    /// deletion cannot remove an object an upload is about to reference
+    pub fn with_key_leases(mut self, db: PgPool) -> Self {
+        self.key_leases = Some(db);
+        self
+    }
+
+    /// Releases the media key leases taken while uploading a capture
     ///
-    /// # Arguments
-    /// * `capture_id` - Unique capture identifier
-    /// * `photo_bytes` - Raw JPEG photo data
+    /// Call once the capture row has committed. Failures are logged only;
+    /// the leases then expire on their own.
+    pub async fn release_key_leases(&self, capture_id: Uuid) {
+        let Some(db) = &self.key_leases else {
+            return;
+        };
+        if let Err(e) = release_media_keys(db, capture_id).await {
+            warn!(capture_id = %capture_id, error = %e, "Failed to release media key leases");
+        }
+    }
+
+    /// True when capture media is encrypted at rest
     ///
-    /// # Returns
-    /// The S3 key where the photo was stored
-    pub async fn upload_photo(
+    /// Sealed objects cannot be served by presigned URL; originals are
+    /// proxied through the API instead.
+    pub fn encrypts_at_rest(&self) -> bool {
+        self.envelope.is_some()
+    }
+
+    /// Creates a StorageService backed by an empty in-memory store
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self::with_store(Arc::new(MemoryBlobStore::new()))
+    }
+
+    /// Name of the active blob store backend
+    pub fn backend_name(&self) -> &'static str {
+        self.store.name()
+    }
+
+    /// Stores uploaded media under its content-addressed key
+    ///
+    /// When an object of the same size already exists at that key the upload
+    /// is skipped; the key is derived from the bytes, so it is the same media.
+    /// With a data key the bytes are sealed first and keyed by the sealed
+    /// digest: each capture's object is encrypted under its own data key, so
+    /// sealed objects are never shared and always written.
+    async fn upload_content_addressed(
         &self,
         capture_id: Uuid,
-        photo_bytes: Vec<u8>,
+        bytes: Vec<u8>,
+        extension: &str,
+        content_type: &str,
+        label: &str,
+        data_key: Option<&DataKey>,
     ) -> Result<String, ApiError> {
-        let key = photo_s3_key(capture_id);
-        let size = photo_bytes.len();
+        if let Some(data_key) = data_key {
+            let sealed = data_key.seal(&bytes);
+            let sha256 = sha256_digest(&sealed);
+            let key = content_addressed_key(&sha256, extension);
+            return self
+                .upload_file(capture_id, key, sealed, &sha256, content_type, label)
+                .await;
+        }
+
+        let sha256 = sha256_digest(&bytes);
+        let key = content_addressed_key(&sha256, extension);
+        if let Some(db) = &self.key_leases {
+            lease_media_key(db, &key, capture_id).await.map_err(|e| {
+                warn!(capture_id = %capture_id, key = %key, error = %e, "Failed to lease {label} key");
+                ApiError::Database(e)
+            })?;
+        }
 
+        match self.store.head(&key).await {
+            Ok(Some(existing)) if existing.size == bytes.len() as u64 => {
+                info!(
+                    capture_id = %capture_id,
+                    key = %key,
+                    "Deduplicated {label}; identical object already stored"
+                );
+                return Ok(key);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!(
+                    capture_id = %capture_id,
+                    key = %key,
+                    error = %e,
+                    "Failed to check for existing {label}, uploading anyway"
+                );
+            }
+        }
+
+        self.upload_file(capture_id, key, bytes, &sha256, content_type, label)
+            .await
+    }
+
+    /// Stores one capture file, logging under `label` (e.g. "photo")
+    async fn upload_file(
+        &self,
+        capture_id: Uuid,
+        key: String,
+        bytes: Vec<u8>,
+        sha256: &[u8; 32],
+        content_type: &str,
+        label: &str,
+    ) -> Result<String, ApiError> {
         info!(
             capture_id = %capture_id,
             key = %key,
-            size_bytes = size,
-            "Uploading photo to S3"
+            size_bytes = bytes.len(),
+            backend = self.store.name(),
+            "Uploading {label} to storage"
         );
 
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .body(ByteStream::from(photo_bytes))
-            .content_type("image/jpeg")
-            .send()
+        self.store
+            .put(&key, bytes, content_type, sha256)
             .await
             .map_err(|e| {
                 warn!(
                     capture_id = %capture_id,
                     error = %e,
-                    "Failed to upload photo to S3"
+                    "Failed to upload {label} to storage"
                 );
-                ApiError::StorageError("Failed to upload photo".to_string())
+                ApiError::StorageError(format!("Failed to upload {label}"))
             })?;
 
         info!(
             capture_id = %capture_id,
             key = %key,
-            "Photo uploaded successfully"
+            "Uploaded {label} successfully"
         );
 
         Ok(key)
     }
 
-    /// Uploads a depth map to S3
+    /// Uploads a photo
+    ///
+    /// # Arguments
+    /// * `capture_id` - Unique capture identifier
+    /// * `photo_bytes` - Raw JPEG photo data
+    ///
+    /// # Returns
+    /// The content-addressed storage key of the photo
+    pub async fn upload_photo(
+        &self,
+        capture_id: Uuid,
+        photo_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
+    ) -> Result<String, ApiError> {
+        self.upload_content_addressed(
+            capture_id,
+            photo_bytes,
+            "jpg",
+            "image/jpeg",
+            "photo",
+            data_key,
+        )
+        .await
+    }
+
+    /// Uploads a depth map
     ///
     /// # Arguments
     /// * `capture_id` - Unique capture identifier
     /// * `depth_map_bytes` - Gzipped depth map data
     ///
     /// # Returns
-    /// The S3 key where the depth map was stored
+    /// The storage key where the depth map was stored
     pub async fn upload_depth_map(
         &self,
         capture_id: Uuid,
         depth_map_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<String, ApiError> {
-        let key = depth_map_s3_key(capture_id);
-        let size = depth_map_bytes.len();
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            size_bytes = size,
-            "Uploading depth map to S3"
-        );
-
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .body(ByteStream::from(depth_map_bytes))
-            .content_type("application/gzip")
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    error = %e,
-                    "Failed to upload depth map to S3"
-                );
-                ApiError::StorageError("Failed to upload depth map".to_string())
-            })?;
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            "Depth map uploaded successfully"
-        );
-
-        Ok(key)
+        self.upload_content_addressed(
+            capture_id,
+            depth_map_bytes,
+            "gz",
+            "application/gzip",
+            "depth map",
+            data_key,
+        )
+        .await
     }
 
     /// Uploads both photo and depth map for a capture
@@ -208,6 +416,7 @@ impl StorageService {
     /// * `capture_id` - Unique capture identifier
     /// * `photo_bytes` - Raw JPEG photo data
     /// * `depth_map_bytes` - Gzipped depth map data
+    /// * `data_key` - Capture data key to seal the files with, if encrypting
     ///
     /// # Returns
     /// Tuple of (photo_s3_key, depth_map_s3_key)
@@ -216,15 +425,12 @@ impl StorageService {
         capture_id: Uuid,
         photo_bytes: Vec<u8>,
         depth_map_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<(String, String), ApiError> {
-        // Clone self for the parallel uploads
-        let storage_photo = self.clone();
-        let storage_depth = self.clone();
-
         // Upload in parallel
         let (photo_result, depth_result) = tokio::join!(
-            storage_photo.upload_photo(capture_id, photo_bytes),
-            storage_depth.upload_depth_map(capture_id, depth_map_bytes),
+            self.upload_photo(capture_id, photo_bytes, data_key),
+            self.upload_depth_map(capture_id, depth_map_bytes, data_key),
         );
 
         // Handle results
@@ -234,166 +440,89 @@ impl StorageService {
         Ok((photo_key, depth_key))
     }
 
-    /// Returns the bucket name (for testing/debugging)
-    #[allow(dead_code)]
-    pub fn bucket(&self) -> &str {
-        &self.bucket
-    }
-
-    /// Uploads a video file to S3
+    /// Uploads a video file
     ///
     /// # Arguments
     /// * `capture_id` - Unique capture identifier
     /// * `video_bytes` - Raw video file data (MP4/MOV)
     ///
     /// # Returns
-    /// The S3 key where the video was stored
+    /// The content-addressed storage key of the video
     pub async fn upload_video(
         &self,
         capture_id: Uuid,
         video_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<String, ApiError> {
-        let key = video_s3_key(capture_id);
-        let size = video_bytes.len();
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            size_bytes = size,
-            "Uploading video to S3"
-        );
-
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .body(ByteStream::from(video_bytes))
-            .content_type("video/mp4")
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    error = %e,
-                    "Failed to upload video to S3"
-                );
-                ApiError::StorageError("Failed to upload video".to_string())
-            })?;
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            "Video uploaded successfully"
-        );
-
-        Ok(key)
+        self.upload_content_addressed(
+            capture_id,
+            video_bytes,
+            "mp4",
+            "video/mp4",
+            "video",
+            data_key,
+        )
+        .await
     }
 
-    /// Uploads video depth data to S3
+    /// Uploads video depth data
     ///
     /// # Arguments
     /// * `capture_id` - Unique capture identifier
     /// * `depth_bytes` - Gzipped depth keyframe data
     ///
     /// # Returns
-    /// The S3 key where the depth data was stored
+    /// The storage key where the depth data was stored
     pub async fn upload_video_depth(
         &self,
         capture_id: Uuid,
         depth_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<String, ApiError> {
-        let key = video_depth_s3_key(capture_id);
-        let size = depth_bytes.len();
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            size_bytes = size,
-            "Uploading video depth data to S3"
-        );
-
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .body(ByteStream::from(depth_bytes))
-            .content_type("application/gzip")
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    error = %e,
-                    "Failed to upload video depth data to S3"
-                );
-                ApiError::StorageError("Failed to upload video depth data".to_string())
-            })?;
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            "Video depth data uploaded successfully"
-        );
-
-        Ok(key)
+        self.upload_content_addressed(
+            capture_id,
+            depth_bytes,
+            "gz",
+            "application/gzip",
+            "video depth data",
+            data_key,
+        )
+        .await
     }
 
-    /// Uploads hash chain JSON to S3
+    /// Uploads hash chain JSON
     ///
     /// # Arguments
     /// * `capture_id` - Unique capture identifier
     /// * `hash_chain_bytes` - JSON hash chain data
     ///
     /// # Returns
-    /// The S3 key where the hash chain was stored
+    /// The storage key where the hash chain was stored
     pub async fn upload_hash_chain(
         &self,
         capture_id: Uuid,
         hash_chain_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<String, ApiError> {
-        let key = hash_chain_s3_key(capture_id);
-        let size = hash_chain_bytes.len();
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            size_bytes = size,
-            "Uploading hash chain to S3"
-        );
-
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .body(ByteStream::from(hash_chain_bytes))
-            .content_type("application/json")
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    error = %e,
-                    "Failed to upload hash chain to S3"
-                );
-                ApiError::StorageError("Failed to upload hash chain".to_string())
-            })?;
-
-        info!(
-            capture_id = %capture_id,
-            key = %key,
-            "Hash chain uploaded successfully"
-        );
-
-        Ok(key)
+        self.upload_content_addressed(
+            capture_id,
+            hash_chain_bytes,
+            "json",
+            "application/json",
+            "hash chain",
+            data_key,
+        )
+        .await
     }
 
-    /// Uploads all video capture files to S3 in parallel
+    /// Uploads all video capture files in parallel
     ///
     /// # Arguments
     /// * `capture_id` - Unique capture identifier
     /// * `video_bytes` - Raw video file data
     /// * `depth_bytes` - Gzipped depth keyframe data
     /// * `hash_chain_bytes` - JSON hash chain data
+    /// * `data_key` - Capture data key to seal the files with, if encrypting
     ///
     /// # Returns
     /// Tuple of (video_s3_key, depth_s3_key, hash_chain_s3_key)
@@ -403,17 +532,13 @@ impl StorageService {
         video_bytes: Vec<u8>,
         depth_bytes: Vec<u8>,
         hash_chain_bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
     ) -> Result<(String, String, String), ApiError> {
-        // Clone self for parallel uploads
-        let storage_video = self.clone();
-        let storage_depth = self.clone();
-        let storage_hash = self.clone();
-
         // Upload in parallel
         let (video_result, depth_result, hash_result) = tokio::join!(
-            storage_video.upload_video(capture_id, video_bytes),
-            storage_depth.upload_video_depth(capture_id, depth_bytes),
-            storage_hash.upload_hash_chain(capture_id, hash_chain_bytes),
+            self.upload_video(capture_id, video_bytes, data_key),
+            self.upload_video_depth(capture_id, depth_bytes, data_key),
+            self.upload_hash_chain(capture_id, hash_chain_bytes, data_key),
         );
 
         // Handle results
@@ -424,96 +549,198 @@ impl StorageService {
         Ok((video_key, depth_key, hash_key))
     }
 
-    /// Downloads a depth map from S3
+    /// Uploads a server-generated derivative (thumbnail, poster, heatmap)
     ///
-    /// # Arguments
-    /// * `capture_id` - Unique capture identifier
+    /// Derivatives render the original media, so they are sealed with the
+    /// capture's data key like the media itself.
     ///
     /// # Returns
-    /// The raw gzipped depth map bytes
-    pub async fn download_depth_map(&self, capture_id: Uuid) -> Result<Vec<u8>, ApiError> {
-        let key = depth_map_s3_key(capture_id);
+    /// The storage key where the derivative was stored
+    pub async fn upload_derivative(
+        &self,
+        capture_id: Uuid,
+        kind: DerivativeKind,
+        bytes: Vec<u8>,
+        data_key: Option<&DataKey>,
+    ) -> Result<String, ApiError> {
+        let bytes = match data_key {
+            Some(data_key) => data_key.seal(&bytes),
+            None => bytes,
+        };
+        let sha256 = sha256_digest(&bytes);
+        self.upload_file(
+            capture_id,
+            kind.s3_key(capture_id),
+            bytes,
+            &sha256,
+            kind.content_type(),
+            kind.label(),
+        )
+        .await
+    }
 
-        tracing::debug!(
-            capture_id = %capture_id,
-            key = %key,
-            "Downloading depth map from S3"
-        );
+    /// Downloads a stored capture file by key, decrypting sealed objects
+    pub async fn download(&self, key: &str) -> Result<Vec<u8>, ApiError> {
+        self.download_authenticated(key)
+            .await?
+            .ok_or_else(|| ApiError::StorageError(format!("Failed to decrypt {key}")))
+    }
 
-        let response = self
-            .client
-            .get_object()
-            .bucket(&self.bucket)
-            .key(&key)
-            .send()
+    /// Downloads and decrypts a stored object, reporting tampering as None
+    ///
+    /// A sealed object that fails authentication (altered ciphertext or
+    /// wrapped key) yields `Ok(None)`; a missing or unknown master key is
+    /// still a storage error, as the object itself may be intact.
+    pub async fn download_authenticated(&self, key: &str) -> Result<Option<Vec<u8>>, ApiError> {
+        let bytes = self.download_stored(key).await?;
+        if !Envelope::is_sealed(&bytes) {
+            return Ok(Some(bytes));
+        }
+
+        let Some(envelope) = &self.envelope else {
+            warn!(key = %key, "Stored object is encrypted but no master key is configured");
+            return Err(ApiError::StorageError(format!(
+                "Cannot decrypt {key}: no master key configured"
+            )));
+        };
+        match envelope.open(bytes).await {
+            Ok(plaintext) => Ok(Some(plaintext)),
+            Err(e @ (EnvelopeError::Decrypt | EnvelopeError::Malformed)) => {
+                warn!(key = %key, error = %e, "Stored object failed authentication");
+                Ok(None)
+            }
+            Err(e) => {
+                warn!(key = %key, error = %e, "Failed to decrypt stored object");
+                Err(ApiError::StorageError(format!("Failed to decrypt {key}")))
+            }
+        }
+    }
+
+    /// Downloads the object exactly as stored (sealed objects stay sealed)
+    async fn download_stored(&self, key: &str) -> Result<Vec<u8>, ApiError> {
+        self.store.get(key).await.map_err(|e| {
+            warn!(key = %key, error = %e, "Failed to download file from storage");
+            match e {
+                BlobStoreError::NotFound(_) => ApiError::CaptureNotFound,
+                e => ApiError::StorageError(format!("Failed to download {key}: {e}")),
+            }
+        })
+    }
+
+    /// Re-wraps a sealed object's data key under the active master key
+    ///
+    /// Sealed objects are keyed by their ciphertext digest, so the re-wrapped
+    /// ciphertext is written under its own content-addressed key. The original
+    /// object is left in place; the caller deletes it once nothing references
+    /// it any more.
+    ///
+    /// # Returns
+    /// The new key if a re-wrapped copy was written; None if the object is
+    /// unsealed or already uses the active key
+    pub async fn rewrap(&self, key: &str) -> Result<Option<String>, ApiError> {
+        let Some(envelope) = &self.envelope else {
+            return Ok(None);
+        };
+        let stored = self.download_stored(key).await?;
+        let rewrapped = envelope.rewrap(&stored).await.map_err(|e| {
+            warn!(key = %key, error = %e, "Failed to re-wrap stored object");
+            ApiError::StorageError(format!("Failed to re-wrap {key}"))
+        })?;
+        let Some(rewrapped) = rewrapped else {
+            return Ok(None);
+        };
+
+        let content_type = match self.store.head(key).await {
+            Ok(Some(meta)) => meta.content_type,
+            _ => None,
+        }
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+        let extension = key
+            .rsplit_once('/')
+            .map_or(key, |(_, file)| file)
+            .rsplit_once('.')
+            .map_or("bin", |(_, extension)| extension);
+        let sha256 = sha256_digest(&rewrapped);
+        let new_key = content_addressed_key(&sha256, extension);
+        self.store
+            .put(&new_key, rewrapped, &content_type, &sha256)
             .await
             .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    key = %key,
-                    error = %e,
-                    "Failed to download depth map from S3"
-                );
-                ApiError::StorageError(format!("Failed to download depth map: {e}"))
+                warn!(key = %key, new_key = %new_key, error = %e, "Failed to store re-wrapped object");
+                ApiError::StorageError(format!("Failed to re-wrap {key}"))
             })?;
+        Ok(Some(new_key))
+    }
 
-        let bytes = response
-            .body
-            .collect()
+    /// Removes a stored object (succeeds if it does not exist)
+    pub async fn delete(&self, key: &str) -> Result<(), ApiError> {
+        self.store.delete(key).await.map_err(|e| {
+            warn!(key = %key, error = %e, "Failed to delete file from storage");
+            ApiError::StorageError(format!("Failed to delete {key}"))
+        })
+    }
+
+    /// Issues a presigned GET URL for a publicly shareable asset
+    ///
+    /// Returns None for owner-only assets (see `MediaAsset::access`); those
+    /// are served through authenticated endpoints instead. Also None for
+    /// every asset when encrypting at rest, as stored objects are sealed, and
+    /// for anything on a backend that cannot sign URLs (filesystem, memory);
+    /// originals and derivatives are then served through `/verify/{id}/media`.
+    pub async fn public_media_url(
+        &self,
+        asset: MediaAsset,
+        key: &str,
+    ) -> Result<Option<MediaUrl>, ApiError> {
+        if asset.access() != MediaAccess::Presigned {
+            return Ok(None);
+        }
+        if self.encrypts_at_rest() {
+            return Ok(None);
+        }
+
+        let expires_at = Utc::now()
+            + chrono::Duration::from_std(self.media_url_ttl).unwrap_or(chrono::Duration::zero());
+        let url = self
+            .store
+            .presign_get(key, self.media_url_ttl)
             .await
             .map_err(|e| {
-                warn!(
-                    capture_id = %capture_id,
-                    error = %e,
-                    "Failed to read depth map body from S3"
-                );
-                ApiError::StorageError(format!("Failed to read depth map body: {e}"))
-            })?
-            .into_bytes()
-            .to_vec();
-
-        tracing::debug!(
-            capture_id = %capture_id,
-            key = %key,
-            size_bytes = bytes.len(),
-            "Depth map downloaded successfully"
-        );
+                warn!(key = %key, error = %e, "Failed to presign media URL");
+                ApiError::StorageError(format!("Failed to presign {key}"))
+            })?;
 
-        Ok(bytes)
+        Ok(url.map(|url| MediaUrl { url, expires_at }))
     }
 
-    /// Uploads a JSON string to S3 at a given key (Story 8-5)
+    /// Uploads a JSON string at a given key (Story 8-5)
     ///
     /// Generic method for uploading JSON documents like C2PA manifests.
     ///
     /// # Arguments
-    /// * `key` - S3 object key
+    /// * `key` - Storage object key
     /// * `json_content` - JSON string to upload
     ///
     /// # Returns
-    /// The S3 key where the JSON was stored
+    /// The storage key where the JSON was stored
     pub async fn upload_json(&self, key: &str, json_content: &str) -> Result<String, ApiError> {
-        let size = json_content.len();
-
         info!(
             key = %key,
-            size_bytes = size,
-            "Uploading JSON to S3"
+            size_bytes = json_content.len(),
+            backend = self.store.name(),
+            "Uploading JSON to storage"
         );
 
-        self.client
-            .put_object()
-            .bucket(&self.bucket)
-            .key(key)
-            .body(ByteStream::from(json_content.as_bytes().to_vec()))
-            .content_type("application/json")
-            .send()
+        let bytes = json_content.as_bytes().to_vec();
+        let sha256 = sha256_digest(&bytes);
+        self.store
+            .put(key, bytes, "application/json", &sha256)
             .await
             .map_err(|e| {
                 warn!(
                     key = %key,
                     error = %e,
-                    "Failed to upload JSON to S3"
+                    "Failed to upload JSON to storage"
                 );
                 ApiError::StorageError(format!("Failed to upload JSON to {key}"))
             })?;
@@ -534,54 +761,446 @@ impl StorageService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::blob_store::BlobMetadata;
 
     #[test]
-    fn test_photo_s3_key() {
-        let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let key = photo_s3_key(capture_id);
+    fn test_content_addressed_key() {
+        let digest = sha256_digest(b"photo bytes");
+        let hex = hex::encode(digest);
+        assert_eq!(
+            content_addressed_key(&digest, "jpg"),
+            format!("media/sha256/{}/{hex}.jpg", &hex[..2])
+        );
         assert_eq!(
-            key,
-            "captures/550e8400-e29b-41d4-a716-446655440000/photo.jpg"
+            hex::encode(sha256_digest(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
         );
     }
 
-    #[test]
-    fn test_depth_map_s3_key() {
-        let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let key = depth_map_s3_key(capture_id);
+    #[tokio::test]
+    async fn test_upload_capture_files_round_trip() {
+        let storage = StorageService::in_memory();
+        let capture_id = Uuid::new_v4();
+
+        let (photo_key, depth_key) = storage
+            .upload_capture_files(capture_id, vec![0xFF, 0xD8], vec![0x1F, 0x8B, 0x08], None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            photo_key,
+            content_addressed_key(&sha256_digest(&[0xFF, 0xD8]), "jpg")
+        );
+        assert_eq!(
+            depth_key,
+            content_addressed_key(&sha256_digest(&[0x1F, 0x8B, 0x08]), "gz")
+        );
         assert_eq!(
-            key,
-            "captures/550e8400-e29b-41d4-a716-446655440000/depth.gz"
+            storage.download(&depth_key).await.unwrap(),
+            vec![0x1F, 0x8B, 0x08]
         );
     }
 
-    #[test]
-    fn test_video_s3_key() {
-        let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let key = video_s3_key(capture_id);
+    #[tokio::test]
+    async fn test_video_files_are_content_addressed() {
+        use crate::services::envelope::LocalKeyring;
+
+        let storage = StorageService::in_memory();
+        let (video_key, depth_key, hash_chain_key) = storage
+            .upload_video_files(
+                Uuid::new_v4(),
+                b"video".to_vec(),
+                b"depth".to_vec(),
+                b"chain".to_vec(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            video_key,
+            content_addressed_key(&sha256_digest(b"video"), "mp4")
+        );
+        assert_eq!(
+            depth_key,
+            content_addressed_key(&sha256_digest(b"depth"), "gz")
+        );
         assert_eq!(
-            key,
-            "captures/550e8400-e29b-41d4-a716-446655440000/video.mp4"
+            hash_chain_key,
+            content_addressed_key(&sha256_digest(b"chain"), "json")
+        );
+
+        // Sealed files are keyed by the digest of the stored ciphertext
+        let envelope = Arc::new(Envelope::new(Arc::new(LocalKeyring::generate(&["k1"]))));
+        let store = Arc::new(MemoryBlobStore::new());
+        let storage = StorageService::with_store(store.clone()).with_envelope(envelope.clone());
+        let data_key = envelope.generate_data_key().await.unwrap();
+        let (video_key, depth_key, hash_chain_key) = storage
+            .upload_video_files(
+                Uuid::new_v4(),
+                b"video".to_vec(),
+                b"depth".to_vec(),
+                b"chain".to_vec(),
+                Some(&data_key),
+            )
+            .await
+            .unwrap();
+
+        for (key, extension) in [
+            (&video_key, "mp4"),
+            (&depth_key, "gz"),
+            (&hash_chain_key, "json"),
+        ] {
+            let sealed = store.get(key).await.unwrap();
+            assert!(Envelope::is_sealed(&sealed));
+            assert_eq!(
+                *key,
+                content_addressed_key(&sha256_digest(&sealed), extension)
+            );
+        }
+        assert_ne!(
+            video_key,
+            content_addressed_key(&sha256_digest(b"video"), "mp4")
         );
     }
 
+    #[tokio::test]
+    async fn test_identical_media_is_deduplicated() {
+        let storage = StorageService::in_memory();
+        let first = storage
+            .upload_photo(Uuid::new_v4(), vec![1, 2, 3], None)
+            .await
+            .unwrap();
+        let second = storage
+            .upload_photo(Uuid::new_v4(), vec![1, 2, 3], None)
+            .await
+            .unwrap();
+        assert_eq!(first, second);
+
+        let other = storage
+            .upload_photo(Uuid::new_v4(), vec![4, 5, 6], None)
+            .await
+            .unwrap();
+        assert_ne!(first, other);
+    }
+
     #[test]
-    fn test_hash_chain_s3_key() {
-        let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let key = hash_chain_s3_key(capture_id);
+    fn test_depth_maps_are_owner_only() {
+        assert_eq!(MediaAsset::Photo.access(), MediaAccess::Presigned);
+        assert_eq!(MediaAsset::Video.access(), MediaAccess::Presigned);
+        assert_eq!(MediaAsset::Thumbnail.access(), MediaAccess::Presigned);
+        assert_eq!(MediaAsset::DepthHeatmap.access(), MediaAccess::Presigned);
+        assert_eq!(MediaAsset::DepthMap.access(), MediaAccess::OwnerOnly);
+    }
+
+    /// Memory store that signs URLs like S3, for presigning policy tests
+    #[derive(Default)]
+    struct SigningStore(MemoryBlobStore);
+
+    #[async_trait::async_trait]
+    impl BlobStore for SigningStore {
+        fn name(&self) -> &'static str {
+            "signing"
+        }
+
+        async fn put(
+            &self,
+            key: &str,
+            bytes: Vec<u8>,
+            content_type: &str,
+            sha256: &[u8; 32],
+        ) -> Result<(), BlobStoreError> {
+            self.0.put(key, bytes, content_type, sha256).await
+        }
+
+        async fn get(&self, key: &str) -> Result<Vec<u8>, BlobStoreError> {
+            self.0.get(key).await
+        }
+
+        async fn head(&self, key: &str) -> Result<Option<BlobMetadata>, BlobStoreError> {
+            self.0.head(key).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), BlobStoreError> {
+            self.0.delete(key).await
+        }
+
+        async fn presign_get(
+            &self,
+            key: &str,
+            expires_in: Duration,
+        ) -> Result<Option<String>, BlobStoreError> {
+            Ok(Some(format!(
+                "https://signed.test/{key}?expires_in={}",
+                expires_in.as_secs()
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsignable_backend_yields_no_url() {
+        let storage = StorageService::in_memory();
+        let (photo_key, _) = storage
+            .upload_capture_files(Uuid::new_v4(), vec![5], vec![6], None)
+            .await
+            .unwrap();
+        assert!(storage
+            .public_media_url(MediaAsset::Photo, &photo_key)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_public_media_url_respects_policy_and_ttl() {
+        let storage = StorageService::with_store(Arc::new(SigningStore::default()))
+            .with_media_url_ttl(Duration::from_secs(120));
+        let capture_id = Uuid::new_v4();
+        let (photo_key, depth_key) = storage
+            .upload_capture_files(capture_id, vec![1], vec![2], None)
+            .await
+            .unwrap();
+
+        let before = Utc::now();
+        let photo = storage
+            .public_media_url(MediaAsset::Photo, &photo_key)
+            .await
+            .unwrap()
+            .expect("photos are presigned");
+        assert!(photo.url.contains(&photo_key));
+        assert!(photo.url.contains("expires_in=120"));
+        assert!(photo.expires_at >= before + chrono::Duration::seconds(120));
+        assert!(photo.expires_at <= Utc::now() + chrono::Duration::seconds(120));
+
+        let depth = storage
+            .public_media_url(MediaAsset::DepthMap, &depth_key)
+            .await
+            .unwrap();
+        assert!(depth.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_media_round_trip() {
+        use crate::services::envelope::LocalKeyring;
+
+        let envelope = Arc::new(Envelope::new(Arc::new(LocalKeyring::generate(&["k1"]))));
+        let store = Arc::new(MemoryBlobStore::new());
+        let storage = StorageService::with_store(store.clone()).with_envelope(envelope.clone());
+        let data_key = envelope.generate_data_key().await.unwrap();
+
+        let (photo_key, _) = storage
+            .upload_capture_files(Uuid::new_v4(), vec![0xFF, 0xD8], vec![2], Some(&data_key))
+            .await
+            .unwrap();
+
+        // Keyed by the sealed digest, stored sealed, downloaded as plaintext
+        let sealed = store.get(&photo_key).await.unwrap();
+        assert!(Envelope::is_sealed(&sealed));
         assert_eq!(
-            key,
-            "captures/550e8400-e29b-41d4-a716-446655440000/hash_chain.json"
+            photo_key,
+            content_addressed_key(&sha256_digest(&sealed), "jpg")
         );
+        assert_eq!(
+            storage.download(&photo_key).await.unwrap(),
+            vec![0xFF, 0xD8]
+        );
+
+        // Presigned URLs would hand out ciphertext
+        assert!(storage
+            .public_media_url(MediaAsset::Photo, &photo_key)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Without the master key the object cannot be read
+        let unkeyed = StorageService::with_store(store);
+        assert!(matches!(
+            unkeyed.download(&photo_key).await,
+            Err(ApiError::StorageError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_derivative_round_trip() {
+        use crate::services::envelope::LocalKeyring;
+
+        let envelope = Arc::new(Envelope::new(Arc::new(LocalKeyring::generate(&["k1"]))));
+        let store = Arc::new(MemoryBlobStore::new());
+        let storage = StorageService::with_store(store.clone()).with_envelope(envelope.clone());
+        let data_key = envelope.generate_data_key().await.unwrap();
+        let capture_id = Uuid::new_v4();
+
+        let key = storage
+            .upload_derivative(
+                capture_id,
+                DerivativeKind::Poster,
+                vec![0xFF, 0xD8],
+                Some(&data_key),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(key, DerivativeKind::Poster.s3_key(capture_id));
+        assert!(Envelope::is_sealed(&store.get(&key).await.unwrap()));
+        assert_eq!(storage.download(&key).await.unwrap(), vec![0xFF, 0xD8]);
+        for asset in [MediaAsset::Thumbnail, MediaAsset::DepthHeatmap] {
+            assert!(storage
+                .public_media_url(asset, &key)
+                .await
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_identical_sealed_media_is_not_shared() {
+        use crate::services::envelope::LocalKeyring;
+
+        let envelope = Arc::new(Envelope::new(Arc::new(LocalKeyring::generate(&["k1"]))));
+        let storage = StorageService::in_memory().with_envelope(envelope.clone());
+        let plaintext = vec![0xFF, 0xD8, 0x42];
+
+        let first_key = envelope.generate_data_key().await.unwrap();
+        let second_key = envelope.generate_data_key().await.unwrap();
+        let first = storage
+            .upload_photo(Uuid::new_v4(), plaintext.clone(), Some(&first_key))
+            .await
+            .unwrap();
+        let second = storage
+            .upload_photo(Uuid::new_v4(), plaintext.clone(), Some(&second_key))
+            .await
+            .unwrap();
+
+        // Each capture gets its own object under its own data key
+        assert_ne!(first, second);
+        assert_eq!(storage.download(&first).await.unwrap(), plaintext);
+        assert_eq!(storage.download(&second).await.unwrap(), plaintext);
+
+        // Shredding one capture's object leaves the other readable
+        storage.delete(&first).await.unwrap();
+        assert!(matches!(
+            storage.download(&first).await,
+            Err(ApiError::CaptureNotFound)
+        ));
+        assert_eq!(storage.download(&second).await.unwrap(), plaintext);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_sealed_object_fails_authentication() {
+        use crate::services::envelope::LocalKeyring;
+
+        let envelope = Arc::new(Envelope::new(Arc::new(LocalKeyring::generate(&["k1"]))));
+        let store = Arc::new(MemoryBlobStore::new());
+        let storage = StorageService::with_store(store.clone()).with_envelope(envelope.clone());
+        let data_key = envelope.generate_data_key().await.unwrap();
+        let (photo_key, _) = storage
+            .upload_capture_files(
+                Uuid::new_v4(),
+                vec![0xFF, 0xD8, 0x01],
+                vec![2],
+                Some(&data_key),
+            )
+            .await
+            .unwrap();
+
+        // Flip the last ciphertext byte (part of the GCM tag)
+        let mut sealed = store.get(&photo_key).await.unwrap();
+        *sealed.last_mut().unwrap() ^= 0x01;
+        let sha256 = sha256_digest(&sealed);
+        store
+            .put(&photo_key, sealed, "image/jpeg", &sha256)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.download_authenticated(&photo_key).await.unwrap(),
+            None
+        );
+        assert!(matches!(
+            storage.download(&photo_key).await,
+            Err(ApiError::StorageError(_))
+        ));
+
+        // Without the master key the object may be intact; still an error
+        let unkeyed = StorageService::with_store(store);
+        assert!(matches!(
+            unkeyed.download_authenticated(&photo_key).await,
+            Err(ApiError::StorageError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rewrap_stores_under_new_content_addressed_key() {
+        use crate::services::envelope::LocalKeyring;
+        use base64::Engine;
+
+        let old_line = format!(
+            "old\t{}",
+            base64::engine::general_purpose::STANDARD.encode([1u8; 32])
+        );
+        let new_line = format!(
+            "new\t{}",
+            base64::engine::general_purpose::STANDARD.encode([2u8; 32])
+        );
+        let old = Arc::new(Envelope::new(Arc::new(
+            LocalKeyring::from_tsv(&old_line).unwrap(),
+        )));
+        let rotated = Arc::new(Envelope::new(Arc::new(
+            LocalKeyring::from_tsv(&format!("{old_line}\n{new_line}")).unwrap(),
+        )));
+        let store = Arc::new(MemoryBlobStore::new());
+        let data_key = old.generate_data_key().await.unwrap();
+        let photo_key = StorageService::with_store(store.clone())
+            .with_envelope(old)
+            .upload_photo(Uuid::new_v4(), vec![0xFF, 0xD8, 0x07], Some(&data_key))
+            .await
+            .unwrap();
+
+        let storage = StorageService::with_store(store.clone()).with_envelope(rotated);
+        let new_key = storage.rewrap(&photo_key).await.unwrap().unwrap();
+
+        // The new object's key matches its ciphertext; the old one is untouched
+        let sealed = store.get(&new_key).await.unwrap();
+        assert_ne!(new_key, photo_key);
+        assert_eq!(
+            new_key,
+            content_addressed_key(&sha256_digest(&sealed), "jpg")
+        );
+        assert_eq!(
+            storage.download(&new_key).await.unwrap(),
+            vec![0xFF, 0xD8, 0x07]
+        );
+        assert_eq!(
+            photo_key,
+            content_addressed_key(&sha256_digest(&store.get(&photo_key).await.unwrap()), "jpg")
+        );
+
+        // Already on the active key
+        assert_eq!(storage.rewrap(&new_key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_download_missing_key_is_not_found() {
+        let storage = StorageService::in_memory();
+        let result = storage.download("captures/missing/depth.gz").await;
+        assert!(matches!(result, Err(ApiError::CaptureNotFound)));
     }
 
     #[test]
-    fn test_video_depth_s3_key() {
+    fn test_derivative_s3_keys() {
         let capture_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
-        let key = video_depth_s3_key(capture_id);
         assert_eq!(
-            key,
-            "captures/550e8400-e29b-41d4-a716-446655440000/video_depth.gz"
+            DerivativeKind::ThumbnailWebp.s3_key(capture_id),
+            "captures/550e8400-e29b-41d4-a716-446655440000/thumbnail.webp"
         );
+        assert_eq!(
+            DerivativeKind::DepthHeatmap.s3_key(capture_id),
+            "captures/550e8400-e29b-41d4-a716-446655440000/depth_heatmap.png"
+        );
+        assert_eq!(DerivativeKind::Poster.content_type(), "image/jpeg");
+        for kind in DerivativeKind::ALL {
+            assert_eq!(DerivativeKind::from_file_name(kind.file_name()), Some(kind));
+        }
+        assert_eq!(DerivativeKind::from_file_name("depth.gz"), None);
     }
 }
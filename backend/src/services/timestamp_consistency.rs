@@ -0,0 +1,301 @@
+//! Timestamp Consistency Service
+//!
+//! `validate_timestamp` only compares `captured_at` with server time at
+//! upload. It cannot tell whether the timestamp agrees with the device's own
+//! history, e.g. a capture dated earlier than one the device took before it.
+//! This service checks the timestamp against that history.
+//!
+//! ## Checks
+//! 1. **Registration:** a capture cannot be taken before its device was
+//!    registered (allowing the usual clock skew window)
+//! 2. **Counter order:** the assertion counter is the device's true capture
+//!    order, so `captured_at` must not be earlier than a capture with a lower
+//!    counter, or later than a capture with a higher counter
+//! 3. **Capture rate:** the number of captures within a short window around
+//!    `captured_at` must be physically achievable
+//!
+//! All checks are NON-BLOCKING; violations are recorded in
+//! `MetadataEvidence.timestamp_consistency` with a reason.
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::models::TimestampConsistency;
+
+// ============================================================================
+// Configuration Constants
+// ============================================================================
+
+/// Allowed device clock skew relative to registration (matches the 15 minute
+/// server-time window)
+const REGISTRATION_SKEW_SECONDS: i64 = 900;
+
+/// Allowed out-of-order slack between captures of the same device clock
+const ORDER_SKEW_SECONDS: i64 = 120;
+
+/// Half-width of the window used to measure capture rate
+const RATE_WINDOW_SECONDS: i64 = 30;
+
+/// Maximum captures (including this one) within the rate window
+const MAX_CAPTURES_PER_WINDOW: i64 = 60;
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+/// Capture history of a device relative to a new capture
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeviceCaptureHistory {
+    /// Latest `captured_at` among captures with a lower assertion counter
+    pub latest_before: Option<DateTime<Utc>>,
+    /// Earliest `captured_at` among captures with a higher assertion counter
+    pub earliest_after: Option<DateTime<Utc>>,
+    /// Existing captures within `RATE_WINDOW_SECONDS` of `captured_at`
+    pub captures_in_window: i64,
+}
+
+// ============================================================================
+// History Loading
+// ============================================================================
+
+/// Loads the device's capture history around a new capture
+///
+/// # Arguments
+/// * `pool` - Database pool
+/// * `device_id` - Uploading device
+/// * `captured_at` - Claimed capture time
+/// * `counter` - Assertion counter of the capture (None skips order lookups)
+pub async fn load_device_capture_history(
+    pool: &PgPool,
+    device_id: Uuid,
+    captured_at: DateTime<Utc>,
+    counter: Option<i64>,
+) -> Result<DeviceCaptureHistory, sqlx::Error> {
+    let window = Duration::seconds(RATE_WINDOW_SECONDS);
+    let (latest_before, earliest_after, captures_in_window) =
+        sqlx::query_as::<_, (Option<DateTime<Utc>>, Option<DateTime<Utc>>, i64)>(
+            r#"
+            SELECT
+                MAX(captured_at) FILTER (WHERE assertion_counter < $2),
+                MIN(captured_at) FILTER (WHERE assertion_counter > $2),
+                COUNT(*) FILTER (WHERE captured_at BETWEEN $3 AND $4)::BIGINT
+            FROM captures
+            WHERE device_id = $1
+            "#,
+        )
+        .bind(device_id)
+        .bind(counter)
+        .bind(captured_at - window)
+        .bind(captured_at + window)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(DeviceCaptureHistory {
+        latest_before,
+        earliest_after,
+        captures_in_window,
+    })
+}
+
+// ============================================================================
+// Consistency Check
+// ============================================================================
+
+/// Checks a capture timestamp against device registration and history
+///
+/// # Arguments
+/// * `captured_at` - Claimed capture time
+/// * `registered_at` - When the device was registered (`devices.first_seen_at`)
+/// * `history` - Device capture history around `captured_at`
+///
+/// # Returns
+/// TimestampConsistency with the joined violation reasons, if any
+pub fn check_timestamp_consistency(
+    captured_at: DateTime<Utc>,
+    registered_at: DateTime<Utc>,
+    history: &DeviceCaptureHistory,
+) -> TimestampConsistency {
+    let mut reasons = Vec::new();
+
+    if captured_at < registered_at - Duration::seconds(REGISTRATION_SKEW_SECONDS) {
+        reasons.push(format!(
+            "captured_at {} precedes device registration at {}",
+            captured_at.to_rfc3339(),
+            registered_at.to_rfc3339()
+        ));
+    }
+
+    let order_skew = Duration::seconds(ORDER_SKEW_SECONDS);
+    if let Some(latest_before) = history.latest_before {
+        if captured_at < latest_before - order_skew {
+            reasons.push(format!(
+                "captured_at is earlier than a capture with a lower assertion counter ({})",
+                latest_before.to_rfc3339()
+            ));
+        }
+    }
+    if let Some(earliest_after) = history.earliest_after {
+        if captured_at > earliest_after + order_skew {
+            reasons.push(format!(
+                "captured_at is later than a capture with a higher assertion counter ({})",
+                earliest_after.to_rfc3339()
+            ));
+        }
+    }
+
+    let captures = history.captures_in_window + 1;
+    if captures > MAX_CAPTURES_PER_WINDOW {
+        reasons.push(format!(
+            "{captures} captures within {}s exceeds the plausible capture rate",
+            RATE_WINDOW_SECONDS * 2
+        ));
+    }
+
+    debug!(
+        captured_at = %captured_at,
+        registered_at = %registered_at,
+        captures_in_window = history.captures_in_window,
+        violations = reasons.len(),
+        "[timestamp_consistency] Timestamp checked against device history"
+    );
+
+    TimestampConsistency {
+        consistent: reasons.is_empty(),
+        reason: (!reasons.is_empty()).then(|| reasons.join("; ")),
+    }
+}
+
+/// Loads the device history and checks a capture timestamp against it
+///
+/// # Arguments
+/// * `pool` - Database pool
+/// * `device_id` - Uploading device
+/// * `registered_at` - When the device was registered
+/// * `captured_at` - Claimed capture time
+/// * `counter` - Assertion counter of the capture, if verified
+pub async fn check_device_timestamp(
+    pool: &PgPool,
+    device_id: Uuid,
+    registered_at: DateTime<Utc>,
+    captured_at: DateTime<Utc>,
+    counter: Option<i64>,
+) -> Result<TimestampConsistency, sqlx::Error> {
+    let history = load_device_capture_history(pool, device_id, captured_at, counter).await?;
+    Ok(check_timestamp_consistency(
+        captured_at,
+        registered_at,
+        &history,
+    ))
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registered_at() -> DateTime<Utc> {
+        "2025-12-01T00:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_consistent_without_history() {
+        let result = check_timestamp_consistency(
+            registered_at() + Duration::days(1),
+            registered_at(),
+            &DeviceCaptureHistory::default(),
+        );
+        assert!(result.consistent);
+        assert!(result.reason.is_none());
+    }
+
+    #[test]
+    fn test_precedes_registration() {
+        let result = check_timestamp_consistency(
+            registered_at() - Duration::hours(2),
+            registered_at(),
+            &DeviceCaptureHistory::default(),
+        );
+        assert!(!result.consistent);
+        assert!(result
+            .reason
+            .unwrap()
+            .contains("precedes device registration"));
+    }
+
+    #[test]
+    fn test_registration_skew_tolerated() {
+        let result = check_timestamp_consistency(
+            registered_at() - Duration::minutes(5),
+            registered_at(),
+            &DeviceCaptureHistory::default(),
+        );
+        assert!(result.consistent);
+    }
+
+    #[test]
+    fn test_earlier_than_lower_counter_capture() {
+        let captured_at = registered_at() + Duration::days(1);
+        let history = DeviceCaptureHistory {
+            latest_before: Some(captured_at + Duration::hours(1)),
+            ..Default::default()
+        };
+        let result = check_timestamp_consistency(captured_at, registered_at(), &history);
+        assert!(!result.consistent);
+        assert!(result.reason.unwrap().contains("lower assertion counter"));
+    }
+
+    #[test]
+    fn test_later_than_higher_counter_capture() {
+        let captured_at = registered_at() + Duration::days(1);
+        let history = DeviceCaptureHistory {
+            earliest_after: Some(captured_at - Duration::hours(1)),
+            ..Default::default()
+        };
+        let result = check_timestamp_consistency(captured_at, registered_at(), &history);
+        assert!(!result.consistent);
+        assert!(result.reason.unwrap().contains("higher assertion counter"));
+    }
+
+    #[test]
+    fn test_order_skew_tolerated() {
+        let captured_at = registered_at() + Duration::days(1);
+        let history = DeviceCaptureHistory {
+            latest_before: Some(captured_at + Duration::seconds(60)),
+            earliest_after: Some(captured_at - Duration::seconds(60)),
+            captures_in_window: 2,
+        };
+        let result = check_timestamp_consistency(captured_at, registered_at(), &history);
+        assert!(result.consistent);
+    }
+
+    #[test]
+    fn test_impossible_capture_rate() {
+        let history = DeviceCaptureHistory {
+            captures_in_window: MAX_CAPTURES_PER_WINDOW,
+            ..Default::default()
+        };
+        let result = check_timestamp_consistency(
+            registered_at() + Duration::days(1),
+            registered_at(),
+            &history,
+        );
+        assert!(!result.consistent);
+        assert!(result.reason.unwrap().contains("capture rate"));
+    }
+
+    #[test]
+    fn test_multiple_violations_joined() {
+        let captured_at = registered_at() - Duration::days(1);
+        let history = DeviceCaptureHistory {
+            latest_before: Some(registered_at() + Duration::days(1)),
+            ..Default::default()
+        };
+        let result = check_timestamp_consistency(captured_at, registered_at(), &history);
+        assert_eq!(result.reason.unwrap().split("; ").count(), 2);
+    }
+}
@@ -0,0 +1,475 @@
+//! Verification Activity Log
+//!
+//! Records `/verify-file` and `/verify/{id}` lookups, capture uploads and
+//! device registrations in `verification_logs`, and summarizes them for
+//! capture owners and operators. Rows are written by the
+//! `log_verification_activity` middleware.
+//!
+//! ## Client IP Privacy
+//! Client IPs are never stored as received. `VERIFICATION_LOG_IP_MODE`:
+//! - `truncate` (default): keep only the /24 (IPv4) or /48 (IPv6) network
+//! - `hash`: keep a salted SHA-256 (`VERIFICATION_LOG_IP_SALT`), enough to
+//!   count distinct clients without recording addresses
+//! - `none`: keep nothing
+//!
+//! ## Error Handling
+//! Writes are fire-and-forget: a failed insert is logged and never fails
+//! the request being recorded.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::services::storage::sha256_digest;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Longest User-Agent stored; longer values are cut
+pub const MAX_USER_AGENT_LEN: usize = 512;
+
+/// Most recent checks returned to capture owners
+const RECENT_CHECKS_LIMIT: i64 = 50;
+
+/// Days of daily counts returned to capture owners
+const OWNER_HISTORY_DAYS: i32 = 30;
+
+/// Most-verified captures listed in operator stats
+const TOP_CAPTURES_LIMIT: i64 = 10;
+
+// ============================================================================
+// Actions
+// ============================================================================
+
+/// Kind of request recorded in `verification_logs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationAction {
+    /// POST /verify-file
+    VerifyFile,
+    /// GET /verify/{id}
+    Verify,
+    /// Photo, video or hash-only capture upload
+    Upload,
+    /// POST /devices/register
+    Register,
+}
+
+impl VerificationAction {
+    /// Value stored in `verification_logs.action`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VerificationAction::VerifyFile => "verify_file",
+            VerificationAction::Verify => "verify",
+            VerificationAction::Upload => "upload",
+            VerificationAction::Register => "register",
+        }
+    }
+}
+
+impl fmt::Display for VerificationAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Actions that count as someone checking a capture
+const CHECK_ACTIONS: [&str; 2] = ["verify", "verify_file"];
+
+// ============================================================================
+// Client IP Privacy
+// ============================================================================
+
+/// How client IPs are reduced before storage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IpLogMode {
+    /// Keep the /24 (IPv4) or /48 (IPv6) network
+    #[default]
+    Truncate,
+    /// Keep a salted SHA-256 of the address
+    Hash,
+    /// Keep nothing
+    Omit,
+}
+
+impl FromStr for IpLogMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "truncate" => Ok(IpLogMode::Truncate),
+            "hash" => Ok(IpLogMode::Hash),
+            "none" => Ok(IpLogMode::Omit),
+            _ => Err(format!("Unknown IP log mode: {s}")),
+        }
+    }
+}
+
+/// Zeroes the host part of an address (/24 for IPv4, /48 for IPv6)
+pub fn truncate_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, c, _] = v4.octets();
+            IpAddr::V4(Ipv4Addr::new(a, b, c, 0))
+        }
+        IpAddr::V6(v6) => {
+            // IPv4-mapped addresses are truncated as IPv4
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return truncate_ip(IpAddr::V4(v4));
+            }
+            let s = v6.segments();
+            IpAddr::V6(Ipv6Addr::new(s[0], s[1], s[2], 0, 0, 0, 0, 0))
+        }
+    }
+}
+
+/// Salted SHA-256 of an address, hex encoded
+pub fn hash_ip(ip: IpAddr, salt: &str) -> String {
+    hex::encode(sha256_digest(format!("{salt}:{ip}").as_bytes()))
+}
+
+// ============================================================================
+// Logger
+// ============================================================================
+
+/// One request to record
+#[derive(Debug, Clone)]
+pub struct NewVerificationLog {
+    pub action: VerificationAction,
+    pub outcome: String,
+    /// Capture concerned; ignored if no such capture exists
+    pub capture_id: Option<Uuid>,
+    pub device_id: Option<Uuid>,
+    pub client_ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+/// Writes `verification_logs` rows with client IPs reduced per config
+#[derive(Clone)]
+pub struct VerificationLogger {
+    db: PgPool,
+    ip_mode: IpLogMode,
+    ip_salt: String,
+}
+
+impl VerificationLogger {
+    /// Creates a logger; `ip_salt` is only used in `IpLogMode::Hash`
+    pub fn new(db: PgPool, ip_mode: IpLogMode, ip_salt: String) -> Self {
+        Self {
+            db,
+            ip_mode,
+            ip_salt,
+        }
+    }
+
+    /// Stored forms of a client IP: (truncated address, hash)
+    pub fn reduce_ip(&self, ip: Option<IpAddr>) -> (Option<IpAddr>, Option<String>) {
+        match (ip, self.ip_mode) {
+            (Some(ip), IpLogMode::Truncate) => (Some(truncate_ip(ip)), None),
+            (Some(ip), IpLogMode::Hash) => (None, Some(hash_ip(ip, &self.ip_salt))),
+            _ => (None, None),
+        }
+    }
+
+    /// Records an entry in the background
+    pub fn record(&self, entry: NewVerificationLog) {
+        let logger = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = logger.insert(&entry).await {
+                warn!(
+                    action = %entry.action,
+                    error = %e,
+                    "[verification_log] Failed to record verification activity"
+                );
+            }
+        });
+    }
+
+    /// Inserts an entry
+    pub async fn insert(&self, entry: &NewVerificationLog) -> Result<(), sqlx::Error> {
+        let (client_ip, client_ip_hash) = self.reduce_ip(entry.client_ip);
+        let user_agent = entry
+            .user_agent
+            .as_deref()
+            .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect::<String>());
+
+        // Unknown capture IDs (e.g. a 404 on /verify/{id}) are stored as NULL
+        // NOTE: Using runtime query to avoid SQLX_OFFLINE cache issues
+        sqlx::query(
+            r#"
+            INSERT INTO verification_logs
+                (capture_id, action, outcome, device_id, client_ip, client_ip_hash, user_agent)
+            VALUES (
+                (SELECT id FROM captures WHERE id = $1),
+                $2, $3, $4, $5::INET, $6, $7
+            )
+            "#,
+        )
+        .bind(entry.capture_id)
+        .bind(entry.action.as_str())
+        .bind(&entry.outcome)
+        .bind(entry.device_id)
+        .bind(client_ip.map(|ip| ip.to_string()))
+        .bind(client_ip_hash)
+        .bind(user_agent)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Owner History
+// ============================================================================
+
+/// Number of entries on one UTC day
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DailyCount {
+    pub day: NaiveDate,
+    pub count: i64,
+}
+
+/// One check of a capture, as shown to its owner (no client details)
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct CaptureCheck {
+    pub action: String,
+    pub outcome: String,
+    pub checked_at: DateTime<Utc>,
+}
+
+/// How often and when a capture was checked
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureVerificationSummary {
+    pub capture_id: Uuid,
+    /// All-time number of checks
+    pub total_checks: i64,
+    /// All-time checks per action ("verify", "verify_file")
+    pub checks_by_action: BTreeMap<String, i64>,
+    pub first_checked_at: Option<DateTime<Utc>>,
+    pub last_checked_at: Option<DateTime<Utc>>,
+    /// Checks per day over the last 30 days (days without checks omitted)
+    pub daily: Vec<DailyCount>,
+    /// Most recent checks, newest first
+    pub recent: Vec<CaptureCheck>,
+}
+
+/// Summarizes the checks of one capture
+pub async fn capture_verification_summary(
+    pool: &PgPool,
+    capture_id: Uuid,
+) -> Result<CaptureVerificationSummary, sqlx::Error> {
+    let totals = sqlx::query_as::<_, (String, i64, DateTime<Utc>, DateTime<Utc>)>(
+        r#"
+        SELECT action, COUNT(*), MIN(created_at), MAX(created_at)
+        FROM verification_logs
+        WHERE capture_id = $1 AND action = ANY($2)
+        GROUP BY action
+        "#,
+    )
+    .bind(capture_id)
+    .bind(&CHECK_ACTIONS[..])
+    .fetch_all(pool)
+    .await?;
+
+    let daily = sqlx::query_as::<_, DailyCount>(
+        r#"
+        SELECT (created_at AT TIME ZONE 'UTC')::DATE AS day, COUNT(*) AS count
+        FROM verification_logs
+        WHERE capture_id = $1 AND action = ANY($2)
+          AND created_at >= NOW() - make_interval(days => $3)
+        GROUP BY day
+        ORDER BY day
+        "#,
+    )
+    .bind(capture_id)
+    .bind(&CHECK_ACTIONS[..])
+    .bind(OWNER_HISTORY_DAYS)
+    .fetch_all(pool)
+    .await?;
+
+    let recent = sqlx::query_as::<_, CaptureCheck>(
+        r#"
+        SELECT action, outcome, created_at AS checked_at
+        FROM verification_logs
+        WHERE capture_id = $1 AND action = ANY($2)
+        ORDER BY created_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(capture_id)
+    .bind(&CHECK_ACTIONS[..])
+    .bind(RECENT_CHECKS_LIMIT)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(CaptureVerificationSummary {
+        capture_id,
+        total_checks: totals.iter().map(|(_, count, _, _)| count).sum(),
+        first_checked_at: totals.iter().map(|(_, _, first, _)| *first).min(),
+        last_checked_at: totals.iter().map(|(_, _, _, last)| *last).max(),
+        checks_by_action: totals
+            .into_iter()
+            .map(|(action, count, _, _)| (action, count))
+            .collect(),
+        daily,
+        recent,
+    })
+}
+
+// ============================================================================
+// Operator Stats
+// ============================================================================
+
+/// Entries with one action and outcome
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ActionOutcomeCount {
+    pub action: String,
+    pub outcome: String,
+    pub count: i64,
+}
+
+/// Entries with one action on one UTC day
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct DailyActionCount {
+    pub day: NaiveDate,
+    pub action: String,
+    pub count: i64,
+}
+
+/// Check count of one capture
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct CaptureCheckCount {
+    pub capture_id: Uuid,
+    pub count: i64,
+}
+
+/// Aggregate verification activity over a window
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationStats {
+    /// Window length in days, ending now
+    pub days: i32,
+    pub total: i64,
+    pub by_outcome: Vec<ActionOutcomeCount>,
+    pub daily: Vec<DailyActionCount>,
+    /// Distinct clients by stored (truncated or hashed) IP
+    pub distinct_clients: i64,
+    /// Most-checked captures
+    pub top_captures: Vec<CaptureCheckCount>,
+}
+
+/// Aggregates all activity in the last `days` days
+pub async fn verification_stats(
+    pool: &PgPool,
+    days: i32,
+) -> Result<VerificationStats, sqlx::Error> {
+    let by_outcome = sqlx::query_as::<_, ActionOutcomeCount>(
+        r#"
+        SELECT action, outcome, COUNT(*) AS count
+        FROM verification_logs
+        WHERE created_at >= NOW() - make_interval(days => $1)
+        GROUP BY action, outcome
+        ORDER BY action, outcome
+        "#,
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await?;
+
+    let daily = sqlx::query_as::<_, DailyActionCount>(
+        r#"
+        SELECT (created_at AT TIME ZONE 'UTC')::DATE AS day, action, COUNT(*) AS count
+        FROM verification_logs
+        WHERE created_at >= NOW() - make_interval(days => $1)
+        GROUP BY day, action
+        ORDER BY day, action
+        "#,
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await?;
+
+    let distinct_clients = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(DISTINCT COALESCE(client_ip_hash, host(client_ip)))
+        FROM verification_logs
+        WHERE created_at >= NOW() - make_interval(days => $1)
+        "#,
+    )
+    .bind(days)
+    .fetch_one(pool)
+    .await?;
+
+    let top_captures = sqlx::query_as::<_, CaptureCheckCount>(
+        r#"
+        SELECT capture_id, COUNT(*) AS count
+        FROM verification_logs
+        WHERE created_at >= NOW() - make_interval(days => $1)
+          AND capture_id IS NOT NULL AND action = ANY($2)
+        GROUP BY capture_id
+        ORDER BY count DESC, capture_id
+        LIMIT $3
+        "#,
+    )
+    .bind(days)
+    .bind(&CHECK_ACTIONS[..])
+    .bind(TOP_CAPTURES_LIMIT)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(VerificationStats {
+        days,
+        total: by_outcome.iter().map(|c| c.count).sum(),
+        by_outcome,
+        daily,
+        distinct_clients,
+        top_captures,
+    })
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_ip() {
+        assert_eq!(
+            truncate_ip("203.0.113.77".parse().unwrap()),
+            "203.0.113.0".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            truncate_ip("2001:db8:85a3:8d3:1319:8a2e:370:7348".parse().unwrap()),
+            "2001:db8:85a3::".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            truncate_ip("::ffff:198.51.100.9".parse().unwrap()),
+            "198.51.100.0".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hash_ip_is_salted() {
+        let ip: IpAddr = "203.0.113.77".parse().unwrap();
+        assert_eq!(hash_ip(ip, "salt"), hash_ip(ip, "salt"));
+        assert_ne!(hash_ip(ip, "salt"), hash_ip(ip, "pepper"));
+        assert_eq!(hash_ip(ip, "salt").len(), 64);
+    }
+
+    #[test]
+    fn test_ip_log_mode_from_str() {
+        assert_eq!("truncate".parse(), Ok(IpLogMode::Truncate));
+        assert_eq!("HASH".parse(), Ok(IpLogMode::Hash));
+        assert_eq!("none".parse(), Ok(IpLogMode::Omit));
+        assert!("full".parse::<IpLogMode>().is_err());
+    }
+}
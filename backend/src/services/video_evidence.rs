@@ -1,6 +1,8 @@
 //! Video Evidence Service (Story 7-11)
 //!
 //! Service for assembling video evidence packages and calculating confidence.
+//! Video evidence uses the unified `EvidencePackage` with its `video` and
+//! `hash_chain` sections.
 //! Aggregates results from:
 //! - Hardware attestation validation (DCAppAttest)
 //! - Hash chain verification (Story 7-10)
@@ -29,17 +31,34 @@
 use std::time::Instant;
 use tracing::{debug, info, instrument, warn};
 
+use crate::models::{
+    CheckStatus, ConfidenceLevel, ConfidenceScore, DepthAnalysis, EvidencePackage,
+    HardwareAttestation, HashChainEvidence, HashChainStatus, MetadataEvidence,
+    PartialAttestationInfo, ProcessingInfo, ScoreBuilder, TemporalDepthEvidence, VideoDetails,
+};
 use crate::types::hash_chain_verification::HashChainVerification;
+use crate::types::hash_only::AnalysisSource;
 use crate::types::video_depth_analysis::VideoDepthAnalysis;
-use crate::types::video_evidence::{
-    DepthAnalysisEvidence, HardwareAttestationEvidence, HashChainEvidence, MetadataEvidence,
-    PartialAttestationInfo, ProcessingInfo, VideoConfidenceLevel, VideoEvidence,
-};
 
 // ============================================================================
 // Configuration
 // ============================================================================
 
+/// Score points for passing hardware attestation (videos)
+const HARDWARE_POINTS: i32 = 30;
+
+/// Score points for a fully verified hash chain
+const HASH_CHAIN_POINTS: i32 = 40;
+
+/// Score points for a partial hash chain with a verified checkpoint
+const PARTIAL_CHAIN_POINTS: i32 = 20;
+
+/// Score points for strong temporal depth metrics
+const DEPTH_POINTS: i32 = 30;
+
+/// Score points for a real scene with depth metrics below thresholds
+const DEGRADED_DEPTH_POINTS: i32 = 10;
+
 /// Configuration for video evidence service
 #[derive(Debug, Clone)]
 pub struct VideoEvidenceConfig {
@@ -116,7 +135,7 @@ impl VideoEvidenceService {
     #[allow(clippy::too_many_arguments)]
     pub fn build_evidence(
         &self,
-        hw_attestation: HardwareAttestationEvidence,
+        hw_attestation: HardwareAttestation,
         hash_chain: &HashChainVerification,
         depth_analysis: Option<&VideoDepthAnalysis>,
         metadata: MetadataEvidence,
@@ -125,14 +144,14 @@ impl VideoEvidenceService {
         duration_ms: u64,
         frame_count: u32,
         start_time: Instant,
-    ) -> VideoEvidence {
+    ) -> EvidencePackage {
         let processing_time_ms = start_time.elapsed().as_millis() as u64;
 
         // Build hash chain evidence from verification result
         let hash_chain_evidence = HashChainEvidence::from_verification(hash_chain);
 
-        // Build depth analysis evidence if available
-        let depth_evidence = depth_analysis.map(DepthAnalysisEvidence::from_analysis);
+        // Build temporal depth evidence if available
+        let temporal_depth = depth_analysis.map(TemporalDepthEvidence::from_analysis);
 
         // Build partial attestation info
         let partial_info = if is_partial {
@@ -147,30 +166,47 @@ impl VideoEvidenceService {
 
         // Build checks performed list
         let mut checks = vec!["hardware".to_string(), "hash_chain".to_string()];
-        if depth_evidence.is_some() {
+        if temporal_depth.is_some() {
             checks.push("depth".to_string());
         }
         checks.push("metadata".to_string());
 
         // Build processing info
         let processing =
-            ProcessingInfo::new(processing_time_ms, self.backend_version.clone(), checks);
+            ProcessingInfo::new(processing_time_ms, &self.backend_version).with_checks(checks);
 
         info!(
             "Built video evidence: {} frames, {}ms, partial={}",
             frame_count, duration_ms, is_partial
         );
 
-        VideoEvidence::new(
-            duration_ms,
-            frame_count,
-            hw_attestation,
-            hash_chain_evidence,
-            depth_evidence,
-            metadata,
-            partial_info,
-            processing,
-        )
+        // Summarize temporal depth in the shared depth check (server-side LiDAR)
+        let depth_summary = DepthAnalysis {
+            status: match &temporal_depth {
+                Some(depth) if depth.is_likely_real_scene => CheckStatus::Pass,
+                Some(_) => CheckStatus::Fail,
+                None => CheckStatus::Unavailable,
+            },
+            is_likely_real_scene: temporal_depth
+                .as_ref()
+                .is_some_and(|depth| depth.is_likely_real_scene),
+            source: Some(AnalysisSource::Server),
+            method: Some("lidar".to_string()),
+            ..Default::default()
+        };
+
+        // Videos are currently recorded on iOS only
+        let mut evidence =
+            EvidencePackage::for_ios(hw_attestation, depth_summary, metadata, processing)
+                .with_video(VideoDetails {
+                    duration_ms,
+                    frame_count,
+                    temporal_depth,
+                    partial_attestation: partial_info,
+                })
+                .with_hash_chain(hash_chain_evidence);
+        evidence.confidence_score = Some(self.calculate_score(&evidence));
+        evidence
     }
 
     /// Calculate confidence level for video evidence
@@ -181,104 +217,145 @@ impl VideoEvidenceService {
     /// - MEDIUM: Core checks pass, depth degraded/unavailable or partial
     /// - LOW: Multiple checks unavailable
     #[instrument(skip(self, evidence))]
-    pub fn calculate_confidence(&self, evidence: &VideoEvidence) -> VideoConfidenceLevel {
-        // SUSPICIOUS: Hardware attestation failed
-        if evidence.hardware_attestation.status == "fail" {
-            warn!("Confidence SUSPICIOUS: hardware attestation failed");
-            return VideoConfidenceLevel::Suspicious;
-        }
-
-        // SUSPICIOUS: Hash chain verification failed
-        if evidence.hash_chain.status == "fail" {
-            warn!("Confidence SUSPICIOUS: hash chain verification failed");
-            return VideoConfidenceLevel::Suspicious;
-        }
+    pub fn calculate_confidence(&self, evidence: &EvidencePackage) -> ConfidenceLevel {
+        self.calculate_score(evidence).level
+    }
 
-        // SUSPICIOUS: Hash chain broken (tampering detected)
-        if !evidence.hash_chain.chain_intact {
-            warn!("Confidence SUSPICIOUS: hash chain broken (possible tampering)");
-            return VideoConfidenceLevel::Suspicious;
-        }
+    /// Calculate the numeric confidence score for video evidence
+    ///
+    /// Points: hardware 30, verified hash chain 40 (partial with checkpoint
+    /// 20), strong temporal depth 30 (degraded 10). Without a fully verified
+    /// hash chain confidence is capped at LOW, or at MEDIUM for a partial
+    /// recording with a verified checkpoint and hardware pass.
+    pub fn calculate_score(&self, evidence: &EvidencePackage) -> ConfidenceScore {
+        let mut score = ScoreBuilder::new();
+        let hw = &evidence.hardware_attestation;
+        let video = evidence.video.as_ref();
+        let is_partial = video.is_some_and(|v| v.partial_attestation.is_partial);
 
-        // SUSPICIOUS: Depth analysis detected suspicious scene
-        if let Some(ref depth) = evidence.depth_analysis {
-            if !depth.is_likely_real_scene {
-                warn!("Confidence SUSPICIOUS: depth analysis detected suspicious scene");
-                return VideoConfidenceLevel::Suspicious;
+        // SUSPICIOUS: Hardware attestation failed
+        match hw.status {
+            CheckStatus::Fail => {
+                warn!("Confidence SUSPICIOUS: hardware attestation failed");
+                score.veto("hardware", -HARDWARE_POINTS, "attestation failed");
+            }
+            CheckStatus::Pass => score.add("hardware", HARDWARE_POINTS, "attestation verified"),
+            CheckStatus::Unavailable | CheckStatus::Pending => {
+                score.add("hardware", 0, "attestation unavailable")
             }
         }
+        let hw_pass = hw.status == CheckStatus::Pass;
 
-        // Check for HIGH confidence conditions
-        let hw_pass = evidence.hardware_attestation.status == "pass";
-        let hash_pass = evidence.hash_chain.status == "pass"
-            && evidence.hash_chain.chain_intact
-            && evidence.hash_chain.attestation_valid;
-
-        let depth_pass = evidence
-            .depth_analysis
-            .as_ref()
-            .map(|d| {
-                d.is_likely_real_scene
-                    && d.depth_consistency >= self.config.depth_consistency_threshold
-                    && d.scene_stability >= self.config.scene_stability_threshold
-            })
-            .unwrap_or(false);
-
-        // HIGH: All checks pass with strong metrics
-        if hw_pass && hash_pass && depth_pass {
-            debug!(
-                "Confidence HIGH: hw={}, hash={}, depth={} (consistency={:.2}, stability={:.2})",
-                hw_pass,
-                hash_pass,
-                depth_pass,
-                evidence
-                    .depth_analysis
-                    .as_ref()
-                    .map(|d| d.depth_consistency)
-                    .unwrap_or(0.0),
-                evidence
-                    .depth_analysis
-                    .as_ref()
-                    .map(|d| d.scene_stability)
-                    .unwrap_or(0.0)
-            );
-            return VideoConfidenceLevel::High;
+        let Some(chain) = evidence.hash_chain.as_ref() else {
+            score.cap(ConfidenceLevel::Low, "hash_chain", 0, "not verified");
+            self.score_temporal_depth(&mut score, video.and_then(|v| v.temporal_depth.as_ref()));
+            return self.finish_score(score);
+        };
+
+        // SUSPICIOUS: Hash chain verification failed or chain broken (tampering)
+        if chain.status == HashChainStatus::Fail || !chain.chain_intact {
+            warn!("Confidence SUSPICIOUS: hash chain verification failed or chain broken");
+            let reason = match chain.broken_at_frame {
+                Some(frame) => format!("chain broken at frame {frame} (possible tampering)"),
+                None if !chain.chain_intact => "chain broken (possible tampering)".to_string(),
+                None => "verification failed".to_string(),
+            };
+            score.veto("hash_chain", -HASH_CHAIN_POINTS, reason);
         }
 
-        // MEDIUM: Hardware + hash chain pass, depth unavailable/degraded
-        if hw_pass && hash_pass {
-            debug!(
-                "Confidence MEDIUM: hw={}, hash={}, depth unavailable or degraded",
-                hw_pass, hash_pass
+        let hash_pass =
+            chain.status == HashChainStatus::Pass && chain.chain_intact && chain.attestation_valid;
+        if hash_pass {
+            score.add(
+                "hash_chain",
+                HASH_CHAIN_POINTS,
+                format!("{} frames verified", chain.verified_frames),
+            );
+        } else if is_partial && chain.checkpoint_verified {
+            let reason = format!(
+                "partial recording, {}/{} frames verified at checkpoint",
+                chain.verified_frames, chain.total_frames
             );
-            return VideoConfidenceLevel::Medium;
+            let ceiling = if hw_pass {
+                ConfidenceLevel::Medium
+            } else {
+                ConfidenceLevel::Low
+            };
+            score.cap(ceiling, "hash_chain", PARTIAL_CHAIN_POINTS, reason);
+        } else {
+            score.cap(ConfidenceLevel::Low, "hash_chain", 0, "not verified");
         }
 
-        // MEDIUM: Partial verification with checkpoint
-        if evidence.partial_attestation.is_partial
-            && evidence.hash_chain.checkpoint_verified
-            && hw_pass
-        {
-            debug!("Confidence MEDIUM: partial attestation with checkpoint verified",);
-            return VideoConfidenceLevel::Medium;
-        }
+        self.score_temporal_depth(&mut score, video.and_then(|v| v.temporal_depth.as_ref()));
+        self.finish_score(score)
+    }
 
-        // MEDIUM: Hardware unavailable but hash chain and depth pass
-        if evidence.hardware_attestation.status == "unavailable" && hash_pass {
-            debug!("Confidence MEDIUM: hw unavailable, hash pass");
-            return VideoConfidenceLevel::Medium;
+    /// Adds the temporal depth factor
+    fn score_temporal_depth(
+        &self,
+        score: &mut ScoreBuilder,
+        depth: Option<&TemporalDepthEvidence>,
+    ) {
+        match depth {
+            // SUSPICIOUS: Depth analysis detected suspicious scene
+            Some(depth) if !depth.is_likely_real_scene => {
+                warn!("Confidence SUSPICIOUS: depth analysis detected suspicious scene");
+                score.veto(
+                    "depth",
+                    -DEPTH_POINTS,
+                    format!(
+                        "screen-like scene ({} suspicious frames)",
+                        depth.suspicious_frames.len()
+                    ),
+                );
+            }
+            Some(depth)
+                if depth.depth_consistency >= self.config.depth_consistency_threshold
+                    && depth.scene_stability >= self.config.scene_stability_threshold =>
+            {
+                score.add(
+                    "depth",
+                    DEPTH_POINTS,
+                    format!(
+                        "consistent real scene (consistency {:.2}, stability {:.2})",
+                        depth.depth_consistency, depth.scene_stability
+                    ),
+                );
+            }
+            Some(depth) => score.add(
+                "depth",
+                DEGRADED_DEPTH_POINTS,
+                format!(
+                    "real scene below thresholds (consistency {:.2}, stability {:.2})",
+                    depth.depth_consistency, depth.scene_stability
+                ),
+            ),
+            None => score.add("depth", 0, "temporal depth unavailable"),
         }
+    }
 
-        // LOW: Multiple checks unavailable but no explicit failures
-        debug!("Confidence LOW: multiple checks unavailable");
-        VideoConfidenceLevel::Low
+    /// Finishes the score and logs its factors
+    fn finish_score(&self, score: ScoreBuilder) -> ConfidenceScore {
+        let result = score.finish();
+        debug!(
+            "Confidence {:?}: score={}, factors=[{}]",
+            result.level,
+            result.score,
+            result
+                .factors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        result
     }
 
     /// Convenience method to build evidence and calculate confidence in one call
     #[allow(clippy::too_many_arguments)]
     pub fn process(
         &self,
-        hw_attestation: HardwareAttestationEvidence,
+        hw_attestation: HardwareAttestation,
         hash_chain: &HashChainVerification,
         depth_analysis: Option<&VideoDepthAnalysis>,
         metadata: MetadataEvidence,
@@ -287,7 +364,7 @@ impl VideoEvidenceService {
         duration_ms: u64,
         frame_count: u32,
         start_time: Instant,
-    ) -> (VideoEvidence, VideoConfidenceLevel) {
+    ) -> (EvidencePackage, ConfidenceLevel) {
         let evidence = self.build_evidence(
             hw_attestation,
             hash_chain,
@@ -317,8 +394,34 @@ impl Default for VideoEvidenceService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{AttestationLevel, CaptureType};
     use crate::types::hash_chain_verification::VerificationStatus;
-    use chrono::Utc;
+
+    fn hw_pass() -> HardwareAttestation {
+        HardwareAttestation::pass("iPhone 15 Pro".to_string(), AttestationLevel::SecureEnclave)
+    }
+
+    fn hw_fail() -> HardwareAttestation {
+        HardwareAttestation::fail(
+            "iPhone 15 Pro".to_string(),
+            AttestationLevel::SecureEnclave,
+            false,
+            false,
+        )
+    }
+
+    fn hw_unavailable() -> HardwareAttestation {
+        HardwareAttestation::unavailable("Unknown".to_string(), AttestationLevel::Unverified)
+    }
+
+    fn video_metadata(valid: bool) -> MetadataEvidence {
+        MetadataEvidence {
+            model_name: "iPhone 15 Pro".to_string(),
+            timestamp_valid: valid,
+            location_available: valid,
+            ..Default::default()
+        }
+    }
 
     fn make_passing_hash_chain() -> HashChainVerification {
         HashChainVerification {
@@ -332,6 +435,9 @@ mod tests {
             failure_reason: None,
             is_partial: false,
             checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 
@@ -347,6 +453,9 @@ mod tests {
             failure_reason: Some("Chain broken at frame 150".to_string()),
             is_partial: false,
             checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 
@@ -362,6 +471,9 @@ mod tests {
             failure_reason: None,
             is_partial: true,
             checkpoint_index: Some(1),
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 
@@ -406,10 +518,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -418,7 +530,7 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::High);
+        assert_eq!(confidence, ConfidenceLevel::High);
     }
 
     #[test]
@@ -429,10 +541,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::fail(Utc::now()),
+            hw_fail(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -441,7 +553,32 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::Suspicious);
+        assert_eq!(confidence, ConfidenceLevel::Suspicious);
+    }
+
+    #[test]
+    fn test_score_factors_explain_hw_fail() {
+        let service = VideoEvidenceService::new();
+        let hash_chain = make_passing_hash_chain();
+        let depth = make_passing_depth();
+        let start = Instant::now();
+
+        let evidence = service.build_evidence(
+            hw_fail(),
+            &hash_chain,
+            Some(&depth),
+            video_metadata(true),
+            false,
+            None,
+            15000,
+            450,
+            start,
+        );
+
+        let score = evidence.confidence_score.as_ref().unwrap();
+        assert_eq!(score.level, ConfidenceLevel::Suspicious);
+        assert_eq!(score.factors[0].check, "hardware");
+        assert_eq!(*score, service.calculate_score(&evidence));
     }
 
     #[test]
@@ -452,10 +589,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -464,7 +601,7 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::Suspicious);
+        assert_eq!(confidence, ConfidenceLevel::Suspicious);
     }
 
     #[test]
@@ -475,10 +612,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -487,7 +624,7 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::Suspicious);
+        assert_eq!(confidence, ConfidenceLevel::Suspicious);
     }
 
     #[test]
@@ -497,10 +634,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None, // No depth analysis
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -509,7 +646,7 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::Medium);
+        assert_eq!(confidence, ConfidenceLevel::Medium);
     }
 
     #[test]
@@ -520,10 +657,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -533,7 +670,7 @@ mod tests {
 
         let confidence = service.calculate_confidence(&evidence);
         // Depth is_likely_real_scene=true but metrics below threshold -> MEDIUM
-        assert_eq!(confidence, VideoConfidenceLevel::Medium);
+        assert_eq!(confidence, ConfidenceLevel::Medium);
     }
 
     #[test]
@@ -543,10 +680,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None,
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             true,
             Some(1),
             10000,
@@ -555,7 +692,7 @@ mod tests {
         );
 
         let confidence = service.calculate_confidence(&evidence);
-        assert_eq!(confidence, VideoConfidenceLevel::Medium);
+        assert_eq!(confidence, ConfidenceLevel::Medium);
     }
 
     #[test]
@@ -572,14 +709,17 @@ mod tests {
             failure_reason: Some("Attestation unavailable".to_string()),
             is_partial: false,
             checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         };
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::unavailable(),
+            hw_unavailable(),
             &hash_chain,
             None,
-            MetadataEvidence::new("Unknown".to_string(), false, false),
+            video_metadata(false),
             false,
             None,
             0,
@@ -590,7 +730,7 @@ mod tests {
         let confidence = service.calculate_confidence(&evidence);
         // Hash chain failed -> SUSPICIOUS (not LOW)
         // This is correct behavior - failed checks are worse than unavailable
-        assert_eq!(confidence, VideoConfidenceLevel::Suspicious);
+        assert_eq!(confidence, ConfidenceLevel::Suspicious);
     }
 
     #[test]
@@ -601,10 +741,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -637,10 +777,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None,
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -648,7 +788,7 @@ mod tests {
             start,
         );
 
-        assert!(evidence.depth_analysis.is_none());
+        assert!(evidence.video.as_ref().unwrap().temporal_depth.is_none());
         assert!(!evidence
             .processing
             .checks_performed
@@ -663,10 +803,10 @@ mod tests {
         let start = Instant::now();
 
         let (evidence, confidence) = service.process(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -674,8 +814,8 @@ mod tests {
             start,
         );
 
-        assert_eq!(evidence.evidence_type, "video");
-        assert_eq!(confidence, VideoConfidenceLevel::High);
+        assert_eq!(evidence.capture_type, CaptureType::Video);
+        assert_eq!(confidence, ConfidenceLevel::High);
     }
 
     #[test]
@@ -688,10 +828,10 @@ mod tests {
         std::thread::sleep(std::time::Duration::from_millis(10));
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None,
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -709,10 +849,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None,
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -733,10 +873,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             None,
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             true,
             Some(1),
             10000,
@@ -744,12 +884,43 @@ mod tests {
             start,
         );
 
-        assert!(evidence.partial_attestation.is_partial);
-        assert_eq!(evidence.partial_attestation.checkpoint_index, Some(1));
-        assert_eq!(evidence.partial_attestation.verified_frames, 300);
-        assert_eq!(evidence.partial_attestation.total_frames, 400);
+        assert!(
+            evidence
+                .video
+                .as_ref()
+                .unwrap()
+                .partial_attestation
+                .is_partial
+        );
+        assert_eq!(
+            evidence
+                .video
+                .as_ref()
+                .unwrap()
+                .partial_attestation
+                .checkpoint_index,
+            Some(1)
+        );
+        assert_eq!(
+            evidence
+                .video
+                .as_ref()
+                .unwrap()
+                .partial_attestation
+                .verified_frames,
+            300
+        );
+        assert_eq!(
+            evidence
+                .video
+                .as_ref()
+                .unwrap()
+                .partial_attestation
+                .total_frames,
+            400
+        );
         assert_eq!(
-            evidence.partial_attestation.reason,
+            evidence.video.as_ref().unwrap().partial_attestation.reason,
             Some("checkpoint_attestation".to_string())
         );
     }
@@ -767,10 +938,10 @@ mod tests {
         let start = Instant::now();
 
         let evidence = service.build_evidence(
-            HardwareAttestationEvidence::pass(Utc::now()),
+            hw_pass(),
             &hash_chain,
             Some(&depth),
-            MetadataEvidence::new("iPhone 15 Pro".to_string(), true, true),
+            video_metadata(true),
             false,
             None,
             15000,
@@ -780,6 +951,6 @@ mod tests {
 
         let confidence = service.calculate_confidence(&evidence);
         // With stricter thresholds (0.9, 0.95), the depth metrics don't pass
-        assert_eq!(confidence, VideoConfidenceLevel::Medium);
+        assert_eq!(confidence, ConfidenceLevel::Medium);
     }
 }
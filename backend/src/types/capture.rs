@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::error::ApiError;
+use crate::types::location_privacy::LocationPrivacyPolicy;
 
 // ============================================================================
 // Constants
@@ -67,6 +68,10 @@ pub struct CaptureMetadataPayload {
     /// Capture location, optional
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<CaptureLocation>,
+    /// Location privacy policy requested by the device, optional
+    /// (server default applies when absent; clamped to server bounds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_privacy: Option<LocationPrivacyPolicy>,
 }
 
 /// Parsed and validated capture data from multipart form
@@ -95,6 +100,17 @@ pub struct CaptureUploadResponse {
     pub verification_url: String,
 }
 
+/// Response data for DELETE /captures/{id}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureDeletedResponse {
+    /// Deleted capture identifier
+    pub capture_id: Uuid,
+    /// When the capture was deleted (ISO 8601)
+    pub deleted_at: String,
+    /// SHA-256 of the evidence at deletion time (hex), kept in the tombstone
+    pub evidence_digest: String,
+}
+
 /// Response data for GET /captures/{id}
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CaptureDetailsResponse {
@@ -115,6 +131,9 @@ pub struct CaptureDetailsResponse {
     /// Coarse location (city/region) for privacy
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location_coarse: Option<String>,
+    /// Reverse-geocoded label for the coarse location (e.g. "San Francisco, CA, US")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_label: Option<String>,
     /// URL to view verification results
     pub verification_url: String,
 
@@ -168,6 +187,10 @@ pub struct CaptureDetailsResponse {
     /// Number of detection methods used
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detection_method_count: Option<u8>,
+
+    /// Server-side detection results computed from the uploaded photo (JSONB)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_detection: Option<serde_json::Value>,
 }
 
 // ============================================================================
@@ -192,6 +215,13 @@ impl CaptureMetadataPayload {
         // Validate location if present
         self.validate_location()?;
 
+        // Validate requested location privacy policy if present
+        if let Some(policy) = self.location_privacy {
+            policy
+                .validate()
+                .map_err(|e| ApiError::Validation(format!("location_privacy is invalid: {e}")))?;
+        }
+
         Ok(())
     }
 
@@ -345,6 +375,7 @@ mod tests {
             },
             assertion: None,
             location: None,
+            location_privacy: None,
         }
     }
 
@@ -354,6 +385,16 @@ mod tests {
         assert!(metadata.validate().is_ok());
     }
 
+    #[test]
+    fn test_location_privacy_policy_validation() {
+        let mut metadata = valid_metadata();
+        metadata.location_privacy = Some(LocationPrivacyPolicy::Geohash { precision: 5 });
+        assert!(metadata.validate().is_ok());
+
+        metadata.location_privacy = Some(LocationPrivacyPolicy::Geohash { precision: 20 });
+        assert!(metadata.validate().is_err());
+    }
+
     #[test]
     fn test_invalid_captured_at_empty() {
         let mut metadata = valid_metadata();
@@ -14,6 +14,7 @@
 //! DateTime fields use ISO 8601 format via chrono's default serde implementation.
 
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -129,6 +130,30 @@ pub enum MoireAnalysisStatus {
     Failed,
 }
 
+// ============================================================================
+// Server-side Detection
+// ============================================================================
+
+/// Detection results computed by the backend from the uploaded photo
+///
+/// Stored alongside the client `DetectionResults` so that a client reporting
+/// a clean result for a photo of a screen or print can be caught.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ServerDetectionResults {
+    /// Independent moire analysis of the photo
+    pub moire: MoireAnalysisResult,
+
+    /// Whether the periodic pattern looks like a print halftone screen
+    #[serde(default)]
+    pub halftone_detected: bool,
+
+    /// Whether the client moire result agrees with the server's
+    /// (None when the client reported no completed moire analysis)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_agreement: Option<bool>,
+}
+
 // ============================================================================
 // Texture Classification (matches iOS TextureClassificationResult.swift)
 // ============================================================================
@@ -348,7 +373,7 @@ impl AggregatedConfidenceLevel {
 }
 
 /// Confidence flags indicating concerns or issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ConfidenceFlag {
     PrimarySignalFailed,
@@ -362,6 +387,8 @@ pub enum ConfidenceFlag {
     ConsistencyAnomaly,
     TemporalInconsistency,
     HighUncertainty,
+    /// Server-side analysis contradicts the client result (backend only)
+    ServerClientDisagree,
 }
 
 /// Aggregation status
@@ -10,10 +10,23 @@
 //! - iOS gets the final hash ATTESTED by Apple's DCAppAttest
 //! - Backend verifies attestation is valid for the submitted hash
 //! - Trust established through attestation, not recomputation
+//!
+//! Chain format v2 additionally carries the per-frame content digests
+//! `d_n`, so the backend can recompute `H_n = SHA256(H_{n-1} || d_n)` from a
+//! genesis value bound to the device's registration challenge.
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Legacy chain format: frame hashes only, derivation not checkable
+pub const HASH_CHAIN_VERSION_V1: u32 = 1;
+
+/// Chain format with per-frame content digests (`H_n = SHA256(H_{n-1} || d_n)`)
+pub const HASH_CHAIN_VERSION_V2: u32 = 2;
+
+/// Domain separator for deriving the v2 chain genesis from the registration challenge
+pub const HASH_CHAIN_GENESIS_DOMAIN: &[u8] = b"realitycam.hash_chain.v2.genesis";
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -63,6 +76,19 @@ pub struct HashChainData {
     /// Final hash (last frame hash) for attestation verification
     /// Base64-encoded SHA256
     pub final_hash: String,
+
+    /// Chain format version (1 = hashes only, 2 = hashes + frame digests)
+    #[serde(default = "default_chain_version")]
+    pub version: u32,
+
+    /// Per-frame content digests `d_n` (v2 only, same length as `frame_hashes`)
+    /// Each digest is base64-encoded SHA256
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frame_digests: Vec<String>,
+}
+
+fn default_chain_version() -> u32 {
+    HASH_CHAIN_VERSION_V1
 }
 
 impl HashChainData {
@@ -71,6 +97,11 @@ impl HashChainData {
         self.frame_hashes.len()
     }
 
+    /// True if the chain carries frame digests that allow derivation checks
+    pub fn is_v2(&self) -> bool {
+        self.version >= HASH_CHAIN_VERSION_V2
+    }
+
     /// Estimated video duration in seconds
     pub fn estimated_duration_secs(&self, fps: u32) -> f64 {
         self.frame_hashes.len() as f64 / fps as f64
@@ -122,6 +153,35 @@ pub struct VideoAttestation {
     pub checkpoint_index: Option<u32>,
 }
 
+/// Hash chain summary for no-media verification (hash-only videos).
+///
+/// Privacy-mode uploads never send per-frame hashes, so only the final hash,
+/// the claimed chain length and the checkpoints (each with its own
+/// DCAppAttest assertion) can be checked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashChainSummary {
+    /// Final hash of the chain (base64-encoded SHA256)
+    pub final_hash: String,
+
+    /// Claimed number of frames in the chain
+    pub chain_length: u32,
+
+    /// Checkpoint hashes submitted with the summary
+    #[serde(default)]
+    pub checkpoints: Vec<HashCheckpoint>,
+
+    /// Indices of checkpoints whose assertion was verified against the device key
+    #[serde(default)]
+    pub attested_checkpoints: Vec<u32>,
+}
+
+impl HashChainSummary {
+    /// Number of checkpoints a chain of this length must carry.
+    pub fn expected_checkpoint_count(&self, checkpoint_interval: u32) -> usize {
+        (self.chain_length / checkpoint_interval.max(1)) as usize
+    }
+}
+
 // ============================================================================
 // Data Structures - Verification Results
 // ============================================================================
@@ -167,6 +227,18 @@ pub struct HashChainVerification {
     /// Verified checkpoint index (if partial)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checkpoint_index: Option<u32>,
+
+    /// Every link `H_n = SHA256(H_{n-1} || d_n)` was recomputed (v2 chains only)
+    #[serde(default)]
+    pub derivation_verified: bool,
+
+    /// Genesis hash was bound to the device registration challenge
+    #[serde(default)]
+    pub genesis_bound: bool,
+
+    /// First frame (1-based) whose hash does not derive from its predecessor
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broken_at_frame: Option<u32>,
 }
 
 impl Default for HashChainVerification {
@@ -182,6 +254,9 @@ impl Default for HashChainVerification {
             failure_reason: None,
             is_partial: false,
             checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 }
@@ -200,6 +275,9 @@ impl HashChainVerification {
             failure_reason: None,
             is_partial: false,
             checkpoint_index: None,
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 
@@ -216,6 +294,9 @@ impl HashChainVerification {
             failure_reason: None,
             is_partial: true,
             checkpoint_index: Some(checkpoint_index),
+            derivation_verified: false,
+            genesis_bound: false,
+            broken_at_frame: None,
         }
     }
 
@@ -228,6 +309,14 @@ impl HashChainVerification {
         }
     }
 
+    /// Create a failed result for a chain whose derivation breaks at `frame`
+    pub fn broken(frame: u32, reason: impl Into<String>) -> Self {
+        Self {
+            broken_at_frame: Some(frame),
+            ..Self::fail(reason)
+        }
+    }
+
     /// Check if verification passed (full or partial)
     pub fn is_valid(&self) -> bool {
         matches!(
@@ -292,6 +381,28 @@ pub enum HashChainVerificationError {
 
     #[error("Missing checkpoint for partial attestation: expected checkpoint {index}")]
     MissingCheckpoint { index: u32 },
+
+    #[error("Unsupported hash chain version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("Hash chain version {0} cannot be bound to the device genesis; version 2 is required")]
+    UnboundChainVersion(u32),
+
+    #[error("Frame digest count mismatch: expected {expected}, got {actual}")]
+    FrameDigestCountMismatch { expected: usize, actual: usize },
+
+    #[error("Chain derivation broken at frame {frame}: hash doesn't derive from previous hash and frame digest")]
+    DerivationBroken { frame: u32 },
+
+    #[error("Checkpoint {index} at frame {frame} is beyond the chain length {chain_length}")]
+    CheckpointBeyondChain {
+        index: u32,
+        frame: u32,
+        chain_length: u32,
+    },
+
+    #[error("Checkpoint {index} has no valid device assertion")]
+    CheckpointNotAttested { index: u32 },
 }
 
 // ============================================================================
@@ -317,6 +428,8 @@ mod tests {
             frame_hashes: vec!["hash1".to_string(), "hash2".to_string()],
             checkpoints: vec![],
             final_hash: "hash2".to_string(),
+            version: HASH_CHAIN_VERSION_V1,
+            frame_digests: vec![],
         };
         assert_eq!(chain.frame_count(), 2);
     }
@@ -327,6 +440,8 @@ mod tests {
             frame_hashes: vec!["h".to_string(); 150],
             checkpoints: vec![],
             final_hash: "final".to_string(),
+            version: HASH_CHAIN_VERSION_V1,
+            frame_digests: vec![],
         };
         assert!((chain.estimated_duration_secs(30) - 5.0).abs() < 0.01);
     }
@@ -390,12 +505,32 @@ mod tests {
                 timestamp: 5.0,
             }],
             final_hash: "YWJj".to_string(),
+            version: HASH_CHAIN_VERSION_V1,
+            frame_digests: vec![],
         };
 
         let json = serde_json::to_string(&chain).unwrap();
         assert!(json.contains("\"frame_hashes\""));
         assert!(json.contains("\"checkpoints\""));
         assert!(json.contains("\"final_hash\""));
+        assert!(!json.contains("frame_digests")); // Empty digests skipped for v1
+    }
+
+    #[test]
+    fn test_hash_chain_data_defaults_to_v1() {
+        let json = r#"{"frame_hashes":["YWJj"],"checkpoints":[],"final_hash":"YWJj"}"#;
+        let chain: HashChainData = serde_json::from_str(json).unwrap();
+        assert_eq!(chain.version, HASH_CHAIN_VERSION_V1);
+        assert!(!chain.is_v2());
+        assert!(chain.frame_digests.is_empty());
+    }
+
+    #[test]
+    fn test_verification_broken() {
+        let result = HashChainVerification::broken(42, "broken link");
+        assert_eq!(result.status, VerificationStatus::Fail);
+        assert_eq!(result.broken_at_frame, Some(42));
+        assert!(!result.derivation_verified);
     }
 
     #[test]
@@ -429,5 +564,25 @@ mod tests {
 
         let error = HashChainVerificationError::CheckpointMismatch { index: 1 };
         assert!(error.to_string().contains("index 1"));
+
+        let error = HashChainVerificationError::CheckpointNotAttested { index: 2 };
+        assert!(error.to_string().contains("Checkpoint 2"));
+    }
+
+    #[test]
+    fn test_summary_expected_checkpoint_count() {
+        let mut summary = HashChainSummary {
+            final_hash: String::new(),
+            chain_length: 450,
+            checkpoints: vec![],
+            attested_checkpoints: vec![],
+        };
+        assert_eq!(summary.expected_checkpoint_count(150), 3);
+
+        summary.chain_length = 299;
+        assert_eq!(summary.expected_checkpoint_count(150), 1);
+
+        summary.chain_length = 100;
+        assert_eq!(summary.expected_checkpoint_count(150), 0);
     }
 }
@@ -13,17 +13,20 @@
 //! serialized JSON payload (excluding the assertion field itself).
 
 use base64::Engine;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::error::ApiError;
+use crate::types::hash_chain_verification::{HashChainSummary, HashCheckpoint};
+use crate::types::location_privacy::LocationPrivacyPolicy;
 
 // ============================================================================
 // Enums
 // ============================================================================
 
 /// Capture mode discriminator
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CaptureMode {
     /// Full capture with media upload
@@ -55,7 +58,7 @@ impl std::str::FromStr for CaptureMode {
 }
 
 /// Source of depth analysis
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AnalysisSource {
     /// Depth analysis performed on the server
@@ -74,6 +77,41 @@ impl std::fmt::Display for AnalysisSource {
     }
 }
 
+/// Metadata field that can be committed to in privacy mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitmentField {
+    /// Capture location as "latitude,longitude"
+    Location,
+    /// Capture timestamp (RFC3339)
+    Timestamp,
+    /// Device model name
+    DeviceModel,
+}
+
+impl std::fmt::Display for CommitmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommitmentField::Location => write!(f, "location"),
+            CommitmentField::Timestamp => write!(f, "timestamp"),
+            CommitmentField::DeviceModel => write!(f, "device_model"),
+        }
+    }
+}
+
+impl std::str::FromStr for CommitmentField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "location" => Ok(CommitmentField::Location),
+            "timestamp" => Ok(CommitmentField::Timestamp),
+            "device_model" => Ok(CommitmentField::DeviceModel),
+            _ => Err(format!("Invalid commitment field: {s}")),
+        }
+    }
+}
+
 // ============================================================================
 // Request Structures
 // ============================================================================
@@ -128,6 +166,15 @@ pub struct HashOnlyCapturePayload {
     /// Duration in milliseconds for video captures
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration_ms: Option<i32>,
+
+    /// Salted commitments to metadata fields, disclosable later by the owner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_commitments: Option<MetadataCommitments>,
+
+    /// Location privacy policy for the published location (clamped to server
+    /// bounds). When absent, `metadata_flags.location_level` formatting applies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_privacy: Option<LocationPrivacyPolicy>,
 }
 
 impl HashOnlyCapturePayload {
@@ -183,6 +230,9 @@ impl HashOnlyCapturePayload {
                     "video captures require duration_ms".to_string(),
                 ));
             }
+            if let Some(ref chain) = self.hash_chain {
+                chain.validate()?;
+            }
             // Validate frame count and duration are positive
             if let Some(fc) = self.frame_count {
                 if fc <= 0 {
@@ -203,6 +253,16 @@ impl HashOnlyCapturePayload {
         // AC 2.4: depth_analysis must have all required fields (validated by struct)
         self.depth_analysis.validate()?;
 
+        if let Some(ref commitments) = self.metadata_commitments {
+            commitments.validate()?;
+        }
+
+        if let Some(policy) = self.location_privacy {
+            policy
+                .validate()
+                .map_err(|e| ApiError::Validation(format!("location_privacy is invalid: {e}")))?;
+        }
+
         // AC 2.7: captured_at must be valid ISO8601 timestamp
         if chrono::DateTime::parse_from_rfc3339(&self.captured_at).is_err() {
             return Err(ApiError::Validation(format!(
@@ -360,6 +420,54 @@ pub struct MetadataFlags {
     pub device_info_level: String,
 }
 
+/// Salted hash commitments to metadata fields
+///
+/// Each commitment is a SHA-256 hex string computed by the client over a
+/// random salt and the canonical field value (see
+/// `services::privacy::compute_metadata_commitment`). The payload assertion
+/// binds the commitments; the value and salt stay on the device until the
+/// owner chooses to disclose them.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct MetadataCommitments {
+    /// Commitment to "latitude,longitude"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// Commitment to the exact RFC3339 capture timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+
+    /// Commitment to the device model name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_model: Option<String>,
+}
+
+impl MetadataCommitments {
+    /// Validates that every present commitment is a SHA-256 hex string
+    pub fn validate(&self) -> Result<(), ApiError> {
+        for (field, commitment) in self.entries() {
+            if commitment.len() != 64 || !commitment.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ApiError::Validation(format!(
+                    "metadata_commitments.{field} must be 64 hex characters"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the present commitments paired with their field
+    pub fn entries(&self) -> Vec<(CommitmentField, &str)> {
+        [
+            (CommitmentField::Location, &self.location),
+            (CommitmentField::Timestamp, &self.timestamp),
+            (CommitmentField::DeviceModel, &self.device_model),
+        ]
+        .into_iter()
+        .filter_map(|(field, commitment)| commitment.as_deref().map(|c| (field, c)))
+        .collect()
+    }
+}
+
 // ============================================================================
 // Video-specific Types (Story 8-8)
 // ============================================================================
@@ -368,9 +476,11 @@ pub struct MetadataFlags {
 ///
 /// Contains a summary of the frame hash chain for video verification.
 /// Sent with video hash-only captures to prove temporal integrity.
+/// When `checkpoints` are included, each carries its own DCAppAttest
+/// assertion and the summary is verified by `HashChainVerifier::verify_summary`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct VideoHashChainData {
-    /// Final hash of the hash chain (SHA-256 hex)
+    /// Final hash of the hash chain (Base64-encoded SHA-256)
     pub final_hash: String,
 
     /// Number of frame hashes in the chain
@@ -383,12 +493,98 @@ pub struct VideoHashChainData {
     /// Number of checkpoint attestations included
     #[serde(default)]
     pub checkpoint_count: i32,
+
+    /// Checkpoint hashes with their assertions (optional)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checkpoints: Vec<VideoHashCheckpoint>,
 }
 
 fn default_version() -> String {
     "1.0".to_string()
 }
 
+impl VideoHashChainData {
+    /// Validates the hash chain summary fields
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if self.chain_length <= 0 {
+            return Err(ApiError::Validation(
+                "hash_chain.chain_length must be positive".to_string(),
+            ));
+        }
+
+        if self.checkpoints.is_empty() {
+            return Ok(());
+        }
+
+        if self.checkpoint_count != self.checkpoints.len() as i32 {
+            return Err(ApiError::Validation(format!(
+                "hash_chain.checkpoint_count is {} but {} checkpoints were included",
+                self.checkpoint_count,
+                self.checkpoints.len()
+            )));
+        }
+
+        for checkpoint in &self.checkpoints {
+            if checkpoint.assertion.trim().is_empty()
+                || base64::engine::general_purpose::STANDARD
+                    .decode(&checkpoint.assertion)
+                    .is_err()
+            {
+                return Err(ApiError::Validation(format!(
+                    "hash_chain.checkpoints[{}].assertion must be valid Base64",
+                    checkpoint.index
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts to a `HashChainSummary` for no-media verification.
+    ///
+    /// `attested_checkpoints` lists the checkpoint indices whose assertion
+    /// was verified against the device key.
+    pub fn to_summary(&self, attested_checkpoints: Vec<u32>) -> HashChainSummary {
+        HashChainSummary {
+            final_hash: self.final_hash.clone(),
+            chain_length: self.chain_length.max(0) as u32,
+            checkpoints: self
+                .checkpoints
+                .iter()
+                .map(|c| HashCheckpoint {
+                    index: c.index,
+                    frame_number: c.frame_number,
+                    hash: c.hash.clone(),
+                    timestamp: c.timestamp,
+                })
+                .collect(),
+            attested_checkpoints,
+        }
+    }
+}
+
+/// Checkpoint hash with its DCAppAttest assertion (privacy mode).
+///
+/// The assertion's clientDataHash is SHA-256 of the raw checkpoint hash bytes,
+/// matching the iOS `VideoAttestationService` checkpoint attestation.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct VideoHashCheckpoint {
+    /// Checkpoint index (sequential, 0-based)
+    pub index: u32,
+
+    /// Frame number at this checkpoint
+    pub frame_number: u32,
+
+    /// Cumulative hash at this point (Base64)
+    pub hash: String,
+
+    /// Timestamp within video (seconds)
+    pub timestamp: f64,
+
+    /// DCAppAttest assertion over the checkpoint hash (Base64 CBOR)
+    pub assertion: String,
+}
+
 /// Client-computed temporal depth analysis for video privacy mode.
 ///
 /// Contains per-keyframe analyses and aggregate temporal metrics.
@@ -454,6 +650,63 @@ impl ClientTemporalDepthAnalysis {
     }
 }
 
+// ============================================================================
+// Metadata Disclosure Types
+// ============================================================================
+
+/// Request body for disclosing a committed metadata field
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetadataDisclosureRequest {
+    /// Field being disclosed
+    pub field: CommitmentField,
+
+    /// Canonical field value that was committed to
+    pub value: String,
+
+    /// Commitment salt as hex string
+    pub salt: String,
+}
+
+/// Disclosure state of a committed metadata field
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MetadataDisclosure {
+    /// Committed field
+    pub field: CommitmentField,
+
+    /// "disclosed_verified" once the owner revealed a matching value,
+    /// otherwise "committed"
+    pub status: String,
+
+    /// Disclosed value (only present after a verified disclosure)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Human-readable summary for the verification page
+    pub summary: String,
+}
+
+impl MetadataDisclosure {
+    /// Field is committed but its value has not been revealed
+    pub fn committed(field: CommitmentField) -> Self {
+        Self {
+            field,
+            status: "committed".to_string(),
+            value: None,
+            summary: format!("{field} committed, not disclosed"),
+        }
+    }
+
+    /// Field was disclosed and the value matched its commitment
+    pub fn disclosed(field: CommitmentField, value: String) -> Self {
+        Self {
+            field,
+            status: "disclosed_verified".to_string(),
+            value: Some(value),
+            summary: format!("{field} disclosed and matches commitment"),
+        }
+    }
+}
+
 // ============================================================================
 // Response Structures
 // ============================================================================
@@ -509,6 +762,12 @@ pub struct InsertHashOnlyCaptureParams {
 
     /// Optional coarse location from filtered metadata
     pub location_coarse: Option<String>,
+
+    /// Optional reverse-geocoded label for the coarse location
+    pub location_label: Option<String>,
+
+    /// Assertion counter of the verified capture assertion
+    pub assertion_counter: Option<i64>,
 }
 
 // ============================================================================
@@ -551,6 +810,8 @@ mod tests {
             temporal_depth_analysis: None,
             frame_count: None,
             duration_ms: None,
+            metadata_commitments: None,
+            location_privacy: None,
         }
     }
 
@@ -580,10 +841,11 @@ mod tests {
             captured_at: "2025-12-01T10:00:00Z".to_string(),
             assertion: STANDARD.encode("test-assertion"),
             hash_chain: Some(VideoHashChainData {
-                final_hash: "c".repeat(64),
+                final_hash: STANDARD.encode([0xCC; 32]),
                 chain_length: 450,
                 version: "1.0".to_string(),
                 checkpoint_count: 3,
+                checkpoints: vec![],
             }),
             temporal_depth_analysis: Some(ClientTemporalDepthAnalysis {
                 keyframe_analyses: vec![],
@@ -596,6 +858,8 @@ mod tests {
             }),
             frame_count: Some(450),
             duration_ms: Some(15000),
+            metadata_commitments: None,
+            location_privacy: None,
         }
     }
 
@@ -683,6 +947,63 @@ mod tests {
         assert!(err.to_string().contains("duration_ms must be positive"));
     }
 
+    fn test_checkpoint(index: u32) -> VideoHashCheckpoint {
+        VideoHashCheckpoint {
+            index,
+            frame_number: (index + 1) * 150,
+            hash: STANDARD.encode([index as u8; 32]),
+            timestamp: (index + 1) as f64 * 5.0,
+            assertion: STANDARD.encode("checkpoint-assertion"),
+        }
+    }
+
+    #[test]
+    fn test_video_zero_chain_length_fails() {
+        let mut payload = valid_video_payload();
+        payload.hash_chain.as_mut().unwrap().chain_length = 0;
+        let err = payload.validate().unwrap_err();
+        assert!(err.to_string().contains("chain_length must be positive"));
+    }
+
+    #[test]
+    fn test_video_with_checkpoints_passes_validation() {
+        let mut payload = valid_video_payload();
+        payload.hash_chain.as_mut().unwrap().checkpoints = (0..3).map(test_checkpoint).collect();
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn test_video_checkpoint_count_mismatch_fails() {
+        let mut payload = valid_video_payload();
+        payload.hash_chain.as_mut().unwrap().checkpoints = vec![test_checkpoint(0)];
+        let err = payload.validate().unwrap_err();
+        assert!(err.to_string().contains("checkpoint_count is 3"));
+    }
+
+    #[test]
+    fn test_video_checkpoint_invalid_assertion_fails() {
+        let mut payload = valid_video_payload();
+        let chain = payload.hash_chain.as_mut().unwrap();
+        chain.checkpoints = (0..3).map(test_checkpoint).collect();
+        chain.checkpoints[2].assertion = "not base64!!!".to_string();
+        let err = payload.validate().unwrap_err();
+        assert!(err.to_string().contains("checkpoints[2].assertion"));
+    }
+
+    #[test]
+    fn test_video_hash_chain_to_summary() {
+        let mut payload = valid_video_payload();
+        let chain = payload.hash_chain.as_mut().unwrap();
+        chain.checkpoints = (0..3).map(test_checkpoint).collect();
+
+        let summary = chain.to_summary(vec![0, 2]);
+        assert_eq!(summary.chain_length, 450);
+        assert_eq!(summary.final_hash, chain.final_hash);
+        assert_eq!(summary.checkpoints.len(), 3);
+        assert_eq!(summary.checkpoints[1].frame_number, 300);
+        assert_eq!(summary.attested_checkpoints, vec![0, 2]);
+    }
+
     #[test]
     fn test_invalid_timestamp_fails() {
         let mut payload = valid_payload();
@@ -807,4 +1128,68 @@ mod tests {
         assert_eq!(json["location_included"], true);
         assert_eq!(json["location_level"], "coarse");
     }
+
+    #[test]
+    fn test_commitment_field_round_trip() {
+        for field in [
+            CommitmentField::Location,
+            CommitmentField::Timestamp,
+            CommitmentField::DeviceModel,
+        ] {
+            assert_eq!(field.to_string().parse::<CommitmentField>().unwrap(), field);
+        }
+        assert!("altitude".parse::<CommitmentField>().is_err());
+    }
+
+    #[test]
+    fn test_metadata_commitments_validation() {
+        let mut payload = valid_payload();
+        payload.metadata_commitments = Some(MetadataCommitments {
+            location: Some("ab".repeat(32)),
+            timestamp: None,
+            device_model: Some("cd".repeat(32)),
+        });
+        assert!(payload.validate().is_ok());
+
+        payload.metadata_commitments.as_mut().unwrap().timestamp = Some("xyz".to_string());
+        let err = payload.validate().unwrap_err();
+        assert!(err.to_string().contains("metadata_commitments.timestamp"));
+    }
+
+    #[test]
+    fn test_metadata_commitments_entries() {
+        let commitments = MetadataCommitments {
+            location: None,
+            timestamp: Some("ab".repeat(32)),
+            device_model: Some("cd".repeat(32)),
+        };
+        let fields: Vec<_> = commitments.entries().into_iter().map(|(f, _)| f).collect();
+        assert_eq!(
+            fields,
+            vec![CommitmentField::Timestamp, CommitmentField::DeviceModel]
+        );
+    }
+
+    #[test]
+    fn test_payload_without_commitments_deserializes() {
+        let json = serde_json::to_value(valid_payload()).unwrap();
+        assert!(json.get("metadata_commitments").is_none());
+        let parsed: HashOnlyCapturePayload = serde_json::from_value(json).unwrap();
+        assert!(parsed.metadata_commitments.is_none());
+    }
+
+    #[test]
+    fn test_metadata_disclosure_summary() {
+        let disclosed =
+            MetadataDisclosure::disclosed(CommitmentField::Location, "37.7793,-122.4193".into());
+        assert_eq!(disclosed.status, "disclosed_verified");
+        assert_eq!(
+            disclosed.summary,
+            "location disclosed and matches commitment"
+        );
+
+        let committed = MetadataDisclosure::committed(CommitmentField::DeviceModel);
+        assert_eq!(committed.status, "committed");
+        assert!(committed.value.is_none());
+    }
 }
@@ -0,0 +1,197 @@
+//! Location privacy policy types
+//!
+//! Defines the selectable policies used to generalize capture locations
+//! before they appear in evidence or on the public verification page.
+//!
+//! ## Policies
+//! - `rounded`: legacy fixed decimal-place rounding (default: 2 places)
+//! - `geohash`: snap to the center of a geohash cell
+//! - `hex_cell`: snap to the center of an H3-style equal-area hexagon
+//! - `planar_laplace`: geo-indistinguishable jitter with a per-capture seed
+//!
+//! Policies serialize as tagged JSON (`{"method": "geohash", "precision": 5}`)
+//! and parse from the compact `method:param` form used in configuration
+//! (`geohash:5`).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Maximum decimal places for rounded locations
+pub const MAX_ROUNDED_DECIMAL_PLACES: u8 = 6;
+
+/// Maximum geohash precision (characters)
+pub const MAX_GEOHASH_PRECISION: u8 = 12;
+
+/// Maximum H3-style hex resolution
+pub const MAX_HEX_RESOLUTION: u8 = 15;
+
+/// Location privacy policy selected by the device or server default
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum LocationPrivacyPolicy {
+    /// Round coordinates to a fixed number of decimal places
+    Rounded { decimal_places: u8 },
+    /// Snap to the center of a geohash cell (1-12 characters)
+    Geohash { precision: u8 },
+    /// Snap to the center of an equal-area hexagon (resolution 0-15)
+    HexCell { resolution: u8 },
+    /// Planar Laplace jitter with the given expected displacement in meters
+    PlanarLaplace { radius_m: f64 },
+}
+
+impl Default for LocationPrivacyPolicy {
+    /// Legacy behavior: 2 decimal places (~1.1km at the equator)
+    fn default() -> Self {
+        LocationPrivacyPolicy::Rounded { decimal_places: 2 }
+    }
+}
+
+impl LocationPrivacyPolicy {
+    /// Validates the policy parameter range
+    pub fn validate(&self) -> Result<(), String> {
+        match *self {
+            LocationPrivacyPolicy::Rounded { decimal_places }
+                if decimal_places > MAX_ROUNDED_DECIMAL_PLACES =>
+            {
+                Err(format!(
+                    "rounded decimal_places must be at most {MAX_ROUNDED_DECIMAL_PLACES}"
+                ))
+            }
+            LocationPrivacyPolicy::Geohash { precision }
+                if !(1..=MAX_GEOHASH_PRECISION).contains(&precision) =>
+            {
+                Err(format!(
+                    "geohash precision must be between 1 and {MAX_GEOHASH_PRECISION}"
+                ))
+            }
+            LocationPrivacyPolicy::HexCell { resolution } if resolution > MAX_HEX_RESOLUTION => {
+                Err(format!(
+                    "hex_cell resolution must be at most {MAX_HEX_RESOLUTION}"
+                ))
+            }
+            LocationPrivacyPolicy::PlanarLaplace { radius_m }
+                if !radius_m.is_finite() || radius_m <= 0.0 =>
+            {
+                Err("planar_laplace radius_m must be a positive number".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::fmt::Display for LocationPrivacyPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocationPrivacyPolicy::Rounded { decimal_places } => {
+                write!(f, "rounded:{decimal_places}")
+            }
+            LocationPrivacyPolicy::Geohash { precision } => write!(f, "geohash:{precision}"),
+            LocationPrivacyPolicy::HexCell { resolution } => write!(f, "hex_cell:{resolution}"),
+            LocationPrivacyPolicy::PlanarLaplace { radius_m } => {
+                write!(f, "planar_laplace:{radius_m}")
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for LocationPrivacyPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, param) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid location privacy policy: {s}"))?;
+        let invalid = || format!("Invalid location privacy parameter: {s}");
+
+        let policy = match method {
+            "rounded" => LocationPrivacyPolicy::Rounded {
+                decimal_places: param.parse().map_err(|_| invalid())?,
+            },
+            "geohash" => LocationPrivacyPolicy::Geohash {
+                precision: param.parse().map_err(|_| invalid())?,
+            },
+            "hex_cell" => LocationPrivacyPolicy::HexCell {
+                resolution: param.parse().map_err(|_| invalid())?,
+            },
+            "planar_laplace" => LocationPrivacyPolicy::PlanarLaplace {
+                radius_m: param.parse().map_err(|_| invalid())?,
+            },
+            _ => return Err(format!("Invalid location privacy policy: {s}")),
+        };
+
+        policy.validate()?;
+        Ok(policy)
+    }
+}
+
+/// Location privacy policy applied to a capture, recorded in evidence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LocationPrivacyEvidence {
+    /// Policy that was applied
+    pub policy: LocationPrivacyPolicy,
+
+    /// Approximate uncertainty radius of the published location in meters
+    pub radius_m: f64,
+
+    /// Cell identifier for cell-based policies (geohash or hex cell)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell: Option<String>,
+
+    /// Whether the requested policy was adjusted to fit server bounds
+    pub adjusted: bool,
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_default_is_legacy_rounding() {
+        assert_eq!(
+            LocationPrivacyPolicy::default(),
+            LocationPrivacyPolicy::Rounded { decimal_places: 2 }
+        );
+    }
+
+    #[test]
+    fn test_policy_from_str_round_trip() {
+        for s in [
+            "rounded:2",
+            "geohash:5",
+            "hex_cell:7",
+            "planar_laplace:1000",
+        ] {
+            let policy: LocationPrivacyPolicy = s.parse().unwrap();
+            assert_eq!(policy.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_policy_from_str_rejects_invalid() {
+        assert!("geohash".parse::<LocationPrivacyPolicy>().is_err());
+        assert!("geohash:13".parse::<LocationPrivacyPolicy>().is_err());
+        assert!("hex_cell:16".parse::<LocationPrivacyPolicy>().is_err());
+        assert!("planar_laplace:-5"
+            .parse::<LocationPrivacyPolicy>()
+            .is_err());
+        assert!("square:3".parse::<LocationPrivacyPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_policy_serde_tagged() {
+        let json = serde_json::to_value(LocationPrivacyPolicy::Geohash { precision: 5 }).unwrap();
+        assert_eq!(json["method"], "geohash");
+        assert_eq!(json["precision"], 5);
+
+        let parsed: LocationPrivacyPolicy =
+            serde_json::from_str(r#"{"method":"planar_laplace","radius_m":800.0}"#).unwrap();
+        assert_eq!(
+            parsed,
+            LocationPrivacyPolicy::PlanarLaplace { radius_m: 800.0 }
+        );
+    }
+}
@@ -6,14 +6,14 @@ pub mod capture;
 pub mod detection;
 pub mod hash_chain_verification;
 pub mod hash_only;
+pub mod location_privacy;
 pub mod video_capture;
 pub mod video_depth_analysis;
-pub mod video_evidence;
 
 pub use capture::{
-    CaptureDetailsResponse, CaptureLocation, CaptureMetadataPayload, CaptureUploadResponse,
-    DepthMapDimensions, ParsedCaptureUpload, MAX_DEPTH_DIMENSION, MAX_DEPTH_MAP_SIZE,
-    MAX_PHOTO_SIZE,
+    CaptureDeletedResponse, CaptureDetailsResponse, CaptureLocation, CaptureMetadataPayload,
+    CaptureUploadResponse, DepthMapDimensions, ParsedCaptureUpload, MAX_DEPTH_DIMENSION,
+    MAX_DEPTH_MAP_SIZE, MAX_PHOTO_SIZE,
 };
 
 pub use video_capture::{
@@ -29,21 +29,19 @@ pub use video_depth_analysis::{
 };
 
 pub use hash_chain_verification::{
-    HashChainData, HashChainVerification, HashChainVerificationError, HashChainVerifierConfig,
-    VerificationStatus, VideoAttestation,
-};
-
-pub use video_evidence::{
-    DepthAnalysisEvidence, HardwareAttestationEvidence, HashChainEvidence, MetadataEvidence,
-    PartialAttestationInfo, ProcessingInfo, VideoConfidenceLevel, VideoEvidence,
+    HashChainData, HashChainSummary, HashChainVerification, HashChainVerificationError,
+    HashChainVerifierConfig, VerificationStatus, VideoAttestation,
 };
 
 pub use hash_only::{
-    AnalysisSource, CaptureMode, ClientDepthAnalysis, ClientTemporalDepthAnalysis,
+    AnalysisSource, CaptureMode, ClientDepthAnalysis, ClientTemporalDepthAnalysis, CommitmentField,
     FilteredLocation, FilteredMetadata, HashOnlyCapturePayload, HashOnlyCaptureResponse,
-    InsertHashOnlyCaptureParams, MetadataFlags, VideoHashChainData,
+    InsertHashOnlyCaptureParams, MetadataCommitments, MetadataDisclosure,
+    MetadataDisclosureRequest, MetadataFlags, VideoHashChainData, VideoHashCheckpoint,
 };
 
+pub use location_privacy::{LocationPrivacyEvidence, LocationPrivacyPolicy};
+
 pub use detection::{DetectionResults, DetectionSummary};
 
 use chrono::{DateTime, Utc};
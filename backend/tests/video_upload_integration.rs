@@ -4,9 +4,10 @@
 //! Tests multipart upload, rate limiting, and response handling.
 //!
 //! ## Test Requirements
-//! These tests require:
-//! - PostgreSQL database (SQLX_OFFLINE=true for CI)
-//! - S3/LocalStack for storage tests
+//! These tests check response contracts only and need no database or S3.
+//! Upload handler tests against a live database and the in-memory blob
+//! store live in `src/routes/captures_video.rs`; storage key tests live in
+//! `src/services/storage.rs`, as this binary crate cannot be linked here.
 //!
 //! ## Running Tests
 //! ```bash
@@ -138,21 +139,6 @@ fn test_video_size_limits() {
     assert_eq!(max_metadata_size, 102_400);
 }
 
-/// Test S3 key format for video captures
-#[test]
-fn test_video_s3_key_format() {
-    let capture_id = uuid::Uuid::new_v4();
-
-    let video_key = format!("captures/{capture_id}/video.mp4");
-    let depth_key = format!("captures/{capture_id}/depth.gz");
-    let hash_chain_key = format!("captures/{capture_id}/hash_chain.json");
-
-    assert!(video_key.starts_with("captures/"));
-    assert!(video_key.ends_with("/video.mp4"));
-    assert!(depth_key.ends_with("/depth.gz"));
-    assert!(hash_chain_key.ends_with("/hash_chain.json"));
-}
-
 /// Test evidence package initial structure for video
 #[test]
 fn test_video_evidence_initial_structure() {
@@ -23,6 +23,7 @@ export interface QueryLogsResponse {
   logs: DebugLog[];
   count: number;
   has_more: boolean;
+  next_cursor?: string;
 }
 
 export interface DeleteResponse {
@@ -43,6 +44,12 @@ export interface QueryFilters {
   level?: 'debug' | 'info' | 'warn' | 'error';
   event?: string;
   since?: string; // ISO timestamp
+  until?: string; // ISO timestamp
+  device_id?: string;
+  session_id?: string;
+  q?: string; // Full-text search over event and payload
+  payload?: Record<string, unknown>; // JSON containment filter
+  cursor?: string; // next_cursor from a previous page
   limit?: number;
   order?: 'asc' | 'desc';
 }
@@ -83,6 +90,12 @@ export class DebugApiClient {
       if (filters.level) params.set('level', filters.level);
       if (filters.event) params.set('event', filters.event);
       if (filters.since) params.set('since', filters.since);
+      if (filters.until) params.set('until', filters.until);
+      if (filters.device_id) params.set('device_id', filters.device_id);
+      if (filters.session_id) params.set('session_id', filters.session_id);
+      if (filters.q) params.set('q', filters.q);
+      if (filters.payload) params.set('payload', JSON.stringify(filters.payload));
+      if (filters.cursor) params.set('cursor', filters.cursor);
       if (filters.limit) params.set('limit', filters.limit.toString());
       if (filters.order) params.set('order', filters.order);
 